@@ -24,6 +24,7 @@ import type {
   DisputeType,
   VoteChoice,
   AppealVoteChoice,
+  RewardSplit,
 } from "./types";
 import idl from "./idl.json";
 
@@ -150,6 +151,23 @@ export class TribunalCraftClient {
     return { signature, accounts: { protocolConfig } };
   }
 
+  /**
+   * Initialize the global config (one-time, program upgrade authority only)
+   * Needed on a fresh deployment before defender pools can delegate to a stake pool
+   */
+  async initializeGlobalConfig(): Promise<TransactionResult> {
+    const { program } = this.getWalletAndProgram();
+    const [protocolConfig] = this.pda.protocolConfig();
+    const [programData] = this.pda.programData();
+
+    const signature = await program.methods
+      .initializeGlobalConfig()
+      .accountsPartial({ programData })
+      .rpc();
+
+    return { signature, accounts: { protocolConfig } };
+  }
+
   /**
    * Update treasury address (admin only)
    */
@@ -213,6 +231,8 @@ export class TribunalCraftClient {
 
   /**
    * Create a standalone subject with initial stake
+   * categoryId 0 = uncategorized; other ids must be in the config's category registry
+   * Opening a new index page past 0 needs previousIndexPage (the creator's full page before it)
    */
   async createSubject(params: {
     subjectId: PublicKey;
@@ -222,6 +242,13 @@ export class TribunalCraftClient {
     freeCase?: boolean;
     votingPeriod: BN;
     stake: BN;
+    categoryId?: number;
+    categoryRegistry?: PublicKey;
+    rewardSplit?: RewardSplit;
+    rationaleRequired?: boolean;
+    autoRevalidate?: boolean;
+    indexPage?: number;
+    previousIndexPage?: PublicKey;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [subject] = this.pda.subject(params.subjectId);
@@ -229,6 +256,7 @@ export class TribunalCraftClient {
       subject,
       wallet.publicKey
     );
+    const [protocolConfig] = this.pda.protocolConfig();
 
     const signature = await program.methods
       .createSubject(
@@ -238,8 +266,18 @@ export class TribunalCraftClient {
         params.matchMode,
         params.freeCase ?? false,
         params.votingPeriod,
-        params.stake
+        params.stake,
+        params.categoryId ?? 0,
+        params.rewardSplit ?? null,
+        params.rationaleRequired ?? false,
+        params.autoRevalidate ?? false,
+        params.indexPage ?? 0
       )
+      .accountsPartial({
+        protocolConfig,
+        categoryRegistry: params.categoryRegistry ?? null,
+        previousIndexPage: params.previousIndexPage ?? null,
+      })
       .rpc();
 
     return { signature, accounts: { subject, defenderRecord } };
@@ -247,6 +285,8 @@ export class TribunalCraftClient {
 
   /**
    * Create a subject linked to a defender pool
+   * categoryId 0 = uncategorized; other ids must be in the config's category registry
+   * Opening a new index page past 0 needs previousIndexPage (the creator's full page before it)
    */
   async createLinkedSubject(params: {
     defenderPool: PublicKey;
@@ -256,9 +296,17 @@ export class TribunalCraftClient {
     matchMode: boolean;
     freeCase?: boolean;
     votingPeriod: BN;
+    categoryId?: number;
+    categoryRegistry?: PublicKey;
+    rewardSplit?: RewardSplit;
+    rationaleRequired?: boolean;
+    autoRevalidate?: boolean;
+    indexPage?: number;
+    previousIndexPage?: PublicKey;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [subject] = this.pda.subject(params.subjectId);
+    const [protocolConfig] = this.pda.protocolConfig();
 
     const signature = await program.methods
       .createLinkedSubject(
@@ -267,10 +315,18 @@ export class TribunalCraftClient {
         params.maxStake,
         params.matchMode,
         params.freeCase ?? false,
-        params.votingPeriod
+        params.votingPeriod,
+        params.categoryId ?? 0,
+        params.rewardSplit ?? null,
+        params.rationaleRequired ?? false,
+        params.autoRevalidate ?? false,
+        params.indexPage ?? 0
       )
       .accountsPartial({
         defenderPool: params.defenderPool,
+        protocolConfig,
+        categoryRegistry: params.categoryRegistry ?? null,
+        previousIndexPage: params.previousIndexPage ?? null,
       })
       .rpc();
 
@@ -279,17 +335,34 @@ export class TribunalCraftClient {
 
   /**
    * Create a free subject (no stake required)
+   * Opening a new index page past 0 needs previousIndexPage (the creator's full page before it)
    */
   async createFreeSubject(params: {
     subjectId: PublicKey;
     detailsCid: string;
     votingPeriod: BN;
+    categoryId?: number;
+    categoryRegistry?: PublicKey;
+    indexPage?: number;
+    previousIndexPage?: PublicKey;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [subject] = this.pda.subject(params.subjectId);
+    const [protocolConfig] = this.pda.protocolConfig();
 
     const signature = await program.methods
-      .createFreeSubject(params.subjectId, params.detailsCid, params.votingPeriod)
+      .createFreeSubject(
+        params.subjectId,
+        params.detailsCid,
+        params.votingPeriod,
+        params.categoryId ?? 0,
+        params.indexPage ?? 0
+      )
+      .accountsPartial({
+        protocolConfig,
+        categoryRegistry: params.categoryRegistry ?? null,
+        previousIndexPage: params.previousIndexPage ?? null,
+      })
       .rpc();
 
     return { signature, accounts: { subject } };
@@ -430,6 +503,7 @@ export class TribunalCraftClient {
     defenderPool?: PublicKey;
     detailsCid: string;
     bond: BN;
+    disputeType?: DisputeType;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [challengerRecord] = this.pda.challengerRecord(
@@ -438,7 +512,7 @@ export class TribunalCraftClient {
     );
 
     const signature = await program.methods
-      .addToDispute(params.detailsCid, params.bond)
+      .addToDispute(params.detailsCid, params.bond, params.disputeType ?? null)
       .accountsPartial({
         subject: params.subject,
         dispute: params.dispute,
@@ -482,6 +556,7 @@ export class TribunalCraftClient {
     choice: VoteChoice;
     stakeAllocation: BN;
     rationaleCid?: string;
+    rationaleHash?: number[];
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [voteRecord] = this.pda.voteRecord(params.dispute, wallet.publicKey);
@@ -490,7 +565,8 @@ export class TribunalCraftClient {
       .voteOnDispute(
         params.choice,
         params.stakeAllocation,
-        params.rationaleCid ?? ""
+        params.rationaleCid ?? "",
+        params.rationaleHash ?? null
       )
       .accountsPartial({ dispute: params.dispute })
       .rpc();
@@ -506,6 +582,7 @@ export class TribunalCraftClient {
     choice: AppealVoteChoice;
     stakeAllocation: BN;
     rationaleCid?: string;
+    rationaleHash?: number[];
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [voteRecord] = this.pda.voteRecord(params.dispute, wallet.publicKey);
@@ -514,7 +591,8 @@ export class TribunalCraftClient {
       .voteOnAppeal(
         params.choice,
         params.stakeAllocation,
-        params.rationaleCid ?? ""
+        params.rationaleCid ?? "",
+        params.rationaleHash ?? null
       )
       .accountsPartial({ dispute: params.dispute })
       .rpc();
@@ -564,6 +642,11 @@ export class TribunalCraftClient {
       throw new Error("Protocol config not initialized");
     }
 
+    // The fee epoch takes its share of the platform fee until it ends
+    const feeEpochRunning =
+      !configAccount.feeEpoch.equals(PublicKey.default) &&
+      configAccount.feeEpochEndsAt.toNumber() > Date.now() / 1000;
+
     const signature = await program.methods
       .resolveDispute()
       .accountsPartial({
@@ -572,6 +655,7 @@ export class TribunalCraftClient {
         escrow,
         protocolConfig,
         treasury: configAccount.treasury,
+        jurorEpoch: feeEpochRunning ? configAccount.feeEpoch : null,
       })
       .rpc();
 
@@ -605,12 +689,13 @@ export class TribunalCraftClient {
     dispute: PublicKey;
     subject: PublicKey;
     voteRecord: PublicKey;
+    claimToWallet?: boolean;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [escrow] = this.pda.escrow(params.dispute);
 
     const signature = await program.methods
-      .claimJurorReward()
+      .claimJurorReward(params.claimToWallet ?? false)
       .accountsPartial({
         dispute: params.dispute,
         subject: params.subject,
@@ -653,17 +738,24 @@ export class TribunalCraftClient {
     dispute: PublicKey;
     subject: PublicKey;
     defenderRecord: PublicKey;
+    claimToWallet?: boolean;
   }): Promise<TransactionResult> {
     const { wallet, program } = this.getWalletAndProgram();
     const [escrow] = this.pda.escrow(params.dispute);
 
+    // Pool-sourced stake goes back to its pool and frees the delegate that committed it
+    const record = await this.fetchDefenderRecord(params.defenderRecord);
+    const isSet = (key?: PublicKey) => key && !key.equals(PublicKey.default);
+
     const signature = await program.methods
-      .claimDefenderReward()
+      .claimDefenderReward(params.claimToWallet ?? false)
       .accountsPartial({
         dispute: params.dispute,
         subject: params.subject,
         escrow,
         defenderRecord: params.defenderRecord,
+        defenderPool: isSet(record?.payoutPool) ? record!.payoutPool : null,
+        poolDelegate: isSet(record?.poolDelegate) ? record!.poolDelegate : null,
       })
       .rpc();
 
@@ -5,6 +5,11 @@ export const PROGRAM_ID = new PublicKey(
   "4b9qTHcLrkjURroj8X9TCr8xKPNqDT7pNrCqi9brLiZX"
 );
 
+// BPF upgradeable loader (owns the program data account holding the upgrade authority)
+export const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
 // PDA Seeds
 export const PROTOCOL_CONFIG_SEED = Buffer.from("protocol_config");
 export const DEFENDER_POOL_SEED = Buffer.from("defender_pool");
@@ -14,19 +14,19 @@ export type Tribunalcraft = {
   },
   "instructions": [
     {
-      "name": "addJurorStake",
+      "name": "acknowledgeEvidence",
       "docs": [
-        "Add more stake to juror account"
+        "Acknowledge a dispute's evidence by its CID hash (voting opens review_delay later)"
       ],
       "discriminator": [
-        42,
-        194,
-        234,
-        159,
-        186,
-        115,
-        32,
-        169
+        116,
+        116,
+        168,
+        164,
+        204,
+        177,
+        156,
+        150
       ],
       "accounts": [
         {
@@ -39,7 +39,6 @@ export type Tribunalcraft = {
         },
         {
           "name": "jurorAccount",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -59,6 +58,42 @@ export type Tribunalcraft = {
             ]
           }
         },
+        {
+          "name": "dispute"
+        },
+        {
+          "name": "evidenceAck",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101,
+                  95,
+                  97,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -66,29 +101,34 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "evidenceHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "addToDispute",
+      "name": "activateDispute",
       "docs": [
-        "Add to existing dispute (additional challengers)"
+        "Turn a pledge pool that reached its threshold into a live dispute"
       ],
       "discriminator": [
-        110,
-        2,
-        131,
-        29,
-        204,
-        133,
-        164,
-        234
+        83,
+        48,
+        195,
+        98,
+        247,
+        174,
+        57,
+        138
       ],
       "accounts": [
         {
-          "name": "challenger",
+          "name": "activator",
           "writable": true,
           "signer": true
         },
@@ -96,9 +136,43 @@ export type Tribunalcraft = {
           "name": "subject",
           "writable": true,
           "relations": [
-            "dispute"
+            "pledgePool",
+            "poolDefenderRecord"
           ]
         },
+        {
+          "name": "pledgePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
+              }
+            ]
+          }
+        },
         {
           "name": "defenderPool",
           "docs": [
@@ -108,40 +182,46 @@ export type Tribunalcraft = {
           "optional": true
         },
         {
-          "name": "challengerAccount",
+          "name": "poolDefenderRecord",
+          "docs": [
+            "Optional: pool owner's defender record (required when stake is pulled from the pool)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "dispute",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  114
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "challenger"
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
               }
             ]
           }
         },
-        {
-          "name": "dispute",
-          "writable": true
-        },
         {
           "name": "escrow",
           "docs": [
-            "Escrow PDA for this dispute"
+            "Escrow PDA holds all funds for this dispute"
           ],
           "writable": true,
           "pda": {
@@ -165,116 +245,111 @@ export type Tribunalcraft = {
           }
         },
         {
-          "name": "challengerRecord",
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (cooldown escalation)"
+          ]
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  114,
-                  95,
+                  112,
                   114,
-                  101,
+                  111,
+                  116,
+                  111,
                   99,
                   111,
-                  114,
-                  100
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "dispute"
-              },
-              {
-                "kind": "account",
-                "path": "challenger"
               }
             ]
           }
         },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "detailsCid",
-          "type": "string"
-        },
-        {
-          "name": "bond",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "addToStake",
+      "name": "addAllowedCreator",
       "docs": [
-        "Add stake to a standalone subject"
+        "Add a wallet to the subject creator allowlist (admin only)"
       ],
       "discriminator": [
-        227,
-        50,
-        25,
-        66,
-        59,
-        214,
+        31,
+        33,
         58,
-        213
+        50,
+        208,
+        142,
+        154,
+        240
       ],
       "accounts": [
         {
-          "name": "staker",
+          "name": "authority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "subject",
-          "writable": true
+          "name": "config"
         },
         {
-          "name": "defenderRecord",
+          "name": "creatorAllowlist",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  102,
-                  101,
-                  110,
-                  100,
-                  101,
-                  114,
-                  95,
+                  99,
                   114,
                   101,
-                  99,
+                  97,
+                  116,
                   111,
                   114,
-                  100
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "subject"
-              },
-              {
-                "kind": "account",
-                "path": "staker"
+                "path": "config"
               }
             ]
           }
@@ -286,89 +361,127 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "stake",
-          "type": "u64"
+          "name": "creator",
+          "type": "pubkey"
         }
       ]
     },
     {
-      "name": "addToVote",
+      "name": "addCategory",
       "docs": [
-        "Add more stake to an existing vote"
+        "Add a platform-defined category (admin only)"
       ],
       "discriminator": [
-        202,
-        66,
-        94,
-        152,
-        90,
-        103,
-        240,
-        68
+        185,
+        180,
+        5,
+        133,
+        136,
+        4,
+        113,
+        87
       ],
       "accounts": [
         {
-          "name": "juror",
+          "name": "authority",
           "writable": true,
           "signer": true,
           "relations": [
-            "jurorAccount",
-            "voteRecord"
+            "config"
           ]
         },
         {
-          "name": "jurorAccount",
+          "name": "config"
+        },
+        {
+          "name": "categoryRegistry",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  106,
-                  117,
-                  114,
+                  99,
+                  97,
+                  116,
+                  101,
+                  103,
                   111,
-                  114
+                  114,
+                  121,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "subject",
-          "relations": [
-            "dispute"
-          ]
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "categoryId",
+          "type": "u16"
         },
         {
-          "name": "dispute",
+          "name": "metadataCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "addJurorStake",
+      "docs": [
+        "Add more stake to juror account"
+      ],
+      "discriminator": [
+        42,
+        194,
+        234,
+        159,
+        186,
+        115,
+        32,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "juror",
           "writable": true,
+          "signer": true,
           "relations": [
-            "voteRecord"
+            "jurorAccount"
           ]
         },
         {
-          "name": "voteRecord",
+          "name": "jurorAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  106,
+                  117,
+                  114,
                   111,
-                  116,
-                  101
+                  114
                 ]
               },
-              {
-                "kind": "account",
-                "path": "dispute"
-              },
               {
                 "kind": "account",
                 "path": "juror"
@@ -383,35 +496,56 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "additionalStake",
+          "name": "amount",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "claimChallengerReward",
+      "name": "addToDispute",
       "docs": [
-        "Claim challenger reward (if dispute upheld)"
+        "Add to existing dispute (additional challengers, optionally claiming a different type)"
       ],
       "discriminator": [
-        173,
-        143,
-        119,
-        13,
-        142,
-        25,
-        102,
-        36
+        110,
+        2,
+        131,
+        29,
+        204,
+        133,
+        164,
+        234
       ],
       "accounts": [
         {
           "name": "challenger",
           "writable": true,
-          "signer": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
           "relations": [
-            "challengerRecord"
+            "poolDefenderRecord",
+            "dispute"
           ]
         },
+        {
+          "name": "defenderPool",
+          "docs": [
+            "Optional: defender pool if subject is linked"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "poolDefenderRecord",
+          "docs": [
+            "Optional: pool owner's defender record (required when stake is pulled from the pool)"
+          ],
+          "writable": true,
+          "optional": true
+        },
         {
           "name": "challengerAccount",
           "writable": true,
@@ -439,23 +573,14 @@ export type Tribunalcraft = {
             ]
           }
         },
-        {
-          "name": "subject",
-          "relations": [
-            "dispute"
-          ]
-        },
         {
           "name": "dispute",
-          "writable": true,
-          "relations": [
-            "challengerRecord"
-          ]
+          "writable": true
         },
         {
           "name": "escrow",
           "docs": [
-            "Escrow PDA holds all funds"
+            "Escrow PDA for this dispute"
           ],
           "writable": true,
           "pda": {
@@ -480,76 +605,99 @@ export type Tribunalcraft = {
         },
         {
           "name": "challengerRecord",
-          "writable": true
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "challenger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (late join cutoff)"
+          ]
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "bond",
+          "type": "u64"
+        },
+        {
+          "name": "disputeType",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "disputeType"
+              }
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "claimDefenderReward",
+      "name": "addToStake",
       "docs": [
-        "Claim defender reward (if dispute dismissed)"
+        "Add stake to a standalone subject"
       ],
       "discriminator": [
-        189,
-        13,
-        90,
-        154,
-        251,
-        183,
-        166,
-        135
+        227,
+        50,
+        25,
+        66,
+        59,
+        214,
+        58,
+        213
       ],
       "accounts": [
         {
-          "name": "defender",
+          "name": "staker",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "defenderRecord"
-          ]
+          "signer": true
         },
         {
           "name": "subject",
-          "relations": [
-            "dispute",
-            "defenderRecord"
-          ]
-        },
-        {
-          "name": "dispute",
           "writable": true
         },
-        {
-          "name": "escrow",
-          "docs": [
-            "Escrow PDA holds all funds"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  101,
-                  115,
-                  99,
-                  114,
-                  111,
-                  119
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "dispute"
-              }
-            ]
-          }
-        },
         {
           "name": "defenderRecord",
           "writable": true,
@@ -581,7 +729,7 @@ export type Tribunalcraft = {
               },
               {
                 "kind": "account",
-                "path": "defender"
+                "path": "staker"
               }
             ]
           }
@@ -591,22 +739,27 @@ export type Tribunalcraft = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "stake",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "claimJurorReward",
+      "name": "addToVote",
       "docs": [
-        "Claim juror reward for correct vote"
+        "Add more stake to an existing vote"
       ],
       "discriminator": [
-        220,
-        82,
-        126,
-        176,
-        119,
+        202,
+        66,
+        94,
+        152,
+        90,
         103,
-        33,
-        25
+        240,
+        68
       ],
       "accounts": [
         {
@@ -648,6 +801,7 @@ export type Tribunalcraft = {
         },
         {
           "name": "dispute",
+          "writable": true,
           "relations": [
             "voteRecord"
           ]
@@ -655,9 +809,8 @@ export type Tribunalcraft = {
         {
           "name": "escrow",
           "docs": [
-            "Escrow PDA holds all funds"
+            "Escrow totals for the reward estimate"
           ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -680,148 +833,159 @@ export type Tribunalcraft = {
         },
         {
           "name": "voteRecord",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closeEscrow",
-      "docs": [
-        "Close escrow after all claims are complete",
-        "Returns rent to closer, sends any dust to treasury"
-      ],
-      "discriminator": [
-        139,
-        171,
-        94,
-        146,
-        191,
-        91,
-        144,
-        50
-      ],
-      "accounts": [
-        {
-          "name": "closer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "dispute"
-        },
-        {
-          "name": "escrow",
-          "docs": [
-            "Escrow to close - must have all claims complete"
-          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  101,
-                  115,
-                  99,
-                  114,
+                  118,
                   111,
-                  119
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
                 "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
               }
             ]
           }
         },
         {
-          "name": "protocolConfig",
+          "name": "voteStats",
           "docs": [
-            "Protocol config for treasury"
+            "Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)"
           ],
+          "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
+                  118,
                   111,
                   116,
-                  111,
-                  99,
-                  111,
-                  108,
+                  101,
                   95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
               }
             ]
           }
         },
         {
-          "name": "treasury",
-          "writable": true
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (stake-age weighting and allocation caps)"
+          ]
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "additionalStake",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "createFreeSubject",
+      "name": "assignEpochDispute",
       "docs": [
-        "Create a free subject (no stake required, just Subject account)"
+        "Assign a dispute to an enrolled epoch juror (admin only)"
       ],
       "discriminator": [
-        131,
-        154,
-        217,
-        251,
-        107,
-        165,
-        155,
-        197
+        12,
+        23,
+        61,
+        147,
+        132,
+        21,
+        226,
+        243
       ],
       "accounts": [
         {
-          "name": "creator",
+          "name": "authority",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "jurorEpoch"
         },
         {
           "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute"
+        },
+        {
+          "name": "enrollment",
+          "writable": true
+        },
+        {
+          "name": "assignment",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  117,
-                  98,
-                  106,
                   101,
+                  112,
+                  111,
                   99,
+                  104,
+                  95,
+                  97,
+                  115,
+                  115,
+                  105,
+                  103,
+                  110,
+                  109,
+                  101,
+                  110,
                   116
                 ]
               },
               {
-                "kind": "arg",
-                "path": "subjectId"
+                "kind": "account",
+                "path": "jurorEpoch"
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "enrollment.juror",
+                "account": "epochEnrollment"
               }
             ]
           }
@@ -831,95 +995,129 @@ export type Tribunalcraft = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "beginResolution",
+      "docs": [
+        "Start paginated resolution: fix the outcome after voting ends"
+      ],
+      "discriminator": [
+        227,
+        106,
+        197,
+        41,
+        160,
+        120,
+        124,
+        182
+      ],
+      "accounts": [
         {
-          "name": "subjectId",
-          "type": "pubkey"
+          "name": "resolver",
+          "signer": true
         },
         {
-          "name": "detailsCid",
-          "type": "string"
+          "name": "dispute",
+          "writable": true
         },
         {
-          "name": "votingPeriod",
-          "type": "i64"
+          "name": "subject",
+          "docs": [
+            "Subject's tie policy decides tied tallies"
+          ],
+          "relations": [
+            "dispute"
+          ]
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "createLinkedSubject",
+      "name": "boostJurorPool",
       "docs": [
-        "Create a subject linked to a defender pool"
+        "Add a juror incentive to an open dispute (anyone, paid to jurors by voting power)"
       ],
       "discriminator": [
-        42,
-        140,
+        78,
+        132,
+        222,
+        222,
         162,
-        241,
-        23,
-        166,
-        71,
-        51
+        92,
+        236,
+        138
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "booster",
           "writable": true,
-          "signer": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
           "relations": [
-            "defenderPool"
+            "dispute"
           ]
         },
         {
-          "name": "defenderPool",
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds the incentive until claims"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  102,
-                  101,
-                  110,
-                  100,
                   101,
+                  115,
+                  99,
                   114,
-                  95,
-                  112,
                   111,
-                  111,
-                  108
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "dispute"
               }
             ]
           }
         },
         {
-          "name": "subject",
+          "name": "jurorBoost",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
+                  106,
                   117,
+                  114,
+                  111,
+                  114,
+                  95,
                   98,
-                  106,
-                  101,
-                  99,
+                  111,
+                  111,
+                  115,
                   116
                 ]
               },
               {
-                "kind": "arg",
-                "path": "subjectId"
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "booster"
               }
             ]
           }
@@ -931,135 +1129,258 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "subjectId",
-          "type": "pubkey"
-        },
-        {
-          "name": "detailsCid",
-          "type": "string"
-        },
-        {
-          "name": "maxStake",
+          "name": "amount",
           "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "changeVote",
+      "docs": [
+        "Flip an existing vote before voting ends (voting power penalty applies)"
+      ],
+      "discriminator": [
+        184,
+        39,
+        97,
+        137,
+        83,
+        108,
+        185,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
         },
         {
-          "name": "matchMode",
-          "type": "bool"
+          "name": "dispute",
+          "writable": true,
+          "relations": [
+            "voteRecord"
+          ]
         },
         {
-          "name": "freeCase",
-          "type": "bool"
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
         },
         {
-          "name": "votingPeriod",
-          "type": "i64"
+          "name": "voteStats",
+          "docs": [
+            "Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "createPool",
+      "name": "claimChallengerReward",
       "docs": [
-        "Create a defender pool with initial stake"
+        "Claim challenger reward (if dispute upheld)"
       ],
       "discriminator": [
-        233,
-        146,
-        209,
+        173,
+        143,
+        119,
+        13,
         142,
-        207,
-        104,
-        64,
-        188
+        25,
+        102,
+        36
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "challenger",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "challengerRecord"
+          ]
         },
         {
-          "name": "defenderPool",
+          "name": "challengerAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  102,
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
                   101,
                   110,
-                  100,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "relations": [
+            "challengerRecord"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   101,
+                  115,
+                  99,
                   114,
-                  95,
-                  112,
                   111,
-                  111,
-                  108
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "dispute"
               }
             ]
           }
         },
+        {
+          "name": "challengerRecord",
+          "writable": true
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "initialStake",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createSubject",
+      "name": "claimDefenderAndBond",
       "docs": [
-        "Create a standalone subject with initial stake"
+        "Claim defender reward and stake it on another subject you created (plus optional extra stake)"
       ],
       "discriminator": [
-        243,
-        24,
-        101,
-        208,
-        170,
-        5,
-        242,
-        26
+        216,
+        137,
+        93,
+        59,
+        228,
+        181,
+        152,
+        101
       ],
       "accounts": [
         {
-          "name": "creator",
+          "name": "defender",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "defenderRecord"
+          ]
         },
         {
           "name": "subject",
+          "relations": [
+            "dispute",
+            "defenderRecord"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  115,
-                  117,
-                  98,
-                  106,
                   101,
+                  115,
                   99,
-                  116
+                  114,
+                  111,
+                  119
                 ]
               },
               {
-                "kind": "arg",
-                "path": "subjectId"
+                "kind": "account",
+                "path": "dispute"
               }
             ]
           }
@@ -1095,142 +1416,66 @@ export type Tribunalcraft = {
               },
               {
                 "kind": "account",
-                "path": "creator"
+                "path": "defender"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "subjectId",
-          "type": "pubkey"
-        },
-        {
-          "name": "detailsCid",
-          "type": "string"
-        },
-        {
-          "name": "maxStake",
-          "type": "u64"
-        },
-        {
-          "name": "matchMode",
-          "type": "bool"
-        },
-        {
-          "name": "freeCase",
-          "type": "bool"
+          "name": "defenderPool",
+          "docs": [
+            "Optional: the record's payout pool (required when pool-sourced stake returns to the pool)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "votingPeriod",
-          "type": "i64"
+          "name": "poolDelegate",
+          "docs": [
+            "(required when defender_record.delegate_committed > 0; may already be removed)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "stake",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initializeConfig",
-      "docs": [
-        "Initialize protocol config (one-time setup by deployer)"
-      ],
-      "discriminator": [
-        208,
-        127,
-        21,
-        1,
-        194,
-        190,
-        196,
-        70
-      ],
-      "accounts": [
-        {
-          "name": "authority",
-          "writable": true,
-          "signer": true
+          "name": "targetSubject",
+          "docs": [
+            "Subject receiving the payout as stake (must be created by the defender)"
+          ],
+          "writable": true
         },
         {
-          "name": "config",
+          "name": "targetDefenderRecord",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
                   114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108,
                   95,
+                  114,
+                  101,
                   99,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
-              }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "registerJuror",
-      "docs": [
-        "Register as a juror with initial stake"
-      ],
-      "discriminator": [
-        116,
-        81,
-        98,
-        42,
-        220,
-        219,
-        2,
-        141
-      ],
-      "accounts": [
-        {
-          "name": "juror",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "jurorAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  106,
-                  117,
                   114,
-                  111,
-                  114
+                  100
                 ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "targetSubject"
+              },
+              {
+                "kind": "account",
+                "path": "defender"
               }
             ]
           }
@@ -1242,47 +1487,50 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "stakeAmount",
+          "name": "extraStake",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "resolveDispute",
+      "name": "claimDefenderReward",
       "docs": [
-        "Resolve a dispute after voting period ends"
+        "Claim defender reward (if dispute dismissed); claim_to_wallet skips the pool"
       ],
       "discriminator": [
-        231,
-        6,
-        202,
-        6,
-        96,
-        103,
-        12,
-        230
+        189,
+        13,
+        90,
+        154,
+        251,
+        183,
+        166,
+        135
       ],
       "accounts": [
         {
-          "name": "resolver",
+          "name": "defender",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "dispute",
-          "writable": true
+          "signer": true,
+          "relations": [
+            "defenderRecord"
+          ]
         },
         {
           "name": "subject",
-          "writable": true,
           "relations": [
-            "dispute"
+            "dispute",
+            "defenderRecord"
           ]
         },
+        {
+          "name": "dispute",
+          "writable": true
+        },
         {
           "name": "escrow",
           "docs": [
-            "Escrow PDA holds all funds for this dispute"
+            "Escrow PDA holds all funds"
           ],
           "writable": true,
           "pda": {
@@ -1306,285 +1554,224 @@ export type Tribunalcraft = {
           }
         },
         {
-          "name": "protocolConfig",
-          "docs": [
-            "Protocol config for treasury address"
-          ],
+          "name": "defenderRecord",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
                   114,
-                  111,
-                  116,
-                  111,
-                  99,
-                  111,
-                  108,
                   95,
+                  114,
+                  101,
                   99,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  114,
+                  100
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "defender"
               }
             ]
           }
         },
         {
-          "name": "treasury",
-          "writable": true
+          "name": "defenderPool",
+          "docs": [
+            "Optional: the record's payout pool (required when pool-sourced stake returns to the pool)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "poolDelegate",
+          "docs": [
+            "(required when defender_record.delegate_committed > 0; may already be removed)"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "claimToWallet",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "stakePool",
+      "name": "claimEpochReward",
       "docs": [
-        "Add stake to an existing pool"
+        "Claim a juror epoch's base reward into the stake balance after the term"
       ],
       "discriminator": [
-        186,
-        105,
-        178,
-        191,
-        181,
-        236,
-        39,
-        162
+        226,
+        196,
+        170,
+        104,
+        125,
+        21,
+        190,
+        232
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "juror",
           "writable": true,
           "signer": true,
           "relations": [
-            "defenderPool"
+            "jurorAccount",
+            "enrollment"
           ]
         },
         {
-          "name": "defenderPool",
+          "name": "jurorAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  101,
-                  102,
-                  101,
-                  110,
-                  100,
-                  101,
+                  106,
+                  117,
                   114,
-                  95,
-                  112,
                   111,
-                  111,
-                  108
+                  114
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "juror"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "submitAppeal",
-      "docs": [
-        "Submit an appeal against an invalidated subject",
-        "Appeals allow community to reverse previous invalidation decisions",
-        "Appellant stakes (no bond required), voting period is 2x previous"
-      ],
-      "discriminator": [
-        119,
-        88,
-        95,
-        156,
-        252,
-        220,
-        32,
-        91
-      ],
-      "accounts": [
-        {
-          "name": "appellant",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "subject",
+          "name": "jurorEpoch",
           "writable": true
         },
         {
-          "name": "dispute",
+          "name": "enrollment",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  105,
-                  115,
+                  101,
                   112,
-                  117,
-                  116,
-                  101
+                  111,
+                  99,
+                  104,
+                  95,
+                  101,
+                  110,
+                  114,
+                  111,
+                  108,
+                  108,
+                  109,
+                  101,
+                  110,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "subject"
+                "path": "jurorEpoch"
               },
               {
                 "kind": "account",
-                "path": "subject.dispute_count",
-                "account": "subject"
+                "path": "juror"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "disputeType",
-          "type": {
-            "defined": {
-              "name": "disputeType"
-            }
-          }
-        },
-        {
-          "name": "detailsCid",
-          "type": "string"
-        },
-        {
-          "name": "stakeAmount",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "submitDispute",
+      "name": "claimJurorReward",
       "docs": [
-        "Submit a new dispute against a subject (first challenger)"
+        "Claim juror reward for correct vote (to the stake balance, or the wallet with the withdrawal slash burned)"
       ],
       "discriminator": [
-        216,
-        199,
-        236,
-        25,
-        212,
-        79,
-        19,
-        19
+        220,
+        82,
+        126,
+        176,
+        119,
+        103,
+        33,
+        25
       ],
       "accounts": [
         {
-          "name": "challenger",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "subject",
-          "writable": true
-        },
-        {
-          "name": "defenderPool",
-          "docs": [
-            "Optional: defender pool if subject is linked"
-          ],
+          "name": "juror",
           "writable": true,
-          "optional": true
+          "signer": true,
+          "relations": [
+            "jurorAccount",
+            "voteRecord"
+          ]
         },
         {
-          "name": "challengerAccount",
+          "name": "jurorAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
+                  106,
+                  117,
+                  114,
+                  111,
                   114
                 ]
               },
               {
                 "kind": "account",
-                "path": "challenger"
+                "path": "juror"
               }
             ]
           }
         },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
         {
           "name": "dispute",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  100,
-                  105,
-                  115,
-                  112,
-                  117,
-                  116,
-                  101
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "subject"
-              },
-              {
-                "kind": "account",
-                "path": "subject.dispute_count",
-                "account": "subject"
-              }
-            ]
-          }
+          "relations": [
+            "voteRecord"
+          ]
         },
         {
           "name": "escrow",
           "docs": [
-            "Escrow PDA holds all funds for this dispute"
+            "Escrow PDA holds all funds"
           ],
           "writable": true,
           "pda": {
@@ -1608,42 +1795,27 @@ export type Tribunalcraft = {
           }
         },
         {
-          "name": "challengerRecord",
+          "name": "voteRecord",
+          "writable": true
+        },
+        {
+          "name": "beneficiary",
+          "docs": [
+            "less the reputation slash (burned)"
+          ],
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  104,
-                  97,
-                  108,
-                  108,
-                  101,
-                  110,
-                  103,
-                  101,
-                  114,
-                  95,
-                  114,
-                  101,
-                  99,
-                  111,
-                  114,
-                  100
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "dispute"
-              },
-              {
-                "kind": "account",
-                "path": "challenger"
-              }
-            ]
-          }
+          "optional": true
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (rebate tiers)"
+          ]
+        },
+        {
+          "name": "incinerator",
+          "writable": true,
+          "address": "1nc1nerator11111111111111111111111111111111"
         },
         {
           "name": "systemProgram",
@@ -1652,199 +1824,187 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "disputeType",
-          "type": {
-            "defined": {
-              "name": "disputeType"
-            }
-          }
-        },
-        {
-          "name": "detailsCid",
-          "type": "string"
-        },
-        {
-          "name": "bond",
-          "type": "u64"
+          "name": "claimToWallet",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "submitFreeDispute",
+      "name": "claimOppositionReward",
       "docs": [
-        "Submit a free dispute (no bond required, just Dispute account)"
+        "Claim an opposer's payout after a restoration resolves"
       ],
       "discriminator": [
-        140,
-        225,
-        220,
-        24,
-        64,
-        19,
-        209,
-        197
-      ],
-      "accounts": [
+        154,
+        73,
+        93,
+        232,
+        253,
+        61,
+        174,
+        234
+      ],
+      "accounts": [
         {
-          "name": "challenger",
+          "name": "opposer",
           "writable": true,
-          "signer": true
+          "signer": true,
+          "relations": [
+            "oppositionRecord"
+          ]
         },
         {
           "name": "subject",
-          "writable": true
+          "relations": [
+            "dispute"
+          ]
         },
         {
           "name": "dispute",
           "writable": true,
+          "relations": [
+            "oppositionRecord"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds"
+          ],
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
-                  105,
+                  101,
                   115,
-                  112,
-                  117,
-                  116,
-                  101
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "subject"
-              },
-              {
-                "kind": "account",
-                "path": "subject.dispute_count",
-                "account": "subject"
+                "path": "dispute"
               }
             ]
           }
         },
+        {
+          "name": "oppositionRecord",
+          "writable": true
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "disputeType",
-          "type": {
-            "defined": {
-              "name": "disputeType"
-            }
-          }
-        },
-        {
-          "name": "detailsCid",
-          "type": "string"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "unlockJurorStake",
+      "name": "closeEscrow",
       "docs": [
-        "Unlock juror stake after 7-day buffer"
+        "Close escrow after all claims are complete",
+        "Returns rent to closer, sends any dust to treasury"
       ],
       "discriminator": [
-        109,
-        73,
-        56,
-        32,
-        115,
-        125,
-        5,
-        242
+        139,
+        171,
+        94,
+        146,
+        191,
+        91,
+        144,
+        50
       ],
       "accounts": [
         {
-          "name": "juror",
+          "name": "closer",
           "writable": true,
-          "signer": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
           "relations": [
-            "jurorAccount",
-            "voteRecord"
+            "dispute"
           ]
         },
         {
-          "name": "jurorAccount",
+          "name": "dispute"
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow to close - must have all claims complete"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  106,
-                  117,
+                  101,
+                  115,
+                  99,
                   114,
                   111,
-                  114
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "dispute"
               }
             ]
           }
         },
         {
-          "name": "dispute",
-          "relations": [
-            "voteRecord"
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config for treasury"
           ]
         },
         {
-          "name": "voteRecord",
+          "name": "treasury",
           "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "unregisterJuror",
-      "docs": [
-        "Unregister juror and withdraw all available stake"
-      ],
-      "discriminator": [
-        199,
-        200,
-        113,
-        139,
-        182,
-        118,
-        206,
-        124
-      ],
-      "accounts": [
+        },
         {
-          "name": "juror",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "jurorAccount"
-          ]
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
         },
         {
-          "name": "jurorAccount",
+          "name": "expiryQueue",
+          "docs": [
+            "Optional: the subject's config's keeper expiry queue, kept current when passed"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  106,
-                  117,
+                  101,
+                  120,
+                  112,
+                  105,
                   114,
-                  111,
-                  114
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "subject.config",
+                "account": "subject"
               }
             ]
           }
@@ -1857,115 +2017,135 @@ export type Tribunalcraft = {
       "args": []
     },
     {
-      "name": "updateTreasury",
+      "name": "closeEvidenceAck",
       "docs": [
-        "Update treasury address (admin only)"
+        "Close an evidence acknowledgment once the dispute stops taking votes (refunds rent to the juror)"
       ],
       "discriminator": [
-        60,
-        16,
-        243,
-        66,
-        96,
-        59,
-        254,
-        131
+        155,
+        116,
+        84,
+        23,
+        113,
+        168,
+        218,
+        45
       ],
       "accounts": [
         {
-          "name": "authority",
+          "name": "juror",
           "writable": true,
           "signer": true,
           "relations": [
-            "config"
+            "evidenceAck"
           ]
         },
         {
-          "name": "config",
+          "name": "dispute",
+          "relations": [
+            "evidenceAck"
+          ]
+        },
+        {
+          "name": "evidenceAck",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  114,
-                  111,
-                  116,
-                  111,
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
                   99,
-                  111,
-                  108,
+                  101,
                   95,
+                  97,
                   99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
+                  107
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
               }
             ]
           }
         }
       ],
-      "args": [
-        {
-          "name": "newTreasury",
-          "type": "pubkey"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "voteOnAppeal",
+      "name": "closeJurorEpoch",
       "docs": [
-        "Vote on an appeal with stake allocation",
-        "ForRestoration = vote to restore subject to Active",
-        "AgainstRestoration = vote to keep subject Invalidated"
+        "Close a finished juror epoch, sweeping the remainder to the treasury (admin only)"
       ],
       "discriminator": [
-        50,
-        167,
-        54,
-        13,
-        106,
-        100,
-        219,
-        200
+        89,
+        230,
+        53,
+        222,
+        156,
+        160,
+        1,
+        155
       ],
       "accounts": [
         {
-          "name": "juror",
-          "writable": true,
+          "name": "authority",
           "signer": true,
           "relations": [
-            "jurorAccount"
+            "config"
           ]
         },
         {
-          "name": "jurorAccount",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  106,
-                  117,
-                  114,
-                  111,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "juror"
-              }
-            ]
-          }
+          "name": "config"
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "jurorEpoch",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeSubject",
+      "docs": [
+        "Close an invalidated subject with its last dispute and escrow, returning rent"
+      ],
+      "discriminator": [
+        83,
+        14,
+        88,
+        90,
+        59,
+        156,
+        4,
+        140
+      ],
+      "accounts": [
+        {
+          "name": "closer",
+          "writable": true,
+          "signer": true
         },
         {
           "name": "subject",
+          "writable": true,
           "relations": [
             "dispute"
           ]
@@ -1975,122 +2155,214 @@ export type Tribunalcraft = {
           "writable": true
         },
         {
-          "name": "voteRecord",
+          "name": "escrow",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  101,
+                  115,
+                  99,
+                  114,
                   111,
-                  116,
-                  101
+                  119
                 ]
               },
               {
                 "kind": "account",
                 "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectTombstone",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  116,
+                  111,
+                  109,
+                  98,
+                  115,
+                  116,
+                  111,
+                  110,
+                  101
+                ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "subject.subject_id",
+                "account": "subject"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "rentRecipient",
+          "writable": true
+        },
         {
-          "name": "choice",
-          "type": {
-            "defined": {
-              "name": "appealVoteChoice"
-            }
-          }
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config for treasury address"
+          ]
         },
         {
-          "name": "stakeAllocation",
-          "type": "u64"
+          "name": "treasury",
+          "writable": true
         },
         {
-          "name": "rationaleCid",
-          "type": "string"
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "voteOnDispute",
+      "name": "convertPledge",
       "docs": [
-        "Vote on a dispute with stake allocation"
+        "Convert a pledge into a challenger record on the activated dispute"
       ],
       "discriminator": [
-        7,
-        213,
-        96,
-        171,
-        252,
-        59,
-        55,
-        23
+        199,
+        109,
+        53,
+        179,
+        88,
+        115,
+        119,
+        70
       ],
       "accounts": [
         {
-          "name": "juror",
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "pledger",
           "writable": true,
-          "signer": true,
           "relations": [
-            "jurorAccount"
+            "pledge"
           ]
         },
         {
-          "name": "jurorAccount",
+          "name": "opener",
+          "writable": true
+        },
+        {
+          "name": "pledgePool",
+          "writable": true
+        },
+        {
+          "name": "pledge",
+          "writable": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA (challenger count corrected when merging into an existing record)"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  106,
-                  117,
+                  101,
+                  115,
+                  99,
                   114,
                   111,
-                  114
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "dispute"
               }
             ]
           }
         },
         {
-          "name": "subject",
-          "relations": [
-            "dispute"
-          ]
-        },
-        {
-          "name": "dispute",
-          "writable": true
+          "name": "challengerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pledger"
+              }
+            ]
+          }
         },
         {
-          "name": "voteRecord",
+          "name": "challengerRecord",
+          "docs": [
+            "Pledger's record on the dispute; may already exist if they also added bond from their wallet"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  118,
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
                   111,
-                  116,
-                  101
+                  114,
+                  100
                 ]
               },
               {
@@ -2099,7 +2371,7 @@ export type Tribunalcraft = {
               },
               {
                 "kind": "account",
-                "path": "juror"
+                "path": "pledger"
               }
             ]
           }
@@ -2109,71 +2381,99 @@ export type Tribunalcraft = {
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "choice",
-          "type": {
-            "defined": {
-              "name": "voteChoice"
-            }
-          }
-        },
-        {
-          "name": "stakeAllocation",
-          "type": "u64"
-        },
-        {
-          "name": "rationaleCid",
-          "type": "string"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "withdrawJurorStake",
+      "name": "createDisputesBatch",
       "docs": [
-        "Withdraw available stake (with reputation-based slashing)"
+        "Open disputes on several subjects for one incident (shared incident_id)",
+        "remaining_accounts: 6 per subject, see instructions::create_disputes_batch"
       ],
       "discriminator": [
-        178,
-        43,
-        144,
-        250,
-        188,
-        199,
-        135,
-        133
+        164,
+        231,
+        59,
+        0,
+        244,
+        201,
+        171,
+        190
       ],
       "accounts": [
         {
-          "name": "juror",
+          "name": "challenger",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "jurorAccount"
-          ]
+          "signer": true
         },
         {
-          "name": "jurorAccount",
+          "name": "challengerAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  106,
-                  117,
-                  114,
-                  111,
-                  114
-                ]
-              },
-              {
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
                 "kind": "account",
-                "path": "juror"
+                "path": "challenger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config every subject in the batch must belong to"
+          ]
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
               }
             ]
           }
         },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -2181,462 +2481,11437 @@ export type Tribunalcraft = {
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "incidentId",
+          "type": "pubkey"
+        },
+        {
+          "name": "disputeType",
+          "type": {
+            "defined": {
+              "name": "disputeType"
+            }
+          }
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "bonds",
+          "type": {
+            "vec": "u64"
+          }
         }
       ]
     },
     {
-      "name": "withdrawPool",
+      "name": "createFreeSubject",
       "docs": [
-        "Withdraw available stake from pool"
+        "Create a free subject (no stake required, just Subject account)"
       ],
       "discriminator": [
-        190,
-        43,
-        148,
-        248,
-        68,
-        5,
-        215,
-        136
+        131,
+        154,
+        217,
+        251,
+        107,
+        165,
+        155,
+        197
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "creator",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "defenderPool"
-          ]
+          "signer": true
         },
         {
-          "name": "defenderPool",
+          "name": "subject",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  100,
+                  115,
+                  117,
+                  98,
+                  106,
                   101,
-                  102,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "categoryRegistry",
+          "docs": [
+            "Optional: category registry (required when category_id != 0)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
                   101,
-                  110,
-                  100,
+                  103,
+                  111,
+                  114,
+                  121,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol instance the subject is created under (creator gate, categories, economics)"
+          ]
+        },
+        {
+          "name": "creatorAllowlist",
+          "docs": [
+            "Optional: creator allowlist (required when the gate is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
                   101,
+                  97,
+                  116,
+                  111,
                   114,
                   95,
-                  112,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectTombstone",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  116,
                   111,
+                  109,
+                  98,
+                  115,
+                  116,
                   111,
-                  108
+                  110,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectIndex",
+          "docs": [
+            "Creator's subject index page (first page that is not full)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  105,
+                  110,
+                  100,
+                  101,
+                  120
                 ]
               },
               {
                 "kind": "account",
-                "path": "owner"
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "indexPage"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "previousIndexPage",
+          "docs": [
+            "Creator's previous index page (required when index_page opens a new page past 0)"
+          ],
+          "optional": true
+        },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "challengerAccount",
-      "discriminator": [
-        63,
-        207,
-        170,
-        69,
-        229,
-        2,
-        163,
-        201
-      ]
-    },
-    {
-      "name": "challengerRecord",
-      "discriminator": [
-        8,
-        80,
-        134,
-        108,
-        192,
-        142,
-        121,
-        14
-      ]
-    },
-    {
-      "name": "defenderPool",
-      "discriminator": [
-        227,
-        100,
-        92,
-        157,
-        203,
-        252,
-        63,
-        118
-      ]
-    },
-    {
-      "name": "defenderRecord",
-      "discriminator": [
-        14,
-        3,
-        219,
-        215,
-        38,
-        254,
-        254,
-        92
-      ]
-    },
-    {
-      "name": "dispute",
-      "discriminator": [
-        36,
-        49,
-        241,
-        67,
-        40,
-        36,
-        241,
-        74
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "subjectId",
+          "type": "pubkey"
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        },
+        {
+          "name": "categoryId",
+          "type": "u16"
+        },
+        {
+          "name": "indexPage",
+          "type": "u32"
+        }
       ]
     },
     {
-      "name": "disputeEscrow",
+      "name": "createLinkedSubject",
+      "docs": [
+        "Create a subject linked to a defender pool"
+      ],
       "discriminator": [
-        4,
-        51,
-        35,
-        202,
-        114,
+        42,
         140,
-        92,
-        65
-      ]
-    },
-    {
-      "name": "jurorAccount",
-      "discriminator": [
-        138,
-        222,
-        50,
-        194,
-        222,
-        131,
-        255,
-        186
-      ]
-    },
-    {
-      "name": "protocolConfig",
-      "discriminator": [
-        207,
-        91,
-        250,
-        28,
-        152,
-        179,
-        215,
-        209
-      ]
-    },
-    {
-      "name": "subject",
-      "discriminator": [
-        52,
-        161,
-        41,
-        165,
-        202,
-        238,
-        138,
-        166
-      ]
-    },
-    {
-      "name": "voteRecord",
-      "discriminator": [
-        112,
-        9,
-        123,
-        165,
-        234,
-        9,
-        157,
-        167
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "unauthorized",
-      "msg": "unauthorized"
-    },
-    {
-      "code": 6001,
-      "name": "invalidConfig",
-      "msg": "Invalid configuration parameter"
-    },
-    {
-      "code": 6002,
-      "name": "stakeBelowMinimum",
-      "msg": "Stake amount below minimum"
+        162,
+        241,
+        23,
+        166,
+        71,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord",
+          "docs": [
+            "Pool owner's record - receives pool_stake when disputes pull from the pool"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "categoryRegistry",
+          "docs": [
+            "Optional: category registry (required when category_id != 0)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  101,
+                  103,
+                  111,
+                  114,
+                  121,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol instance the subject is created under (creator gate, categories, economics)"
+          ]
+        },
+        {
+          "name": "creatorAllowlist",
+          "docs": [
+            "Optional: creator allowlist (required when the gate is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectTombstone",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  116,
+                  111,
+                  109,
+                  98,
+                  115,
+                  116,
+                  111,
+                  110,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectIndex",
+          "docs": [
+            "Creator's subject index page (first page that is not full)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  105,
+                  110,
+                  100,
+                  101,
+                  120
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "arg",
+                "path": "indexPage"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousIndexPage",
+          "docs": [
+            "Creator's previous index page (required when index_page opens a new page past 0)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "subjectId",
+          "type": "pubkey"
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "maxStake",
+          "type": "u64"
+        },
+        {
+          "name": "matchMode",
+          "type": "bool"
+        },
+        {
+          "name": "freeCase",
+          "type": "bool"
+        },
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        },
+        {
+          "name": "categoryId",
+          "type": "u16"
+        },
+        {
+          "name": "rewardSplit",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "rewardSplit"
+              }
+            }
+          }
+        },
+        {
+          "name": "rationaleRequired",
+          "type": "bool"
+        },
+        {
+          "name": "autoRevalidate",
+          "type": "bool"
+        },
+        {
+          "name": "indexPage",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "createPool",
+      "docs": [
+        "Create a defender pool with initial stake"
+      ],
+      "discriminator": [
+        233,
+        146,
+        209,
+        142,
+        207,
+        104,
+        64,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "initialStake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createSubject",
+      "docs": [
+        "Create a standalone subject with initial stake"
+      ],
+      "discriminator": [
+        243,
+        24,
+        101,
+        208,
+        170,
+        5,
+        242,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "categoryRegistry",
+          "docs": [
+            "Optional: category registry (required when category_id != 0)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  101,
+                  103,
+                  111,
+                  114,
+                  121,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol instance the subject is created under (creator gate, categories, economics)"
+          ]
+        },
+        {
+          "name": "creatorAllowlist",
+          "docs": [
+            "Optional: creator allowlist (required when the gate is enabled)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "protocolConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectTombstone",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  116,
+                  111,
+                  109,
+                  98,
+                  115,
+                  116,
+                  111,
+                  110,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "subjectId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subjectIndex",
+          "docs": [
+            "Creator's subject index page (first page that is not full)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  117,
+                  98,
+                  106,
+                  101,
+                  99,
+                  116,
+                  95,
+                  105,
+                  110,
+                  100,
+                  101,
+                  120
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "arg",
+                "path": "indexPage"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousIndexPage",
+          "docs": [
+            "Creator's previous index page (required when index_page opens a new page past 0)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "subjectId",
+          "type": "pubkey"
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "maxStake",
+          "type": "u64"
+        },
+        {
+          "name": "matchMode",
+          "type": "bool"
+        },
+        {
+          "name": "freeCase",
+          "type": "bool"
+        },
+        {
+          "name": "votingPeriod",
+          "type": "i64"
+        },
+        {
+          "name": "stake",
+          "type": "u64"
+        },
+        {
+          "name": "categoryId",
+          "type": "u16"
+        },
+        {
+          "name": "rewardSplit",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "rewardSplit"
+              }
+            }
+          }
+        },
+        {
+          "name": "rationaleRequired",
+          "type": "bool"
+        },
+        {
+          "name": "autoRevalidate",
+          "type": "bool"
+        },
+        {
+          "name": "indexPage",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "defendFromPool",
+      "docs": [
+        "Back a disputed subject from any defender pool (pool owner or delegate)"
+      ],
+      "discriminator": [
+        62,
+        221,
+        93,
+        164,
+        249,
+        104,
+        253,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Pool owner or delegate"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defender_pool.owner",
+                "account": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolDelegate",
+          "docs": [
+            "Optional: authority's delegate grant (required unless the authority is the pool owner)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord",
+          "docs": [
+            "Pool owner's record - credited with the pool stake"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "defender_pool.owner",
+                "account": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (late join cutoff)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "depositPoolYield",
+      "docs": [
+        "Delegate available pool stake to the config's approved stake pool (owner only)"
+      ],
+      "discriminator": [
+        224,
+        7,
+        151,
+        194,
+        167,
+        224,
+        61,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Global config (deployer-owned, see initialize_global_config): pools are shared across",
+            "instances, so a per-authority config anyone can create cannot approve a stake pool"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "yieldVault",
+          "docs": [
+            "Signs the stake pool deposit and owns the pool tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakePoolCpi",
+          "accounts": [
+            {
+              "name": "stakePool",
+              "writable": true
+            },
+            {
+              "name": "withdrawAuthority"
+            },
+            {
+              "name": "reserveStake",
+              "writable": true
+            },
+            {
+              "name": "managerFeeAccount",
+              "writable": true
+            },
+            {
+              "name": "poolMint",
+              "writable": true
+            },
+            {
+              "name": "poolTokens",
+              "writable": true
+            },
+            {
+              "name": "stakePoolProgram",
+              "address": "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+            },
+            {
+              "name": "tokenProgram",
+              "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            }
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "enrollJurorEpoch",
+      "docs": [
+        "Commit stake to a juror epoch before it starts"
+      ],
+      "discriminator": [
+        255,
+        228,
+        237,
+        188,
+        40,
+        21,
+        235,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jurorEpoch",
+          "writable": true
+        },
+        {
+          "name": "enrollment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  112,
+                  111,
+                  99,
+                  104,
+                  95,
+                  101,
+                  110,
+                  114,
+                  111,
+                  108,
+                  108,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "jurorEpoch"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "stake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeResolution",
+      "docs": [
+        "Finish paginated resolution: collect fees and update subject status"
+      ],
+      "discriminator": [
+        191,
+        74,
+        94,
+        214,
+        45,
+        150,
+        152,
+        125
+      ],
+      "accounts": [
+        {
+          "name": "resolver",
+          "docs": [
+            "Receives the resolver fee"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds for this dispute"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config for treasury address"
+          ]
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "expiryQueue",
+          "docs": [
+            "Optional: the subject's config's keeper expiry queue, kept current when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject.config",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jurorEpoch",
+          "docs": [
+            "Optional: the config's fee epoch, required while it runs and epoch_fee_bps is set"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "freezeSubject",
+      "docs": [
+        "Freeze a subject for a legal hold: no new disputes, stakes, bonds or restorations",
+        "(authority until lifted, or the creator for up to MAX_CREATOR_FREEZE)"
+      ],
+      "discriminator": [
+        45,
+        113,
+        144,
+        105,
+        199,
+        192,
+        170,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundJurorEpoch",
+      "docs": [
+        "Add to a juror epoch's base reward (anyone)"
+      ],
+      "discriminator": [
+        40,
+        108,
+        73,
+        86,
+        158,
+        149,
+        132,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "funder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "jurorEpoch",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initVoteStats",
+      "docs": [
+        "Create a dispute's vote statistics PDA (before the first vote)"
+      ],
+      "discriminator": [
+        108,
+        193,
+        62,
+        156,
+        244,
+        233,
+        77,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "voteStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeConfig",
+      "docs": [
+        "Initialize a protocol config instance (one per authority)"
+      ],
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeExpiryQueue",
+      "docs": [
+        "Initialize a config's keeper expiry queue (one-time, config authority)"
+      ],
+      "discriminator": [
+        162,
+        52,
+        134,
+        236,
+        35,
+        206,
+        149,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "expiryQueue",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeGlobalConfig",
+      "docs": [
+        "Initialize the global config (one-time, program upgrade authority only)"
+      ],
+      "discriminator": [
+        113,
+        216,
+        122,
+        131,
+        225,
+        209,
+        22,
+        55
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "4b9qTHcLrkjURroj8X9TCr8xKPNqDT7pNrCqi9brLiZX"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeStats",
+      "docs": [
+        "Initialize protocol statistics account (one-time, program upgrade authority only)"
+      ],
+      "discriminator": [
+        144,
+        201,
+        117,
+        76,
+        127,
+        118,
+        176,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "protocolStats",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "4b9qTHcLrkjURroj8X9TCr8xKPNqDT7pNrCqi9brLiZX"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeTreasury",
+      "docs": [
+        "Create the config's accounting treasury PDA and route fees to it (admin only)"
+      ],
+      "discriminator": [
+        124,
+        186,
+        211,
+        195,
+        85,
+        165,
+        129,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "linkDefenderPool",
+      "docs": [
+        "Link a standalone subject to any defender pool (creator and pool owner or delegate sign)"
+      ],
+      "discriminator": [
+        130,
+        67,
+        41,
+        182,
+        65,
+        250,
+        61,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "authority",
+          "docs": [
+            "Pool owner or delegate consenting to back the subject"
+          ],
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defender_pool.owner",
+                "account": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolDelegate",
+          "docs": [
+            "Optional: authority's delegate grant (required unless the authority is the pool owner)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord",
+          "docs": [
+            "Pool owner's record - receives pool_stake when disputes pull from the pool",
+            "May already exist if the owner staked on the subject directly"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "defender_pool.owner",
+                "account": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAccount",
+      "docs": [
+        "Upgrade a program account to the current layout version (permissionless)"
+      ],
+      "discriminator": [
+        177,
+        228,
+        60,
+        125,
+        13,
+        116,
+        44,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "mintResolutionAttestation",
+      "docs": [
+        "Write an immutable outcome attestation for a resolved dispute (permissionless)"
+      ],
+      "discriminator": [
+        124,
+        148,
+        145,
+        96,
+        76,
+        230,
+        238,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute"
+        },
+        {
+          "name": "attestation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "notifyDependentEscrow",
+      "docs": [
+        "Deliver the attested outcome to the linked escrow's on_tribunal_resolution (permissionless)"
+      ],
+      "discriminator": [
+        184,
+        7,
+        217,
+        112,
+        114,
+        82,
+        66,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "dispute",
+          "writable": true,
+          "relations": [
+            "attestation"
+          ]
+        },
+        {
+          "name": "attestation",
+          "docs": [
+            "Attestation signs the callback to prove the outcome came from this program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dependentProgram"
+        },
+        {
+          "name": "dependentAccount",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "openJurorEpoch",
+      "docs": [
+        "Open a juror epoch with an enrollment window and availability penalty (admin only)"
+      ],
+      "discriminator": [
+        204,
+        72,
+        136,
+        140,
+        77,
+        36,
+        96,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "jurorEpoch",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114,
+                  95,
+                  101,
+                  112,
+                  111,
+                  99,
+                  104
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              },
+              {
+                "kind": "arg",
+                "path": "index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "index",
+          "type": "u32"
+        },
+        {
+          "name": "startsAt",
+          "type": "i64"
+        },
+        {
+          "name": "endsAt",
+          "type": "i64"
+        },
+        {
+          "name": "missPenaltyBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "opposeRestore",
+      "docs": [
+        "Stake against a pending restoration (defender side of the appeal)"
+      ],
+      "discriminator": [
+        36,
+        24,
+        24,
+        112,
+        199,
+        181,
+        232,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "opposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA for this appeal"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oppositionRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  112,
+                  112,
+                  111,
+                  115,
+                  105,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "opposer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (late join cutoff)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "stake",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "pledgeChallenge",
+      "docs": [
+        "Pledge a bond toward a future dispute (opens the pledge pool on first pledge)"
+      ],
+      "discriminator": [
+        145,
+        32,
+        240,
+        60,
+        49,
+        164,
+        110,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "pledger",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject"
+        },
+        {
+          "name": "pledgePool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pledge",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  101,
+                  100,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pledgePool"
+              },
+              {
+                "kind": "account",
+                "path": "pledger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (CID validation)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "disputeType",
+          "type": {
+            "defined": {
+              "name": "disputeType"
+            }
+          }
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "previewClaimChallenger",
+      "docs": [
+        "Preview a challenger's claimable payout (simulate; no state change)"
+      ],
+      "discriminator": [
+        46,
+        235,
+        55,
+        249,
+        46,
+        150,
+        99,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "relations": [
+            "challengerRecord"
+          ]
+        },
+        {
+          "name": "escrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengerRecord"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "claimPayout"
+        }
+      }
+    },
+    {
+      "name": "previewClaimDefender",
+      "docs": [
+        "Preview a defender's claimable payout (simulate; no state change)"
+      ],
+      "discriminator": [
+        178,
+        168,
+        2,
+        217,
+        251,
+        30,
+        85,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "subject",
+          "relations": [
+            "dispute",
+            "defenderRecord"
+          ]
+        },
+        {
+          "name": "dispute"
+        },
+        {
+          "name": "escrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "claimPayout"
+        }
+      }
+    },
+    {
+      "name": "previewClaimJuror",
+      "docs": [
+        "Preview a juror's claimable reward (simulate; no state change)"
+      ],
+      "discriminator": [
+        4,
+        94,
+        129,
+        202,
+        160,
+        29,
+        65,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "escrow",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord"
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "previewOutcome",
+      "docs": [
+        "Preview the outcome current tallies resolve to (simulate; no state change)"
+      ],
+      "discriminator": [
+        41,
+        61,
+        28,
+        161,
+        42,
+        94,
+        177,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute"
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "resolutionOutcome"
+        }
+      }
+    },
+    {
+      "name": "processResolutionChunk",
+      "docs": [
+        "Process a chunk of [vote_record, juror_account] pairs via remaining_accounts"
+      ],
+      "discriminator": [
+        34,
+        145,
+        173,
+        109,
+        22,
+        3,
+        128,
+        27
+      ],
+      "accounts": [
+        {
+          "name": "resolver",
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pruneExpiryQueue",
+      "docs": [
+        "Drop expiry queue entries for escrows closed without the queue (anyone)"
+      ],
+      "discriminator": [
+        40,
+        99,
+        253,
+        250,
+        57,
+        34,
+        201,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "signer": true
+        },
+        {
+          "name": "expiryQueue",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "expiry_queue.config",
+                "account": "expiryQueue"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "recordEpochService",
+      "docs": [
+        "Mark an epoch assignment served once the juror has voted (permissionless)"
+      ],
+      "discriminator": [
+        43,
+        92,
+        105,
+        230,
+        168,
+        115,
+        132,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "assignment",
+          "writable": true
+        },
+        {
+          "name": "enrollment",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  112,
+                  111,
+                  99,
+                  104,
+                  95,
+                  101,
+                  110,
+                  114,
+                  111,
+                  108,
+                  108,
+                  109,
+                  101,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment.epoch",
+                "account": "epochAssignment"
+              },
+              {
+                "kind": "account",
+                "path": "assignment.juror",
+                "account": "epochAssignment"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "assignment.dispute",
+                "account": "epochAssignment"
+              },
+              {
+                "kind": "account",
+                "path": "assignment.juror",
+                "account": "epochAssignment"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundJurorBoost",
+      "docs": [
+        "Refund a juror incentive after the dispute resolved with no votes"
+      ],
+      "discriminator": [
+        131,
+        186,
+        103,
+        18,
+        83,
+        71,
+        184,
+        194
+      ],
+      "accounts": [
+        {
+          "name": "booster",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorBoost"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "relations": [
+            "jurorBoost"
+          ]
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jurorBoost",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refundPledge",
+      "docs": [
+        "Refund a pledge to its pledger after the pool expires or loses its dispute slot (anyone)"
+      ],
+      "discriminator": [
+        94,
+        154,
+        65,
+        172,
+        38,
+        217,
+        41,
+        206
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "signer": true
+        },
+        {
+          "name": "pledger",
+          "writable": true,
+          "relations": [
+            "pledge"
+          ]
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "pledgePool"
+          ]
+        },
+        {
+          "name": "opener",
+          "writable": true
+        },
+        {
+          "name": "pledgePool",
+          "writable": true
+        },
+        {
+          "name": "pledge",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerDependentEscrow",
+      "docs": [
+        "Link an external escrow account to an open dispute (CPI from the escrow program, creator co-signs)"
+      ],
+      "discriminator": [
+        14,
+        132,
+        112,
+        37,
+        151,
+        85,
+        241,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "docs": [
+            "Subject creator consenting to the link"
+          ],
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dependentProgram"
+        },
+        {
+          "name": "dependentAccount",
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerJuror",
+      "docs": [
+        "Register as a juror with initial stake"
+      ],
+      "discriminator": [
+        116,
+        81,
+        98,
+        42,
+        220,
+        219,
+        2,
+        141
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "stakeAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "removeAllowedCreator",
+      "docs": [
+        "Remove a wallet from the subject creator allowlist (admin only)"
+      ],
+      "discriminator": [
+        162,
+        219,
+        44,
+        169,
+        252,
+        93,
+        25,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "creatorAllowlist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "creator",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "removeCategory",
+      "docs": [
+        "Remove a category from the registry (admin only)"
+      ],
+      "discriminator": [
+        230,
+        240,
+        92,
+        199,
+        206,
+        194,
+        137,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config"
+        },
+        {
+          "name": "categoryRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  116,
+                  101,
+                  103,
+                  111,
+                  114,
+                  121,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "categoryId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "removePoolDelegate",
+      "docs": [
+        "Revoke a pool delegate (owner only)"
+      ],
+      "discriminator": [
+        60,
+        48,
+        116,
+        193,
+        185,
+        224,
+        40,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              },
+              {
+                "kind": "account",
+                "path": "pool_delegate.delegate",
+                "account": "poolDelegate"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "requireEvidenceReview",
+      "docs": [
+        "Require jurors to acknowledge the evidence before voting (dispute opener, before any votes)"
+      ],
+      "discriminator": [
+        133,
+        166,
+        27,
+        203,
+        93,
+        13,
+        150,
+        5
+      ],
+      "accounts": [
+        {
+          "name": "challenger",
+          "signer": true,
+          "relations": [
+            "challengerRecord"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "relations": [
+            "challengerRecord"
+          ]
+        },
+        {
+          "name": "challengerRecord",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "challenger"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "reviewDelay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "docs": [
+        "Resolve a dispute after voting period ends",
+        "A creator resolving a successful appeal can pass their pool to re-bond the subject from it"
+      ],
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "resolver",
+          "docs": [
+            "Receives the resolver fee"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds for this dispute"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config for treasury address"
+          ]
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "expiryQueue",
+          "docs": [
+            "Optional: the subject's config's keeper expiry queue, kept current when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject.config",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "jurorEpoch",
+          "docs": [
+            "Optional: the config's fee epoch, required while it runs and epoch_fee_bps is set"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "revealRationale",
+      "docs": [
+        "Reveal the rationale CID committed with a vote (must match the keccak hash)"
+      ],
+      "discriminator": [
+        179,
+        251,
+        163,
+        79,
+        209,
+        39,
+        141,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "writable": true
+        },
+        {
+          "name": "dispute",
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (CID validation)"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "rationaleCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "reviveSubject",
+      "docs": [
+        "Reactivate an opted-in dormant subject by bonding from its defender pool (anyone, pays a small fee)"
+      ],
+      "discriminator": [
+        112,
+        252,
+        159,
+        109,
+        55,
+        201,
+        68,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "defenderPool",
+          "writable": true
+        },
+        {
+          "name": "defenderRecord",
+          "docs": [
+            "Pool owner's stake record on the subject (receives the revival bond)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "defender_pool.owner",
+                "account": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setAutoRevalidate",
+      "docs": [
+        "Choose whether a defender win returns the subject to Active or Dormant (creator only)"
+      ],
+      "discriminator": [
+        125,
+        136,
+        94,
+        156,
+        148,
+        196,
+        242,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "autoRevalidate",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setBeneficiary",
+      "docs": [
+        "Set the wallet that can receive claimed juror rewards"
+      ],
+      "discriminator": [
+        10,
+        81,
+        219,
+        4,
+        237,
+        149,
+        57,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "beneficiary",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "setCidValidation",
+      "docs": [
+        "Enable or disable CID format validation for details and rationale CIDs (admin only)"
+      ],
+      "discriminator": [
+        3,
+        206,
+        91,
+        154,
+        92,
+        132,
+        183,
+        213
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setFeeEpoch",
+      "docs": [
+        "Route a share of treasury fees into a juror epoch, or stop routing with no epoch (admin only)"
+      ],
+      "discriminator": [
+        196,
+        148,
+        184,
+        90,
+        254,
+        10,
+        29,
+        79
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "relations": [
+            "jurorEpoch"
+          ]
+        },
+        {
+          "name": "jurorEpoch",
+          "docs": [
+            "Epoch receiving the fee share (None = fees stay with the treasury)"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "epochFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setGradedVerdicts",
+      "docs": [
+        "Decide disputes by graded votes and a median slash instead of a binary verdict (creator only)"
+      ],
+      "discriminator": [
+        92,
+        158,
+        85,
+        4,
+        108,
+        242,
+        41,
+        192
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setJurorWeightCap",
+      "docs": [
+        "Cap one juror's counted weight at a share of the total (creator only, 0 = uncapped)"
+      ],
+      "discriminator": [
+        223,
+        190,
+        55,
+        41,
+        65,
+        107,
+        218,
+        93
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxJurorWeightBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setPledgeThreshold",
+      "docs": [
+        "Set the pledged bond total that activates a pledged dispute (creator only)"
+      ],
+      "discriminator": [
+        136,
+        244,
+        212,
+        206,
+        66,
+        47,
+        132,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPoolDelegate",
+      "docs": [
+        "Authorize a wallet to link subjects to the pool up to a spending cap (owner only)"
+      ],
+      "discriminator": [
+        131,
+        67,
+        163,
+        15,
+        232,
+        105,
+        249,
+        250
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolDelegate",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  111,
+                  111,
+                  108,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              },
+              {
+                "kind": "arg",
+                "path": "delegate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        },
+        {
+          "name": "spendingCap",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setSubjectCreatorGate",
+      "docs": [
+        "Enable/disable allowlist gating of subject creation (admin only)"
+      ],
+      "discriminator": [
+        218,
+        141,
+        1,
+        39,
+        170,
+        214,
+        208,
+        139
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        },
+        {
+          "name": "creatorAllowlist",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  114,
+                  101,
+                  97,
+                  116,
+                  111,
+                  114,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setTiePolicy",
+      "docs": [
+        "Choose how tied votes resolve: defender, challenger, or one voting extension (creator only)"
+      ],
+      "discriminator": [
+        142,
+        168,
+        177,
+        6,
+        239,
+        62,
+        117,
+        163
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "subject"
+          ]
+        },
+        {
+          "name": "subject",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tiePolicy",
+          "type": {
+            "defined": {
+              "name": "tiePolicy"
+            }
+          }
+        },
+        {
+          "name": "tieExtension",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "setYieldStakePool",
+      "docs": [
+        "Approve the stake pool for idle defender pool stake and the delegable share (admin only)"
+      ],
+      "discriminator": [
+        82,
+        12,
+        253,
+        247,
+        152,
+        245,
+        39,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "stakePool",
+          "type": "pubkey"
+        },
+        {
+          "name": "maxYieldBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "stakePool",
+      "docs": [
+        "Add stake to an existing pool"
+      ],
+      "discriminator": [
+        186,
+        105,
+        178,
+        191,
+        181,
+        236,
+        39,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitAppeal",
+      "docs": [
+        "Submit an appeal against an invalidated subject",
+        "Appeals allow community to reverse previous invalidation decisions",
+        "Appellant stakes (no bond required), voting period is 2x previous"
+      ],
+      "discriminator": [
+        119,
+        88,
+        95,
+        156,
+        252,
+        220,
+        32,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "appellant",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds the appeal and opposition stakes"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "appellant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengerRecord",
+          "docs": [
+            "Appellant's claim on the escrow (bond = appeal stake)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "appellant"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (CID validation)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "disputeType",
+          "type": {
+            "defined": {
+              "name": "disputeType"
+            }
+          }
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitDispute",
+      "docs": [
+        "Submit a new dispute against a subject (first challenger)"
+      ],
+      "discriminator": [
+        216,
+        199,
+        236,
+        25,
+        212,
+        79,
+        19,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "challenger",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "relations": [
+            "poolDefenderRecord"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "docs": [
+            "Optional: defender pool if subject is linked"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "poolDefenderRecord",
+          "docs": [
+            "Optional: pool owner's defender record (required when stake is pulled from the pool)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "challengerAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "challenger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow PDA holds all funds for this dispute"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengerRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "challenger"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (cooldown escalation)"
+          ]
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "disputeType",
+          "type": {
+            "defined": {
+              "name": "disputeType"
+            }
+          }
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        },
+        {
+          "name": "bond",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "submitEvidence",
+      "docs": [
+        "Append evidence to a dispute (challengers/defenders, first half of voting)"
+      ],
+      "discriminator": [
+        12,
+        169,
+        228,
+        194,
+        229,
+        31,
+        44,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "submitter",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute"
+        },
+        {
+          "name": "evidenceLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "challengerRecord",
+          "docs": [
+            "Optional: submitter's challenger record (submitting as challenger)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  104,
+                  97,
+                  108,
+                  108,
+                  101,
+                  110,
+                  103,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "submitter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderRecord",
+          "docs": [
+            "Optional: submitter's defender record (submitting as defender)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "submitter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (CID validation)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "cid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "submitFreeDispute",
+      "docs": [
+        "Submit a free dispute (no bond required, just Dispute account)"
+      ],
+      "discriminator": [
+        140,
+        225,
+        220,
+        24,
+        64,
+        19,
+        209,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "challenger",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "dispute",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  105,
+                  115,
+                  112,
+                  117,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.dispute_count",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (CID validation)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "disputeType",
+          "type": {
+            "defined": {
+              "name": "disputeType"
+            }
+          }
+        },
+        {
+          "name": "detailsCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "tallyCappedVotes",
+      "docs": [
+        "Count a chunk of vote records against the subject's juror weight cap"
+      ],
+      "discriminator": [
+        140,
+        143,
+        138,
+        28,
+        126,
+        137,
+        242,
+        68
+      ],
+      "accounts": [
+        {
+          "name": "resolver",
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "timeoutRefund",
+      "docs": [
+        "Refund a dispute nobody resolved within DISPUTE_REFUND_TIMEOUT of voting end (permissionless)"
+      ],
+      "discriminator": [
+        194,
+        205,
+        141,
+        37,
+        231,
+        118,
+        147,
+        9
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "signer": true
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "noopProgram",
+          "optional": true,
+          "address": "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+        },
+        {
+          "name": "expiryQueue",
+          "docs": [
+            "Optional: the subject's config's keeper expiry queue, kept current when passed"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  113,
+                  117,
+                  101,
+                  117,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject.config",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unfreezeSubject",
+      "docs": [
+        "Lift a subject freeze (authority, or the creator for their own freeze)"
+      ],
+      "discriminator": [
+        30,
+        197,
+        99,
+        117,
+        141,
+        157,
+        113,
+        35
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "signer": true
+        },
+        {
+          "name": "subject",
+          "writable": true
+        },
+        {
+          "name": "protocolConfig"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unlockJurorStake",
+      "docs": [
+        "Unlock juror stake after 7-day buffer (immediately for timed-out or voided rounds)"
+      ],
+      "discriminator": [
+        109,
+        73,
+        56,
+        32,
+        115,
+        125,
+        5,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount",
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dispute",
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "voteRecord",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unregisterJuror",
+      "docs": [
+        "Unregister juror and withdraw all available stake"
+      ],
+      "discriminator": [
+        199,
+        200,
+        113,
+        139,
+        182,
+        118,
+        206,
+        124
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "incinerator",
+          "writable": true,
+          "address": "1nc1nerator11111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAllocationCaps",
+      "docs": [
+        "Update juror per-dispute and concurrent lock caps (admin only)"
+      ],
+      "discriminator": [
+        224,
+        101,
+        103,
+        146,
+        78,
+        5,
+        48,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "maxVoteAllocationBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxLockedBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateDisputeCooldown",
+      "docs": [
+        "Update the post-defense dispute cooldown and escalation (admin only)"
+      ],
+      "discriminator": [
+        60,
+        231,
+        114,
+        41,
+        8,
+        25,
+        22,
+        76
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "defaultDisputeCooldown",
+          "type": "i64"
+        },
+        {
+          "name": "cooldownEscalationBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateLateJoinCutoff",
+      "docs": [
+        "Update the final share of voting closed to new challenger bond (admin only)"
+      ],
+      "discriminator": [
+        152,
+        116,
+        112,
+        190,
+        155,
+        6,
+        163,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "lateJoinCutoffBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateMinVoteAllocation",
+      "docs": [
+        "Update the minimum stake per vote, absolute and relative to juror stake (admin only)"
+      ],
+      "discriminator": [
+        49,
+        185,
+        140,
+        51,
+        255,
+        58,
+        201,
+        95
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "minVoteAllocation",
+          "type": "u64"
+        },
+        {
+          "name": "minVoteAllocationBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateRebateTiers",
+      "docs": [
+        "Update the reputation tiers that rebate part of the treasury fee to jurors (admin only)"
+      ],
+      "discriminator": [
+        163,
+        75,
+        242,
+        34,
+        244,
+        40,
+        99,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rebateTiers",
+          "type": {
+            "array": [
+              {
+                "defined": {
+                  "name": "rebateTier"
+                }
+              },
+              3
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateResolverFee",
+      "docs": [
+        "Update the resolver fee and its per-resolution cap (admin only)"
+      ],
+      "discriminator": [
+        126,
+        116,
+        18,
+        128,
+        59,
+        43,
+        201,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "resolverFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxResolverFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateShareBounds",
+      "docs": [
+        "Update bounds for per-subject reward split overrides (admin only)"
+      ],
+      "discriminator": [
+        78,
+        254,
+        52,
+        249,
+        181,
+        131,
+        121,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "minJurorShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxJurorShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "minWinnerShareBps",
+          "type": "u16"
+        },
+        {
+          "name": "maxWinnerShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateStakeMaturityPeriod",
+      "docs": [
+        "Update how long new juror stake takes to reach full voting weight (admin only)"
+      ],
+      "discriminator": [
+        43,
+        189,
+        182,
+        124,
+        103,
+        37,
+        181,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "period",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateSweepBounty",
+      "docs": [
+        "Update the sweep bounty curve paid to escrow closers (admin only)"
+      ],
+      "discriminator": [
+        80,
+        156,
+        58,
+        88,
+        122,
+        57,
+        93,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "sweepBountyMin",
+          "type": "u64"
+        },
+        {
+          "name": "sweepBountyBaseBps",
+          "type": "u16"
+        },
+        {
+          "name": "sweepBountyBpsPerDay",
+          "type": "u16"
+        },
+        {
+          "name": "sweepBountyMax",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateTreasury",
+      "docs": [
+        "Update treasury address (admin only)"
+      ],
+      "discriminator": [
+        60,
+        16,
+        243,
+        66,
+        96,
+        59,
+        254,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newTreasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "voidResolution",
+      "docs": [
+        "Void a corrupted resolution before any claims (config authority, emergency only)"
+      ],
+      "discriminator": [
+        104,
+        85,
+        89,
+        125,
+        102,
+        90,
+        122,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "Repays the fees collected at resolution and funds the audit record"
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "protocolConfig"
+          ]
+        },
+        {
+          "name": "protocolConfig"
+        },
+        {
+          "name": "subject",
+          "writable": true,
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "resolutionVoid",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  111,
+                  108,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110,
+                  95,
+                  118,
+                  111,
+                  105,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "attestation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  116,
+                  116,
+                  101,
+                  115,
+                  116,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "protocolStats",
+          "docs": [
+            "Optional: protocol-wide statistics, updated when passed (see ProtocolStats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  116,
+                  111,
+                  99,
+                  111,
+                  108,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "defenderPool",
+          "docs": [
+            "Pool the restored subject was linked to at resolution (required if dispute.rebond_pool is set)"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "rebondRecord",
+          "docs": [
+            "Creator's stake record the restoration rebond credited (required if dispute.rebond_amount is set)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  114,
+                  101,
+                  99,
+                  111,
+                  114,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "subject"
+              },
+              {
+                "kind": "account",
+                "path": "subject.creator",
+                "account": "subject"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "reasonCid",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "voteGraded",
+      "docs": [
+        "Vote a slash grade on a graded dispute (0 = none, 1-4 = 25-100% of the defender stake)"
+      ],
+      "discriminator": [
+        125,
+        147,
+        61,
+        176,
+        67,
+        2,
+        53,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow totals for the reward estimate"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteStats",
+          "docs": [
+            "Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "evidenceAck",
+          "docs": [
+            "Optional: juror's evidence acknowledgment (required when the dispute requires review)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101,
+                  95,
+                  97,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (stake-age weighting and allocation caps)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "grade",
+          "type": "u8"
+        },
+        {
+          "name": "stakeAllocation",
+          "type": "u64"
+        },
+        {
+          "name": "rationaleCid",
+          "type": "string"
+        },
+        {
+          "name": "rationaleHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "voteOnAppeal",
+      "docs": [
+        "Vote on an appeal with stake allocation",
+        "ForRestoration = vote to restore subject to Active",
+        "AgainstRestoration = vote to keep subject Invalidated"
+      ],
+      "discriminator": [
+        50,
+        167,
+        54,
+        13,
+        106,
+        100,
+        219,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow totals for the reward estimate"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteStats",
+          "docs": [
+            "Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "evidenceAck",
+          "docs": [
+            "Optional: juror's evidence acknowledgment (required when the dispute requires review)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101,
+                  95,
+                  97,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (stake-age weighting and allocation caps)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "choice",
+          "type": {
+            "defined": {
+              "name": "appealVoteChoice"
+            }
+          }
+        },
+        {
+          "name": "stakeAllocation",
+          "type": "u64"
+        },
+        {
+          "name": "rationaleCid",
+          "type": "string"
+        },
+        {
+          "name": "rationaleHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "voteOnDispute",
+      "docs": [
+        "Vote on a dispute with stake allocation",
+        "Abstain locks a reduced stake for a flat share of the juror pot and stays out of the tally"
+      ],
+      "discriminator": [
+        7,
+        213,
+        96,
+        171,
+        252,
+        59,
+        55,
+        23
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "subject",
+          "relations": [
+            "dispute"
+          ]
+        },
+        {
+          "name": "dispute",
+          "writable": true
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow totals for the reward estimate"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteStats",
+          "docs": [
+            "Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  115
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              }
+            ]
+          }
+        },
+        {
+          "name": "evidenceAck",
+          "docs": [
+            "Optional: juror's evidence acknowledgment (required when the dispute requires review)"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  118,
+                  105,
+                  100,
+                  101,
+                  110,
+                  99,
+                  101,
+                  95,
+                  97,
+                  99,
+                  107
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "dispute"
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "protocolConfig",
+          "docs": [
+            "Protocol config (stake-age weighting and allocation caps)"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "choice",
+          "type": {
+            "defined": {
+              "name": "voteChoice"
+            }
+          }
+        },
+        {
+          "name": "stakeAllocation",
+          "type": "u64"
+        },
+        {
+          "name": "rationaleCid",
+          "type": "string"
+        },
+        {
+          "name": "rationaleHash",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawJurorStake",
+      "docs": [
+        "Withdraw available stake (with reputation-based slashing)"
+      ],
+      "discriminator": [
+        178,
+        43,
+        144,
+        250,
+        188,
+        199,
+        135,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "juror",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "jurorAccount"
+          ]
+        },
+        {
+          "name": "jurorAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  106,
+                  117,
+                  114,
+                  111,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "juror"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawPool",
+      "docs": [
+        "Withdraw available stake from pool"
+      ],
+      "discriminator": [
+        190,
+        43,
+        148,
+        248,
+        68,
+        5,
+        215,
+        136
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawPoolYield",
+      "docs": [
+        "Redeem stake pool tokens back into available pool stake (owner only)"
+      ],
+      "discriminator": [
+        186,
+        200,
+        71,
+        212,
+        111,
+        62,
+        143,
+        194
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "defenderPool"
+          ]
+        },
+        {
+          "name": "defenderPool",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  102,
+                  101,
+                  110,
+                  100,
+                  101,
+                  114,
+                  95,
+                  112,
+                  111,
+                  111,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "owner"
+              }
+            ]
+          }
+        },
+        {
+          "name": "yieldVault",
+          "docs": [
+            "Holds the pool tokens and receives the withdrawn lamports"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  121,
+                  105,
+                  101,
+                  108,
+                  100,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "defenderPool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakePoolCpi",
+          "accounts": [
+            {
+              "name": "stakePool",
+              "writable": true
+            },
+            {
+              "name": "withdrawAuthority"
+            },
+            {
+              "name": "reserveStake",
+              "writable": true
+            },
+            {
+              "name": "managerFeeAccount",
+              "writable": true
+            },
+            {
+              "name": "poolMint",
+              "writable": true
+            },
+            {
+              "name": "poolTokens",
+              "writable": true
+            },
+            {
+              "name": "stakePoolProgram",
+              "address": "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy"
+            },
+            {
+              "name": "tokenProgram",
+              "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            }
+          ]
+        },
+        {
+          "name": "clock",
+          "address": "SysvarC1ock11111111111111111111111111111111"
+        },
+        {
+          "name": "stakeHistory",
+          "address": "SysvarStakeHistory1111111111111111111111111"
+        },
+        {
+          "name": "stakeProgram",
+          "address": "Stake11111111111111111111111111111111111111"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "poolTokens",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawTreasury",
+      "docs": [
+        "Withdraw from the accounting treasury PDA (admin only)"
+      ],
+      "discriminator": [
+        40,
+        63,
+        122,
+        158,
+        144,
+        216,
+        83,
+        96
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "relations": [
+            "treasury"
+          ]
+        },
+        {
+          "name": "treasury",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "categoryRegistry",
+      "discriminator": [
+        200,
+        120,
+        147,
+        155,
+        17,
+        238,
+        115,
+        156
+      ]
+    },
+    {
+      "name": "challengePledge",
+      "discriminator": [
+        103,
+        139,
+        240,
+        116,
+        57,
+        237,
+        82,
+        137
+      ]
+    },
+    {
+      "name": "challengePledgePool",
+      "discriminator": [
+        164,
+        178,
+        58,
+        102,
+        241,
+        78,
+        198,
+        86
+      ]
+    },
+    {
+      "name": "challengerAccount",
+      "discriminator": [
+        63,
+        207,
+        170,
+        69,
+        229,
+        2,
+        163,
+        201
+      ]
+    },
+    {
+      "name": "challengerRecord",
+      "discriminator": [
+        8,
+        80,
+        134,
+        108,
+        192,
+        142,
+        121,
+        14
+      ]
+    },
+    {
+      "name": "creatorAllowlist",
+      "discriminator": [
+        129,
+        166,
+        148,
+        229,
+        28,
+        87,
+        252,
+        129
+      ]
+    },
+    {
+      "name": "defenderPool",
+      "discriminator": [
+        227,
+        100,
+        92,
+        157,
+        203,
+        252,
+        63,
+        118
+      ]
+    },
+    {
+      "name": "defenderRecord",
+      "discriminator": [
+        14,
+        3,
+        219,
+        215,
+        38,
+        254,
+        254,
+        92
+      ]
+    },
+    {
+      "name": "dispute",
+      "discriminator": [
+        36,
+        49,
+        241,
+        67,
+        40,
+        36,
+        241,
+        74
+      ]
+    },
+    {
+      "name": "disputeEscrow",
+      "discriminator": [
+        4,
+        51,
+        35,
+        202,
+        114,
+        140,
+        92,
+        65
+      ]
+    },
+    {
+      "name": "epochAssignment",
+      "discriminator": [
+        134,
+        55,
+        159,
+        71,
+        11,
+        50,
+        14,
+        230
+      ]
+    },
+    {
+      "name": "epochEnrollment",
+      "discriminator": [
+        191,
+        20,
+        185,
+        168,
+        77,
+        68,
+        151,
+        161
+      ]
+    },
+    {
+      "name": "evidenceAck",
+      "discriminator": [
+        14,
+        215,
+        38,
+        10,
+        93,
+        193,
+        159,
+        129
+      ]
+    },
+    {
+      "name": "evidenceLog",
+      "discriminator": [
+        71,
+        190,
+        232,
+        188,
+        81,
+        253,
+        163,
+        152
+      ]
+    },
+    {
+      "name": "expiryQueue",
+      "discriminator": [
+        126,
+        194,
+        117,
+        192,
+        79,
+        7,
+        153,
+        27
+      ]
+    },
+    {
+      "name": "jurorAccount",
+      "discriminator": [
+        138,
+        222,
+        50,
+        194,
+        222,
+        131,
+        255,
+        186
+      ]
+    },
+    {
+      "name": "jurorBoost",
+      "discriminator": [
+        220,
+        129,
+        71,
+        254,
+        126,
+        238,
+        177,
+        55
+      ]
+    },
+    {
+      "name": "jurorEpoch",
+      "discriminator": [
+        89,
+        10,
+        14,
+        116,
+        102,
+        104,
+        135,
+        168
+      ]
+    },
+    {
+      "name": "oppositionRecord",
+      "discriminator": [
+        87,
+        108,
+        99,
+        96,
+        201,
+        188,
+        223,
+        138
+      ]
+    },
+    {
+      "name": "poolDelegate",
+      "discriminator": [
+        148,
+        249,
+        208,
+        18,
+        131,
+        32,
+        229,
+        219
+      ]
+    },
+    {
+      "name": "protocolConfig",
+      "discriminator": [
+        207,
+        91,
+        250,
+        28,
+        152,
+        179,
+        215,
+        209
+      ]
+    },
+    {
+      "name": "protocolStats",
+      "discriminator": [
+        133,
+        227,
+        216,
+        241,
+        235,
+        172,
+        35,
+        31
+      ]
+    },
+    {
+      "name": "resolutionAttestation",
+      "discriminator": [
+        159,
+        48,
+        18,
+        144,
+        218,
+        165,
+        146,
+        93
+      ]
+    },
+    {
+      "name": "resolutionVoid",
+      "discriminator": [
+        151,
+        13,
+        72,
+        189,
+        182,
+        33,
+        80,
+        165
+      ]
+    },
+    {
+      "name": "subject",
+      "discriminator": [
+        52,
+        161,
+        41,
+        165,
+        202,
+        238,
+        138,
+        166
+      ]
+    },
+    {
+      "name": "subjectIndex",
+      "discriminator": [
+        250,
+        1,
+        241,
+        252,
+        77,
+        21,
+        139,
+        91
+      ]
+    },
+    {
+      "name": "subjectTombstone",
+      "discriminator": [
+        62,
+        143,
+        184,
+        246,
+        16,
+        85,
+        170,
+        5
+      ]
+    },
+    {
+      "name": "treasury",
+      "discriminator": [
+        238,
+        239,
+        123,
+        238,
+        89,
+        1,
+        168,
+        253
+      ]
+    },
+    {
+      "name": "voteRecord",
+      "discriminator": [
+        112,
+        9,
+        123,
+        165,
+        234,
+        9,
+        157,
+        167
+      ]
+    },
+    {
+      "name": "voteStats",
+      "discriminator": [
+        135,
+        252,
+        97,
+        215,
+        200,
+        42,
+        68,
+        115
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigratedEvent",
+      "discriminator": [
+        109,
+        3,
+        25,
+        119,
+        155,
+        108,
+        69,
+        61
+      ]
+    },
+    {
+      "name": "challengerRewardClaimedEvent",
+      "discriminator": [
+        33,
+        78,
+        230,
+        96,
+        247,
+        37,
+        156,
+        163
+      ]
+    },
+    {
+      "name": "dependentEscrowNotifiedEvent",
+      "discriminator": [
+        76,
+        47,
+        151,
+        48,
+        246,
+        102,
+        224,
+        62
+      ]
+    },
+    {
+      "name": "dependentEscrowRegisteredEvent",
+      "discriminator": [
+        183,
+        70,
+        0,
+        36,
+        205,
+        190,
+        44,
+        248
+      ]
+    },
+    {
+      "name": "disputeBatchCreatedEvent",
+      "discriminator": [
+        254,
+        254,
+        171,
+        133,
+        44,
+        154,
+        160,
+        104
+      ]
+    },
+    {
+      "name": "disputeResolvedEvent",
+      "discriminator": [
+        152,
+        37,
+        98,
+        245,
+        229,
+        39,
+        150,
+        78
+      ]
+    },
+    {
+      "name": "disputeTimedOutEvent",
+      "discriminator": [
+        252,
+        247,
+        193,
+        186,
+        48,
+        196,
+        113,
+        55
+      ]
+    },
+    {
+      "name": "epochRewardClaimedEvent",
+      "discriminator": [
+        12,
+        252,
+        122,
+        57,
+        188,
+        93,
+        206,
+        17
+      ]
+    },
+    {
+      "name": "evidenceAcknowledgedEvent",
+      "discriminator": [
+        118,
+        12,
+        145,
+        13,
+        171,
+        151,
+        234,
+        157
+      ]
+    },
+    {
+      "name": "evidenceSubmittedEvent",
+      "discriminator": [
+        202,
+        231,
+        219,
+        220,
+        101,
+        71,
+        194,
+        70
+      ]
+    },
+    {
+      "name": "lamportsMovedEvent",
+      "discriminator": [
+        91,
+        100,
+        52,
+        97,
+        11,
+        38,
+        233,
+        250
+      ]
+    },
+    {
+      "name": "poolDefendedEvent",
+      "discriminator": [
+        192,
+        255,
+        186,
+        118,
+        144,
+        245,
+        96,
+        144
+      ]
+    },
+    {
+      "name": "rationaleRevealedEvent",
+      "discriminator": [
+        108,
+        197,
+        42,
+        192,
+        251,
+        97,
+        16,
+        64
+      ]
+    },
+    {
+      "name": "resolutionAttestedEvent",
+      "discriminator": [
+        57,
+        216,
+        177,
+        202,
+        227,
+        107,
+        211,
+        38
+      ]
+    },
+    {
+      "name": "resolutionVoidedEvent",
+      "discriminator": [
+        237,
+        193,
+        38,
+        35,
+        16,
+        109,
+        194,
+        127
+      ]
+    },
+    {
+      "name": "subjectClosedEvent",
+      "discriminator": [
+        239,
+        249,
+        66,
+        103,
+        41,
+        243,
+        38,
+        254
+      ]
+    },
+    {
+      "name": "subjectFreezeChangedEvent",
+      "discriminator": [
+        163,
+        247,
+        237,
+        201,
+        75,
+        194,
+        238,
+        237
+      ]
+    },
+    {
+      "name": "tieVotingExtendedEvent",
+      "discriminator": [
+        224,
+        101,
+        193,
+        119,
+        247,
+        84,
+        59,
+        58
+      ]
+    },
+    {
+      "name": "voteCastEvent",
+      "discriminator": [
+        241,
+        151,
+        159,
+        134,
+        250,
+        234,
+        71,
+        234
+      ]
+    },
+    {
+      "name": "voteChangedEvent",
+      "discriminator": [
+        43,
+        204,
+        80,
+        151,
+        79,
+        32,
+        21,
+        143
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6001,
+      "name": "invalidConfig",
+      "msg": "Invalid configuration parameter"
+    },
+    {
+      "code": 6002,
+      "name": "stakeBelowMinimum",
+      "msg": "Stake amount below minimum"
+    },
+    {
+      "code": 6003,
+      "name": "insufficientAvailableStake",
+      "msg": "Insufficient available stake"
+    },
+    {
+      "code": 6004,
+      "name": "insufficientHeldStake",
+      "msg": "Insufficient held stake"
+    },
+    {
+      "code": 6005,
+      "name": "stakeStillLocked",
+      "msg": "Stake still locked"
+    },
+    {
+      "code": 6006,
+      "name": "stakeAlreadyUnlocked",
+      "msg": "Stake already unlocked"
+    },
+    {
+      "code": 6007,
+      "name": "bondBelowMinimum",
+      "msg": "Bond amount below minimum"
+    },
+    {
+      "code": 6008,
+      "name": "bondExceedsAvailable",
+      "msg": "Bond exceeds staker's available pool"
+    },
+    {
+      "code": 6009,
+      "name": "subjectCannotBeStaked",
+      "msg": "Subject cannot accept stakes"
+    },
+    {
+      "code": 6010,
+      "name": "subjectCannotBeDisputed",
+      "msg": "Subject cannot be disputed"
+    },
+    {
+      "code": 6011,
+      "name": "subjectCannotBeAppealed",
+      "msg": "Subject cannot be appealed"
+    },
+    {
+      "code": 6012,
+      "name": "appealStakeBelowMinimum",
+      "msg": "Appeal stake below minimum (must match previous dispute total)"
+    },
+    {
+      "code": 6013,
+      "name": "cannotSelfDispute",
+      "msg": "Cannot dispute own subject"
+    },
+    {
+      "code": 6014,
+      "name": "disputeAlreadyExists",
+      "msg": "Dispute already exists for this subject"
+    },
+    {
+      "code": 6015,
+      "name": "disputeNotFound",
+      "msg": "Dispute not found"
+    },
+    {
+      "code": 6016,
+      "name": "disputeAlreadyResolved",
+      "msg": "Dispute already resolved"
+    },
+    {
+      "code": 6017,
+      "name": "votingNotEnded",
+      "msg": "Voting period not ended"
+    },
+    {
+      "code": 6018,
+      "name": "votingEnded",
+      "msg": "Voting period has ended"
+    },
+    {
+      "code": 6019,
+      "name": "cannotVoteOnOwnDispute",
+      "msg": "Cannot vote on own dispute"
+    },
+    {
+      "code": 6020,
+      "name": "alreadyVoted",
+      "msg": "Already voted on this dispute"
+    },
+    {
+      "code": 6021,
+      "name": "voteAllocationBelowMinimum",
+      "msg": "Vote allocation below minimum"
+    },
+    {
+      "code": 6022,
+      "name": "invalidVoteChoice",
+      "msg": "Invalid vote choice"
+    },
+    {
+      "code": 6023,
+      "name": "jurorNotActive",
+      "msg": "Juror not active"
+    },
+    {
+      "code": 6024,
+      "name": "jurorAlreadyRegistered",
+      "msg": "Juror already registered"
+    },
+    {
+      "code": 6025,
+      "name": "challengerNotFound",
+      "msg": "Challenger not found"
+    },
+    {
+      "code": 6026,
+      "name": "rewardAlreadyClaimed",
+      "msg": "Reward already claimed"
+    },
+    {
+      "code": 6027,
+      "name": "notEligibleForReward",
+      "msg": "Not eligible for reward"
+    },
+    {
+      "code": 6028,
+      "name": "reputationAlreadyProcessed",
+      "msg": "Reputation already processed"
+    },
+    {
+      "code": 6029,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6030,
+      "name": "divisionByZero",
+      "msg": "Division by zero"
+    },
+    {
+      "code": 6031,
+      "name": "claimsNotComplete",
+      "msg": "Not all claims have been processed"
+    },
+    {
+      "code": 6032,
+      "name": "creatorNotAllowed",
+      "msg": "Creator is not allowed to create subjects"
+    },
+    {
+      "code": 6033,
+      "name": "invalidRewardSplit",
+      "msg": "Reward split outside protocol bounds"
+    },
+    {
+      "code": 6034,
+      "name": "subjectCannotBeClosed",
+      "msg": "Subject cannot be closed yet"
+    },
+    {
+      "code": 6035,
+      "name": "subjectClosed",
+      "msg": "Subject was closed and cannot be re-created"
+    },
+    {
+      "code": 6036,
+      "name": "disputeNotResolving",
+      "msg": "Dispute is not in paginated resolution"
+    },
+    {
+      "code": 6037,
+      "name": "evidenceWindowClosed",
+      "msg": "Evidence submission window has closed"
+    },
+    {
+      "code": 6038,
+      "name": "evidenceLogFull",
+      "msg": "Evidence log is full"
+    },
+    {
+      "code": 6039,
+      "name": "disputeCooldownActive",
+      "msg": "Subject is in its dispute cooldown - bond is below the escalation requirement"
+    },
+    {
+      "code": 6040,
+      "name": "delegateCapExceeded",
+      "msg": "Pool delegate spending cap exceeded"
+    },
+    {
+      "code": 6041,
+      "name": "beneficiaryMismatch",
+      "msg": "Account does not match the juror's beneficiary"
+    },
+    {
+      "code": 6042,
+      "name": "voteStatsMismatch",
+      "msg": "Dispute vote stats account missing or not expected"
+    },
+    {
+      "code": 6043,
+      "name": "subjectIndexPageFull",
+      "msg": "Subject index page is full - use the next page"
+    },
+    {
+      "code": 6044,
+      "name": "invalidCid",
+      "msg": "CID is too long or not a valid IPFS CID / Arweave id"
+    },
+    {
+      "code": 6045,
+      "name": "weightTallyIncomplete",
+      "msg": "Juror weight cap is set - tally_capped_votes must count every vote first"
+    },
+    {
+      "code": 6046,
+      "name": "refundTimeoutNotReached",
+      "msg": "Dispute can only be refunded long after voting ends"
+    },
+    {
+      "code": 6047,
+      "name": "resolutionNotVoidable",
+      "msg": "Resolution can only be voided within the void window and before any claims"
+    },
+    {
+      "code": 6048,
+      "name": "boostNotRefundable",
+      "msg": "Juror incentive is only refundable after a NoParticipation outcome"
+    },
+    {
+      "code": 6049,
+      "name": "boostRefundsPending",
+      "msg": "Boosters can still refund juror incentive from this escrow"
+    },
+    {
+      "code": 6050,
+      "name": "lateJoinClosed",
+      "msg": "Dispute is closed to new challenger bond this late in voting"
+    },
+    {
+      "code": 6051,
+      "name": "gradedVoteMismatch",
+      "msg": "Vote type does not match the dispute's graded verdict mode"
+    },
+    {
+      "code": 6052,
+      "name": "invalidGrade",
+      "msg": "Grade must be 0-4 (0%, 25%, 50%, 75%, 100% slash)"
+    },
+    {
+      "code": 6053,
+      "name": "invalidDisputeBatch",
+      "msg": "Dispute batch needs 1-8 subjects, one bond each, with 6 accounts per subject"
+    },
+    {
+      "code": 6054,
+      "name": "epochEnrollmentClosed",
+      "msg": "Juror epoch enrollment is closed"
+    },
+    {
+      "code": 6055,
+      "name": "epochNotEnded",
+      "msg": "Juror epoch has not ended"
+    },
+    {
+      "code": 6056,
+      "name": "stakeCommittedToEpoch",
+      "msg": "Stake is committed to a juror epoch until it ends"
+    },
+    {
+      "code": 6057,
+      "name": "epochClaimsPending",
+      "msg": "Juror epoch still has unclaimed rewards"
+    },
+    {
+      "code": 6058,
+      "name": "dependentEscrowAlreadySet",
+      "msg": "Dispute already has a dependent escrow"
+    },
+    {
+      "code": 6059,
+      "name": "dependentEscrowNotSet",
+      "msg": "Dispute has no dependent escrow to notify"
+    },
+    {
+      "code": 6060,
+      "name": "dependentEscrowAlreadyNotified",
+      "msg": "Dependent escrow was already notified"
+    },
+    {
+      "code": 6061,
+      "name": "subjectFrozen",
+      "msg": "Subject is frozen"
+    },
+    {
+      "code": 6062,
+      "name": "subjectNotFrozen",
+      "msg": "Subject is not frozen"
+    },
+    {
+      "code": 6063,
+      "name": "voteAbstained",
+      "msg": "Abstentions cannot be topped up or changed"
+    },
+    {
+      "code": 6064,
+      "name": "freeCaseMismatch",
+      "msg": "Instruction does not match the subject's free-case mode"
+    },
+    {
+      "code": 6065,
+      "name": "appealMismatch",
+      "msg": "Instruction does not match the dispute's appeal status"
+    },
+    {
+      "code": 6066,
+      "name": "invalidVotingPeriod",
+      "msg": "Voting period must be positive"
+    },
+    {
+      "code": 6067,
+      "name": "invalidTieExtension",
+      "msg": "Tie extension must be positive for TiePolicy::ExtendVoting"
+    },
+    {
+      "code": 6068,
+      "name": "weightCapConflict",
+      "msg": "Juror weight cap and graded verdicts cannot be combined"
+    },
+    {
+      "code": 6069,
+      "name": "weightCapNotSet",
+      "msg": "Subject has no juror weight cap"
+    },
+    {
+      "code": 6070,
+      "name": "invalidRemainingAccounts",
+      "msg": "Remaining accounts do not match the expected layout"
+    },
+    {
+      "code": 6071,
+      "name": "voteRecordMismatch",
+      "msg": "Vote record belongs to another dispute or juror"
+    },
+    {
+      "code": 6072,
+      "name": "rationaleNotPending",
+      "msg": "No committed rationale to reveal"
+    },
+    {
+      "code": 6073,
+      "name": "invalidEpochWindow",
+      "msg": "Epoch must start in the future and end after it starts"
+    },
+    {
+      "code": 6074,
+      "name": "invalidReviewDelay",
+      "msg": "Review delay must be positive and at most MAX_REVIEW_DELAY"
+    },
+    {
+      "code": 6075,
+      "name": "evidenceReviewLocked",
+      "msg": "Evidence review can only be required by the sole challenger before any votes"
+    },
+    {
+      "code": 6076,
+      "name": "evidenceReviewNotRequired",
+      "msg": "Dispute does not require evidence review"
+    },
+    {
+      "code": 6077,
+      "name": "evidenceHashMismatch",
+      "msg": "Evidence hash does not match the dispute's evidence"
+    },
+    {
+      "code": 6078,
+      "name": "evidenceNotReviewed",
+      "msg": "Juror has not acknowledged the evidence long enough before voting"
+    },
+    {
+      "code": 6079,
+      "name": "subjectNotDormant",
+      "msg": "Subject is not dormant"
+    },
+    {
+      "code": 6080,
+      "name": "pledgingDisabled",
+      "msg": "Subject does not accept challenge pledges"
+    },
+    {
+      "code": 6081,
+      "name": "pledgeWindowClosed",
+      "msg": "Pledge window has closed"
+    },
+    {
+      "code": 6082,
+      "name": "pledgeThresholdNotMet",
+      "msg": "Total pledged is below the subject's threshold"
+    },
+    {
+      "code": 6083,
+      "name": "pledgePoolFull",
+      "msg": "Pledge pool is full"
+    },
+    {
+      "code": 6084,
+      "name": "pledgeNotRefundable",
+      "msg": "Pledge can only be refunded after the pool lapses"
+    },
+    {
+      "code": 6085,
+      "name": "voteAllocationAboveCap",
+      "msg": "Vote allocation exceeds the per-dispute cap"
+    },
+    {
+      "code": 6086,
+      "name": "lockedStakeAboveCap",
+      "msg": "Locked stake would exceed the concurrent lock cap"
+    },
+    {
+      "code": 6087,
+      "name": "rationaleHashRequired",
+      "msg": "Subject requires a rationale hash with each vote"
+    },
+    {
+      "code": 6088,
+      "name": "rationaleHashMismatch",
+      "msg": "Revealed rationale does not match the committed hash"
+    },
+    {
+      "code": 6089,
+      "name": "rationaleNotRevealed",
+      "msg": "Rationale must be revealed before claiming"
+    },
+    {
+      "code": 6090,
+      "name": "jurorClaimsPending",
+      "msg": "Jurors still have rewards to claim"
+    },
+    {
+      "code": 6091,
+      "name": "categoryNotFound",
+      "msg": "Category not found in registry"
+    },
+    {
+      "code": 6092,
+      "name": "categoryAlreadyExists",
+      "msg": "Category already exists"
+    },
+    {
+      "code": 6093,
+      "name": "categoryRegistryFull",
+      "msg": "Category registry is full"
+    },
+    {
+      "code": 6094,
+      "name": "revivalNotOptedIn",
+      "msg": "Subject creator has not opted into revalidation"
+    },
+    {
+      "code": 6095,
+      "name": "revivalBondTooLow",
+      "msg": "Defender pool cannot cover the minimum revival bond"
+    },
+    {
+      "code": 6096,
+      "name": "poolStakeFromOtherPool",
+      "msg": "Defender record already holds stake from another pool"
+    },
+    {
+      "code": 6097,
+      "name": "subjectIndexPageOutOfOrder",
+      "msg": "Subject index page out of order - fill the previous page first"
+    },
+    {
+      "code": 6098,
+      "name": "creatorFreezeCooldown",
+      "msg": "Creator froze this subject too recently to freeze it again"
+    },
+    {
+      "code": 6099,
+      "name": "feeEpochMismatch",
+      "msg": "Juror epoch missing or not the config's fee epoch"
+    },
+    {
+      "code": 6100,
+      "name": "insufficientTreasuryBalance",
+      "msg": "Treasury balance above its rent-exempt minimum is too low"
+    },
+    {
+      "code": 6101,
+      "name": "creatorAlreadyAllowed",
+      "msg": "Creator is already on the allowlist"
+    },
+    {
+      "code": 6102,
+      "name": "creatorAllowlistFull",
+      "msg": "Creator allowlist is full"
+    },
+    {
+      "code": 6103,
+      "name": "delegateCommitConflict",
+      "msg": "Defender record already carries another delegate's commitment"
+    },
+    {
+      "code": 6104,
+      "name": "subjectAlreadyLinked",
+      "msg": "Subject is already linked to a defender pool"
+    },
+    {
+      "code": 6105,
+      "name": "zeroAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6106,
+      "name": "poolAccountMismatch",
+      "msg": "Defender pool or pool record does not match the subject"
+    },
+    {
+      "code": 6107,
+      "name": "subjectNotLinked",
+      "msg": "Subject is not linked to a defender pool"
+    },
+    {
+      "code": 6108,
+      "name": "subjectMismatch",
+      "msg": "Subject does not match the dispute"
+    },
+    {
+      "code": 6109,
+      "name": "beneficiaryConflict",
+      "msg": "Claim to wallet and beneficiary payout are mutually exclusive"
+    },
+    {
+      "code": 6110,
+      "name": "sameTargetSubject",
+      "msg": "Target subject must differ from the source subject"
+    },
+    {
+      "code": 6111,
+      "name": "epochNotCoveringDispute",
+      "msg": "Juror epoch does not cover the dispute's voting window"
+    },
+    {
+      "code": 6112,
+      "name": "assignmentAlreadyServed",
+      "msg": "Epoch assignment already served"
+    },
+    {
+      "code": 6113,
+      "name": "epochAccountMismatch",
+      "msg": "Juror epoch or enrollment does not match"
+    },
+    {
+      "code": 6114,
+      "name": "pledgeAccountMismatch",
+      "msg": "Pledge does not belong to this pledge pool"
+    },
+    {
+      "code": 6115,
+      "name": "dependentEscrowMismatch",
+      "msg": "Dependent program or account does not match the dispute"
+    },
+    {
+      "code": 6116,
+      "name": "stakePoolNotApproved",
+      "msg": "Stake pool is not the approved or already-delegated yield pool"
+    },
+    {
+      "code": 6117,
+      "name": "sweepTooEarly",
+      "msg": "Escrow cannot be swept before the dispute resolves"
+    },
+    {
+      "code": 6118,
+      "name": "sweepWindowPassed",
+      "msg": "Claim window has passed - the escrow is open for sweeping"
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigratedEvent",
+      "docs": [
+        "Emitted when migrate_account upgrades an account to the current layout"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "fromVersion",
+            "type": "u8"
+          },
+          {
+            "name": "toVersion",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "appealVoteChoice",
+      "docs": [
+        "Vote choice for appeals (separate enum for clearer semantics)"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "forRestoration"
+          },
+          {
+            "name": "againstRestoration"
+          },
+          {
+            "name": "abstain"
+          }
+        ]
+      }
+    },
+    {
+      "name": "category",
+      "docs": [
+        "Platform-defined category (id + metadata describing it off-chain)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "Category identifier (0 is reserved for \"uncategorized\")"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "metadataCid",
+            "docs": [
+              "Metadata CID (IPFS/Arweave) - name, description, rules"
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "categoryRegistry",
+      "docs": [
+        "Admin-managed category registry",
+        "Lets platforms define their own taxonomy without a program upgrade"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "categories",
+            "docs": [
+              "Registered categories"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "category"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengePledge",
+      "docs": [
+        "Individual pledger's contribution to a pledge pool"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool this pledge belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pledger",
+            "docs": [
+              "Pledger's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports pledged (becomes the challenger bond on conversion)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pledgedAt",
+            "docs": [
+              "First pledge timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengePledgePool",
+      "docs": [
+        "Holding PDA for challenge pledges against a subject's next dispute slot",
+        "Pledged lamports sit here until activate_dispute moves them into a new",
+        "dispute's escrow, or pledgers refund after the window expires"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "docs": [
+              "Subject the pledges target"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeIndex",
+            "docs": [
+              "Subject's dispute_count when the pool opened (the dispute PDA index it can activate)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "opener",
+            "docs": [
+              "Wallet that opened the pool (receives rent when the pool closes)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeType",
+            "docs": [
+              "Dispute type proposed by the opener"
+            ],
+            "type": {
+              "defined": {
+                "name": "disputeType"
+              }
+            }
+          },
+          {
+            "name": "detailsCid",
+            "docs": [
+              "Details CID proposed by the opener (used for every converted challenger record)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Subject's pledge threshold snapshotted at open"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPledged",
+            "docs": [
+              "Total lamports pledged"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pledgerCount",
+            "docs": [
+              "Number of distinct pledgers"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "pendingPledges",
+            "docs": [
+              "Pledges not yet converted or refunded (pool closes at zero)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Pledges can be added and activated until this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "dispute",
+            "docs": [
+              "Dispute created by activate_dispute (default = not activated)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengerAccount",
+      "docs": [
+        "Challenger account tracking reputation - global per wallet"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenger",
+            "docs": [
+              "Challenger's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reputation",
+            "docs": [
+              "Reputation score (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "disputesSubmitted",
+            "docs": [
+              "Total disputes submitted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "disputesUpheld",
+            "docs": [
+              "Disputes that were upheld (challenger was correct)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "disputesDismissed",
+            "docs": [
+              "Disputes that were dismissed (challenger was wrong)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "First dispute timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastDisputeAt",
+            "docs": [
+              "Last dispute timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengerRecord",
+      "docs": [
+        "Individual challenger's contribution to a dispute",
+        "Supports cumulative disputes where multiple challengers contribute"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "The dispute this record belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "challenger",
+            "docs": [
+              "Challenger's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "challengerAccount",
+            "docs": [
+              "Challenger account PDA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bond",
+            "docs": [
+              "Bond amount contributed by this challenger (wallet + pledged)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "detailsCid",
+            "docs": [
+              "Evidence CID (IPFS hash)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "rewardClaimed",
+            "docs": [
+              "Whether reward has been claimed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "challengedAt",
+            "docs": [
+              "Timestamp when this challenger joined"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pledgedBond",
+            "docs": [
+              "Part of the bond that came from a converted challenge pledge"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reputationSnapshot",
+            "docs": [
+              "Challenger reputation when they first joined the dispute (None = pre-v24 record)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengerRewardClaimedEvent",
+      "docs": [
+        "Emitted when a challenger claims, with the payout split by where the bond came from",
+        "pledged_share is the part backed by a converted challenge pledge (see ChallengerRecord::pledged_bond)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "challenger",
+            "type": "pubkey"
+          },
+          {
+            "name": "outcome",
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "walletShare",
+            "type": "u64"
+          },
+          {
+            "name": "pledgedShare",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "claimPayout",
+      "docs": [
+        "Claim payout split by the escrow bucket it is drawn from"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromBonds",
+            "docs": [
+              "Paid out of challenger bonds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fromStakes",
+            "docs": [
+              "Paid out of defender stakes"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "creatorAllowlist",
+      "docs": [
+        "Allowlist of wallets permitted to create subjects",
+        "Only enforced while ProtocolConfig.subject_creator_gate points at it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "creators",
+            "docs": [
+              "Wallets allowed to create subjects"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "defenderPool",
+      "docs": [
+        "Defender's pool that can back multiple subjects - global per wallet"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Pool owner's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalStake",
+            "docs": [
+              "Total stake deposited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "available",
+            "docs": [
+              "Available stake (not held by disputes)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "held",
+            "docs": [
+              "Held stake (locked by pending disputes)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "subjectCount",
+            "docs": [
+              "Number of subjects linked to this pool"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "pendingDisputes",
+            "docs": [
+              "Number of pending disputes against subjects in this pool"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Last update timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "yieldPrincipal",
+            "docs": [
+              "Lamports delegated to the stake pool (not counted in total_stake or available)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "yieldPoolTokens",
+            "docs": [
+              "Stake pool tokens held for this pool by its yield vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "yieldStakePool",
+            "docs": [
+              "Stake pool the tokens belong to (one at a time; default = none)"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "defenderRecord",
+      "docs": [
+        "Individual defender's contribution to backing a subject",
+        "Supports cumulative staking where multiple defenders back a subject"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "docs": [
+              "The subject this record belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "defender",
+            "docs": [
+              "Defender's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Amount staked directly from the defender's wallet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardClaimed",
+            "docs": [
+              "Whether reward has been claimed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "stakedAt",
+            "docs": [
+              "Timestamp when this defender joined"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "poolStake",
+            "docs": [
+              "Amount pulled from the defender's pool into the current dispute's escrow",
+              "Tracked separately so claims return it to the pool, not the wallet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "payoutPool",
+            "docs": [
+              "Pool that pool_stake is paid back to (default = wallet only, no pool stake)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "poolDelegate",
+            "docs": [
+              "PoolDelegate grant charged for pool stake a delegate committed via defend_from_pool"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "delegateCommitted",
+            "docs": [
+              "Amount charged to pool_delegate's cap, given back when the defender claim settles"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dependentEscrowNotifiedEvent",
+      "docs": [
+        "Emitted when a linked external escrow receives the outcome callback"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "outcome",
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dependentEscrowRegisteredEvent",
+      "docs": [
+        "Emitted when an external escrow account is linked to a dispute"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentProgram",
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dispute",
+      "docs": [
+        "Dispute (supports cumulative challengers)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subject",
+            "docs": [
+              "Subject account being disputed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "disputeType",
+            "docs": [
+              "Dispute type"
+            ],
+            "type": {
+              "defined": {
+                "name": "disputeType"
+              }
+            }
+          },
+          {
+            "name": "totalBond",
+            "docs": [
+              "Total bond from all challengers (cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeHeld",
+            "docs": [
+              "Stake held from pool (match mode, linked subjects)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "directStakeHeld",
+            "docs": [
+              "Stake held from direct stakers on subject (match mode)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "challengerCount",
+            "docs": [
+              "Number of challengers who contributed"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Dispute status"
+            ],
+            "type": {
+              "defined": {
+                "name": "disputeStatus"
+              }
+            }
+          },
+          {
+            "name": "outcome",
+            "docs": [
+              "Resolution outcome"
+            ],
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "votesFavorWeight",
+            "docs": [
+              "Cumulative voting power for \"ForChallenger\" votes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "votesAgainstWeight",
+            "docs": [
+              "Cumulative voting power for \"ForDefender\" votes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "voteCount",
+            "docs": [
+              "Number of jurors who voted (including abstentions)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "votingStarted",
+            "docs": [
+              "Whether voting has started (match mode waits for matching)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "votingStartsAt",
+            "docs": [
+              "Voting start timestamp (0 if not started)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "votingEndsAt",
+            "docs": [
+              "Voting end timestamp (0 if not started)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "resolvedAt",
+            "docs": [
+              "Resolution timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "poolRewardClaimed",
+            "docs": [
+              "Pool reward claimed (for linked mode)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "snapshotTotalStake",
+            "docs": [
+              "Snapshot of subject's total_stake at dispute creation"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "snapshotDefenderCount",
+            "docs": [
+              "Snapshot of subject's defender_count at dispute creation"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "challengersClaimed",
+            "docs": [
+              "Number of challengers who have claimed their reward/refund"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "defendersClaimed",
+            "docs": [
+              "Number of direct defenders who have claimed their reward/refund"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "isAppeal",
+            "docs": [
+              "True if this dispute is an appeal (reverses the meaning of outcomes)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "appealStake",
+            "docs": [
+              "Stake posted by appellant (for appeals only)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "categoryId",
+            "docs": [
+              "Subject's category id at dispute creation (0 = uncategorized)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "reputationProcessedCount",
+            "docs": [
+              "Vote records whose reputation was processed by resolution chunks"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tieExtended",
+            "docs": [
+              "Voting was already reopened once under TiePolicy::ExtendVoting"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "jurorsClaimed",
+            "docs": [
+              "Number of jurors who have claimed their reward (including zero rewards)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "hasVoteStats",
+            "docs": [
+              "A VoteStats PDA exists and has counted every vote so far (cleared by a vote that skips it)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rebateReserve",
+            "docs": [
+              "Treasury fee held back in escrow for juror rebates (swept to treasury on close)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rebateBps",
+            "docs": [
+              "Highest tier rebate (bps) when the reserve was set aside - caps later tier changes"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "weightCapBps",
+            "docs": [
+              "Per-juror cap (bps of total weight) the capped tally is using (0 = uncapped)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cappedFavorWeight",
+            "docs": [
+              "Capped voting power for \"ForChallenger\" votes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cappedAgainstWeight",
+            "docs": [
+              "Capped voting power for \"ForDefender\" votes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cappedVotesTallied",
+            "docs": [
+              "Vote records counted into the capped tally this round"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "tallyRound",
+            "docs": [
+              "Bumped when a tie extension reopens voting (the capped tally starts over)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "timedOut",
+            "docs": [
+              "Never resolved and refunded by timeout_refund (no outcome, no fees, no juror rewards)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "voided",
+            "docs": [
+              "Resolution voided by the config authority (see ResolutionVoid)",
+              "Refunds like timed_out; reputation applied by resolution chunks is reversed per vote record"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "jurorIncentive",
+            "docs": [
+              "Juror incentive deposited by boost_juror_pool (held in escrow, paid to jurors by voting power)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "incentiveRefunded",
+            "docs": [
+              "Juror incentive refunded to boosters after a NoParticipation outcome"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "oppositionStake",
+            "docs": [
+              "Appeals only: total staked against the restoration by oppose_restore"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "opposerCount",
+            "docs": [
+              "Appeals only: number of distinct opposers"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "graded",
+            "docs": [
+              "Decided by graded votes (copied from Subject::graded_verdicts at creation)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "gradeWeights",
+            "docs": [
+              "Graded disputes: voting power per grade (index = grade)"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                5
+              ]
+            }
+          },
+          {
+            "name": "incidentId",
+            "docs": [
+              "Incident shared by disputes opened together by create_disputes_batch (default = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentProgram",
+            "docs": [
+              "External program whose account is released on this dispute's outcome (default = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentAccount",
+            "docs": [
+              "External escrow account linked by register_dependent_escrow"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "dependentNotified",
+            "docs": [
+              "notify_dependent_escrow has delivered the outcome callback"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustSwept",
+            "docs": [
+              "Party rounding residue has been checked after the final party claim"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "dustCredited",
+            "docs": [
+              "Rounding residue credited to the juror incentive by the sweep"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "abstainCount",
+            "docs": [
+              "Jurors who abstained (included in vote_count, never in the weight tallies)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "reviewDelay",
+            "docs": [
+              "Seconds a juror must wait after acknowledge_evidence before voting (0 = no review required)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "evidenceHash",
+            "docs": [
+              "keccak256 of the opener's evidence CID jurors must acknowledge"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "typeBonds",
+            "docs": [
+              "Challenger bond per dispute type, indexed by DisputeType::index (all zero before v35)"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                8
+              ]
+            }
+          },
+          {
+            "name": "resolverFeePaid",
+            "docs": [
+              "Resolver fee paid out of the platform fee (the rest of platform_fee_paid went to treasury and the fee epoch)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rebondPool",
+            "docs": [
+              "Pool a restored subject was linked to at resolution (unlinked again if voided)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "claimsCompletedAt",
+            "docs": [
+              "When the last expected party claim landed (0 = still pending)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "epochFeePaid",
+            "docs": [
+              "Share of the platform fee routed into the config's fee epoch (see ProtocolConfig::epoch_fee)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rebondAmount",
+            "docs": [
+              "Bond a restoration rebond moved from rebond_pool into the subject (returned if voided)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "insufficientAvailableStake",
-      "msg": "Insufficient available stake"
+      "name": "disputeBatchCreatedEvent",
+      "docs": [
+        "Emitted when create_disputes_batch opens linked disputes for one incident"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "incidentId",
+            "type": "pubkey"
+          },
+          {
+            "name": "challenger",
+            "type": "pubkey"
+          },
+          {
+            "name": "disputes",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "totalBond",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "insufficientHeldStake",
-      "msg": "Insufficient held stake"
+      "name": "disputeEscrow",
+      "docs": [
+        "DisputeEscrow holds all funds for a single dispute.",
+        "One PDA per dispute - consolidates bonds and stakes in one place.",
+        "",
+        "Zero-copy: resolve and every claim touch the escrow, so it is read in place",
+        "instead of being Borsh-deserialized on each instruction. Fields keep their",
+        "original order; explicit padding after the one-byte fields aligns created_at.",
+        "That padding makes the account 3 bytes larger than the Borsh layout (136 vs 133);",
+        "migrate_account grows older escrows. The compute saving has not been benchmarked."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "Associated dispute account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "subject",
+            "docs": [
+              "Associated subject account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalBonds",
+            "docs": [
+              "Total challenger bonds deposited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalStakes",
+            "docs": [
+              "Total defender stakes deposited (from subject + pool)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondsClaimed",
+            "docs": [
+              "Bonds withdrawn via challenger claims"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakesClaimed",
+            "docs": [
+              "Stakes withdrawn via defender claims"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "jurorRewardsPaid",
+            "docs": [
+              "Rewards paid to jurors"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "platformFeePaid",
+            "docs": [
+              "Platform fee sent to treasury"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "challengersClaimed",
+            "docs": [
+              "Number of challengers who have claimed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "defendersClaimed",
+            "docs": [
+              "Number of defenders who have claimed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expectedChallengers",
+            "docs": [
+              "Expected number of challengers (for close validation)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expectedDefenders",
+            "docs": [
+              "Expected number of defenders (snapshot at dispute creation)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (0 = created before versioning)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "docs": [
+              "Explicit padding to 8-byte alignment"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                2
+              ]
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "stakeStillLocked",
-      "msg": "Stake still locked"
+      "name": "disputeResolvedEvent",
+      "docs": [
+        "Emitted when a dispute or appeal resolves, with the subject's updated track record",
+        "dominant_type is the dispute type backed by the most challenger bond"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "subject",
+            "type": "pubkey"
+          },
+          {
+            "name": "outcome",
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "dominantType",
+            "type": {
+              "defined": {
+                "name": "disputeType"
+              }
+            }
+          },
+          {
+            "name": "isAppeal",
+            "type": "bool"
+          },
+          {
+            "name": "disputesWon",
+            "type": "u32"
+          },
+          {
+            "name": "disputesLost",
+            "type": "u32"
+          },
+          {
+            "name": "restorationsWon",
+            "type": "u32"
+          },
+          {
+            "name": "restorationsLost",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "stakeAlreadyUnlocked",
-      "msg": "Stake already unlocked"
+      "name": "disputeStatus",
+      "docs": [
+        "Dispute status"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "pending"
+          },
+          {
+            "name": "resolved"
+          },
+          {
+            "name": "resolving"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "bondBelowMinimum",
-      "msg": "Bond amount below minimum"
+      "name": "disputeTimedOutEvent",
+      "docs": [
+        "Emitted when a never-resolved dispute is refunded by timeout_refund"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "subject",
+            "type": "pubkey"
+          },
+          {
+            "name": "isAppeal",
+            "type": "bool"
+          },
+          {
+            "name": "totalBond",
+            "type": "u64"
+          },
+          {
+            "name": "totalStake",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "bondExceedsAvailable",
-      "msg": "Bond exceeds staker's available pool"
+      "name": "disputeType",
+      "docs": [
+        "Dispute type (generic categories)"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "other"
+          },
+          {
+            "name": "breach"
+          },
+          {
+            "name": "fraud"
+          },
+          {
+            "name": "qualityDispute"
+          },
+          {
+            "name": "nonDelivery"
+          },
+          {
+            "name": "misrepresentation"
+          },
+          {
+            "name": "policyViolation"
+          },
+          {
+            "name": "damagesClaim"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "subjectCannotBeStaked",
-      "msg": "Subject cannot accept stakes"
+      "name": "epochAssignment",
+      "docs": [
+        "A dispute assigned to an enrolled juror during an epoch"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "docs": [
+              "Epoch the assignment counts toward"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "dispute",
+            "docs": [
+              "Assigned dispute"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "docs": [
+              "Assigned juror's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "served",
+            "docs": [
+              "Juror voted on the dispute (recorded by record_epoch_service)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "assignedAt",
+            "docs": [
+              "Assignment timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "subjectCannotBeDisputed",
-      "msg": "Subject cannot be disputed"
+      "name": "epochEnrollment",
+      "docs": [
+        "A juror's enrollment in an epoch"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "docs": [
+              "Epoch enrolled in"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "docs": [
+              "Juror's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "committedStake",
+            "docs": [
+              "Stake committed for the term (not withdrawable until it ends)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "assigned",
+            "docs": [
+              "Disputes assigned to the juror during the term"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "served",
+            "docs": [
+              "Assigned disputes the juror voted on"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "rewardClaimed",
+            "docs": [
+              "Whether the epoch reward has been claimed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enrolledAt",
+            "docs": [
+              "Enrollment timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "subjectCannotBeAppealed",
-      "msg": "Subject cannot be appealed"
+      "name": "epochRewardClaimedEvent",
+      "docs": [
+        "Emitted when a juror claims their epoch reward"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "type": "pubkey"
+          },
+          {
+            "name": "assigned",
+            "type": "u16"
+          },
+          {
+            "name": "served",
+            "type": "u16"
+          },
+          {
+            "name": "reward",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "appealStakeBelowMinimum",
-      "msg": "Appeal stake below minimum (must match previous dispute total)"
+      "name": "evidenceAck",
+      "docs": [
+        "Juror's acknowledgment of a dispute's evidence (one PDA per dispute per juror)",
+        "Required before voting when the dispute opener set a review delay",
+        "Closed by the juror via close_evidence_ack once the dispute stops taking votes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "The dispute whose evidence was acknowledged"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "docs": [
+              "Juror's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "acknowledgedAt",
+            "docs": [
+              "Acknowledgment timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "cannotSelfDispute",
-      "msg": "Cannot dispute own subject"
+      "name": "evidenceAcknowledgedEvent",
+      "docs": [
+        "Emitted when a juror acknowledges a dispute's evidence ahead of voting"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "type": "pubkey"
+          },
+          {
+            "name": "votableAt",
+            "type": "i64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
-    {
-      "code": 6014,
-      "name": "disputeAlreadyExists",
-      "msg": "Dispute already exists for this subject"
+    {
+      "name": "evidenceEntry",
+      "docs": [
+        "Single evidence entry appended during the voting period"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submitter",
+            "docs": [
+              "Wallet that submitted the evidence"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "side",
+            "docs": [
+              "Side the submitter is on"
+            ],
+            "type": {
+              "defined": {
+                "name": "evidenceSide"
+              }
+            }
+          },
+          {
+            "name": "cid",
+            "docs": [
+              "Evidence CID (IPFS/Arweave)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "submittedAt",
+            "docs": [
+              "Submission timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "disputeNotFound",
-      "msg": "Dispute not found"
+      "name": "evidenceLog",
+      "docs": [
+        "Append-only evidence log for a dispute (one PDA per dispute)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "The dispute this log belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "docs": [
+              "Evidence entries in submission order"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "evidenceEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "disputeAlreadyResolved",
-      "msg": "Dispute already resolved"
+      "name": "evidenceSide",
+      "docs": [
+        "Which side of the dispute submitted evidence"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "challenger"
+          },
+          {
+            "name": "defender"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "votingNotEnded",
-      "msg": "Voting period not ended"
+      "name": "evidenceSubmittedEvent",
+      "docs": [
+        "Emitted when a party appends evidence to a dispute"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "submitter",
+            "type": "pubkey"
+          },
+          {
+            "name": "side",
+            "type": {
+              "defined": {
+                "name": "evidenceSide"
+              }
+            }
+          },
+          {
+            "name": "cid",
+            "type": "string"
+          },
+          {
+            "name": "index",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "votingEnded",
-      "msg": "Voting period has ended"
+      "name": "expiryEntry",
+      "docs": [
+        "A resolved round whose escrow is still open"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "Resolved dispute (close_escrow takes it with its subject)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "escrow",
+            "docs": [
+              "The dispute's escrow PDA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "sweepAfter",
+            "docs": [
+              "When close_escrow stops waiting for juror claims and boost refunds (resolved_at + JUROR_CLAIM_DEADLINE)",
+              "The escrow can be swept earlier once every claim is in"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "cannotVoteOnOwnDispute",
-      "msg": "Cannot vote on own dispute"
+      "name": "expiryQueue",
+      "docs": [
+        "Per-config list of resolved rounds with open escrows, for keepers",
+        "Entries are added at resolution and removed when the escrow is closed, so keepers",
+        "find claimable and sweepable rounds without scanning every escrow. Like ProtocolStats",
+        "it never blocks an instruction: a full queue skips the entry and counts it in `dropped`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "docs": [
+              "Open rounds, unordered (keepers sort by sweep_after)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "expiryEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "dropped",
+            "docs": [
+              "Rounds not tracked because the queue was full"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Last update timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Config instance whose rounds this queue tracks (PDA seed)"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "alreadyVoted",
-      "msg": "Already voted on this dispute"
+      "name": "jurorAccount",
+      "docs": [
+        "Juror (arbiter) account - global per wallet",
+        "",
+        "Balance Model:",
+        "- `total_stake`: Total SOL held in this PDA (actual lamports)",
+        "- `available_stake`: SOL available to vote or withdraw",
+        "- Held (locked): `total_stake - available_stake` (locked in active disputes)",
+        "- `locked_stake` / `active_votes`: running summary of vote locks (locked_stake == held)",
+        "",
+        "SOL only transfers on deposit/withdraw. Voting is accounting only.",
+        "",
+        "Stake Age:",
+        "- Deposits are tracked as tranches (oldest first) so fresh stake votes with less power",
+        "- Stake not covered by tranches (rewards, pre-tranche stake, merged-out deposits) is mature"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "juror",
+            "docs": [
+              "Juror's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalStake",
+            "docs": [
+              "Total stake held in this PDA (actual lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "availableStake",
+            "docs": [
+              "Available stake (not locked in active disputes)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reputation",
+            "docs": [
+              "Reputation score (basis points, 0-10000+)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "votesCast",
+            "docs": [
+              "Total votes cast"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "correctVotes",
+            "docs": [
+              "Correct votes (aligned with outcome)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isActive",
+            "docs": [
+              "Whether juror is active"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "joinedAt",
+            "docs": [
+              "Registration timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastVoteAt",
+            "docs": [
+              "Last activity timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tranches",
+            "docs": [
+              "Recent deposits, oldest first (untracked remainder of total_stake is mature)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "stakeTranche"
+                }
+              }
+            }
+          },
+          {
+            "name": "lockedStake",
+            "docs": [
+              "Stake locked across unreleased votes (always total_stake - available_stake)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "activeVotes",
+            "docs": [
+              "Votes whose stake has not been unlocked yet"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "beneficiary",
+            "docs": [
+              "Wallet that can receive claimed rewards instead of the stake balance (default = none)",
+              "Rewards sent there take the withdrawal slash, burned like a wallet claim's"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "epochCommitment",
+            "docs": [
+              "Stake committed to a juror epoch (see EpochEnrollment)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "committedUntil",
+            "docs": [
+              "The commitment holds until this time (end of the epoch)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "voteAllocationBelowMinimum",
-      "msg": "Vote allocation below minimum"
+      "name": "jurorBoost",
+      "docs": [
+        "One booster's juror incentive deposit on a dispute",
+        "Lamports sit in the dispute escrow; the record lets the booster refund",
+        "them if the dispute resolves without votes"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "Boosted dispute"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "booster",
+            "docs": [
+              "Wallet that deposited the incentive"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports deposited"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "First deposit timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidVoteChoice",
-      "msg": "Invalid vote choice"
+      "name": "jurorEpoch",
+      "docs": [
+        "A juror epoch: a term jurors commit stake to in exchange for a base reward",
+        "Funded with lamports (a share of platform fees, see set_fee_epoch) held in this PDA and shared by",
+        "committed stake; jurors who skip assigned disputes lose part of their share"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Protocol config the epoch belongs to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "Sequential epoch index within the config"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "startsAt",
+            "docs": [
+              "Enrollment closes and the term starts"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endsAt",
+            "docs": [
+              "Term ends; rewards become claimable"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "rewardPool",
+            "docs": [
+              "Base reward routed from platform fees at resolution or deposited by fund_juror_epoch"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardsPaid",
+            "docs": [
+              "Rewards paid to jurors so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalCommitted",
+            "docs": [
+              "Stake committed by all enrolled jurors"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "jurorCount",
+            "docs": [
+              "Number of enrolled jurors"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "claimedCount",
+            "docs": [
+              "Number of jurors who claimed their reward"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "missPenaltyBps",
+            "docs": [
+              "Reward lost per skipped assignment (bps of the juror's share)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "jurorNotActive",
-      "msg": "Juror not active"
+      "name": "lamportsMoveReason",
+      "docs": [
+        "Why lamports moved in a LamportsMovedEvent"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "jurorWithdrawal"
+          },
+          {
+            "name": "poolWithdrawal"
+          },
+          {
+            "name": "stakeHold"
+          },
+          {
+            "name": "platformFee"
+          },
+          {
+            "name": "resolverFee"
+          },
+          {
+            "name": "jurorReward"
+          },
+          {
+            "name": "challengerClaim"
+          },
+          {
+            "name": "defenderClaim"
+          },
+          {
+            "name": "defenderPoolClaim"
+          },
+          {
+            "name": "escrowDust"
+          },
+          {
+            "name": "pledgeActivation"
+          },
+          {
+            "name": "pledgeRefund"
+          },
+          {
+            "name": "boostRefund"
+          },
+          {
+            "name": "yieldDeposit"
+          },
+          {
+            "name": "epochReward"
+          },
+          {
+            "name": "treasuryWithdrawal"
+          },
+          {
+            "name": "sweepBounty"
+          },
+          {
+            "name": "reviveFee"
+          },
+          {
+            "name": "jurorSlash"
+          },
+          {
+            "name": "reviveBond"
+          },
+          {
+            "name": "voidRefund"
+          },
+          {
+            "name": "pledgeClaim"
+          },
+          {
+            "name": "epochFee"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "jurorAlreadyRegistered",
-      "msg": "Juror already registered"
+      "name": "lamportsMovedEvent",
+      "docs": [
+        "Emitted for every direct lamport mutation (see utils::transfer_lamports)",
+        "System-program transfers are visible in transaction logs and are not repeated here"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "from",
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "lamportsMoveReason"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "challengerNotFound",
-      "msg": "Challenger not found"
+      "name": "oppositionRecord",
+      "docs": [
+        "Stake placed against a restoration (appeal) by oppose_restore",
+        "The defender side of an appeal: wins when the restoration is rejected"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "Appeal this stake opposes"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "opposer",
+            "docs": [
+              "Opposer's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "Lamports staked against the restoration"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardClaimed",
+            "docs": [
+              "Whether the payout has been claimed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "opposedAt",
+            "docs": [
+              "First stake timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "rewardAlreadyClaimed",
-      "msg": "Reward already claimed"
+      "name": "poolDefendedEvent",
+      "docs": [
+        "Emitted when a defender pool tops up the defense of a pending dispute"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "defenderPool",
+            "type": "pubkey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Pool owner or delegate who committed the stake"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "stakeHeld",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6027,
-      "name": "notEligibleForReward",
-      "msg": "Not eligible for reward"
+      "name": "poolDelegate",
+      "docs": [
+        "Wallet authorized by a pool owner to link subjects to their defender pool",
+        "Each link commits the subject's max_stake (its per-dispute pull limit) against the cap",
+        "for as long as the link lasts; stake committed via defend_from_pool is given back",
+        "when the defender claim for that dispute settles"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Defender pool this delegate can commit"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "docs": [
+              "Delegate's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "spendingCap",
+            "docs": [
+              "Max cumulative max_stake the delegate can link to the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "committed",
+            "docs": [
+              "Linked subjects' max_stake plus defend_from_pool stake not yet settled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "reputationAlreadyProcessed",
-      "msg": "Reputation already processed"
+      "name": "protocolConfig",
+      "docs": [
+        "Protocol-wide configuration account",
+        "Stores treasury address and admin authority for fee collection"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Admin who can update config (deployer initially)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Platform fee recipient address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "subjectCreatorGate",
+            "docs": [
+              "Creator allowlist PDA enforced on subject creation (None = anyone can create)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "minJurorShareBps",
+            "docs": [
+              "Bounds for per-subject juror share of fees (bps)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxJurorShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "minWinnerShareBps",
+            "docs": [
+              "Bounds for per-subject winner share of loser's contribution (bps)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxWinnerShareBps",
+            "type": "u16"
+          },
+          {
+            "name": "stakeMaturityPeriod",
+            "docs": [
+              "Seconds for new juror stake to reach full voting weight (0 = no stake-age weighting)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "maxVoteAllocationBps",
+            "docs": [
+              "Max stake a juror can allocate to one dispute (bps of their total stake)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxLockedBps",
+            "docs": [
+              "Max stake a juror can have locked across all disputes (bps of their total stake)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "resolverFeeBps",
+            "docs": [
+              "Resolver fee (bps of the dispute's total pool), paid out of the platform fee"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxResolverFee",
+            "docs": [
+              "Max resolver fee per resolution (lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "defaultDisputeCooldown",
+            "docs": [
+              "Default cooldown after a defender win, copied to new subjects (seconds)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cooldownEscalationBps",
+            "docs": [
+              "Bond needed during cooldown (bps of the last dispute's total)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "validateCids",
+            "docs": [
+              "Reject CID inputs (details, rationale, evidence, category metadata, void reasons) that are not IPFS CIDv0/v1 or Arweave ids"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rebateTiers",
+            "docs": [
+              "Juror fee rebate tiers, ascending by reputation (all zero = rebates off)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "rebateTier"
+                  }
+                },
+                3
+              ]
+            }
+          },
+          {
+            "name": "lateJoinCutoffBps",
+            "docs": [
+              "Final share of the voting window (bps) in which add_to_dispute is closed (0 = off)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "yieldStakePool",
+            "docs": [
+              "SPL stake pool defender pools may delegate idle stake to (default = yield off)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "maxYieldBps",
+            "docs": [
+              "Max share of a defender pool's stake (bps) delegated to the stake pool"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "minVoteAllocation",
+            "docs": [
+              "Minimum stake per vote (lamports, 0 = off)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minVoteAllocationBps",
+            "docs": [
+              "Minimum stake per vote (bps of the juror's total stake, 0 = off)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "sweepBountyMin",
+            "docs": [
+              "Sweep bounty floor (lamports), never more than the dust swept"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sweepBountyBaseBps",
+            "docs": [
+              "Sweep bounty at resolution (bps of the dust)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "sweepBountyBpsPerDay",
+            "docs": [
+              "Sweep bounty growth per full day since resolution (bps of the dust)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "sweepBountyMax",
+            "docs": [
+              "Max sweep bounty per escrow (lamports)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (0 = original 73-byte global config, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "epochFeeBps",
+            "docs": [
+              "Share of the treasury fee (bps) routed into the fee epoch at resolution (0 = off)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeEpoch",
+            "docs": [
+              "Juror epoch receiving the fee share (default = none)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "feeEpochEndsAt",
+            "docs": [
+              "fee_epoch's end; resolutions after it keep the whole fee in treasury"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6029,
-      "name": "arithmeticOverflow",
-      "msg": "Arithmetic overflow"
+      "name": "protocolStats",
+      "docs": [
+        "Protocol-wide running totals for dashboards - best-effort, not authoritative",
+        "Fixed-size counters only, so clients can read fields at fixed offsets",
+        "Counters saturate rather than fail - stats never block an instruction",
+        "Optional on every instruction that updates it: one global writable account would",
+        "serialize all protocol throughput, so totals only count transactions that passed it",
+        "and undercount otherwise. Exact figures come from indexing the program's events"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalSubjects",
+            "docs": [
+              "Subjects created (all modes)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalDisputes",
+            "docs": [
+              "Disputes submitted (excluding appeals)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalAppeals",
+            "docs": [
+              "Appeals submitted"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "challengerWins",
+            "docs": [
+              "Resolved disputes/appeals where the challenger (or appellant) won"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "defenderWins",
+            "docs": [
+              "Resolved disputes/appeals where the defender won"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "noParticipation",
+            "docs": [
+              "Resolved disputes/appeals with no votes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalVolume",
+            "docs": [
+              "Lamports escrowed (bonds + stakes) across resolved disputes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFeesCollected",
+            "docs": [
+              "Platform fees sent to treasury"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalResolverFees",
+            "docs": [
+              "Resolver fees paid to whoever settled a dispute"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paidResolutions",
+            "docs": [
+              "Resolutions that paid a resolver fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Last update timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6030,
-      "name": "divisionByZero",
-      "msg": "Division by zero"
+      "name": "rationaleRevealedEvent",
+      "docs": [
+        "Emitted when a juror reveals the rationale CID committed at vote time"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "juror",
+            "type": "pubkey"
+          },
+          {
+            "name": "rationaleCid",
+            "type": "string"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6031,
-      "name": "claimsNotComplete",
-      "msg": "Not all claims have been processed"
-    }
-  ],
-  "types": [
-    {
-      "name": "appealVoteChoice",
+      "name": "rebateTier",
       "docs": [
-        "Vote choice for appeals (separate enum for clearer semantics)"
+        "Platform fee rebate for jurors at or above a reputation level"
       ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "forRestoration"
+            "name": "minReputation",
+            "docs": [
+              "Minimum juror reputation (bps) for this tier"
+            ],
+            "type": "u16"
           },
           {
-            "name": "againstRestoration"
+            "name": "rebateBps",
+            "docs": [
+              "Share of the juror's slice of the treasury fee rebated (bps, 0 = tier unused)"
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "challengerAccount",
+      "name": "resolutionAttestation",
       "docs": [
-        "Challenger account tracking reputation - global per wallet"
+        "Immutable proof of a dispute's outcome for off-chain consumers",
+        "Written once after resolution and never closed, so the outcome stays",
+        "provable after the escrow, dispute and subject accounts are closed"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "challenger",
+            "name": "subject",
             "docs": [
-              "Challenger's wallet address"
+              "Subject account the dispute was raised against"
             ],
             "type": "pubkey"
           },
           {
-            "name": "reputation",
+            "name": "subjectId",
             "docs": [
-              "Reputation score (basis points)"
+              "Subject identifier"
             ],
-            "type": "u16"
+            "type": "pubkey"
           },
           {
-            "name": "disputesSubmitted",
+            "name": "dispute",
             "docs": [
-              "Total disputes submitted"
+              "Attested dispute"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "disputesUpheld",
+            "name": "disputeType",
             "docs": [
-              "Disputes that were upheld (challenger was correct)"
+              "Dispute type"
+            ],
+            "type": {
+              "defined": {
+                "name": "disputeType"
+              }
+            }
+          },
+          {
+            "name": "isAppeal",
+            "docs": [
+              "Whether the dispute was an appeal"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outcome",
+            "docs": [
+              "Final outcome"
+            ],
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "votesFavorWeight",
+            "docs": [
+              "Voting power for \"ForChallenger\" / \"ForRestoration\""
             ],
             "type": "u64"
           },
           {
-            "name": "disputesDismissed",
+            "name": "votesAgainstWeight",
             "docs": [
-              "Disputes that were dismissed (challenger was wrong)"
+              "Voting power for \"ForDefender\" / \"AgainstRestoration\""
             ],
             "type": "u64"
           },
           {
-            "name": "bump",
-            "docs": [
-              "Bump seed for PDA"
-            ],
-            "type": "u8"
+            "name": "voteCount",
+            "docs": [
+              "Number of jurors who voted"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "resolvedAt",
+            "docs": [
+              "Resolution timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "attestedAt",
+            "docs": [
+              "Attestation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "voided",
+            "docs": [
+              "Resolution was voided by the config authority - the outcome must not be acted on"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resolutionAttestedEvent",
+      "docs": [
+        "Emitted when an immutable resolution attestation is written"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "attestation",
+            "type": "pubkey"
+          },
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "outcome",
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "resolutionOutcome",
+      "docs": [
+        "Resolution outcome"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "challengerWins"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "First dispute timestamp"
-            ],
-            "type": "i64"
+            "name": "defenderWins"
           },
           {
-            "name": "lastDisputeAt",
-            "docs": [
-              "Last dispute timestamp"
-            ],
-            "type": "i64"
+            "name": "noParticipation"
           }
         ]
       }
     },
     {
-      "name": "challengerRecord",
+      "name": "resolutionVoid",
       "docs": [
-        "Individual challenger's contribution to a dispute",
-        "Supports cumulative disputes where multiple challengers contribute"
+        "Audit record of an emergency void by the config authority",
+        "Written once by void_resolution and never closed"
       ],
       "type": {
         "kind": "struct",
@@ -2644,184 +13919,219 @@ export type Tribunalcraft = {
           {
             "name": "dispute",
             "docs": [
-              "The dispute this record belongs to"
+              "Voided dispute"
             ],
             "type": "pubkey"
           },
           {
-            "name": "challenger",
+            "name": "subject",
             "docs": [
-              "Challenger's wallet address"
+              "Subject the dispute was raised against"
             ],
             "type": "pubkey"
           },
           {
-            "name": "challengerAccount",
+            "name": "config",
             "docs": [
-              "Challenger account PDA"
+              "Config the subject is bound to"
             ],
             "type": "pubkey"
           },
           {
-            "name": "bond",
+            "name": "authority",
             "docs": [
-              "Bond amount contributed by this challenger"
+              "Config authority that voided the round"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "detailsCid",
+            "name": "voidedOutcome",
             "docs": [
-              "Evidence CID (IPFS hash)"
+              "Outcome the resolution had reached before the void"
             ],
-            "type": "string"
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
           },
           {
-            "name": "rewardClaimed",
+            "name": "isAppeal",
             "docs": [
-              "Whether reward has been claimed"
+              "Whether the dispute was an appeal"
             ],
             "type": "bool"
           },
           {
-            "name": "bump",
+            "name": "reasonCid",
             "docs": [
-              "Bump seed for PDA"
+              "Exploit report CID (IPFS hash)"
             ],
-            "type": "u8"
+            "type": "string"
           },
           {
-            "name": "challengedAt",
+            "name": "feesRestored",
             "docs": [
-              "Timestamp when this challenger joined"
+              "Fees the authority paid back into escrow so every party is refunded in full"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "resolvedAt",
+            "docs": [
+              "Original resolution timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "voidedAt",
+            "docs": [
+              "Void timestamp"
             ],
             "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "defenderPool",
+      "name": "resolutionVoidedEvent",
       "docs": [
-        "Defender's pool that can back multiple subjects - global per wallet"
+        "Emitted when the config authority voids a resolution (see ResolutionVoid)"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
-            "docs": [
-              "Pool owner's wallet address"
-            ],
+            "name": "dispute",
             "type": "pubkey"
           },
           {
-            "name": "totalStake",
-            "docs": [
-              "Total stake deposited"
-            ],
-            "type": "u64"
+            "name": "subject",
+            "type": "pubkey"
           },
           {
-            "name": "available",
-            "docs": [
-              "Available stake (not held by disputes)"
-            ],
-            "type": "u64"
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "held",
-            "docs": [
-              "Held stake (locked by pending disputes)"
-            ],
-            "type": "u64"
+            "name": "voidedOutcome",
+            "type": {
+              "defined": {
+                "name": "resolutionOutcome"
+              }
+            }
           },
           {
-            "name": "subjectCount",
-            "docs": [
-              "Number of subjects linked to this pool"
-            ],
-            "type": "u32"
+            "name": "reasonCid",
+            "type": "string"
           },
           {
-            "name": "pendingDisputes",
-            "docs": [
-              "Number of pending disputes against subjects in this pool"
-            ],
-            "type": "u32"
+            "name": "feesRestored",
+            "type": "u64"
           },
           {
-            "name": "bump",
-            "docs": [
-              "Bump seed for PDA"
-            ],
-            "type": "u8"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "rewardSplit",
+      "docs": [
+        "Per-subject reward split override (bps), bounded by ProtocolConfig"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "createdAt",
+            "name": "jurorShareBps",
             "docs": [
-              "Creation timestamp"
+              "Juror share of fees (remainder goes to platform)"
             ],
-            "type": "i64"
+            "type": "u16"
           },
           {
-            "name": "updatedAt",
+            "name": "winnerShareBps",
             "docs": [
-              "Last update timestamp"
+              "Winner share of loser's contribution"
             ],
-            "type": "i64"
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "defenderRecord",
+      "name": "sideStats",
       "docs": [
-        "Individual defender's contribution to backing a subject",
-        "Supports cumulative staking where multiple defenders back a subject"
+        "Running allocation statistics for one side of a vote",
+        "Everything here can be reversed, so changed and topped-up votes stay exact"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "subject",
+            "name": "jurors",
             "docs": [
-              "The subject this record belongs to"
+              "Jurors currently voting on this side"
             ],
-            "type": "pubkey"
+            "type": "u16"
           },
           {
-            "name": "defender",
+            "name": "totalPower",
             "docs": [
-              "Defender's wallet address"
+              "Sum of voting power (mirrors the dispute tally)"
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "stake",
+            "name": "powerSquares",
             "docs": [
-              "Amount staked to back the subject"
+              "Sum of squared voting power (concentration index numerator)"
             ],
-            "type": "u64"
+            "type": "u128"
           },
           {
-            "name": "rewardClaimed",
+            "name": "buckets",
             "docs": [
-              "Whether reward has been claimed"
+              "Juror count per voting power bucket; bucket i covers [4^i, 4^(i+1))"
             ],
-            "type": "bool"
-          },
+            "type": {
+              "array": [
+                "u16",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeTranche",
+      "docs": [
+        "Stake deposited at one time (for stake-age weighting)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bump",
+            "name": "amount",
             "docs": [
-              "Bump seed for PDA"
+              "Remaining amount from this deposit"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "stakedAt",
+            "name": "depositedAt",
             "docs": [
-              "Timestamp when this defender joined"
+              "Deposit timestamp"
             ],
             "type": "i64"
           }
@@ -2829,129 +14139,100 @@ export type Tribunalcraft = {
       }
     },
     {
-      "name": "dispute",
+      "name": "subject",
       "docs": [
-        "Dispute (supports cumulative challengers)"
+        "Subject that defenders back - global (identified by subject_id)"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "subject",
+            "name": "subjectId",
             "docs": [
-              "Subject account being disputed"
+              "Subject identifier (could be PDA from external program)"
             ],
             "type": "pubkey"
           },
           {
-            "name": "disputeType",
-            "docs": [
-              "Dispute type"
-            ],
-            "type": {
-              "defined": {
-                "name": "disputeType"
-              }
-            }
-          },
-          {
-            "name": "totalBond",
-            "docs": [
-              "Total bond from all challengers (cumulative)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "stakeHeld",
-            "docs": [
-              "Stake held from pool (match mode, linked subjects)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "directStakeHeld",
+            "name": "defenderPool",
             "docs": [
-              "Stake held from direct stakers on subject (match mode)"
+              "Optional defender pool (default = standalone mode, set = linked to pool)"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "challengerCount",
+            "name": "detailsCid",
             "docs": [
-              "Number of challengers who contributed"
+              "Details/metadata CID (IPFS/Arweave) - context provided by first staker"
             ],
-            "type": "u16"
+            "type": "string"
           },
           {
             "name": "status",
             "docs": [
-              "Dispute status"
+              "Current status"
             ],
             "type": {
               "defined": {
-                "name": "disputeStatus"
+                "name": "subjectStatus"
               }
             }
           },
           {
-            "name": "outcome",
+            "name": "totalStake",
             "docs": [
-              "Resolution outcome"
+              "Total stake backing this subject (standalone mode only)"
             ],
-            "type": {
-              "defined": {
-                "name": "resolutionOutcome"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "votesFavorWeight",
+            "name": "maxStake",
             "docs": [
-              "Cumulative voting power for \"ForChallenger\" votes"
+              "Max stake at risk per dispute (for match mode)"
             ],
             "type": "u64"
           },
           {
-            "name": "votesAgainstWeight",
+            "name": "votingPeriod",
             "docs": [
-              "Cumulative voting power for \"ForDefender\" votes"
+              "Voting period in seconds for this subject's disputes"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "voteCount",
+            "name": "defenderCount",
             "docs": [
-              "Number of jurors who voted"
+              "Number of defenders (standalone mode only)"
             ],
             "type": "u16"
           },
           {
-            "name": "votingStarted",
+            "name": "disputeCount",
             "docs": [
-              "Whether voting has started (match mode waits for matching)"
+              "Number of disputes (for sequential dispute PDAs)"
             ],
-            "type": "bool"
+            "type": "u32"
           },
           {
-            "name": "votingStartsAt",
+            "name": "matchMode",
             "docs": [
-              "Voting start timestamp (0 if not started)"
+              "Match mode: true = bond must match stake, false = proportionate"
             ],
-            "type": "i64"
+            "type": "bool"
           },
           {
-            "name": "votingEndsAt",
+            "name": "freeCase",
             "docs": [
-              "Voting end timestamp (0 if not started)"
+              "Free case mode: no stake/bond required, no rewards, no reputation impact"
             ],
-            "type": "i64"
+            "type": "bool"
           },
           {
-            "name": "resolvedAt",
+            "name": "dispute",
             "docs": [
-              "Resolution timestamp"
+              "Current active dispute (if any)"
             ],
-            "type": "i64"
+            "type": "pubkey"
           },
           {
             "name": "bump",
@@ -2968,161 +14249,174 @@ export type Tribunalcraft = {
             "type": "i64"
           },
           {
-            "name": "poolRewardClaimed",
+            "name": "updatedAt",
             "docs": [
-              "Pool reward claimed (for linked mode)"
+              "Last update timestamp"
             ],
-            "type": "bool"
+            "type": "i64"
           },
           {
-            "name": "snapshotTotalStake",
+            "name": "lastDisputeTotal",
             "docs": [
-              "Snapshot of subject's total_stake at dispute creation"
+              "Previous dispute's (stake + bond) - minimum stake required for appeal"
             ],
             "type": "u64"
           },
           {
-            "name": "snapshotDefenderCount",
+            "name": "lastVotingPeriod",
             "docs": [
-              "Snapshot of subject's defender_count at dispute creation"
+              "Previous dispute's voting period - appeals use 2x this value"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "categoryId",
+            "docs": [
+              "Platform category id from the CategoryRegistry (0 = uncategorized)"
             ],
             "type": "u16"
           },
           {
-            "name": "challengersClaimed",
+            "name": "jurorShareBps",
             "docs": [
-              "Number of challengers who have claimed their reward/refund"
+              "Juror share of fees (bps) used at resolution"
             ],
             "type": "u16"
           },
           {
-            "name": "defendersClaimed",
+            "name": "winnerShareBps",
             "docs": [
-              "Number of direct defenders who have claimed their reward/refund"
+              "Winner share of loser's contribution (bps) used at resolution"
             ],
             "type": "u16"
           },
           {
-            "name": "isAppeal",
+            "name": "version",
             "docs": [
-              "True if this dispute is an appeal (reverses the meaning of outcomes)"
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Wallet that created the subject (receives rent on close_subject)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "rationaleRequired",
+            "docs": [
+              "Jurors must commit a rationale hash when voting and reveal it before claiming"
             ],
             "type": "bool"
           },
           {
-            "name": "appealStake",
+            "name": "pledgeThreshold",
             "docs": [
-              "Stake posted by appellant (for appeals only)"
+              "Pledged bond total that activates a pledged dispute (0 = pledging disabled)"
             ],
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "disputeEscrow",
-      "docs": [
-        "DisputeEscrow holds all funds for a single dispute.",
-        "One PDA per dispute - consolidates bonds and stakes in one place."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "dispute",
+            "name": "autoRevalidate",
             "docs": [
-              "Associated dispute account"
+              "After a defender win: true = back to Active, false = Dormant until restaked"
             ],
-            "type": "pubkey"
+            "type": "bool"
           },
           {
-            "name": "subject",
+            "name": "disputeCooldown",
             "docs": [
-              "Associated subject account"
+              "Cooldown after a defender win (seconds, from ProtocolConfig default at creation)"
             ],
-            "type": "pubkey"
+            "type": "i64"
           },
           {
-            "name": "totalBonds",
+            "name": "cooldownEndsAt",
             "docs": [
-              "Total challenger bonds deposited"
+              "New disputes need an escalated bond until this time"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "totalStakes",
+            "name": "tiePolicy",
             "docs": [
-              "Total defender stakes deposited (from subject + pool)"
+              "How tied votes resolve"
             ],
-            "type": "u64"
+            "type": {
+              "defined": {
+                "name": "tiePolicy"
+              }
+            }
           },
           {
-            "name": "bondsClaimed",
+            "name": "tieExtension",
             "docs": [
-              "Bonds withdrawn via challenger claims"
+              "Voting extension for TiePolicy::ExtendVoting (seconds)"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "stakesClaimed",
+            "name": "disputesWon",
             "docs": [
-              "Stakes withdrawn via defender claims"
+              "Disputes the defenders won"
             ],
-            "type": "u64"
+            "type": "u32"
           },
           {
-            "name": "jurorRewardsPaid",
+            "name": "disputesLost",
             "docs": [
-              "Rewards paid to jurors"
+              "Disputes the challengers won (subject invalidated)"
             ],
-            "type": "u64"
+            "type": "u32"
           },
           {
-            "name": "platformFeePaid",
+            "name": "restorationsWon",
             "docs": [
-              "Platform fee sent to treasury"
+              "Appeals that restored the subject"
             ],
-            "type": "u64"
+            "type": "u32"
           },
           {
-            "name": "challengersClaimed",
+            "name": "restorationsLost",
             "docs": [
-              "Number of challengers who have claimed"
+              "Appeals that left the subject invalidated"
             ],
-            "type": "u8"
+            "type": "u32"
           },
           {
-            "name": "defendersClaimed",
+            "name": "config",
             "docs": [
-              "Number of defenders who have claimed"
+              "Protocol instance (ProtocolConfig) whose fees, treasury and rules apply"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "expectedChallengers",
+            "name": "maxJurorWeightBps",
             "docs": [
-              "Expected number of challengers (for close validation)"
+              "Max share of total voting weight one juror's vote counts for at resolution (bps, 0 = uncapped)"
             ],
-            "type": "u8"
+            "type": "u16"
           },
           {
-            "name": "expectedDefenders",
+            "name": "gradedVerdicts",
             "docs": [
-              "Expected number of defenders (snapshot at dispute creation)"
+              "Disputes are decided by graded votes (stake-weighted median slash) instead of a binary verdict"
             ],
-            "type": "u8"
+            "type": "bool"
           },
           {
-            "name": "bump",
+            "name": "frozenBy",
             "docs": [
-              "PDA bump"
+              "Who froze the subject for a legal hold (creator or config authority, default = not frozen)"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
+            "name": "frozenAt",
             "docs": [
-              "Creation timestamp"
+              "When the current (or last) freeze started; kept after unfreeze for the creator cooldown"
             ],
             "type": "i64"
           }
@@ -3130,120 +14424,99 @@ export type Tribunalcraft = {
       }
     },
     {
-      "name": "disputeStatus",
+      "name": "subjectClosedEvent",
       "docs": [
-        "Dispute status"
+        "Emitted when an invalidated subject and its last dispute/escrow are closed"
       ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pending"
+            "name": "subject",
+            "type": "pubkey"
           },
           {
-            "name": "resolved"
+            "name": "subjectId",
+            "type": "pubkey"
+          },
+          {
+            "name": "dispute",
+            "type": "pubkey"
+          },
+          {
+            "name": "closer",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "disputeType",
+      "name": "subjectFreezeChangedEvent",
       "docs": [
-        "Dispute type (generic categories)"
+        "Emitted when a subject is frozen or unfrozen for a legal hold"
       ],
       "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "other"
-          },
-          {
-            "name": "breach"
-          },
-          {
-            "name": "fraud"
-          },
+        "kind": "struct",
+        "fields": [
           {
-            "name": "qualityDispute"
+            "name": "subject",
+            "type": "pubkey"
           },
           {
-            "name": "nonDelivery"
+            "name": "by",
+            "type": "pubkey"
           },
           {
-            "name": "misrepresentation"
+            "name": "frozen",
+            "type": "bool"
           },
           {
-            "name": "policyViolation"
+            "name": "hasActiveDispute",
+            "type": "bool"
           },
           {
-            "name": "damagesClaim"
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "jurorAccount",
+      "name": "subjectIndex",
       "docs": [
-        "Juror (arbiter) account - global per wallet",
-        "",
-        "Balance Model:",
-        "- `total_stake`: Total SOL held in this PDA (actual lamports)",
-        "- `available_stake`: SOL available to vote or withdraw",
-        "- Held (locked): `total_stake - available_stake` (locked in active disputes)",
-        "",
-        "SOL only transfers on deposit/withdraw. Voting is accounting only."
+        "One page of a creator's subject list (append-only, filled in page order)",
+        "Clients append to the first page that is not full and enumerate pages from 0",
+        "until one is missing; a page only opens once the previous one is full. Closed subjects stay listed (see SubjectTombstone)."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "juror",
+            "name": "creator",
             "docs": [
-              "Juror's wallet address"
+              "Creator whose subjects are listed"
             ],
             "type": "pubkey"
           },
           {
-            "name": "totalStake",
-            "docs": [
-              "Total stake held in this PDA (actual lamports)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "availableStake",
-            "docs": [
-              "Available stake (not locked in active disputes)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "reputation",
-            "docs": [
-              "Reputation score (basis points, 0-10000+)"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "votesCast",
-            "docs": [
-              "Total votes cast"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "correctVotes",
+            "name": "page",
             "docs": [
-              "Correct votes (aligned with outcome)"
+              "Page number (0-based)"
             ],
-            "type": "u64"
+            "type": "u32"
           },
           {
-            "name": "isActive",
+            "name": "subjectIds",
             "docs": [
-              "Whether juror is active"
+              "Subject ids in creation order"
             ],
-            "type": "bool"
+            "type": {
+              "vec": "pubkey"
+            }
           },
           {
             "name": "bump",
@@ -3251,51 +14524,61 @@ export type Tribunalcraft = {
               "Bump seed for PDA"
             ],
             "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "subjectStatus",
+      "docs": [
+        "Subject status"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
           },
           {
-            "name": "joinedAt",
-            "docs": [
-              "Registration timestamp"
-            ],
-            "type": "i64"
+            "name": "disputed"
           },
           {
-            "name": "lastVoteAt",
-            "docs": [
-              "Last activity timestamp"
-            ],
-            "type": "i64"
+            "name": "invalidated"
+          },
+          {
+            "name": "dormant"
           }
         ]
       }
     },
     {
-      "name": "protocolConfig",
+      "name": "subjectTombstone",
       "docs": [
-        "Protocol-wide configuration account",
-        "Stores treasury address and admin authority for fee collection"
+        "Marker left behind when a subject is closed",
+        "Blocks re-creating the subject at the same PDA, which would let old",
+        "dispute/vote/defender records alias the new subject's accounts"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "subjectId",
             "docs": [
-              "Admin who can update config (deployer initially)"
+              "Closed subject's identifier"
             ],
             "type": "pubkey"
           },
           {
-            "name": "treasury",
+            "name": "closedAt",
             "docs": [
-              "Platform fee recipient address"
+              "Close timestamp"
             ],
-            "type": "pubkey"
+            "type": "i64"
           },
           {
             "name": "bump",
             "docs": [
-              "PDA bump seed"
+              "Bump seed for PDA"
             ],
             "type": "u8"
           }
@@ -3303,178 +14586,187 @@ export type Tribunalcraft = {
       }
     },
     {
-      "name": "resolutionOutcome",
+      "name": "tiePolicy",
       "docs": [
-        "Resolution outcome"
+        "How a dispute with equal favor/against weight resolves"
       ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "none"
+            "name": "defenderWins"
           },
           {
             "name": "challengerWins"
           },
           {
-            "name": "defenderWins"
-          },
-          {
-            "name": "noParticipation"
+            "name": "extendVoting"
           }
         ]
       }
     },
     {
-      "name": "subject",
+      "name": "tieVotingExtendedEvent",
       "docs": [
-        "Subject that defenders back - global (identified by subject_id)"
+        "Emitted when a tied vote reopens voting under TiePolicy::ExtendVoting"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "subjectId",
-            "docs": [
-              "Subject identifier (could be PDA from external program)"
-            ],
+            "name": "dispute",
             "type": "pubkey"
           },
           {
-            "name": "defenderPool",
-            "docs": [
-              "Optional defender pool (default = standalone mode, set = linked to pool)"
-            ],
+            "name": "subject",
             "type": "pubkey"
           },
           {
-            "name": "detailsCid",
-            "docs": [
-              "Details/metadata CID (IPFS/Arweave) - context provided by first staker"
-            ],
-            "type": "string"
+            "name": "votingEndsAt",
+            "type": "i64"
           },
           {
-            "name": "status",
-            "docs": [
-              "Current status"
-            ],
-            "type": {
-              "defined": {
-                "name": "subjectStatus"
-              }
-            }
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasury",
+      "docs": [
+        "Program-owned treasury for one protocol config, with running totals",
+        "Opt-in: initialize_treasury points ProtocolConfig::treasury at this PDA. Fee and sweep",
+        "transfers credit total_collected whenever the configured treasury is this account;",
+        "a plain wallet treasury keeps receiving lamports without accounting"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "totalStake",
+            "name": "config",
             "docs": [
-              "Total stake backing this subject (standalone mode only)"
+              "Config this treasury belongs to (its authority withdraws)"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "maxStake",
+            "name": "totalCollected",
             "docs": [
-              "Max stake at risk per dispute (for match mode)"
+              "Lamports received from fees, escrow sweeps and closed epochs"
             ],
             "type": "u64"
           },
           {
-            "name": "votingPeriod",
-            "docs": [
-              "Voting period in seconds for this subject's disputes"
-            ],
-            "type": "i64"
-          },
-          {
-            "name": "defenderCount",
+            "name": "totalWithdrawn",
             "docs": [
-              "Number of defenders (standalone mode only)"
+              "Lamports withdrawn by the config authority"
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "disputeCount",
+            "name": "bump",
             "docs": [
-              "Number of disputes (for sequential dispute PDAs)"
+              "Bump seed for PDA"
             ],
-            "type": "u32"
+            "type": "u8"
           },
           {
-            "name": "matchMode",
+            "name": "updatedAt",
             "docs": [
-              "Match mode: true = bond must match stake, false = proportionate"
+              "Last update timestamp"
             ],
-            "type": "bool"
-          },
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteCastEvent",
+      "docs": [
+        "Emitted when a juror casts or adds to a vote, with the reward estimate at that point"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "freeCase",
-            "docs": [
-              "Free case mode: no stake/bond required, no rewards, no reputation impact"
-            ],
-            "type": "bool"
+            "name": "dispute",
+            "type": "pubkey"
           },
           {
-            "name": "dispute",
-            "docs": [
-              "Current active dispute (if any)"
-            ],
+            "name": "juror",
             "type": "pubkey"
           },
           {
-            "name": "bump",
-            "docs": [
-              "Bump seed for PDA"
-            ],
-            "type": "u8"
+            "name": "isAppealVote",
+            "type": "bool"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "Creation timestamp"
-            ],
-            "type": "i64"
+            "name": "stakeAllocated",
+            "type": "u64"
           },
           {
-            "name": "updatedAt",
-            "docs": [
-              "Last update timestamp"
-            ],
-            "type": "i64"
+            "name": "votingPower",
+            "type": "u64"
           },
           {
-            "name": "lastDisputeTotal",
-            "docs": [
-              "Previous dispute's (stake + bond) - minimum stake required for appeal"
-            ],
+            "name": "estRewardFloor",
             "type": "u64"
           },
           {
-            "name": "lastVotingPeriod",
-            "docs": [
-              "Previous dispute's voting period - appeals use 2x this value"
-            ],
+            "name": "timestamp",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "subjectStatus",
+      "name": "voteChangedEvent",
       "docs": [
-        "Subject status"
+        "Emitted when a juror flips their vote before voting ends"
       ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "active"
+            "name": "dispute",
+            "type": "pubkey"
           },
           {
-            "name": "disputed"
+            "name": "juror",
+            "type": "pubkey"
           },
           {
-            "name": "invalidated"
+            "name": "isAppealVote",
+            "type": "bool"
+          },
+          {
+            "name": "newChoice",
+            "type": {
+              "defined": {
+                "name": "voteChoice"
+              }
+            }
+          },
+          {
+            "name": "newAppealChoice",
+            "type": {
+              "defined": {
+                "name": "appealVoteChoice"
+              }
+            }
+          },
+          {
+            "name": "oldVotingPower",
+            "type": "u64"
+          },
+          {
+            "name": "newVotingPower",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
@@ -3492,6 +14784,9 @@ export type Tribunalcraft = {
           },
           {
             "name": "forDefender"
+          },
+          {
+            "name": "abstain"
           }
         ]
       }
@@ -3616,6 +14911,132 @@ export type Tribunalcraft = {
               "IPFS CID for vote rationale (optional)"
             ],
             "type": "string"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rationaleHash",
+            "docs": [
+              "keccak256 of the rationale CID committed at vote time (zero = no commitment)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "rationaleRevealed",
+            "docs": [
+              "Whether the committed rationale CID has been revealed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "talliedRound",
+            "docs": [
+              "Capped weight tally round this vote was counted in (Dispute::tally_round + 1, 0 = never)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reputationSnapshot",
+            "docs": [
+              "Juror reputation when the vote was cast (None = pre-v24 record)"
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "grade",
+            "docs": [
+              "Graded disputes only: slash voted for, in GRADE_STEP_BPS steps (0 = none, 4 = full)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reputationDelta",
+            "docs": [
+              "Reputation change applied for this vote, kept so a later void can reverse it"
+            ],
+            "type": "i16"
+          },
+          {
+            "name": "countedCorrect",
+            "docs": [
+              "Whether this vote was counted in the juror's correct_votes"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "estRewardFloor",
+            "docs": [
+              "Reward estimate at the last vote/add_to_vote: fee-pot + incentive share at the totals then",
+              "Excludes fee rebates; later votes dilute it, later bonds/stakes/boosts grow it"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteStats",
+      "docs": [
+        "Optional per-dispute vote statistics (one PDA per dispute)",
+        "Created before the first vote; exact only while Dispute::has_vote_stats is still set"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dispute",
+            "docs": [
+              "The dispute these statistics describe"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "favor",
+            "docs": [
+              "ForChallenger / ForRestoration votes"
+            ],
+            "type": {
+              "defined": {
+                "name": "sideStats"
+              }
+            }
+          },
+          {
+            "name": "against",
+            "docs": [
+              "ForDefender / AgainstRestoration votes"
+            ],
+            "type": {
+              "defined": {
+                "name": "sideStats"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
           }
         ]
       }
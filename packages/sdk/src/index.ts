@@ -23,6 +23,7 @@ export type {
   DisputeType,
   VoteChoice,
   AppealVoteChoice,
+  RewardSplit,
 } from "./types";
 
 // Enum Helpers
@@ -61,6 +62,7 @@ export {
   CHALLENGER_RECORD_SEED,
   DEFENDER_RECORD_SEED,
   VOTE_RECORD_SEED,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
   // Fee Constants
   TOTAL_FEE_BPS,
   PLATFORM_SHARE_BPS,
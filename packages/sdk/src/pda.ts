@@ -1,6 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import {
   PROGRAM_ID,
+  BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
   PROTOCOL_CONFIG_SEED,
   DEFENDER_POOL_SEED,
   SUBJECT_SEED,
@@ -29,6 +30,16 @@ export class PDA {
     );
   }
 
+  /**
+   * Derive the program's program data account (holds the upgrade authority)
+   */
+  programData(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [this.programId.toBuffer()],
+      BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+    );
+  }
+
   /**
    * Derive Defender Pool PDA for an owner
    */
@@ -9,6 +9,9 @@ export interface ProtocolConfig {
   authority: PublicKey;
   treasury: PublicKey;
   bump: number;
+  epochFeeBps: number;
+  feeEpoch: PublicKey;
+  feeEpochEndsAt: BN;
 }
 
 export interface DefenderPool {
@@ -41,6 +44,15 @@ export interface Subject {
   updatedAt: BN;
   lastDisputeTotal: BN;
   lastVotingPeriod: BN;
+  categoryId: number;
+  jurorShareBps: number;
+  winnerShareBps: number;
+}
+
+/** Per-subject override of the fee and winner splits (bps) */
+export interface RewardSplit {
+  jurorShareBps: number;
+  winnerShareBps: number;
 }
 
 export interface Dispute {
@@ -68,6 +80,7 @@ export interface Dispute {
   defendersClaimed: number;
   isAppeal: boolean;
   appealStake: BN;
+  categoryId: number;
 }
 
 export interface DisputeEscrow {
@@ -147,6 +160,11 @@ export interface DefenderRecord {
   rewardClaimed: boolean;
   bump: number;
   stakedAt: BN;
+  poolStake: BN;
+  version: number;
+  payoutPool: PublicKey;
+  poolDelegate: PublicKey;
+  delegateCommitted: BN;
 }
 
 // =============================================================================
@@ -21,9 +21,34 @@ pub const REPUTATION_GAIN_RATE: u16 = 500;
 /// Reputation loss rate on wrong vote (10% of current = 1000 bps)
 pub const REPUTATION_LOSS_RATE: u16 = 1000;
 
+/// Appeal results where the winning side holds less than this share of vote weight (55%)
+/// are narrow: jurors on the losing side of a narrow restoration keep their reputation
+pub const RESTORATION_NARROW_MARGIN_BPS: u16 = 5500;
+
 /// Reputation threshold below which stake is slashed on withdrawal (50% = 5000 bps)
 pub const SLASH_THRESHOLD: u16 = 5000;
 
+/// Voting power lost when a juror changes their vote (10% = 1000 bps)
+pub const VOTE_CHANGE_PENALTY_BPS: u16 = 1000;
+
+/// Share of the requested allocation an abstaining juror locks (50% = 5000 bps)
+pub const ABSTAIN_STAKE_BPS: u16 = 5000;
+
+/// Flat participation fee per abstainer, as a share of the juror pot (1% = 100 bps)
+/// Abstainers together take at most MAX_ABSTAIN_POT_BPS; past that the fee is split evenly
+pub const ABSTAIN_FEE_BPS: u16 = 100;
+pub const MAX_ABSTAIN_POT_BPS: u16 = 1000;
+
+/// Graded verdicts: jurors vote a slash of 0/25/50/75/100% (grade index 0..=4)
+pub const GRADE_STEPS: usize = 5;
+pub const GRADE_STEP_BPS: u16 = 2500;
+
+/// Weight of freshly deposited juror stake (25% = 2500 bps), ramping to 100% at maturity
+pub const FRESH_STAKE_WEIGHT_BPS: u16 = 2500;
+
+/// Default time for juror stake to reach full weight (7 days, configurable in ProtocolConfig)
+pub const DEFAULT_STAKE_MATURITY_PERIOD: i64 = 604_800;
+
 // =============================================================================
 // STAKE UNLOCK BUFFER (Fixed by protocol design)
 // =============================================================================
@@ -32,6 +57,66 @@ pub const SLASH_THRESHOLD: u16 = 5000;
 /// This gives time for resolution and result processing
 pub const STAKE_UNLOCK_BUFFER: i64 = 604_800;
 
+// =============================================================================
+// SUBJECT CLOSURE
+// =============================================================================
+
+/// Time after resolution before the creator can close an invalidated subject (30 days)
+/// Leaves jurors time to unlock stake and claim rewards
+pub const SUBJECT_CLOSE_GRACE: i64 = 2_592_000;
+
+/// Time after resolution before anyone can close an invalidated subject (180 days)
+pub const SUBJECT_CLOSE_TIMEOUT: i64 = 15_552_000;
+
+/// Time after resolution before unclaimed juror rewards can be swept (180 days)
+/// Until then, escrow closes wait for every juror to claim
+pub const JUROR_CLAIM_DEADLINE: i64 = 15_552_000;
+
+/// Time after voting ends before anyone can refund a never-resolved dispute (90 days)
+pub const DISPUTE_REFUND_TIMEOUT: i64 = 7_776_000;
+
+/// Time after resolution during which the config authority can void a corrupted round (2 days)
+pub const RESOLUTION_VOID_WINDOW: i64 = 172_800;
+
+// =============================================================================
+// DISPUTE COOLDOWN
+// =============================================================================
+
+/// Default cooldown after a defender win before plain re-disputes (3 days)
+pub const DEFAULT_DISPUTE_COOLDOWN: i64 = 259_200;
+
+/// Default bond during cooldown (200% of the last dispute's total = 20000 bps)
+pub const DEFAULT_COOLDOWN_ESCALATION_BPS: u16 = 20000;
+
+/// Default share of the voting window, at its end, closed to new challenger bond (20%)
+pub const DEFAULT_LATE_JOIN_CUTOFF_BPS: u16 = 2000;
+
+// =============================================================================
+// EVIDENCE REVIEW
+// =============================================================================
+
+/// Longest review delay a dispute opener can require between acknowledgment and vote (1 day)
+pub const MAX_REVIEW_DELAY: i64 = 86_400;
+
+// =============================================================================
+// CHALLENGE PLEDGES
+// =============================================================================
+
+/// Time a pledge pool stays open for pledges and activation (7 days)
+/// After this pledgers can refund
+pub const PLEDGE_WINDOW: i64 = 604_800;
+
+// =============================================================================
+// SUBJECT FREEZES
+// =============================================================================
+
+/// Longest a creator's own freeze lasts before it lapses (7 days)
+/// Freezes by the config authority last until lifted
+pub const MAX_CREATOR_FREEZE: i64 = 604_800;
+
+/// Time after a creator freeze lapses before the creator can freeze again (7 days)
+pub const CREATOR_FREEZE_COOLDOWN: i64 = 604_800;
+
 // =============================================================================
 // BASE CHALLENGER BOND (Minimum for reputation calculation)
 // =============================================================================
@@ -41,6 +126,9 @@ pub const STAKE_UNLOCK_BUFFER: i64 = 604_800;
 /// Platform can enforce higher requirements at application layer
 pub const BASE_CHALLENGER_BOND: u64 = 10_000_000;
 
+/// Most subjects create_disputes_batch opens in one transaction (6 accounts each)
+pub const MAX_DISPUTE_BATCH: usize = 8;
+
 // =============================================================================
 // FIXED FEE CONSTANTS (Protocol-wide, non-configurable)
 // =============================================================================
@@ -58,10 +146,59 @@ pub const JUROR_SHARE_BPS: u16 = 9500;
 /// Winner share of loser's contribution (80% = 8000 bps)
 pub const WINNER_SHARE_BPS: u16 = 8000;
 
+/// Default bounds for per-subject reward split overrides (admin can change in ProtocolConfig)
+/// Winner share can never exceed 100% - TOTAL_FEE_BPS, or jurors would be underfunded
+pub const DEFAULT_MIN_JUROR_SHARE_BPS: u16 = 5000;
+pub const DEFAULT_MAX_JUROR_SHARE_BPS: u16 = 10000;
+pub const DEFAULT_MIN_WINNER_SHARE_BPS: u16 = 5000;
+pub const DEFAULT_MAX_WINNER_SHARE_BPS: u16 = MAX_BPS - TOTAL_FEE_BPS;
+
+/// Most rounding residue one party claim can leave behind (each payout floors up to 3 terms)
+/// A larger residue means a claim is still outstanding, so nothing is swept
+pub const MAX_DUST_PER_CLAIM: u64 = 4;
+
+/// Default resolver fee (0.1% of total pool = 10 bps), taken from the platform fee
+pub const DEFAULT_RESOLVER_FEE_BPS: u16 = 10;
+
+/// Default cap on the resolver fee per resolution (0.01 SOL)
+pub const DEFAULT_MAX_RESOLVER_FEE: u64 = 10_000_000;
+
+/// Default sweep bounty floor (0.0001 SOL), paid out of escrow dust to whoever closes it
+pub const DEFAULT_SWEEP_BOUNTY_MIN: u64 = 100_000;
+
+/// Default sweep bounty at resolution (1% of the dust = 100 bps)
+pub const DEFAULT_SWEEP_BOUNTY_BASE_BPS: u16 = 100;
+
+/// Default sweep bounty growth per day since resolution (0.5% = 50 bps)
+pub const DEFAULT_SWEEP_BOUNTY_BPS_PER_DAY: u16 = 50;
+
+/// Default cap on the sweep bounty per escrow (0.01 SOL)
+pub const DEFAULT_SWEEP_BOUNTY_MAX: u64 = 10_000_000;
+
+/// Seconds per day, the step of the sweep bounty curve
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Fee paid from the defender pool to whoever revives a dormant linked subject (one signature fee)
+pub const REVIVE_CRANK_FEE: u64 = 5_000;
+
+/// Smallest bond a revival may pull from the defender pool into the subject (0.01 SOL)
+pub const MIN_REVIVE_BOND: u64 = 10_000_000;
+
+// =============================================================================
+// ACCOUNT VERSIONING
+// =============================================================================
+
+/// Current layout version written to new accounts
+/// Bump when a layout changes and add the upgrade step to migrate_account
+pub const ACCOUNT_VERSION: u8 = 40;
+
 // =============================================================================
 // PDA SEEDS (Global - no config dependency)
 // =============================================================================
 
+/// Config instances are [PROTOCOL_CONFIG_SEED, authority]; the original global config is
+/// [PROTOCOL_CONFIG_SEED] and must be upgraded with migrate_account before use
+/// (fresh deployments create it with initialize_global_config)
 pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol_config";
 pub const DEFENDER_POOL_SEED: &[u8] = b"defender_pool";
 pub const SUBJECT_SEED: &[u8] = b"subject";
@@ -72,6 +209,27 @@ pub const DISPUTE_ESCROW_SEED: &[u8] = b"escrow";
 pub const CHALLENGER_RECORD_SEED: &[u8] = b"challenger_record";
 pub const DEFENDER_RECORD_SEED: &[u8] = b"defender_record";
 pub const VOTE_RECORD_SEED: &[u8] = b"vote";
+pub const CATEGORY_REGISTRY_SEED: &[u8] = b"category_registry"; // + config
+pub const EVIDENCE_LOG_SEED: &[u8] = b"evidence";
+pub const CREATOR_ALLOWLIST_SEED: &[u8] = b"creator_allowlist"; // + config
+pub const SUBJECT_TOMBSTONE_SEED: &[u8] = b"subject_tombstone";
+pub const PROTOCOL_STATS_SEED: &[u8] = b"protocol_stats";
+pub const RESOLUTION_ATTESTATION_SEED: &[u8] = b"attestation";
+pub const CHALLENGE_POOL_SEED: &[u8] = b"pledge_pool";
+pub const CHALLENGE_PLEDGE_SEED: &[u8] = b"pledge";
+pub const POOL_DELEGATE_SEED: &[u8] = b"pool_delegate";
+pub const VOTE_STATS_SEED: &[u8] = b"vote_stats";
+pub const SUBJECT_INDEX_SEED: &[u8] = b"subject_index";
+pub const RESOLUTION_VOID_SEED: &[u8] = b"resolution_void";
+pub const JUROR_BOOST_SEED: &[u8] = b"juror_boost";
+pub const OPPOSITION_RECORD_SEED: &[u8] = b"opposition";
+pub const YIELD_VAULT_SEED: &[u8] = b"yield_vault"; // + defender_pool
+pub const JUROR_EPOCH_SEED: &[u8] = b"juror_epoch"; // + config + index
+pub const EPOCH_ENROLLMENT_SEED: &[u8] = b"epoch_enrollment";
+pub const EPOCH_ASSIGNMENT_SEED: &[u8] = b"epoch_assignment";
+pub const EXPIRY_QUEUE_SEED: &[u8] = b"expiry_queue"; // + config
+pub const TREASURY_SEED: &[u8] = b"treasury"; // + config
+pub const EVIDENCE_ACK_SEED: &[u8] = b"evidence_ack";
 
 // =============================================================================
 // STACKED SIGMOID SYSTEM (Two sigmoids added together)
@@ -139,3 +297,17 @@ pub fn stacked_sigmoid(reputation: u16) -> u16 {
     // Sum of both sigmoids (max 10000)
     s1.saturating_add(s2)
 }
+
+/// Reputation gain for a correct result, judged at `reputation`
+/// Callers pass the snapshot taken at participation time (see VoteRecord::reputation_snapshot)
+pub fn reputation_gain(reputation: u16) -> u16 {
+    let remaining = 10000u16.saturating_sub(reputation);
+    let multiplier = stacked_sigmoid(reputation);
+    (remaining as u32 * REPUTATION_GAIN_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16
+}
+
+/// Reputation loss for a wrong result, judged at `reputation`
+pub fn reputation_loss(reputation: u16) -> u16 {
+    let multiplier = stacked_sigmoid(reputation);
+    (reputation as u32 * REPUTATION_LOSS_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16
+}
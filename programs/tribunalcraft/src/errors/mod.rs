@@ -108,4 +108,269 @@ pub enum TribunalCraftError {
     // Escrow errors
     #[msg("Not all claims have been processed")]
     ClaimsNotComplete,
+
+    // Added after the initial release. Anchor error codes are positional:
+    // append new variants at the end, never insert or reorder
+    #[msg("Creator is not allowed to create subjects")]
+    CreatorNotAllowed,
+
+    #[msg("Reward split outside protocol bounds")]
+    InvalidRewardSplit,
+
+    #[msg("Subject cannot be closed yet")]
+    SubjectCannotBeClosed,
+
+    #[msg("Subject was closed and cannot be re-created")]
+    SubjectClosed,
+
+    #[msg("Dispute is not in paginated resolution")]
+    DisputeNotResolving,
+
+    // Evidence errors
+    #[msg("Evidence submission window has closed")]
+    EvidenceWindowClosed,
+
+    #[msg("Evidence log is full")]
+    EvidenceLogFull,
+
+    #[msg("Subject is in its dispute cooldown - bond is below the escalation requirement")]
+    DisputeCooldownActive,
+
+    #[msg("Pool delegate spending cap exceeded")]
+    DelegateCapExceeded,
+
+    #[msg("Account does not match the juror's beneficiary")]
+    BeneficiaryMismatch,
+
+    #[msg("Dispute vote stats account missing or not expected")]
+    VoteStatsMismatch,
+
+    #[msg("Subject index page is full - use the next page")]
+    SubjectIndexPageFull,
+
+    #[msg("CID is too long or not a valid IPFS CID / Arweave id")]
+    InvalidCid,
+
+    #[msg("Juror weight cap is set - tally_capped_votes must count every vote first")]
+    WeightTallyIncomplete,
+
+    #[msg("Dispute can only be refunded long after voting ends")]
+    RefundTimeoutNotReached,
+
+    #[msg("Resolution can only be voided within the void window and before any claims")]
+    ResolutionNotVoidable,
+
+    #[msg("Juror incentive is only refundable after a NoParticipation outcome")]
+    BoostNotRefundable,
+
+    #[msg("Boosters can still refund juror incentive from this escrow")]
+    BoostRefundsPending,
+
+    #[msg("Dispute is closed to new challenger bond this late in voting")]
+    LateJoinClosed,
+
+    #[msg("Vote type does not match the dispute's graded verdict mode")]
+    GradedVoteMismatch,
+
+    #[msg("Grade must be 0-4 (0%, 25%, 50%, 75%, 100% slash)")]
+    InvalidGrade,
+
+    #[msg("Dispute batch needs 1-8 subjects, one bond each, with 6 accounts per subject")]
+    InvalidDisputeBatch,
+
+    #[msg("Juror epoch enrollment is closed")]
+    EpochEnrollmentClosed,
+
+    #[msg("Juror epoch has not ended")]
+    EpochNotEnded,
+
+    #[msg("Stake is committed to a juror epoch until it ends")]
+    StakeCommittedToEpoch,
+
+    #[msg("Juror epoch still has unclaimed rewards")]
+    EpochClaimsPending,
+
+    #[msg("Dispute already has a dependent escrow")]
+    DependentEscrowAlreadySet,
+
+    #[msg("Dispute has no dependent escrow to notify")]
+    DependentEscrowNotSet,
+
+    #[msg("Dependent escrow was already notified")]
+    DependentEscrowAlreadyNotified,
+
+    #[msg("Subject is frozen")]
+    SubjectFrozen,
+
+    #[msg("Subject is not frozen")]
+    SubjectNotFrozen,
+
+    #[msg("Abstentions cannot be topped up or changed")]
+    VoteAbstained,
+
+    #[msg("Instruction does not match the subject's free-case mode")]
+    FreeCaseMismatch,
+
+    #[msg("Instruction does not match the dispute's appeal status")]
+    AppealMismatch,
+
+    #[msg("Voting period must be positive")]
+    InvalidVotingPeriod,
+
+    #[msg("Tie extension must be positive for TiePolicy::ExtendVoting")]
+    InvalidTieExtension,
+
+    #[msg("Juror weight cap and graded verdicts cannot be combined")]
+    WeightCapConflict,
+
+    #[msg("Subject has no juror weight cap")]
+    WeightCapNotSet,
+
+    #[msg("Remaining accounts do not match the expected layout")]
+    InvalidRemainingAccounts,
+
+    #[msg("Vote record belongs to another dispute or juror")]
+    VoteRecordMismatch,
+
+    #[msg("No committed rationale to reveal")]
+    RationaleNotPending,
+
+    #[msg("Epoch must start in the future and end after it starts")]
+    InvalidEpochWindow,
+
+    #[msg("Review delay must be positive and at most MAX_REVIEW_DELAY")]
+    InvalidReviewDelay,
+
+    #[msg("Evidence review can only be required by the sole challenger before any votes")]
+    EvidenceReviewLocked,
+
+    #[msg("Dispute does not require evidence review")]
+    EvidenceReviewNotRequired,
+
+    #[msg("Evidence hash does not match the dispute's evidence")]
+    EvidenceHashMismatch,
+
+    #[msg("Juror has not acknowledged the evidence long enough before voting")]
+    EvidenceNotReviewed,
+
+    #[msg("Subject is not dormant")]
+    SubjectNotDormant,
+
+    #[msg("Subject does not accept challenge pledges")]
+    PledgingDisabled,
+
+    #[msg("Pledge window has closed")]
+    PledgeWindowClosed,
+
+    #[msg("Total pledged is below the subject's threshold")]
+    PledgeThresholdNotMet,
+
+    #[msg("Pledge pool is full")]
+    PledgePoolFull,
+
+    #[msg("Pledge can only be refunded after the pool lapses")]
+    PledgeNotRefundable,
+
+    #[msg("Vote allocation exceeds the per-dispute cap")]
+    VoteAllocationAboveCap,
+
+    #[msg("Locked stake would exceed the concurrent lock cap")]
+    LockedStakeAboveCap,
+
+    #[msg("Subject requires a rationale hash with each vote")]
+    RationaleHashRequired,
+
+    #[msg("Revealed rationale does not match the committed hash")]
+    RationaleHashMismatch,
+
+    #[msg("Rationale must be revealed before claiming")]
+    RationaleNotRevealed,
+
+    #[msg("Jurors still have rewards to claim")]
+    JurorClaimsPending,
+
+    // Category errors
+    #[msg("Category not found in registry")]
+    CategoryNotFound,
+
+    #[msg("Category already exists")]
+    CategoryAlreadyExists,
+
+    #[msg("Category registry is full")]
+    CategoryRegistryFull,
+
+    #[msg("Subject creator has not opted into revalidation")]
+    RevivalNotOptedIn,
+
+    #[msg("Defender pool cannot cover the minimum revival bond")]
+    RevivalBondTooLow,
+
+    #[msg("Defender record already holds stake from another pool")]
+    PoolStakeFromOtherPool,
+
+    #[msg("Subject index page out of order - fill the previous page first")]
+    SubjectIndexPageOutOfOrder,
+
+    #[msg("Creator froze this subject too recently to freeze it again")]
+    CreatorFreezeCooldown,
+
+    #[msg("Juror epoch missing or not the config's fee epoch")]
+    FeeEpochMismatch,
+
+    #[msg("Treasury balance above its rent-exempt minimum is too low")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Creator is already on the allowlist")]
+    CreatorAlreadyAllowed,
+
+    #[msg("Creator allowlist is full")]
+    CreatorAllowlistFull,
+
+    #[msg("Defender record already carries another delegate's commitment")]
+    DelegateCommitConflict,
+
+    #[msg("Subject is already linked to a defender pool")]
+    SubjectAlreadyLinked,
+
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Defender pool or pool record does not match the subject")]
+    PoolAccountMismatch,
+
+    #[msg("Subject is not linked to a defender pool")]
+    SubjectNotLinked,
+
+    #[msg("Subject does not match the dispute")]
+    SubjectMismatch,
+
+    #[msg("Claim to wallet and beneficiary payout are mutually exclusive")]
+    BeneficiaryConflict,
+
+    #[msg("Target subject must differ from the source subject")]
+    SameTargetSubject,
+
+    #[msg("Juror epoch does not cover the dispute's voting window")]
+    EpochNotCoveringDispute,
+
+    #[msg("Epoch assignment already served")]
+    AssignmentAlreadyServed,
+
+    #[msg("Juror epoch or enrollment does not match")]
+    EpochAccountMismatch,
+
+    #[msg("Pledge does not belong to this pledge pool")]
+    PledgeAccountMismatch,
+
+    #[msg("Dependent program or account does not match the dispute")]
+    DependentEscrowMismatch,
+
+    #[msg("Stake pool is not the approved or already-delegated yield pool")]
+    StakePoolNotApproved,
+
+    #[msg("Escrow cannot be swept before the dispute resolves")]
+    SweepTooEarly,
+
+    #[msg("Claim window has passed - the escrow is open for sweeping")]
+    SweepWindowPassed,
 }
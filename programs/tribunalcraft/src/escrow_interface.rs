@@ -0,0 +1,97 @@
+//! Interface for external escrow programs that release funds on tribunal outcomes
+//!
+//! An escrow program links one of its accounts to a dispute by CPI-ing
+//! `register_dependent_escrow` with that account as signer. Once the dispute
+//! resolves and its ResolutionAttestation is minted, anyone can call
+//! `notify_dependent_escrow`, which invokes the escrow program's
+//! `on_tribunal_resolution` handler with the attestation PDA as signer.
+//!
+//! Escrow programs that prefer to poll can read the attestation directly at
+//! the fixed offsets below (Borsh, enums as one byte) after checking that it
+//! is owned by this program and derived from [RESOLUTION_ATTESTATION_SEED, dispute].
+//! A voided resolution flips VOIDED; pollers must ignore such attestations.
+//!
+//! Only the subject creator can link an escrow (co-signing the registration),
+//! so a third party cannot squat a dispute's single escrow slot.
+//!
+//! Callback contract: the handler receives [ResolutionCallback] after the
+//! 8-byte discriminator. It must check that the signing attestation is the
+//! [RESOLUTION_ATTESTATION_SEED, dispute] PDA of this program. Delivery
+//! happens at most once: dependent_notified is set before the CPI, so a
+//! re-entrant notify is rejected. A failing handler reverts the whole
+//! transaction, flag included, and notify can be retried. Voided resolutions
+//! are never delivered.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+// solana_program's invoke routes through the host syscall stubs, so the callback can be unit tested
+use solana_program::program::invoke_signed;
+use crate::state::ResolutionOutcome;
+
+/// ResolutionAttestation byte offsets from the start of the account data
+/// (bytes 0..8 are the discriminator, so the first field starts at 8)
+pub mod attestation_layout {
+    pub const SUBJECT: usize = 8;
+    pub const SUBJECT_ID: usize = 40;
+    pub const DISPUTE: usize = 72;
+    pub const DISPUTE_TYPE: usize = 104;
+    pub const IS_APPEAL: usize = 105;
+    /// ResolutionOutcome: 0 = None, 1 = ChallengerWins, 2 = DefenderWins, 3 = NoParticipation
+    pub const OUTCOME: usize = 106;
+    pub const VOTES_FAVOR_WEIGHT: usize = 107;
+    pub const VOTES_AGAINST_WEIGHT: usize = 115;
+    pub const VOTE_COUNT: usize = 123;
+    pub const RESOLVED_AT: usize = 125;
+    pub const ATTESTED_AT: usize = 133;
+    pub const BUMP: usize = 141;
+    /// bool: 1 = resolution voided, do not act on OUTCOME
+    pub const VOIDED: usize = 142;
+}
+
+/// Anchor discriminator of `on_tribunal_resolution` (sha256("global:on_tribunal_resolution")[..8])
+pub const ON_TRIBUNAL_RESOLUTION: [u8; 8] = [5, 50, 120, 27, 17, 189, 63, 41];
+
+/// Arguments passed to `on_tribunal_resolution`
+/// Accounts: [attestation (signer), dependent_account (writable), ...forwarded remaining accounts]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ResolutionCallback {
+    pub dispute: Pubkey,
+    pub subject_id: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub is_appeal: bool,
+    pub resolved_at: i64,
+}
+
+/// Invoke the dependent program's `on_tribunal_resolution`, signed by the attestation PDA
+pub fn notify<'info>(
+    dependent_program: &AccountInfo<'info>,
+    attestation: &AccountInfo<'info>,
+    dependent_account: &AccountInfo<'info>,
+    forwarded: &[AccountInfo<'info>],
+    callback: &ResolutionCallback,
+    attestation_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut metas = vec![
+        AccountMeta::new_readonly(attestation.key(), true),
+        AccountMeta::new(dependent_account.key(), false),
+    ];
+    metas.extend(forwarded.iter().map(|info| AccountMeta {
+        pubkey: info.key(),
+        is_signer: info.is_signer,
+        is_writable: info.is_writable,
+    }));
+
+    let mut data = ON_TRIBUNAL_RESOLUTION.to_vec();
+    callback.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: dependent_program.key(),
+        accounts: metas,
+        data,
+    };
+
+    let mut infos = vec![attestation.clone(), dependent_account.clone(), dependent_program.clone()];
+    infos.extend_from_slice(forwarded);
+    invoke_signed(&ix, &infos, &[attestation_seeds])?;
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+use crate::state::{EvidenceSide, VoteChoice, AppealVoteChoice, ResolutionOutcome, DisputeType};
+
+/// Emitted when a party appends evidence to a dispute
+#[event]
+pub struct EvidenceSubmittedEvent {
+    pub dispute: Pubkey,
+    pub submitter: Pubkey,
+    pub side: EvidenceSide,
+    pub cid: String,
+    pub index: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a juror casts or adds to a vote, with the reward estimate at that point
+#[event]
+pub struct VoteCastEvent {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub is_appeal_vote: bool,
+    pub stake_allocated: u64,
+    pub voting_power: u64,
+    pub est_reward_floor: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a juror flips their vote before voting ends
+#[event]
+pub struct VoteChangedEvent {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub is_appeal_vote: bool,
+    pub new_choice: VoteChoice,
+    pub new_appeal_choice: AppealVoteChoice,
+    pub old_voting_power: u64,
+    pub new_voting_power: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when migrate_account upgrades an account to the current layout
+#[event]
+pub struct AccountMigratedEvent {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+/// Emitted when an invalidated subject and its last dispute/escrow are closed
+#[event]
+pub struct SubjectClosedEvent {
+    pub subject: Pubkey,
+    pub subject_id: Pubkey,
+    pub dispute: Pubkey,
+    pub closer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a juror reveals the rationale CID committed at vote time
+#[event]
+pub struct RationaleRevealedEvent {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub rationale_cid: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an immutable resolution attestation is written
+#[event]
+pub struct ResolutionAttestedEvent {
+    pub attestation: Pubkey,
+    pub dispute: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub timestamp: i64,
+}
+
+/// Emitted when a dispute or appeal resolves, with the subject's updated track record
+/// dominant_type is the dispute type backed by the most challenger bond
+#[event]
+pub struct DisputeResolvedEvent {
+    pub dispute: Pubkey,
+    pub subject: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub dominant_type: DisputeType,
+    pub is_appeal: bool,
+    pub disputes_won: u32,
+    pub disputes_lost: u32,
+    pub restorations_won: u32,
+    pub restorations_lost: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a never-resolved dispute is refunded by timeout_refund
+#[event]
+pub struct DisputeTimedOutEvent {
+    pub dispute: Pubkey,
+    pub subject: Pubkey,
+    pub is_appeal: bool,
+    pub total_bond: u64,
+    pub total_stake: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the config authority voids a resolution (see ResolutionVoid)
+#[event]
+pub struct ResolutionVoidedEvent {
+    pub dispute: Pubkey,
+    pub subject: Pubkey,
+    pub authority: Pubkey,
+    pub voided_outcome: ResolutionOutcome,
+    pub reason_cid: String,
+    pub fees_restored: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a tied vote reopens voting under TiePolicy::ExtendVoting
+#[event]
+pub struct TieVotingExtendedEvent {
+    pub dispute: Pubkey,
+    pub subject: Pubkey,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when create_disputes_batch opens linked disputes for one incident
+#[event]
+pub struct DisputeBatchCreatedEvent {
+    pub incident_id: Pubkey,
+    pub challenger: Pubkey,
+    pub disputes: Vec<Pubkey>,
+    pub total_bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an external escrow account is linked to a dispute
+#[event]
+pub struct DependentEscrowRegisteredEvent {
+    pub dispute: Pubkey,
+    pub dependent_program: Pubkey,
+    pub dependent_account: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a linked external escrow receives the outcome callback
+#[event]
+pub struct DependentEscrowNotifiedEvent {
+    pub dispute: Pubkey,
+    pub dependent_account: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub timestamp: i64,
+}
+
+/// Emitted when a subject is frozen or unfrozen for a legal hold
+#[event]
+pub struct SubjectFreezeChangedEvent {
+    pub subject: Pubkey,
+    pub by: Pubkey,
+    pub frozen: bool,
+    pub has_active_dispute: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a juror claims their epoch reward
+#[event]
+pub struct EpochRewardClaimedEvent {
+    pub epoch: Pubkey,
+    pub juror: Pubkey,
+    pub assigned: u16,
+    pub served: u16,
+    pub reward: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a juror acknowledges a dispute's evidence ahead of voting
+#[event]
+pub struct EvidenceAcknowledgedEvent {
+    pub dispute: Pubkey,
+    pub juror: Pubkey,
+    pub votable_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a defender pool tops up the defense of a pending dispute
+#[event]
+pub struct PoolDefendedEvent {
+    pub dispute: Pubkey,
+    pub defender_pool: Pubkey,
+    /// Pool owner or delegate who committed the stake
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub stake_held: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a challenger claims, with the payout split by where the bond came from
+/// pledged_share is the part backed by a converted challenge pledge (see ChallengerRecord::pledged_bond)
+#[event]
+pub struct ChallengerRewardClaimedEvent {
+    pub dispute: Pubkey,
+    pub challenger: Pubkey,
+    pub outcome: ResolutionOutcome,
+    pub amount: u64,
+    pub wallet_share: u64,
+    pub pledged_share: u64,
+    pub timestamp: i64,
+}
+
+/// Why lamports moved in a LamportsMovedEvent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LamportsMoveReason {
+    JurorWithdrawal,
+    PoolWithdrawal,
+    StakeHold,
+    PlatformFee,
+    ResolverFee,
+    JurorReward,
+    ChallengerClaim,
+    DefenderClaim,
+    DefenderPoolClaim,
+    EscrowDust,
+    PledgeActivation,
+    PledgeRefund,
+    BoostRefund,
+    YieldDeposit,
+    EpochReward,
+    TreasuryWithdrawal,
+    SweepBounty,
+    ReviveFee,
+    JurorSlash,
+    ReviveBond,
+    VoidRefund,
+    /// Unused: challenger claims pay the pledged share with the rest (see ChallengerRewardClaimedEvent)
+    PledgeClaim,
+    EpochFee,
+}
+
+/// Emitted for every direct lamport mutation (see utils::transfer_lamports)
+/// System-program transfers are visible in transaction logs and are not repeated here
+#[event]
+pub struct LamportsMovedEvent {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub reason: LamportsMoveReason,
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::constants::DISPUTE_SEED;
+use crate::constants::{
+    DISPUTE_SEED, ACCOUNT_VERSION, PROTOCOL_STATS_SEED, DISPUTE_ESCROW_SEED,
+    CHALLENGER_ACCOUNT_SEED, CHALLENGER_RECORD_SEED, OPPOSITION_RECORD_SEED, INITIAL_REPUTATION,
+};
 use crate::errors::TribunalCraftError;
+use crate::utils::validate_cid;
+use crate::lifecycle_log::{LifecycleLog, LifecycleKind, NOOP_PROGRAM_ID};
+use crate::invariants;
 
 /// Submit an appeal against an invalidated subject
 /// Appeals allow community to reverse previous decisions
 /// Appellant stakes (no bond required), voting period is 2x previous
+/// The stake is held in escrow as the challenger side, so the appellant claims
+/// through claim_challenger_reward; oppose_restore fills the defender side
 #[derive(Accounts)]
 pub struct SubmitAppeal<'info> {
     #[account(mut)]
@@ -14,6 +22,7 @@ pub struct SubmitAppeal<'info> {
     #[account(
         mut,
         constraint = subject.can_appeal() @ TribunalCraftError::SubjectCannotBeAppealed,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
         constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
     )]
     pub subject: Account<'info, Subject>,
@@ -27,6 +36,53 @@ pub struct SubmitAppeal<'info> {
     )]
     pub dispute: Account<'info, Dispute>,
 
+    /// Escrow PDA holds the appeal and opposition stakes
+    #[account(
+        init,
+        payer = appellant,
+        space = DisputeEscrow::LEN,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = appellant,
+        space = ChallengerAccount::LEN,
+        seeds = [CHALLENGER_ACCOUNT_SEED, appellant.key().as_ref()],
+        bump
+    )]
+    pub challenger_account: Account<'info, ChallengerAccount>,
+
+    /// Appellant's claim on the escrow (bond = appeal stake)
+    #[account(
+        init,
+        payer = appellant,
+        space = ChallengerRecord::LEN,
+        seeds = [CHALLENGER_RECORD_SEED, dispute.key().as_ref(), appellant.key().as_ref()],
+        bump
+    )]
+    pub challenger_record: Account<'info, ChallengerRecord>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (CID validation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -38,39 +94,59 @@ pub fn submit_appeal(
 ) -> Result<()> {
     let subject = &mut ctx.accounts.subject;
     let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let challenger_account = &mut ctx.accounts.challenger_account;
+    let challenger_record = &mut ctx.accounts.challenger_record;
     let clock = Clock::get()?;
 
+    // Free cases stay free on appeal - nothing is staked
+    require!(!subject.free_case || stake_amount == 0, TribunalCraftError::FreeCaseMismatch);
+    validate_cid(&details_cid, ChallengerRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+
     // Validate stake meets minimum requirement (previous dispute's stake + bond)
     require!(
         stake_amount >= subject.min_appeal_stake(),
         TribunalCraftError::AppealStakeBelowMinimum
     );
 
-    // Transfer stake to dispute account
+    // Transfer stake to escrow
     if stake_amount > 0 {
         let cpi_context = CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             anchor_lang::system_program::Transfer {
                 from: ctx.accounts.appellant.to_account_info(),
-                to: dispute.to_account_info(),
+                to: escrow.to_account_info(),
             },
         );
         anchor_lang::system_program::transfer(cpi_context, stake_amount)?;
     }
 
+    // Appeal stake is the challenger side; opposition stakes join as the defender side
+    let mut escrow_data = escrow.load_init()?;
+    escrow_data.dispute = dispute.key();
+    escrow_data.subject = subject.key();
+    escrow_data.total_bonds = stake_amount;
+    escrow_data.expected_challengers = 1;
+    escrow_data.bump = ctx.bumps.escrow;
+    escrow_data.version = ACCOUNT_VERSION;
+    escrow_data.created_at = clock.unix_timestamp;
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
     // Update subject status
     subject.status = SubjectStatus::Disputed;
     subject.dispute = dispute.key();
-    subject.dispute_count += 1;
+    subject.dispute_count = subject.dispute_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     subject.updated_at = clock.unix_timestamp;
 
     // Initialize dispute as an appeal
     dispute.subject = subject.key();
     dispute.dispute_type = dispute_type;
+    dispute.category_id = subject.category_id;
     dispute.total_bond = 0; // Appeals don't have bonds
     dispute.stake_held = 0;
     dispute.direct_stake_held = 0;
-    dispute.challenger_count = 0; // No challengers in appeals
+    dispute.challenger_count = 1; // The appellant
     dispute.status = DisputeStatus::Pending;
     dispute.outcome = ResolutionOutcome::None;
     dispute.votes_favor_weight = 0;
@@ -78,6 +154,7 @@ pub fn submit_appeal(
     dispute.vote_count = 0;
     dispute.resolved_at = 0;
     dispute.bump = ctx.bumps.dispute;
+    dispute.version = ACCOUNT_VERSION;
     dispute.created_at = clock.unix_timestamp;
     dispute.pool_reward_claimed = false;
 
@@ -91,9 +168,41 @@ pub fn submit_appeal(
     dispute.is_appeal = true;
     dispute.appeal_stake = stake_amount;
 
+    // Initialize challenger account if new
+    if challenger_account.created_at == 0 {
+        challenger_account.challenger = ctx.accounts.appellant.key();
+        challenger_account.reputation = INITIAL_REPUTATION;
+        challenger_account.bump = ctx.bumps.challenger_account;
+        challenger_account.version = ACCOUNT_VERSION;
+        challenger_account.created_at = clock.unix_timestamp;
+    }
+
+    challenger_record.dispute = dispute.key();
+    challenger_record.challenger = ctx.accounts.appellant.key();
+    challenger_record.challenger_account = challenger_account.key();
+    challenger_record.bond = stake_amount;
+    challenger_record.details_cid = details_cid.clone();
+    challenger_record.reward_claimed = false;
+    challenger_record.bump = ctx.bumps.challenger_record;
+    challenger_record.version = ACCOUNT_VERSION;
+    challenger_record.challenged_at = clock.unix_timestamp;
+    challenger_record.reputation_snapshot = Some(challenger_account.reputation);
+
     // Voting starts immediately with 2x previous voting period
     let appeal_voting_period = subject.appeal_voting_period();
     dispute.start_voting(clock.unix_timestamp, appeal_voting_period);
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_dispute(true, clock.unix_timestamp);
+    }
+    LifecycleLog {
+        kind: LifecycleKind::RestorationFiled,
+        subject: subject.key(),
+        dispute: dispute.key(),
+        status: subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: stake_amount,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
 
     msg!(
         "Appeal submitted with {} lamports stake (voting period: {} seconds)",
@@ -104,3 +213,95 @@ pub fn submit_appeal(
 
     Ok(())
 }
+
+/// Stake against a pending restoration (original challengers, defenders or anyone)
+/// Opposers form the defender side of the appeal and split the appellant's stake if
+/// the restoration is rejected
+#[derive(Accounts)]
+pub struct OpposeRestore<'info> {
+    #[account(mut)]
+    pub opposer: Signer<'info>,
+
+    #[account(
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.is_appeal @ TribunalCraftError::AppealMismatch,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA for this appeal
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = opposer,
+        space = OppositionRecord::LEN,
+        seeds = [OPPOSITION_RECORD_SEED, dispute.key().as_ref(), opposer.key().as_ref()],
+        bump
+    )]
+    pub opposition_record: Account<'info, OppositionRecord>,
+
+    /// Protocol config (late join cutoff)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn oppose_restore(ctx: Context<OpposeRestore>, stake: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let record = &mut ctx.accounts.opposition_record;
+    let clock = Clock::get()?;
+
+    require!(stake > 0, TribunalCraftError::StakeBelowMinimum);
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    require!(
+        !dispute.in_late_join_window(clock.unix_timestamp, ctx.accounts.protocol_config.late_join_cutoff_bps),
+        TribunalCraftError::LateJoinClosed
+    );
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: ctx.accounts.opposer.to_account_info(),
+            to: escrow.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_context, stake)?;
+
+    let mut escrow_data = escrow.load_mut()?;
+    escrow_data.add_stake(stake)?;
+
+    if record.opposed_at == 0 {
+        record.dispute = dispute.key();
+        record.opposer = ctx.accounts.opposer.key();
+        record.bump = ctx.bumps.opposition_record;
+        record.opposed_at = clock.unix_timestamp;
+        dispute.opposer_count = dispute.opposer_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        escrow_data.expect_defender()?;
+    }
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+    record.stake = record.stake.checked_add(stake).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    dispute.opposition_stake = dispute.opposition_stake.checked_add(stake)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Restoration opposed: {} lamports (total opposition: {})", stake, dispute.opposition_stake);
+    Ok(())
+}
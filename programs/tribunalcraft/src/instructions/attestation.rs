@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::RESOLUTION_ATTESTATION_SEED;
+use crate::errors::TribunalCraftError;
+use crate::events::{ResolutionAttestedEvent, DependentEscrowRegisteredEvent, DependentEscrowNotifiedEvent};
+use crate::escrow_interface::{self, ResolutionCallback};
+
+/// Write an immutable outcome attestation for a resolved dispute (permissionless)
+/// One per dispute; payer funds rent and the account is never closed
+#[derive(Accounts)]
+pub struct MintResolutionAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+        constraint = !dispute.voided @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ResolutionAttestation::LEN,
+        seeds = [RESOLUTION_ATTESTATION_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, ResolutionAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mint_resolution_attestation(ctx: Context<MintResolutionAttestation>) -> Result<()> {
+    let subject = &ctx.accounts.subject;
+    let dispute = &ctx.accounts.dispute;
+    let attestation = &mut ctx.accounts.attestation;
+    let clock = Clock::get()?;
+
+    attestation.subject = subject.key();
+    attestation.subject_id = subject.subject_id;
+    attestation.dispute = dispute.key();
+    attestation.dispute_type = dispute.dispute_type;
+    attestation.is_appeal = dispute.is_appeal;
+    attestation.outcome = dispute.outcome;
+    attestation.votes_favor_weight = dispute.votes_favor_weight;
+    attestation.votes_against_weight = dispute.votes_against_weight;
+    attestation.vote_count = dispute.vote_count;
+    attestation.resolved_at = dispute.resolved_at;
+    attestation.attested_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+    attestation.voided = false;
+
+    emit!(ResolutionAttestedEvent {
+        attestation: attestation.key(),
+        dispute: dispute.key(),
+        outcome: dispute.outcome,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Resolution attested for dispute {}", dispute.key());
+    Ok(())
+}
+
+/// Link an external escrow account to an open dispute (CPI from the escrow program)
+/// The escrow account signs (as its program's PDA) and must be owned by that program;
+/// the subject creator co-signs so nobody else can claim the dispute's escrow slot
+#[derive(Accounts)]
+pub struct RegisterDependentEscrow<'info> {
+    /// Subject creator consenting to the link
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator @ TribunalCraftError::Unauthorized,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    /// CHECK: Executable program owning the dependent account
+    #[account(executable)]
+    pub dependent_program: UncheckedAccount<'info>,
+
+    /// CHECK: Signs to consent; ownership checked against dependent_program
+    #[account(
+        signer,
+        owner = dependent_program.key() @ TribunalCraftError::DependentEscrowMismatch,
+    )]
+    pub dependent_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+        constraint = !dispute.has_dependent_escrow() @ TribunalCraftError::DependentEscrowAlreadySet,
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
+
+pub fn register_dependent_escrow(ctx: Context<RegisterDependentEscrow>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    dispute.dependent_program = ctx.accounts.dependent_program.key();
+    dispute.dependent_account = ctx.accounts.dependent_account.key();
+
+    emit!(DependentEscrowRegisteredEvent {
+        dispute: dispute.key(),
+        dependent_program: dispute.dependent_program,
+        dependent_account: dispute.dependent_account,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Dependent escrow {} linked to dispute {}", dispute.dependent_account, dispute.key());
+    Ok(())
+}
+
+/// Deliver the attested outcome to a linked external escrow (permissionless, once)
+/// Kept out of resolution so a failing escrow program can't block it
+/// Remaining accounts are forwarded to the callback as-is
+#[derive(Accounts)]
+pub struct NotifyDependentEscrow<'info> {
+    #[account(
+        mut,
+        constraint = dispute.has_dependent_escrow() @ TribunalCraftError::DependentEscrowNotSet,
+        constraint = !dispute.dependent_notified @ TribunalCraftError::DependentEscrowAlreadyNotified,
+        constraint = !dispute.voided @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Attestation signs the callback to prove the outcome came from this program
+    #[account(
+        has_one = dispute,
+        seeds = [RESOLUTION_ATTESTATION_SEED, dispute.key().as_ref()],
+        bump = attestation.bump,
+        constraint = !attestation.voided @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub attestation: Account<'info, ResolutionAttestation>,
+
+    /// CHECK: Address validated against dispute
+    #[account(
+        executable,
+        address = dispute.dependent_program @ TribunalCraftError::DependentEscrowMismatch,
+    )]
+    pub dependent_program: UncheckedAccount<'info>,
+
+    /// CHECK: Address validated against dispute; the dependent program validates contents
+    #[account(
+        mut,
+        address = dispute.dependent_account @ TribunalCraftError::DependentEscrowMismatch,
+    )]
+    pub dependent_account: UncheckedAccount<'info>,
+}
+
+pub fn notify_dependent_escrow<'info>(ctx: Context<'_, '_, 'info, 'info, NotifyDependentEscrow<'info>>) -> Result<()> {
+    let attestation = &ctx.accounts.attestation;
+    let dispute_key = ctx.accounts.dispute.key();
+    let clock = Clock::get()?;
+
+    let callback = ResolutionCallback {
+        dispute: dispute_key,
+        subject_id: attestation.subject_id,
+        outcome: attestation.outcome,
+        is_appeal: attestation.is_appeal,
+        resolved_at: attestation.resolved_at,
+    };
+    let attestation_seeds: &[&[u8]] = &[RESOLUTION_ATTESTATION_SEED, dispute_key.as_ref(), &[attestation.bump]];
+
+    // Flag first so a re-entrant call from the dependent program is rejected
+    ctx.accounts.dispute.dependent_notified = true;
+    ctx.accounts.dispute.exit(&crate::ID)?;
+
+    escrow_interface::notify(
+        &ctx.accounts.dependent_program.to_account_info(),
+        &attestation.to_account_info(),
+        &ctx.accounts.dependent_account.to_account_info(),
+        ctx.remaining_accounts,
+        &callback,
+        attestation_seeds,
+    )?;
+
+    emit!(DependentEscrowNotifiedEvent {
+        dispute: dispute_key,
+        dependent_account: ctx.accounts.dependent_account.key(),
+        outcome: attestation.outcome,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Dependent escrow notified for dispute {}", dispute_key);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::sync::Mutex;
+    use anchor_lang::solana_program::instruction::Instruction;
+    use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use crate::escrow_interface::{attestation_layout, ON_TRIBUNAL_RESOLUTION};
+
+    static CALLBACKS: Mutex<Vec<Instruction>> = Mutex::new(Vec::new());
+
+    /// Host stubs: a fixed clock and a CPI that only records the instruction
+    struct RecordingStubs;
+
+    impl SyscallStubs for RecordingStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { unix_timestamp: 1_000, ..Default::default() };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            0
+        }
+
+        fn sol_invoke_signed(&self, instruction: &Instruction, _: &[AccountInfo], _: &[&[&[u8]]]) -> entrypoint::ProgramResult {
+            CALLBACKS.lock().unwrap().push(instruction.clone());
+            Ok(())
+        }
+    }
+
+    fn leaked_account(key: Pubkey, owner: Pubkey, executable: bool, data: Vec<u8>) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(1_000_000u64)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            executable,
+            0,
+        )
+    }
+
+    fn serialized<T: AccountSerialize>(account: &T, len: usize) -> Vec<u8> {
+        let mut data = Vec::with_capacity(len);
+        account.try_serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    fn notify_accounts(infos: &'static [AccountInfo<'static>]) -> Result<NotifyDependentEscrow<'static>> {
+        let mut cursor = infos;
+        NotifyDependentEscrow::try_accounts(
+            &crate::ID,
+            &mut cursor,
+            &[],
+            &mut NotifyDependentEscrowBumps::default(),
+            &mut BTreeSet::new(),
+        )
+    }
+
+    #[test]
+    fn notify_calls_back_once_and_flags_the_dispute() {
+        set_syscall_stubs(Box::new(RecordingStubs));
+
+        let (dispute_key, program_key, dependent_key) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (attestation_key, bump) =
+            Pubkey::find_program_address(&[RESOLUTION_ATTESTATION_SEED, dispute_key.as_ref()], &crate::ID);
+        let dispute = Dispute {
+            status: DisputeStatus::Resolved,
+            outcome: ResolutionOutcome::ChallengerWins,
+            dependent_program: program_key,
+            dependent_account: dependent_key,
+            ..Default::default()
+        };
+        let attestation = ResolutionAttestation {
+            dispute: dispute_key,
+            subject_id: Pubkey::new_unique(),
+            outcome: ResolutionOutcome::ChallengerWins,
+            resolved_at: 900,
+            bump,
+            ..Default::default()
+        };
+
+        let infos: &'static [AccountInfo<'static>] = Box::leak(Box::new([
+            leaked_account(dispute_key, crate::ID, false, serialized(&dispute, Dispute::LEN)),
+            leaked_account(attestation_key, crate::ID, false, serialized(&attestation, ResolutionAttestation::LEN)),
+            leaked_account(program_key, Pubkey::default(), true, Vec::new()),
+            leaked_account(dependent_key, program_key, false, Vec::new()),
+        ]));
+
+        let mut accounts = notify_accounts(infos).unwrap();
+        notify_dependent_escrow(Context::new(&crate::ID, &mut accounts, &[], NotifyDependentEscrowBumps::default())).unwrap();
+
+        // One callback to the dependent program, signed by the attestation
+        let callbacks = CALLBACKS.lock().unwrap().clone();
+        assert_eq!(callbacks.len(), 1);
+        let ix = &callbacks[0];
+        assert_eq!(ix.program_id, program_key);
+        assert_eq!((ix.accounts[0].pubkey, ix.accounts[0].is_signer, ix.accounts[0].is_writable), (attestation_key, true, false));
+        assert_eq!((ix.accounts[1].pubkey, ix.accounts[1].is_signer, ix.accounts[1].is_writable), (dependent_key, false, true));
+
+        let mut expected = ON_TRIBUNAL_RESOLUTION.to_vec();
+        ResolutionCallback {
+            dispute: dispute_key,
+            subject_id: attestation.subject_id,
+            outcome: ResolutionOutcome::ChallengerWins,
+            is_appeal: false,
+            resolved_at: 900,
+        }.serialize(&mut expected).unwrap();
+        assert_eq!(ix.data, expected);
+
+        // The flag is persisted, so a second delivery is rejected
+        let stored = Dispute::try_deserialize(&mut &infos[0].try_borrow_data().unwrap()[..]).unwrap();
+        assert!(stored.dependent_notified);
+        let err = notify_accounts(infos).err().unwrap();
+        assert_eq!(err, TribunalCraftError::DependentEscrowAlreadyNotified.into());
+    }
+
+    #[test]
+    fn attestation_matches_the_published_layout() {
+        let attestation = ResolutionAttestation {
+            subject: Pubkey::new_from_array([1; 32]),
+            subject_id: Pubkey::new_from_array([2; 32]),
+            dispute: Pubkey::new_from_array([3; 32]),
+            is_appeal: true,
+            outcome: ResolutionOutcome::DefenderWins,
+            votes_favor_weight: 4,
+            votes_against_weight: 5,
+            vote_count: 6,
+            resolved_at: 7,
+            attested_at: 8,
+            bump: 9,
+            voided: true,
+            ..Default::default()
+        };
+        let data = serialized(&attestation, ResolutionAttestation::LEN);
+        let u64_at = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+
+        assert_eq!(data[attestation_layout::SUBJECT..attestation_layout::SUBJECT + 32], [1; 32]);
+        assert_eq!(data[attestation_layout::SUBJECT_ID..attestation_layout::SUBJECT_ID + 32], [2; 32]);
+        assert_eq!(data[attestation_layout::DISPUTE..attestation_layout::DISPUTE + 32], [3; 32]);
+        assert_eq!(data[attestation_layout::IS_APPEAL], 1);
+        assert_eq!(data[attestation_layout::OUTCOME], 2);
+        assert_eq!(u64_at(attestation_layout::VOTES_FAVOR_WEIGHT), 4);
+        assert_eq!(u64_at(attestation_layout::VOTES_AGAINST_WEIGHT), 5);
+        assert_eq!(data[attestation_layout::VOTE_COUNT], 6);
+        assert_eq!(u64_at(attestation_layout::RESOLVED_AT), 7);
+        assert_eq!(u64_at(attestation_layout::ATTESTED_AT), 8);
+        assert_eq!(data[attestation_layout::BUMP], 9);
+        assert_eq!(data[attestation_layout::VOIDED], 1);
+    }
+}
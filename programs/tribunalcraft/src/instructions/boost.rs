@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::{JUROR_BOOST_SEED, DISPUTE_ESCROW_SEED};
+use crate::errors::TribunalCraftError;
+use crate::utils::transfer_lamports;
+use crate::events::LamportsMoveReason;
+use crate::invariants;
+
+// =============================================================================
+// JUROR INCENTIVE BOOSTS (boost -> paid with juror rewards, or refund)
+// =============================================================================
+//
+// Anyone can add lamports to an open dispute's juror pot. The deposit sits in
+// the dispute escrow outside the bond/stake accounting and is paid out with
+// juror rewards by voting power. If the dispute resolves with no votes,
+// boosters refund their deposits instead.
+
+/// Add a juror incentive deposit to an open dispute
+#[derive(Accounts)]
+pub struct BoostJurorPool<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    #[account(
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA holds the incentive until claims
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = booster,
+        space = JurorBoost::LEN,
+        seeds = [JUROR_BOOST_SEED, dispute.key().as_ref(), booster.key().as_ref()],
+        bump
+    )]
+    pub juror_boost: Account<'info, JurorBoost>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn boost_juror_pool(ctx: Context<BoostJurorPool>, amount: u64) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let juror_boost = &mut ctx.accounts.juror_boost;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, TribunalCraftError::ZeroAmount);
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+
+    if juror_boost.created_at == 0 {
+        juror_boost.dispute = dispute.key();
+        juror_boost.booster = ctx.accounts.booster.key();
+        juror_boost.bump = ctx.bumps.juror_boost;
+        juror_boost.created_at = clock.unix_timestamp;
+    }
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: ctx.accounts.booster.to_account_info(),
+            to: ctx.accounts.escrow.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+    juror_boost.amount = juror_boost.amount.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    dispute.juror_incentive = dispute.juror_incentive.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Juror pool boosted: {} lamports (total incentive: {})", amount, dispute.juror_incentive);
+    Ok(())
+}
+
+/// Refund a juror incentive deposit after a NoParticipation outcome
+/// Unrefunded incentive is left for the escrow sweep once JUROR_CLAIM_DEADLINE passes
+#[derive(Accounts)]
+pub struct RefundJurorBoost<'info> {
+    #[account(mut)]
+    pub booster: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = dispute.incentive_refundable() @ TribunalCraftError::BoostNotRefundable,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        mut,
+        close = booster,
+        has_one = dispute,
+        has_one = booster @ TribunalCraftError::Unauthorized,
+    )]
+    pub juror_boost: Account<'info, JurorBoost>,
+}
+
+pub fn refund_juror_boost(ctx: Context<RefundJurorBoost>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let amount = ctx.accounts.juror_boost.amount;
+
+    require!(dispute.is_claim_window_open(Clock::get()?.unix_timestamp), TribunalCraftError::SweepWindowPassed);
+
+    transfer_lamports(&escrow.to_account_info(), &ctx.accounts.booster.to_account_info(), amount, LamportsMoveReason::BoostRefund)?;
+    invariants::check_escrow(&escrow.to_account_info(), &*escrow.load()?);
+
+    dispute.incentive_refunded = dispute.incentive_refunded.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Juror boost refunded: {} lamports", amount);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::CATEGORY_REGISTRY_SEED;
+use crate::errors::TribunalCraftError;
+use crate::utils::validate_cid;
+
+/// Add a category to the registry (admin only, creates registry on first use)
+#[derive(Accounts)]
+pub struct AddCategory<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CategoryRegistry::LEN,
+        seeds = [CATEGORY_REGISTRY_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_category(ctx: Context<AddCategory>, category_id: u16, metadata_cid: String) -> Result<()> {
+    let registry = &mut ctx.accounts.category_registry;
+
+    require!(category_id != 0, TribunalCraftError::InvalidConfig);
+    validate_cid(&metadata_cid, Category::MAX_CID_LEN, ctx.accounts.config.validate_cids)?;
+    require!(!registry.contains(category_id), TribunalCraftError::CategoryAlreadyExists);
+    require!(
+        registry.categories.len() < CategoryRegistry::MAX_CATEGORIES,
+        TribunalCraftError::CategoryRegistryFull
+    );
+
+    registry.bump = ctx.bumps.category_registry;
+    registry.categories.push(Category {
+        id: category_id,
+        metadata_cid,
+    });
+
+    msg!("Category added: {}", category_id);
+    Ok(())
+}
+
+/// Remove a category from the registry (admin only)
+/// Existing subjects keep their category id; only new subjects are affected
+#[derive(Accounts)]
+pub struct RemoveCategory<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [CATEGORY_REGISTRY_SEED, config.key().as_ref()],
+        bump = category_registry.bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+}
+
+pub fn remove_category(ctx: Context<RemoveCategory>, category_id: u16) -> Result<()> {
+    let registry = &mut ctx.accounts.category_registry;
+
+    let index = registry.categories.iter()
+        .position(|c| c.id == category_id)
+        .ok_or(TribunalCraftError::CategoryNotFound)?;
+    registry.categories.remove(index);
+
+    msg!("Category removed: {}", category_id);
+    Ok(())
+}
@@ -3,9 +3,243 @@ use crate::state::*;
 use crate::constants::{
     CHALLENGER_ACCOUNT_SEED, DISPUTE_SEED, DISPUTE_ESCROW_SEED,
     CHALLENGER_RECORD_SEED, INITIAL_REPUTATION, BASE_CHALLENGER_BOND,
-    DEFENDER_POOL_SEED,
+    DEFENDER_POOL_SEED, ACCOUNT_VERSION, PROTOCOL_STATS_SEED, MAX_DISPUTE_BATCH,
 };
 use crate::errors::TribunalCraftError;
+use crate::lifecycle_log::{LifecycleLog, LifecycleKind, NOOP_PROGRAM_ID};
+use crate::utils::{transfer_lamports, validate_cid, create_pda_account};
+use crate::events::{LamportsMoveReason, DisputeBatchCreatedEvent};
+use crate::invariants;
+
+/// Move the defender stake a new dispute holds into escrow (match mode only)
+/// Returns (from_pool, from_subject, pool_defender_added). Pool-sourced stake is
+/// credited to the pool owner's record so claims route it back to the pool.
+pub(crate) fn hold_defender_stake<'info>(
+    subject: &mut Account<'info, Subject>,
+    defender_pool: Option<&mut Account<'info, DefenderPool>>,
+    pool_defender_record: Option<&mut Account<'info, DefenderRecord>>,
+    escrow: &AccountInfo<'info>,
+    bond: u64,
+    now: i64,
+) -> Result<(u64, u64, bool)> {
+    // Free cases hold nothing; proportional mode holds nothing up front
+    if subject.free_case || !subject.match_mode {
+        return Ok((0, 0, false));
+    }
+
+    let (pool_transfer, direct_transfer, pool_owner, pool_key) = if subject.is_linked() {
+        let defender_pool = defender_pool.ok_or(TribunalCraftError::PoolAccountMismatch)?;
+
+        let total_available = defender_pool.available.saturating_add(subject.total_stake);
+        let required_hold = bond.min(subject.max_stake);
+
+        require!(total_available >= required_hold, TribunalCraftError::InsufficientAvailableStake);
+
+        let pool_transfer = required_hold.min(defender_pool.available);
+        let direct_transfer = required_hold.saturating_sub(pool_transfer);
+
+        // Transfer stakes from pool to escrow (if any)
+        if pool_transfer > 0 {
+            // Update pool accounting (reduce available, but NOT using hold_stake since we're transferring)
+            defender_pool.available = defender_pool.available.checked_sub(pool_transfer)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+            defender_pool.total_stake = defender_pool.total_stake.checked_sub(pool_transfer)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+            defender_pool.updated_at = now;
+            transfer_lamports(&defender_pool.to_account_info(), escrow, pool_transfer, LamportsMoveReason::StakeHold)?;
+        }
+
+        (pool_transfer, direct_transfer, defender_pool.owner, defender_pool.key())
+    } else {
+        // Standalone mode
+        require!(subject.total_stake >= bond, TribunalCraftError::InsufficientAvailableStake);
+        (0, bond, Pubkey::default(), Pubkey::default())
+    };
+
+    // Credit pool-sourced stake to the owner's record so claims route it back to the pool
+    let mut pool_defender_added = false;
+    if pool_transfer > 0 {
+        let pool_record = pool_defender_record.ok_or(TribunalCraftError::PoolAccountMismatch)?;
+        require!(pool_record.defender == pool_owner, TribunalCraftError::PoolAccountMismatch);
+        // Owner not already counted as a direct defender
+        pool_defender_added = pool_record.stake == 0;
+        pool_record.credit_pool_stake(pool_key, pool_transfer)?;
+    }
+
+    // Transfer stakes from subject to escrow (if any)
+    if direct_transfer > 0 {
+        transfer_lamports(&subject.to_account_info(), escrow, direct_transfer, LamportsMoveReason::StakeHold)?;
+        // Update subject stake accounting
+        subject.total_stake = subject.total_stake.checked_sub(direct_transfer)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    }
+
+    Ok((pool_transfer, direct_transfer, pool_defender_added))
+}
+
+/// Accounts a new dispute is opened with (shared by submit_dispute and create_disputes_batch)
+pub(crate) struct NewDispute<'a, 'info> {
+    pub challenger: &'a AccountInfo<'info>,
+    pub challenger_account: &'a mut Account<'info, ChallengerAccount>,
+    pub subject: &'a mut Account<'info, Subject>,
+    pub defender_pool: Option<&'a mut Account<'info, DefenderPool>>,
+    pub pool_defender_record: Option<&'a mut Account<'info, DefenderRecord>>,
+    pub dispute: &'a mut Account<'info, Dispute>,
+    pub escrow: &'a AccountLoader<'info, DisputeEscrow>,
+    pub challenger_record: &'a mut Account<'info, ChallengerRecord>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+/// What a challenger opens a dispute with
+pub(crate) struct DisputeTerms {
+    pub dispute_type: DisputeType,
+    pub details_cid: String,
+    pub bond: u64,
+    /// Shared by disputes opened together by create_disputes_batch (default = none)
+    pub incident_id: Pubkey,
+}
+
+/// PDA bumps of the accounts a new dispute creates
+pub(crate) struct DisputeBumps {
+    pub dispute: u8,
+    pub escrow: u8,
+    pub challenger_record: u8,
+}
+
+impl<'info> NewDispute<'_, 'info> {
+    /// Take the bond, hold defender stake, then initialize escrow, dispute and challenger record
+    pub fn open(
+        self,
+        config: &ProtocolConfig,
+        stats: Option<&mut ProtocolStats>,
+        terms: DisputeTerms,
+        bumps: DisputeBumps,
+        now: i64,
+    ) -> Result<()> {
+        let DisputeTerms { dispute_type, details_cid, bond, incident_id } = terms;
+        let subject = self.subject;
+        let challenger_account = self.challenger_account;
+        let dispute = self.dispute;
+        let escrow = self.escrow;
+        let challenger_record = self.challenger_record;
+
+        validate_cid(&details_cid, ChallengerRecord::MAX_CID_LEN, config.validate_cids)?;
+
+        // Free cases: no bond taken or tracked, no stake held, just voting
+        let bond = if subject.free_case { 0 } else { bond };
+        if !subject.free_case {
+            let min_bond = challenger_account.calculate_min_bond(BASE_CHALLENGER_BOND);
+            require!(bond >= min_bond, TribunalCraftError::BondBelowMinimum);
+        }
+
+        // After a defender win, re-disputes need an escalated bond until the cooldown ends
+        subject.check_cooldown_bond(bond, config.cooldown_escalation_bps, now)?;
+
+        // Transfer bond from challenger to escrow
+        if bond > 0 {
+            let cpi_context = CpiContext::new(
+                self.system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: self.challenger.clone(),
+                    to: escrow.to_account_info(),
+                },
+            );
+            anchor_lang::system_program::transfer(cpi_context, bond)?;
+        }
+
+        // Move held defender stake into escrow (match mode only)
+        let (pool_stake_to_transfer, direct_stake_to_transfer, pool_defender_added) = hold_defender_stake(
+            subject,
+            self.defender_pool,
+            self.pool_defender_record,
+            &escrow.to_account_info(),
+            bond,
+            now,
+        )?;
+
+        // Initialize escrow (all lamport moves above must finish before the data borrow)
+        let mut escrow_data = escrow.load_init()?;
+        escrow_data.dispute = dispute.key();
+        escrow_data.subject = subject.key();
+        escrow_data.total_bonds = bond;
+        escrow_data.total_stakes = pool_stake_to_transfer.checked_add(direct_stake_to_transfer)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        escrow_data.bonds_claimed = 0;
+        escrow_data.stakes_claimed = 0;
+        escrow_data.juror_rewards_paid = 0;
+        escrow_data.platform_fee_paid = 0;
+        escrow_data.challengers_claimed = 0;
+        escrow_data.defenders_claimed = 0;
+        escrow_data.expected_challengers = 1;
+        escrow_data.expected_defenders = subject.defender_count as u8 + pool_defender_added as u8;
+        escrow_data.bump = bumps.escrow;
+        escrow_data.version = ACCOUNT_VERSION;
+        escrow_data.created_at = now;
+        invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+        // Update subject status
+        subject.status = SubjectStatus::Disputed;
+        subject.dispute = dispute.key();
+        subject.dispute_count = subject.dispute_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        subject.updated_at = now;
+
+        // Initialize dispute
+        dispute.subject = subject.key();
+        dispute.dispute_type = dispute_type;
+        dispute.category_id = subject.category_id;
+        dispute.total_bond = bond;
+        dispute.add_type_bond(dispute_type, bond)?;
+        dispute.stake_held = pool_stake_to_transfer;
+        dispute.direct_stake_held = direct_stake_to_transfer;
+        dispute.challenger_count = 1;
+        dispute.status = DisputeStatus::Pending;
+        dispute.outcome = ResolutionOutcome::None;
+        dispute.votes_favor_weight = 0;
+        dispute.votes_against_weight = 0;
+        dispute.vote_count = 0;
+        dispute.resolved_at = 0;
+        dispute.bump = bumps.dispute;
+        dispute.version = ACCOUNT_VERSION;
+        dispute.created_at = now;
+        dispute.pool_reward_claimed = false;
+        dispute.graded = subject.graded_verdicts;
+        dispute.incident_id = incident_id;
+
+        // Snapshot defender state for historical record
+        dispute.snapshot_total_stake = subject.total_stake.saturating_add(direct_stake_to_transfer); // Original stake
+        dispute.snapshot_defender_count = subject.defender_count;
+        dispute.challengers_claimed = 0;
+        dispute.defenders_claimed = 0;
+
+        // Voting starts immediately
+        dispute.start_voting(now, subject.voting_period);
+        if let Some(stats) = stats {
+            stats.record_dispute(false, now);
+        }
+        msg!("Dispute submitted - escrow created (stakes: {}, bond: {})",
+            escrow_data.total_stakes, bond);
+
+        // Initialize challenger record
+        challenger_record.dispute = dispute.key();
+        challenger_record.challenger = self.challenger.key();
+        challenger_record.challenger_account = challenger_account.key();
+        challenger_record.bond = bond;
+        challenger_record.details_cid = details_cid;
+        challenger_record.reward_claimed = false;
+        challenger_record.bump = bumps.challenger_record;
+        challenger_record.version = ACCOUNT_VERSION;
+        challenger_record.challenged_at = now;
+        challenger_record.reputation_snapshot = Some(challenger_account.reputation);
+
+        // Update challenger stats
+        challenger_account.disputes_submitted = challenger_account.disputes_submitted.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        challenger_account.last_dispute_at = now;
+
+        Ok(())
+    }
+}
 
 /// Submit a new dispute against a subject (creates dispute + escrow)
 #[derive(Accounts)]
@@ -16,6 +250,7 @@ pub struct SubmitDispute<'info> {
     #[account(
         mut,
         constraint = subject.can_dispute() @ TribunalCraftError::SubjectCannotBeDisputed,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
         constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
     )]
     pub subject: Account<'info, Subject>,
@@ -23,10 +258,17 @@ pub struct SubmitDispute<'info> {
     /// Optional: defender pool if subject is linked
     #[account(
         mut,
-        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::InvalidConfig,
+        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::PoolAccountMismatch,
     )]
     pub defender_pool: Option<Account<'info, DefenderPool>>,
 
+    /// Optional: pool owner's defender record (required when stake is pulled from the pool)
+    #[account(
+        mut,
+        has_one = subject,
+    )]
+    pub pool_defender_record: Option<Account<'info, DefenderRecord>>,
+
     #[account(
         init_if_needed,
         payer = challenger,
@@ -53,7 +295,7 @@ pub struct SubmitDispute<'info> {
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
         bump
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     #[account(
         init,
@@ -64,6 +306,24 @@ pub struct SubmitDispute<'info> {
     )]
     pub challenger_record: Account<'info, ChallengerRecord>,
 
+    /// Protocol config (cooldown escalation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -73,11 +333,7 @@ pub fn submit_dispute(
     details_cid: String,
     bond: u64,
 ) -> Result<()> {
-    let subject = &mut ctx.accounts.subject;
     let challenger_account = &mut ctx.accounts.challenger_account;
-    let dispute = &mut ctx.accounts.dispute;
-    let escrow = &mut ctx.accounts.escrow;
-    let challenger_record = &mut ctx.accounts.challenger_record;
     let clock = Clock::get()?;
 
     // Initialize challenger account if new
@@ -85,140 +341,228 @@ pub fn submit_dispute(
         challenger_account.challenger = ctx.accounts.challenger.key();
         challenger_account.reputation = INITIAL_REPUTATION;
         challenger_account.bump = ctx.bumps.challenger_account;
+        challenger_account.version = ACCOUNT_VERSION;
         challenger_account.created_at = clock.unix_timestamp;
     }
 
-    // Free cases: no bond required, no stake held, just voting
-    let (pool_stake_to_transfer, direct_stake_to_transfer) = if subject.free_case {
-        (0, 0)
-    } else {
-        // Regular case - validate and calculate stakes to transfer
-        let min_bond = challenger_account.calculate_min_bond(BASE_CHALLENGER_BOND);
-        require!(bond >= min_bond, TribunalCraftError::BondBelowMinimum);
-
-        if subject.match_mode {
-            if subject.is_linked() {
-                let defender_pool = ctx.accounts.defender_pool.as_mut()
-                    .ok_or(TribunalCraftError::InvalidConfig)?;
-
-                let total_available = defender_pool.available.saturating_add(subject.total_stake);
-                let required_hold = bond.min(subject.max_stake);
-
-                require!(total_available >= required_hold, TribunalCraftError::InsufficientAvailableStake);
-
-                let pool_transfer = required_hold.min(defender_pool.available);
-                let direct_transfer = required_hold.saturating_sub(pool_transfer);
-
-                // Update pool accounting (reduce available, but NOT using hold_stake since we're transferring)
-                if pool_transfer > 0 {
-                    defender_pool.available = defender_pool.available.saturating_sub(pool_transfer);
-                    defender_pool.total_stake = defender_pool.total_stake.saturating_sub(pool_transfer);
-                    defender_pool.updated_at = clock.unix_timestamp;
-                }
-
-                (pool_transfer, direct_transfer)
-            } else {
-                // Standalone mode
-                require!(subject.total_stake >= bond, TribunalCraftError::InsufficientAvailableStake);
-                (0, bond)
-            }
-        } else {
-            (0, 0) // Proportional mode: no transfer
-        }
-    };
+    NewDispute {
+        challenger: &ctx.accounts.challenger.to_account_info(),
+        challenger_account,
+        subject: &mut ctx.accounts.subject,
+        defender_pool: ctx.accounts.defender_pool.as_mut(),
+        pool_defender_record: ctx.accounts.pool_defender_record.as_mut(),
+        dispute: &mut ctx.accounts.dispute,
+        escrow: &ctx.accounts.escrow,
+        challenger_record: &mut ctx.accounts.challenger_record,
+        system_program: &ctx.accounts.system_program.to_account_info(),
+    }.open(
+        &ctx.accounts.protocol_config,
+        ctx.accounts.protocol_stats.as_deref_mut(),
+        DisputeTerms { dispute_type, details_cid, bond, incident_id: Pubkey::default() },
+        DisputeBumps {
+            dispute: ctx.bumps.dispute,
+            escrow: ctx.bumps.escrow,
+            challenger_record: ctx.bumps.challenger_record,
+        },
+        clock.unix_timestamp,
+    )?;
+
+    LifecycleLog {
+        kind: LifecycleKind::Disputed,
+        subject: ctx.accounts.subject.key(),
+        dispute: ctx.accounts.dispute.key(),
+        status: ctx.accounts.subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: bond,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+    Ok(())
+}
 
-    // Transfer bond from challenger to escrow
-    if !subject.free_case && bond > 0 {
-        let cpi_context = CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.challenger.to_account_info(),
-                to: escrow.to_account_info(),
-            },
-        );
-        anchor_lang::system_program::transfer(cpi_context, bond)?;
-    }
+// =============================================================================
+// BATCH DISPUTES (one incident across many subjects)
+// =============================================================================
+//
+// Opens a dispute on each subject in one transaction, all tagged with the same
+// incident_id. Each subject settles on its own; the shared id only links them
+// for clients and analytics. Every dispute is opened exactly like submit_dispute.
 
-    // Transfer stakes from pool to escrow (if any)
-    if pool_stake_to_transfer > 0 {
-        let defender_pool = ctx.accounts.defender_pool.as_ref()
-            .ok_or(TribunalCraftError::InvalidConfig)?;
-        **defender_pool.to_account_info().try_borrow_mut_lamports()? -= pool_stake_to_transfer;
-        **escrow.to_account_info().try_borrow_mut_lamports()? += pool_stake_to_transfer;
-    }
+#[derive(Accounts)]
+pub struct CreateDisputesBatch<'info> {
+    #[account(mut)]
+    pub challenger: Signer<'info>,
 
-    // Transfer stakes from subject to escrow (if any)
-    if direct_stake_to_transfer > 0 {
-        **subject.to_account_info().try_borrow_mut_lamports()? -= direct_stake_to_transfer;
-        **escrow.to_account_info().try_borrow_mut_lamports()? += direct_stake_to_transfer;
-        // Update subject stake accounting
-        subject.total_stake = subject.total_stake.saturating_sub(direct_stake_to_transfer);
-    }
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = ChallengerAccount::LEN,
+        seeds = [CHALLENGER_ACCOUNT_SEED, challenger.key().as_ref()],
+        bump
+    )]
+    pub challenger_account: Account<'info, ChallengerAccount>,
 
-    // Initialize escrow
-    escrow.dispute = dispute.key();
-    escrow.subject = subject.key();
-    escrow.total_bonds = bond;
-    escrow.total_stakes = pool_stake_to_transfer.saturating_add(direct_stake_to_transfer);
-    escrow.bonds_claimed = 0;
-    escrow.stakes_claimed = 0;
-    escrow.juror_rewards_paid = 0;
-    escrow.platform_fee_paid = 0;
-    escrow.challengers_claimed = 0;
-    escrow.defenders_claimed = 0;
-    escrow.expected_challengers = 1;
-    escrow.expected_defenders = subject.defender_count as u8;
-    escrow.bump = ctx.bumps.escrow;
-    escrow.created_at = clock.unix_timestamp;
-
-    // Update subject status
-    subject.status = SubjectStatus::Disputed;
-    subject.dispute = dispute.key();
-    subject.dispute_count += 1;
-    subject.updated_at = clock.unix_timestamp;
+    /// Protocol config every subject in the batch must belong to
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
-    // Initialize dispute
-    dispute.subject = subject.key();
-    dispute.dispute_type = dispute_type;
-    dispute.total_bond = bond;
-    dispute.stake_held = pool_stake_to_transfer;
-    dispute.direct_stake_held = direct_stake_to_transfer;
-    dispute.challenger_count = 1;
-    dispute.status = DisputeStatus::Pending;
-    dispute.outcome = ResolutionOutcome::None;
-    dispute.votes_favor_weight = 0;
-    dispute.votes_against_weight = 0;
-    dispute.vote_count = 0;
-    dispute.resolved_at = 0;
-    dispute.bump = ctx.bumps.dispute;
-    dispute.created_at = clock.unix_timestamp;
-    dispute.pool_reward_claimed = false;
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
 
-    // Snapshot defender state for historical record
-    dispute.snapshot_total_stake = subject.total_stake.saturating_add(direct_stake_to_transfer); // Original stake
-    dispute.snapshot_defender_count = subject.defender_count;
-    dispute.challengers_claimed = 0;
-    dispute.defenders_claimed = 0;
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
 
-    // Voting starts immediately
-    dispute.start_voting(clock.unix_timestamp, subject.voting_period);
-    msg!("Dispute submitted - escrow created (stakes: {}, bond: {})",
-        escrow.total_stakes, bond);
-
-    // Initialize challenger record
-    challenger_record.dispute = dispute.key();
-    challenger_record.challenger = ctx.accounts.challenger.key();
-    challenger_record.challenger_account = challenger_account.key();
-    challenger_record.bond = bond;
-    challenger_record.details_cid = details_cid;
-    challenger_record.reward_claimed = false;
-    challenger_record.bump = ctx.bumps.challenger_record;
-    challenger_record.challenged_at = clock.unix_timestamp;
-
-    // Update challenger stats
-    challenger_account.disputes_submitted += 1;
-    challenger_account.last_dispute_at = clock.unix_timestamp;
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts, 6 per subject (all writable):
+///   [subject, dispute, escrow, challenger_record, defender_pool, pool_defender_record]
+/// dispute / escrow / challenger_record are the uncreated PDAs submit_dispute would init;
+/// pass the program id for defender_pool / pool_defender_record when not needed
+/// bonds: one per subject, in the same order
+pub fn create_disputes_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateDisputesBatch<'info>>,
+    incident_id: Pubkey,
+    dispute_type: DisputeType,
+    details_cid: String,
+    bonds: Vec<u64>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !bonds.is_empty()
+            && bonds.len() <= MAX_DISPUTE_BATCH
+            && remaining.len() == bonds.len() * 6,
+        TribunalCraftError::InvalidDisputeBatch
+    );
+    require!(incident_id != Pubkey::default(), TribunalCraftError::InvalidDisputeBatch);
+
+    let challenger = ctx.accounts.challenger.to_account_info();
+    let challenger_key = challenger.key();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let config_key = ctx.accounts.protocol_config.key();
+    let clock = Clock::get()?;
+
+    let challenger_account = &mut ctx.accounts.challenger_account;
+    if challenger_account.created_at == 0 {
+        challenger_account.challenger = challenger_key;
+        challenger_account.reputation = INITIAL_REPUTATION;
+        challenger_account.bump = ctx.bumps.challenger_account;
+        challenger_account.version = ACCOUNT_VERSION;
+        challenger_account.created_at = clock.unix_timestamp;
+    }
+
+    let mut disputes = Vec::with_capacity(bonds.len());
+    for (group, bond) in remaining.chunks(6).zip(bonds.iter()) {
+        let mut subject: Account<Subject> = Account::try_from(&group[0])?;
+        require!(subject.config == config_key, TribunalCraftError::InvalidRemainingAccounts);
+        require!(subject.can_dispute(), TribunalCraftError::SubjectCannotBeDisputed);
+        require!(!subject.is_frozen(clock.unix_timestamp), TribunalCraftError::SubjectFrozen);
+        require!(!subject.has_active_dispute(), TribunalCraftError::DisputeAlreadyExists);
+
+        // Same PDAs submit_dispute inits
+        let subject_key = subject.key();
+        let dispute_count = subject.dispute_count.to_le_bytes();
+        let (dispute_key, dispute_bump) = Pubkey::find_program_address(
+            &[DISPUTE_SEED, subject_key.as_ref(), &dispute_count], &crate::ID,
+        );
+        let (escrow_key, escrow_bump) = Pubkey::find_program_address(
+            &[DISPUTE_ESCROW_SEED, dispute_key.as_ref()], &crate::ID,
+        );
+        let (record_key, record_bump) = Pubkey::find_program_address(
+            &[CHALLENGER_RECORD_SEED, dispute_key.as_ref(), challenger_key.as_ref()], &crate::ID,
+        );
+        require!(
+            group[1].key() == dispute_key && group[2].key() == escrow_key && group[3].key() == record_key,
+            TribunalCraftError::InvalidRemainingAccounts
+        );
+
+        create_pda_account(
+            &challenger, &group[1], Dispute::LEN,
+            &[DISPUTE_SEED, subject_key.as_ref(), &dispute_count, &[dispute_bump]], &system_program,
+        )?;
+        create_pda_account(
+            &challenger, &group[2], DisputeEscrow::LEN,
+            &[DISPUTE_ESCROW_SEED, dispute_key.as_ref(), &[escrow_bump]], &system_program,
+        )?;
+        create_pda_account(
+            &challenger, &group[3], ChallengerRecord::LEN,
+            &[CHALLENGER_RECORD_SEED, dispute_key.as_ref(), challenger_key.as_ref(), &[record_bump]], &system_program,
+        )?;
+        let mut dispute: Account<Dispute> = Account::try_from_unchecked(&group[1])?;
+        let escrow: AccountLoader<DisputeEscrow> = AccountLoader::try_from_unchecked(&crate::ID, &group[2])?;
+        let mut challenger_record: Account<ChallengerRecord> = Account::try_from_unchecked(&group[3])?;
+
+        // Optional pool accounts (program id = not passed), checked like SubmitDispute's constraints
+        let mut defender_pool = if group[4].key() == crate::ID {
+            None
+        } else {
+            let pool: Account<DefenderPool> = Account::try_from(&group[4])?;
+            require!(pool.key() == subject.defender_pool, TribunalCraftError::PoolAccountMismatch);
+            Some(pool)
+        };
+        let mut pool_defender_record = if group[5].key() == crate::ID {
+            None
+        } else {
+            let record: Account<DefenderRecord> = Account::try_from(&group[5])?;
+            require!(record.subject == subject_key, TribunalCraftError::PoolAccountMismatch);
+            Some(record)
+        };
+
+        NewDispute {
+            challenger: &challenger,
+            challenger_account: &mut *challenger_account,
+            subject: &mut subject,
+            defender_pool: defender_pool.as_mut(),
+            pool_defender_record: pool_defender_record.as_mut(),
+            dispute: &mut dispute,
+            escrow: &escrow,
+            challenger_record: &mut challenger_record,
+            system_program: &system_program,
+        }.open(
+            &ctx.accounts.protocol_config,
+            ctx.accounts.protocol_stats.as_deref_mut(),
+            DisputeTerms { dispute_type, details_cid: details_cid.clone(), bond: *bond, incident_id },
+            DisputeBumps { dispute: dispute_bump, escrow: escrow_bump, challenger_record: record_bump },
+            clock.unix_timestamp,
+        )?;
+
+        LifecycleLog {
+            kind: LifecycleKind::Disputed,
+            subject: subject_key,
+            dispute: dispute_key,
+            status: subject.status,
+            outcome: ResolutionOutcome::None,
+            amount: *bond,
+            timestamp: clock.unix_timestamp,
+        }.write(ctx.accounts.noop_program.as_deref())?;
+
+        subject.exit(&crate::ID)?;
+        dispute.exit(&crate::ID)?;
+        escrow.exit(&crate::ID)?;
+        challenger_record.exit(&crate::ID)?;
+        if let Some(pool) = defender_pool {
+            pool.exit(&crate::ID)?;
+        }
+        if let Some(record) = pool_defender_record {
+            record.exit(&crate::ID)?;
+        }
+        disputes.push(dispute_key);
+    }
 
+    let total_bond = bonds.iter().try_fold(0u64, |total, bond| total.checked_add(*bond))
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    msg!("Dispute batch opened: {} disputes, {} total bond", disputes.len(), total_bond);
+    emit!(DisputeBatchCreatedEvent {
+        incident_id,
+        challenger: challenger_key,
+        disputes,
+        total_bond,
+        timestamp: clock.unix_timestamp,
+    });
     Ok(())
 }
 
@@ -230,17 +574,25 @@ pub struct AddToDispute<'info> {
 
     #[account(
         mut,
-        constraint = !subject.free_case @ TribunalCraftError::InvalidConfig,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
     )]
     pub subject: Account<'info, Subject>,
 
     /// Optional: defender pool if subject is linked
     #[account(
         mut,
-        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::InvalidConfig,
+        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::PoolAccountMismatch,
     )]
     pub defender_pool: Option<Account<'info, DefenderPool>>,
 
+    /// Optional: pool owner's defender record (required when stake is pulled from the pool)
+    #[account(
+        mut,
+        has_one = subject,
+    )]
+    pub pool_defender_record: Option<Account<'info, DefenderRecord>>,
+
     #[account(
         init_if_needed,
         payer = challenger,
@@ -261,9 +613,9 @@ pub struct AddToDispute<'info> {
     #[account(
         mut,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.load()?.bump
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     #[account(
         init_if_needed,
@@ -274,28 +626,43 @@ pub struct AddToDispute<'info> {
     )]
     pub challenger_record: Account<'info, ChallengerRecord>,
 
+    /// Protocol config (late join cutoff)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
+/// dispute_type: the joining challenger's claim (None = the dispute's original type)
 pub fn add_to_dispute(
     ctx: Context<AddToDispute>,
     details_cid: String,
     bond: u64,
+    dispute_type: Option<DisputeType>,
 ) -> Result<()> {
     let subject = &mut ctx.accounts.subject;
     let challenger_account = &mut ctx.accounts.challenger_account;
     let dispute = &mut ctx.accounts.dispute;
-    let escrow = &mut ctx.accounts.escrow;
+    let escrow = &ctx.accounts.escrow;
     let challenger_record = &mut ctx.accounts.challenger_record;
     let clock = Clock::get()?;
 
+    validate_cid(&details_cid, ChallengerRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
     require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    // Bond arriving after most votes are in would shift the pot against jurors' expectations
+    require!(
+        !dispute.in_late_join_window(clock.unix_timestamp, ctx.accounts.protocol_config.late_join_cutoff_bps),
+        TribunalCraftError::LateJoinClosed
+    );
 
     // Initialize challenger account if new
     if challenger_account.created_at == 0 {
         challenger_account.challenger = ctx.accounts.challenger.key();
         challenger_account.reputation = INITIAL_REPUTATION;
         challenger_account.bump = ctx.bumps.challenger_account;
+        challenger_account.version = ACCOUNT_VERSION;
         challenger_account.created_at = clock.unix_timestamp;
     }
 
@@ -306,7 +673,7 @@ pub fn add_to_dispute(
     let (pool_transfer, direct_transfer) = if subject.match_mode {
         if subject.is_linked() {
             let defender_pool = ctx.accounts.defender_pool.as_mut()
-                .ok_or(TribunalCraftError::InvalidConfig)?;
+                .ok_or(TribunalCraftError::PoolAccountMismatch)?;
 
             let total_held = dispute.total_stake_held();
             let remaining_capacity = subject.max_stake.saturating_sub(total_held);
@@ -322,8 +689,10 @@ pub fn add_to_dispute(
             let direct_amt = required.saturating_sub(pool_amt);
 
             if pool_amt > 0 {
-                defender_pool.available = defender_pool.available.saturating_sub(pool_amt);
-                defender_pool.total_stake = defender_pool.total_stake.saturating_sub(pool_amt);
+                defender_pool.available = defender_pool.available.checked_sub(pool_amt)
+                    .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+                defender_pool.total_stake = defender_pool.total_stake.checked_sub(pool_amt)
+                    .ok_or(TribunalCraftError::ArithmeticOverflow)?;
                 defender_pool.updated_at = clock.unix_timestamp;
             }
 
@@ -350,28 +719,47 @@ pub fn add_to_dispute(
     }
 
     // Transfer stakes from pool to escrow
+    let mut pool_defender_added = false;
     if pool_transfer > 0 {
         let defender_pool = ctx.accounts.defender_pool.as_ref()
-            .ok_or(TribunalCraftError::InvalidConfig)?;
-        **defender_pool.to_account_info().try_borrow_mut_lamports()? -= pool_transfer;
-        **escrow.to_account_info().try_borrow_mut_lamports()? += pool_transfer;
+            .ok_or(TribunalCraftError::PoolAccountMismatch)?;
+        transfer_lamports(&defender_pool.to_account_info(), &escrow.to_account_info(), pool_transfer, LamportsMoveReason::StakeHold)?;
+
+        // Credit pool-sourced stake to the owner's record so claims route it back to the pool
+        let pool_record = ctx.accounts.pool_defender_record.as_mut()
+            .ok_or(TribunalCraftError::PoolAccountMismatch)?;
+        require!(pool_record.defender == defender_pool.owner, TribunalCraftError::PoolAccountMismatch);
+        // First pool contribution in this dispute adds the owner as a claimant
+        pool_defender_added = dispute.stake_held == 0 && pool_record.stake == 0;
+        pool_record.credit_pool_stake(defender_pool.key(), pool_transfer)?;
     }
 
     // Transfer stakes from subject to escrow
     if direct_transfer > 0 {
-        **subject.to_account_info().try_borrow_mut_lamports()? -= direct_transfer;
-        **escrow.to_account_info().try_borrow_mut_lamports()? += direct_transfer;
-        subject.total_stake = subject.total_stake.saturating_sub(direct_transfer);
+        transfer_lamports(&subject.to_account_info(), &escrow.to_account_info(), direct_transfer, LamportsMoveReason::StakeHold)?;
+        subject.total_stake = subject.total_stake.checked_sub(direct_transfer)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     }
 
     // Update escrow
-    escrow.add_bond(bond);
-    escrow.add_stake(pool_transfer.saturating_add(direct_transfer));
+    let mut escrow_data = escrow.load_mut()?;
+    escrow_data.add_bond(bond)?;
+    escrow_data.add_stake(pool_transfer.checked_add(direct_transfer)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?)?;
+    if pool_defender_added {
+        escrow_data.expect_defender()?;
+    }
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
 
     // Update dispute
-    dispute.total_bond += bond;
-    dispute.stake_held += pool_transfer;
-    dispute.direct_stake_held += direct_transfer;
+    dispute.total_bond = dispute.total_bond.checked_add(bond)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    let dispute_type = dispute_type.unwrap_or(dispute.dispute_type);
+    dispute.add_type_bond(dispute_type, bond)?;
+    dispute.stake_held = dispute.stake_held.checked_add(pool_transfer)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    dispute.direct_stake_held = dispute.direct_stake_held.checked_add(direct_transfer)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
     // Check if new challenger
     let is_new_challenger = challenger_record.challenged_at == 0;
@@ -384,16 +772,21 @@ pub fn add_to_dispute(
         challenger_record.details_cid = details_cid;
         challenger_record.reward_claimed = false;
         challenger_record.bump = ctx.bumps.challenger_record;
+        challenger_record.version = ACCOUNT_VERSION;
         challenger_record.challenged_at = clock.unix_timestamp;
+        challenger_record.reputation_snapshot = Some(challenger_account.reputation);
 
-        challenger_account.disputes_submitted += 1;
+        challenger_account.disputes_submitted = challenger_account.disputes_submitted.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
         challenger_account.last_dispute_at = clock.unix_timestamp;
-        dispute.challenger_count += 1;
-        escrow.expected_challengers += 1;
+        dispute.challenger_count = dispute.challenger_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        escrow_data.expect_challenger()?;
 
         msg!("New challenger added: {} bond", bond);
     } else {
-        challenger_record.bond += bond;
+        challenger_record.bond = challenger_record.bond.checked_add(bond)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
         msg!("Added to existing bond: {} (total: {})", bond, challenger_record.bond);
     }
 
@@ -408,8 +801,9 @@ pub struct SubmitFreeDispute<'info> {
 
     #[account(
         mut,
-        constraint = subject.free_case @ TribunalCraftError::InvalidConfig,
+        constraint = subject.free_case @ TribunalCraftError::FreeCaseMismatch,
         constraint = subject.can_dispute() @ TribunalCraftError::SubjectCannotBeDisputed,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
         constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
     )]
     pub subject: Account<'info, Subject>,
@@ -423,6 +817,24 @@ pub struct SubmitFreeDispute<'info> {
     )]
     pub dispute: Account<'info, Dispute>,
 
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (CID validation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -435,13 +847,19 @@ pub fn submit_free_dispute(
     let dispute = &mut ctx.accounts.dispute;
     let clock = Clock::get()?;
 
+    validate_cid(&details_cid, ChallengerRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    // No bond to escalate - free disputes wait out the cooldown
+    require!(!subject.in_cooldown(clock.unix_timestamp), TribunalCraftError::DisputeCooldownActive);
+
     subject.status = SubjectStatus::Disputed;
     subject.dispute = dispute.key();
-    subject.dispute_count += 1;
+    subject.dispute_count = subject.dispute_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     subject.updated_at = clock.unix_timestamp;
 
     dispute.subject = subject.key();
     dispute.dispute_type = dispute_type;
+    dispute.category_id = subject.category_id;
     dispute.total_bond = 0;
     dispute.stake_held = 0;
     dispute.direct_stake_held = 0;
@@ -453,6 +871,7 @@ pub fn submit_free_dispute(
     dispute.vote_count = 0;
     dispute.resolved_at = 0;
     dispute.bump = ctx.bumps.dispute;
+    dispute.version = ACCOUNT_VERSION;
     dispute.created_at = clock.unix_timestamp;
     dispute.pool_reward_claimed = false;
     dispute.snapshot_total_stake = 0;
@@ -461,6 +880,18 @@ pub fn submit_free_dispute(
     dispute.defenders_claimed = 0;
 
     dispute.start_voting(clock.unix_timestamp, subject.voting_period);
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_dispute(false, clock.unix_timestamp);
+    }
+    LifecycleLog {
+        kind: LifecycleKind::Disputed,
+        subject: subject.key(),
+        dispute: dispute.key(),
+        status: subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: 0,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
     msg!("Free dispute submitted: {} - voting started", details_cid);
 
     Ok(())
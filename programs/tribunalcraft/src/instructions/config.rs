@@ -1,8 +1,15 @@
 use anchor_lang::prelude::*;
-use crate::state::ProtocolConfig;
-use crate::constants::PROTOCOL_CONFIG_SEED;
+use crate::state::{ProtocolConfig, CreatorAllowlist, ProtocolStats, RebateTier, ExpiryQueue, Treasury};
+use crate::constants::{
+    PROTOCOL_CONFIG_SEED, CREATOR_ALLOWLIST_SEED, PROTOCOL_STATS_SEED, MAX_BPS, TOTAL_FEE_BPS,
+    EXPIRY_QUEUE_SEED, TREASURY_SEED, ACCOUNT_VERSION,
+};
+use crate::errors::TribunalCraftError;
+use crate::utils::transfer_lamports;
+use crate::events::LamportsMoveReason;
 
-/// Initialize protocol config (one-time setup by deployer)
+/// Initialize a protocol config instance (one per authority)
+/// Each instance has its own treasury, fees, allowlist and categories; subjects bind to one at creation
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
     #[account(mut)]
@@ -12,7 +19,7 @@ pub struct InitializeConfig<'info> {
         init,
         payer = authority,
         space = ProtocolConfig::LEN,
-        seeds = [PROTOCOL_CONFIG_SEED],
+        seeds = [PROTOCOL_CONFIG_SEED, authority.key().as_ref()],
         bump
     )]
     pub config: Account<'info, ProtocolConfig>,
@@ -21,13 +28,206 @@ pub struct InitializeConfig<'info> {
 }
 
 pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
-    let config = &mut ctx.accounts.config;
+    init_config(&mut ctx.accounts.config, ctx.accounts.authority.key(), ctx.bumps.config);
+    Ok(())
+}
 
-    config.authority = ctx.accounts.authority.key();
-    config.treasury = ctx.accounts.authority.key(); // Initially set to deployer
-    config.bump = ctx.bumps.config;
+/// Initialize the global [PROTOCOL_CONFIG_SEED] config (one-time, program upgrade authority only)
+/// Deployments from before config instances already have it (upgrade it with migrate_account).
+/// It is the only config deposit_pool_yield takes the stake pool approval from
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfig::LEN,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ TribunalCraftError::Unauthorized
+    )]
+    pub program: Program<'info, crate::program::Tribunalcraft>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ TribunalCraftError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_global_config(ctx: Context<InitializeGlobalConfig>) -> Result<()> {
+    init_config(&mut ctx.accounts.config, ctx.accounts.authority.key(), ctx.bumps.config);
+    Ok(())
+}
+
+fn init_config(config: &mut ProtocolConfig, authority: Pubkey, bump: u8) {
+    config.authority = authority;
+    config.treasury = authority; // Initially set to deployer
+    config.bump = bump;
+    config.set_defaults();
+    config.version = ACCOUNT_VERSION;
 
     msg!("Protocol config initialized. Treasury: {}", config.treasury);
+}
+
+/// Initialize the protocol statistics account (one-time, program upgrade authority only)
+/// Shared across instances. Optional on every instruction that updates it, so its totals are
+/// best-effort: they only count transactions that passed it (see ProtocolStats)
+#[derive(Accounts)]
+pub struct InitializeStats<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolStats::LEN,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ TribunalCraftError::Unauthorized
+    )]
+    pub program: Program<'info, crate::program::Tribunalcraft>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ TribunalCraftError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_stats(ctx: Context<InitializeStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.protocol_stats;
+    stats.bump = ctx.bumps.protocol_stats;
+    stats.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Protocol stats initialized");
+
+    Ok(())
+}
+
+/// Initialize a config's keeper expiry queue (one-time, config authority)
+/// One queue per instance, so another instance can't fill it or contend for its write lock.
+/// Optional for resolution and close_escrow, which keep it current
+#[derive(Accounts)]
+pub struct InitializeExpiryQueue<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ExpiryQueue::LEN,
+        seeds = [EXPIRY_QUEUE_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub expiry_queue: Account<'info, ExpiryQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_expiry_queue(ctx: Context<InitializeExpiryQueue>) -> Result<()> {
+    let queue = &mut ctx.accounts.expiry_queue;
+    queue.config = ctx.accounts.config.key();
+    queue.bump = ctx.bumps.expiry_queue;
+    queue.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Expiry queue initialized");
+
+    Ok(())
+}
+
+/// Create the config's accounting treasury and route fees to it (admin only)
+/// Later fee and sweep transfers credit Treasury::total_collected
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Treasury::LEN,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.config = ctx.accounts.config.key();
+    treasury.bump = ctx.bumps.treasury;
+    treasury.updated_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.config.treasury = treasury.key();
+
+    msg!("Treasury PDA initialized: {}", treasury.key());
+
+    Ok(())
+}
+
+/// Withdraw collected lamports from the accounting treasury (admin only)
+/// The PDA keeps its rent-exempt minimum
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [TREASURY_SEED, config.key().as_ref()],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Any account chosen by the authority
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+}
+
+pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let rent = Rent::get()?.minimum_balance(Treasury::LEN);
+    let available = treasury.to_account_info().lamports().saturating_sub(rent);
+    require!(amount > 0, TribunalCraftError::ZeroAmount);
+    require!(amount <= available, TribunalCraftError::InsufficientTreasuryBalance);
+
+    transfer_lamports(&treasury.to_account_info(), &ctx.accounts.recipient, amount, LamportsMoveReason::TreasuryWithdrawal)?;
+    treasury.total_withdrawn = treasury.total_withdrawn.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    treasury.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Treasury withdrawal: {} lamports to {}", amount, ctx.accounts.recipient.key());
 
     Ok(())
 }
@@ -40,8 +240,6 @@ pub struct UpdateTreasury<'info> {
 
     #[account(
         mut,
-        seeds = [PROTOCOL_CONFIG_SEED],
-        bump = config.bump,
         has_one = authority,
     )]
     pub config: Account<'info, ProtocolConfig>,
@@ -55,3 +253,429 @@ pub fn update_treasury(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Re
 
     Ok(())
 }
+
+/// Add a wallet to the subject creator allowlist (admin only)
+#[derive(Accounts)]
+pub struct AddAllowedCreator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = CreatorAllowlist::LEN,
+        seeds = [CREATOR_ALLOWLIST_SEED, config.key().as_ref()],
+        bump
+    )]
+    pub creator_allowlist: Account<'info, CreatorAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_allowed_creator(ctx: Context<AddAllowedCreator>, creator: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.creator_allowlist;
+
+    require!(!allowlist.contains(&creator), TribunalCraftError::CreatorAlreadyAllowed);
+    require!(
+        allowlist.creators.len() < CreatorAllowlist::MAX_CREATORS,
+        TribunalCraftError::CreatorAllowlistFull
+    );
+
+    allowlist.bump = ctx.bumps.creator_allowlist;
+    allowlist.creators.push(creator);
+
+    msg!("Allowed creator added: {}", creator);
+    Ok(())
+}
+
+/// Remove a wallet from the subject creator allowlist (admin only)
+#[derive(Accounts)]
+pub struct RemoveAllowedCreator<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_ALLOWLIST_SEED, config.key().as_ref()],
+        bump = creator_allowlist.bump
+    )]
+    pub creator_allowlist: Account<'info, CreatorAllowlist>,
+}
+
+pub fn remove_allowed_creator(ctx: Context<RemoveAllowedCreator>, creator: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.creator_allowlist;
+
+    let index = allowlist.creators.iter()
+        .position(|c| *c == creator)
+        .ok_or(TribunalCraftError::CreatorNotAllowed)?;
+    allowlist.creators.remove(index);
+
+    msg!("Allowed creator removed: {}", creator);
+    Ok(())
+}
+
+/// Enable or disable the subject creator gate (admin only)
+#[derive(Accounts)]
+pub struct SetSubjectCreatorGate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [CREATOR_ALLOWLIST_SEED, config.key().as_ref()],
+        bump = creator_allowlist.bump
+    )]
+    pub creator_allowlist: Account<'info, CreatorAllowlist>,
+}
+
+pub fn set_subject_creator_gate(ctx: Context<SetSubjectCreatorGate>, enabled: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.subject_creator_gate = if enabled {
+        Some(ctx.accounts.creator_allowlist.key())
+    } else {
+        None
+    };
+
+    msg!("Subject creator gate enabled: {}", enabled);
+    Ok(())
+}
+
+/// Update bounds for per-subject reward split overrides (admin only)
+/// Existing subjects keep the split they were created with
+#[derive(Accounts)]
+pub struct UpdateShareBounds<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_share_bounds(
+    ctx: Context<UpdateShareBounds>,
+    min_juror_share_bps: u16,
+    max_juror_share_bps: u16,
+    min_winner_share_bps: u16,
+    max_winner_share_bps: u16,
+) -> Result<()> {
+    require!(
+        min_juror_share_bps <= max_juror_share_bps && max_juror_share_bps <= MAX_BPS,
+        TribunalCraftError::InvalidConfig
+    );
+    require!(
+        min_winner_share_bps <= max_winner_share_bps && max_winner_share_bps <= MAX_BPS - TOTAL_FEE_BPS,
+        TribunalCraftError::InvalidConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.min_juror_share_bps = min_juror_share_bps;
+    config.max_juror_share_bps = max_juror_share_bps;
+    config.min_winner_share_bps = min_winner_share_bps;
+    config.max_winner_share_bps = max_winner_share_bps;
+
+    msg!(
+        "Share bounds updated: juror {}-{}, winner {}-{}",
+        min_juror_share_bps, max_juror_share_bps, min_winner_share_bps, max_winner_share_bps
+    );
+    Ok(())
+}
+
+/// Update how long new juror stake takes to reach full voting weight (admin only)
+#[derive(Accounts)]
+pub struct UpdateStakeMaturityPeriod<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_stake_maturity_period(ctx: Context<UpdateStakeMaturityPeriod>, period: i64) -> Result<()> {
+    require!(period >= 0, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.stake_maturity_period = period;
+
+    msg!("Stake maturity period updated: {} seconds", period);
+    Ok(())
+}
+
+/// Update juror exposure caps (admin only)
+/// Both are bps of the juror's total stake; MAX_BPS disables a cap
+#[derive(Accounts)]
+pub struct UpdateAllocationCaps<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_allocation_caps(
+    ctx: Context<UpdateAllocationCaps>,
+    max_vote_allocation_bps: u16,
+    max_locked_bps: u16,
+) -> Result<()> {
+    require!(
+        max_vote_allocation_bps > 0 && max_vote_allocation_bps <= MAX_BPS,
+        TribunalCraftError::InvalidConfig
+    );
+    require!(
+        max_locked_bps > 0 && max_locked_bps <= MAX_BPS,
+        TribunalCraftError::InvalidConfig
+    );
+
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.min_vote_allocation_bps <= max_vote_allocation_bps,
+        TribunalCraftError::InvalidConfig
+    );
+    config.max_vote_allocation_bps = max_vote_allocation_bps;
+    config.max_locked_bps = max_locked_bps;
+
+    msg!("Allocation caps updated: {} bps per dispute, {} bps locked", max_vote_allocation_bps, max_locked_bps);
+    Ok(())
+}
+
+/// Update the minimum stake per vote (admin only)
+/// The larger of the two floors applies; 0 disables a floor
+#[derive(Accounts)]
+pub struct UpdateMinVoteAllocation<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_min_vote_allocation(
+    ctx: Context<UpdateMinVoteAllocation>,
+    min_vote_allocation: u64,
+    min_vote_allocation_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    // A relative floor above the per-dispute cap would make every vote fail
+    require!(
+        min_vote_allocation_bps <= config.max_vote_allocation_bps,
+        TribunalCraftError::InvalidConfig
+    );
+
+    config.min_vote_allocation = min_vote_allocation;
+    config.min_vote_allocation_bps = min_vote_allocation_bps;
+
+    msg!("Min vote allocation updated: {} lamports, {} bps", min_vote_allocation, min_vote_allocation_bps);
+    Ok(())
+}
+
+/// Update the resolver fee and its per-resolution cap (admin only)
+/// The fee is always capped at the dispute's platform fee
+#[derive(Accounts)]
+pub struct UpdateResolverFee<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_resolver_fee(
+    ctx: Context<UpdateResolverFee>,
+    resolver_fee_bps: u16,
+    max_resolver_fee: u64,
+) -> Result<()> {
+    require!(resolver_fee_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.resolver_fee_bps = resolver_fee_bps;
+    config.max_resolver_fee = max_resolver_fee;
+
+    msg!("Resolver fee updated: {} bps (max {} lamports)", resolver_fee_bps, max_resolver_fee);
+    Ok(())
+}
+
+/// Update the sweep bounty curve (admin only)
+#[derive(Accounts)]
+pub struct UpdateSweepBounty<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_sweep_bounty(
+    ctx: Context<UpdateSweepBounty>,
+    sweep_bounty_min: u64,
+    sweep_bounty_base_bps: u16,
+    sweep_bounty_bps_per_day: u16,
+    sweep_bounty_max: u64,
+) -> Result<()> {
+    require!(sweep_bounty_base_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+    require!(sweep_bounty_bps_per_day <= MAX_BPS, TribunalCraftError::InvalidConfig);
+    require!(sweep_bounty_min <= sweep_bounty_max, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.sweep_bounty_min = sweep_bounty_min;
+    config.sweep_bounty_base_bps = sweep_bounty_base_bps;
+    config.sweep_bounty_bps_per_day = sweep_bounty_bps_per_day;
+    config.sweep_bounty_max = sweep_bounty_max;
+
+    msg!(
+        "Sweep bounty updated: {} bps + {} bps/day, {}..={} lamports",
+        sweep_bounty_base_bps, sweep_bounty_bps_per_day, sweep_bounty_min, sweep_bounty_max
+    );
+    Ok(())
+}
+
+/// Update the post-defense dispute cooldown (admin only)
+/// The cooldown applies to subjects created afterwards; escalation applies immediately
+#[derive(Accounts)]
+pub struct UpdateDisputeCooldown<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_dispute_cooldown(
+    ctx: Context<UpdateDisputeCooldown>,
+    default_dispute_cooldown: i64,
+    cooldown_escalation_bps: u16,
+) -> Result<()> {
+    require!(default_dispute_cooldown >= 0, TribunalCraftError::InvalidConfig);
+    require!(cooldown_escalation_bps >= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.default_dispute_cooldown = default_dispute_cooldown;
+    config.cooldown_escalation_bps = cooldown_escalation_bps;
+
+    msg!("Dispute cooldown updated: {} seconds, {} bps escalation", default_dispute_cooldown, cooldown_escalation_bps);
+    Ok(())
+}
+
+/// Enable or disable CID format validation (admin only)
+#[derive(Accounts)]
+pub struct SetCidValidation<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_cid_validation(ctx: Context<SetCidValidation>, enabled: bool) -> Result<()> {
+    ctx.accounts.config.validate_cids = enabled;
+
+    msg!("CID validation {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+/// Update the juror fee rebate tiers (admin only)
+#[derive(Accounts)]
+pub struct UpdateRebateTiers<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_rebate_tiers(
+    ctx: Context<UpdateRebateTiers>,
+    rebate_tiers: [RebateTier; ProtocolConfig::REBATE_TIERS],
+) -> Result<()> {
+    // Higher reputation never earns a smaller rebate
+    for pair in rebate_tiers.windows(2) {
+        require!(pair[0].min_reputation <= pair[1].min_reputation, TribunalCraftError::InvalidConfig);
+        require!(pair[0].rebate_bps <= pair[1].rebate_bps, TribunalCraftError::InvalidConfig);
+    }
+    require!(
+        rebate_tiers.iter().all(|t| t.min_reputation <= MAX_BPS && t.rebate_bps <= MAX_BPS),
+        TribunalCraftError::InvalidConfig
+    );
+
+    ctx.accounts.config.rebate_tiers = rebate_tiers;
+
+    msg!("Rebate tiers updated: {:?}", rebate_tiers);
+    Ok(())
+}
+
+/// Update the late challenger cutoff (admin only, 0 disables)
+#[derive(Accounts)]
+pub struct UpdateLateJoinCutoff<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_late_join_cutoff(ctx: Context<UpdateLateJoinCutoff>, late_join_cutoff_bps: u16) -> Result<()> {
+    require!(late_join_cutoff_bps < MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    ctx.accounts.config.late_join_cutoff_bps = late_join_cutoff_bps;
+
+    msg!("Late join cutoff updated: last {} bps of voting", late_join_cutoff_bps);
+    Ok(())
+}
+
+/// Approve the SPL stake pool defender pools can delegate idle stake to (admin only)
+/// Pubkey::default() or max_yield_bps = 0 turns new delegations off; existing ones can still be withdrawn
+/// Only the global [PROTOCOL_CONFIG_SEED] config is honoured by deposit_pool_yield. A fresh
+/// deployment creates it with initialize_global_config, which only the upgrade authority can call
+#[derive(Accounts)]
+pub struct SetYieldStakePool<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+}
+
+pub fn set_yield_stake_pool(ctx: Context<SetYieldStakePool>, stake_pool: Pubkey, max_yield_bps: u16) -> Result<()> {
+    require!(max_yield_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.yield_stake_pool = stake_pool;
+    config.max_yield_bps = max_yield_bps;
+
+    msg!("Yield stake pool set: {} (max {} bps)", stake_pool, max_yield_bps);
+    Ok(())
+}
@@ -1,7 +1,14 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::constants::DEFENDER_POOL_SEED;
+use crate::constants::{
+    DEFENDER_POOL_SEED, POOL_DELEGATE_SEED, ACCOUNT_VERSION, YIELD_VAULT_SEED,
+    DISPUTE_ESCROW_SEED, DEFENDER_RECORD_SEED, PROTOCOL_CONFIG_SEED,
+};
 use crate::errors::TribunalCraftError;
+use crate::utils::transfer_lamports;
+use crate::events::{LamportsMoveReason, PoolDefendedEvent};
+use crate::invariants;
+use crate::yield_cpi::{self, StakePoolAccounts, STAKE_POOL_PROGRAM_ID, TOKEN_PROGRAM_ID, STAKE_PROGRAM_ID};
 
 #[derive(Accounts)]
 pub struct CreatePool<'info> {
@@ -44,6 +51,7 @@ pub fn create_pool(ctx: Context<CreatePool>, initial_stake: u64) -> Result<()> {
     defender_pool.subject_count = 0;
     defender_pool.pending_disputes = 0;
     defender_pool.bump = ctx.bumps.defender_pool;
+    defender_pool.version = ACCOUNT_VERSION;
     defender_pool.created_at = clock.unix_timestamp;
     defender_pool.updated_at = clock.unix_timestamp;
 
@@ -84,9 +92,12 @@ pub fn stake_pool(ctx: Context<StakePool>, amount: u64) -> Result<()> {
     anchor_lang::system_program::transfer(cpi_context, amount)?;
 
     // Update pool
-    defender_pool.total_stake += amount;
-    defender_pool.available += amount;
+    defender_pool.total_stake = defender_pool.total_stake.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.available = defender_pool.available.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     defender_pool.updated_at = clock.unix_timestamp;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
 
     msg!("Added {} lamports to pool", amount);
     Ok(())
@@ -115,14 +126,468 @@ pub fn withdraw_pool(ctx: Context<WithdrawPool>, amount: u64) -> Result<()> {
     require!(amount <= defender_pool.available, TribunalCraftError::InsufficientAvailableStake);
 
     // Transfer from pool to owner
-    **defender_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+    transfer_lamports(&defender_pool.to_account_info(), &ctx.accounts.owner.to_account_info(), amount, LamportsMoveReason::PoolWithdrawal)?;
 
     // Update pool
-    defender_pool.total_stake -= amount;
+    defender_pool.total_stake = defender_pool.total_stake.checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     defender_pool.available -= amount;
     defender_pool.updated_at = clock.unix_timestamp;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
 
     msg!("Withdrew {} lamports from pool", amount);
     Ok(())
 }
+
+/// Authorize a wallet to link subjects to the pool, or update its cap (owner only)
+/// Lowering the cap below what is already committed only blocks new links
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct SetPoolDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ TribunalCraftError::Unauthorized,
+        seeds = [DEFENDER_POOL_SEED, owner.key().as_ref()],
+        bump = defender_pool.bump
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = PoolDelegate::LEN,
+        seeds = [POOL_DELEGATE_SEED, defender_pool.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub pool_delegate: Account<'info, PoolDelegate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_pool_delegate(ctx: Context<SetPoolDelegate>, delegate: Pubkey, spending_cap: u64) -> Result<()> {
+    let pool_delegate = &mut ctx.accounts.pool_delegate;
+
+    if pool_delegate.created_at == 0 {
+        pool_delegate.pool = ctx.accounts.defender_pool.key();
+        pool_delegate.delegate = delegate;
+        pool_delegate.committed = 0;
+        pool_delegate.bump = ctx.bumps.pool_delegate;
+        pool_delegate.created_at = Clock::get()?.unix_timestamp;
+    }
+    pool_delegate.spending_cap = spending_cap;
+
+    msg!("Pool delegate {} cap set: {} lamports", delegate, spending_cap);
+    Ok(())
+}
+
+/// Revoke a delegate (owner only); subjects it already linked stay linked
+#[derive(Accounts)]
+pub struct RemovePoolDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        has_one = owner @ TribunalCraftError::Unauthorized,
+        seeds = [DEFENDER_POOL_SEED, owner.key().as_ref()],
+        bump = defender_pool.bump
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [POOL_DELEGATE_SEED, defender_pool.key().as_ref(), pool_delegate.delegate.as_ref()],
+        bump = pool_delegate.bump
+    )]
+    pub pool_delegate: Account<'info, PoolDelegate>,
+}
+
+pub fn remove_pool_delegate(ctx: Context<RemovePoolDelegate>) -> Result<()> {
+    msg!("Pool delegate removed: {}", ctx.accounts.pool_delegate.delegate);
+    Ok(())
+}
+
+/// Back a disputed subject from any defender pool (pool owner or delegate signs)
+/// The stake moves straight into the dispute escrow as the owner's pool_stake, so claims
+/// pay it back to the pool. The subject does not have to be linked to the pool
+#[derive(Accounts)]
+pub struct DefendFromPool<'info> {
+    /// Pool owner or delegate
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = subject.status == SubjectStatus::Disputed @ TribunalCraftError::SubjectCannotBeStaked,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch, // Free subjects hold no stake
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.key() == subject.dispute @ TribunalCraftError::DisputeNotFound,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+        constraint = !dispute.is_appeal @ TribunalCraftError::AppealMismatch, // Appeals are opposed via oppose_restore
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        mut,
+        seeds = [DEFENDER_POOL_SEED, defender_pool.owner.as_ref()],
+        bump = defender_pool.bump
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    /// Optional: authority's delegate grant (required unless the authority is the pool owner)
+    #[account(
+        mut,
+        seeds = [POOL_DELEGATE_SEED, defender_pool.key().as_ref(), authority.key().as_ref()],
+        bump = pool_delegate.bump
+    )]
+    pub pool_delegate: Option<Account<'info, PoolDelegate>>,
+
+    /// Pool owner's record - credited with the pool stake
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = DefenderRecord::LEN,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), defender_pool.owner.as_ref()],
+        bump
+    )]
+    pub defender_record: Account<'info, DefenderRecord>,
+
+    /// Protocol config (late join cutoff)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn defend_from_pool(ctx: Context<DefendFromPool>, amount: u64) -> Result<()> {
+    let defender_pool = &mut ctx.accounts.defender_pool;
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let defender_record = &mut ctx.accounts.defender_record;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, TribunalCraftError::StakeBelowMinimum);
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    // Same cutoff as new bond: stake arriving after most votes are in would shift the pot
+    require!(
+        !dispute.in_late_join_window(clock.unix_timestamp, ctx.accounts.protocol_config.late_join_cutoff_bps),
+        TribunalCraftError::LateJoinClosed
+    );
+    require!(amount <= defender_pool.available, TribunalCraftError::InsufficientAvailableStake);
+
+    if ctx.accounts.authority.key() != defender_pool.owner {
+        let pool_delegate = ctx.accounts.pool_delegate.as_mut()
+            .ok_or(TribunalCraftError::Unauthorized)?;
+        pool_delegate.commit(amount)?;
+        defender_record.charge_delegate(pool_delegate.key(), amount)?;
+    }
+
+    // Initialize pool owner's record if new (no direct stake; staked_at is for direct stakers)
+    if defender_record.subject == Pubkey::default() {
+        defender_record.subject = ctx.accounts.subject.key();
+        defender_record.defender = defender_pool.owner;
+        defender_record.stake = 0;
+        defender_record.pool_stake = 0;
+        defender_record.reward_claimed = false;
+        defender_record.bump = ctx.bumps.defender_record;
+        defender_record.version = ACCOUNT_VERSION;
+        defender_record.staked_at = 0;
+    }
+    // Owners already staking on the subject are counted as claimants already
+    let new_defender = defender_record.total_stake() == 0;
+    defender_record.credit_pool_stake(defender_pool.key(), amount)?;
+
+    // Move the stake from the pool into the dispute escrow
+    defender_pool.available = defender_pool.available.checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.total_stake = defender_pool.total_stake.checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.updated_at = clock.unix_timestamp;
+    transfer_lamports(&defender_pool.to_account_info(), &escrow.to_account_info(), amount, LamportsMoveReason::StakeHold)?;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
+
+    // Match mode re-derives the contested amounts from the escrow totals at claim time
+    let mut escrow_data = escrow.load_mut()?;
+    escrow_data.add_stake(amount)?;
+    if new_defender {
+        escrow_data.expected_defenders = escrow_data.expected_defenders.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    }
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+    dispute.stake_held = dispute.stake_held.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    emit!(PoolDefendedEvent {
+        dispute: dispute.key(),
+        defender_pool: defender_pool.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+        stake_held: dispute.stake_held,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Pool {} defended dispute with {} lamports", defender_pool.key(), amount);
+    Ok(())
+}
+
+// =============================================================================
+// POOL YIELD (idle stake delegated to an SPL stake pool, opt-in)
+// =============================================================================
+//
+// The owner can move part of the pool's available stake into the stake pool
+// approved by the global config (ProtocolConfig::yield_stake_pool), up to
+// max_yield_bps of the pool. Lamports pass through a system-owned vault PDA that signs the
+// deposit and holds the pool tokens. Delegated lamports leave total_stake and
+// available, so disputes only ever hold liquid stake. Withdrawals credit the
+// principal plus any yield back to available.
+
+/// Stake pool accounts passed through to the SPL stake pool program
+#[derive(Accounts)]
+pub struct StakePoolCpi<'info> {
+    /// CHECK: SPL stake pool (validated by the stake pool program)
+    #[account(mut)]
+    pub stake_pool: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool withdraw authority (validated by the stake pool program)
+    pub withdraw_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool reserve stake (validated by the stake pool program)
+    #[account(mut)]
+    pub reserve_stake: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool manager fee account (validated by the stake pool program)
+    #[account(mut)]
+    pub manager_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Stake pool token mint (validated by the stake pool program)
+    #[account(mut)]
+    pub pool_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Vault's pool token account (mint and authority checked by yield_cpi::token_balance)
+    #[account(mut)]
+    pub pool_tokens: UncheckedAccount<'info>,
+
+    /// CHECK: SPL stake pool program
+    #[account(address = STAKE_POOL_PROGRAM_ID)]
+    pub stake_pool_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL token program
+    #[account(address = TOKEN_PROGRAM_ID)]
+    pub token_program: UncheckedAccount<'info>,
+}
+
+impl<'info> StakePoolCpi<'info> {
+    fn accounts(&self) -> StakePoolAccounts<'_, 'info> {
+        StakePoolAccounts {
+            stake_pool_program: self.stake_pool_program.as_ref(),
+            stake_pool: self.stake_pool.as_ref(),
+            withdraw_authority: self.withdraw_authority.as_ref(),
+            reserve_stake: self.reserve_stake.as_ref(),
+            manager_fee_account: self.manager_fee_account.as_ref(),
+            pool_mint: self.pool_mint.as_ref(),
+            token_program: self.token_program.as_ref(),
+        }
+    }
+}
+
+/// Delegate available pool stake to the approved stake pool (owner only)
+#[derive(Accounts)]
+pub struct DepositPoolYield<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ TribunalCraftError::Unauthorized,
+        seeds = [DEFENDER_POOL_SEED, owner.key().as_ref()],
+        bump = defender_pool.bump
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    /// Global config (deployer-owned, see initialize_global_config): pools are shared across
+    /// instances, so a per-authority config anyone can create cannot approve a stake pool
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+        constraint = protocol_config.yield_stake_pool != Pubkey::default()
+            && protocol_config.yield_stake_pool == stake_pool_cpi.stake_pool.key() @ TribunalCraftError::StakePoolNotApproved,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Signs the stake pool deposit and owns the pool tokens
+    #[account(
+        mut,
+        seeds = [YIELD_VAULT_SEED, defender_pool.key().as_ref()],
+        bump
+    )]
+    pub yield_vault: SystemAccount<'info>,
+
+    pub stake_pool_cpi: StakePoolCpi<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_pool_yield(ctx: Context<DepositPoolYield>, amount: u64) -> Result<()> {
+    let defender_pool = &mut ctx.accounts.defender_pool;
+    let cpi = &ctx.accounts.stake_pool_cpi;
+    let vault = ctx.accounts.yield_vault.to_account_info();
+    let stake_pool = cpi.stake_pool.key();
+
+    require!(amount > 0, TribunalCraftError::StakeBelowMinimum);
+    require!(
+        defender_pool.can_delegate(amount, ctx.accounts.protocol_config.max_yield_bps),
+        TribunalCraftError::InsufficientAvailableStake
+    );
+    require!(
+        defender_pool.yield_pool_tokens == 0 || defender_pool.yield_stake_pool == stake_pool,
+        TribunalCraftError::StakePoolNotApproved
+    );
+
+    let tokens_before = yield_cpi::token_balance(&cpi.pool_tokens, &cpi.pool_mint.key(), &vault.key())?;
+
+    // Pool -> vault, then the vault deposits into the stake pool
+    transfer_lamports(&defender_pool.to_account_info(), &vault, amount, LamportsMoveReason::YieldDeposit)?;
+    let pool_key = defender_pool.key();
+    let vault_seeds: &[&[u8]] = &[YIELD_VAULT_SEED, pool_key.as_ref(), &[ctx.bumps.yield_vault]];
+    yield_cpi::deposit_sol(
+        &cpi.accounts(),
+        &vault,
+        &cpi.pool_tokens,
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+        vault_seeds,
+    )?;
+
+    let tokens_after = yield_cpi::token_balance(&cpi.pool_tokens, &cpi.pool_mint.key(), &vault.key())?;
+    let minted = tokens_after.checked_sub(tokens_before).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    defender_pool.total_stake = defender_pool.total_stake.checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.available -= amount;
+    defender_pool.yield_principal = defender_pool.yield_principal.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.yield_pool_tokens = defender_pool.yield_pool_tokens.checked_add(minted)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.yield_stake_pool = stake_pool;
+    defender_pool.updated_at = Clock::get()?.unix_timestamp;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
+
+    msg!("Delegated {} lamports to stake pool ({} pool tokens)", amount, minted);
+    Ok(())
+}
+
+/// Redeem stake pool tokens back into available pool stake (owner only)
+#[derive(Accounts)]
+pub struct WithdrawPoolYield<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner @ TribunalCraftError::Unauthorized,
+        seeds = [DEFENDER_POOL_SEED, owner.key().as_ref()],
+        bump = defender_pool.bump,
+        constraint = defender_pool.yield_stake_pool == stake_pool_cpi.stake_pool.key() @ TribunalCraftError::StakePoolNotApproved,
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    /// Holds the pool tokens and receives the withdrawn lamports
+    #[account(
+        mut,
+        seeds = [YIELD_VAULT_SEED, defender_pool.key().as_ref()],
+        bump
+    )]
+    pub yield_vault: SystemAccount<'info>,
+
+    pub stake_pool_cpi: StakePoolCpi<'info>,
+
+    /// CHECK: Clock sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: Stake history sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: Native stake program
+    #[account(address = STAKE_PROGRAM_ID)]
+    pub stake_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_pool_yield(ctx: Context<WithdrawPoolYield>, pool_tokens: u64) -> Result<()> {
+    let defender_pool = &mut ctx.accounts.defender_pool;
+    let cpi = &ctx.accounts.stake_pool_cpi;
+    let vault = ctx.accounts.yield_vault.to_account_info();
+
+    require!(
+        pool_tokens > 0 && pool_tokens <= defender_pool.yield_pool_tokens,
+        TribunalCraftError::InsufficientAvailableStake
+    );
+    yield_cpi::token_balance(&cpi.pool_tokens, &cpi.pool_mint.key(), &vault.key())?;
+
+    let pool_key = defender_pool.key();
+    let vault_seeds: &[&[u8]] = &[YIELD_VAULT_SEED, pool_key.as_ref(), &[ctx.bumps.yield_vault]];
+    let lamports_before = vault.lamports();
+    yield_cpi::withdraw_sol(
+        &cpi.accounts(),
+        &vault,
+        &cpi.pool_tokens,
+        &ctx.accounts.clock,
+        &ctx.accounts.stake_history,
+        &ctx.accounts.stake_program,
+        pool_tokens,
+        vault_seeds,
+    )?;
+    let received = vault.lamports().checked_sub(lamports_before)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    // Vault is system-owned: move the lamports back with a signed transfer
+    let signer_seeds = [vault_seeds];
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: vault.clone(),
+            to: defender_pool.to_account_info(),
+        },
+        &signer_seeds,
+    );
+    anchor_lang::system_program::transfer(cpi_context, received)?;
+
+    let principal = defender_pool.principal_for_tokens(pool_tokens);
+    defender_pool.yield_principal -= principal;
+    defender_pool.yield_pool_tokens -= pool_tokens;
+    defender_pool.total_stake = defender_pool.total_stake.checked_add(received)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.available = defender_pool.available.checked_add(received)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    if defender_pool.yield_pool_tokens == 0 {
+        defender_pool.yield_stake_pool = Pubkey::default();
+    }
+    defender_pool.updated_at = Clock::get()?.unix_timestamp;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
+
+    msg!(
+        "Redeemed {} pool tokens for {} lamports (principal {})",
+        pool_tokens, received, principal
+    );
+    Ok(())
+}
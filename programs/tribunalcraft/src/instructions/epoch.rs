@@ -0,0 +1,422 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::{
+    JUROR_EPOCH_SEED, EPOCH_ENROLLMENT_SEED, EPOCH_ASSIGNMENT_SEED, JUROR_ACCOUNT_SEED,
+    VOTE_RECORD_SEED, JUROR_CLAIM_DEADLINE, MAX_BPS,
+};
+use crate::errors::TribunalCraftError;
+use crate::utils::transfer_lamports;
+use crate::events::{LamportsMoveReason, EpochRewardClaimedEvent};
+
+// =============================================================================
+// JUROR EPOCHS (open -> fund -> enroll -> assign/serve -> claim -> close)
+// =============================================================================
+//
+// The authority opens fixed terms. Before a term starts, jurors commit part of
+// their available stake; committed stake can still vote but cannot be
+// withdrawn until the term ends. Disputes are assigned to enrolled jurors
+// during the term, and each assignment the juror votes on counts as served.
+// After the term, the funded base reward is split by committed stake, minus
+// miss_penalty_bps for every assignment the juror skipped.
+//
+// The base reward comes from protocol fees: set_fee_epoch points the config at
+// an epoch, and until it ends every resolution routes epoch_fee_bps of the
+// treasury's share of the platform fee into it (see settle_dispute).
+// fund_juror_epoch tops it up by hand.
+
+/// Open a juror epoch (admin only)
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct OpenJurorEpoch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = JurorEpoch::LEN,
+        seeds = [JUROR_EPOCH_SEED, config.key().as_ref(), &index.to_le_bytes()],
+        bump
+    )]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_juror_epoch(
+    ctx: Context<OpenJurorEpoch>,
+    index: u32,
+    starts_at: i64,
+    ends_at: i64,
+    miss_penalty_bps: u16,
+) -> Result<()> {
+    let juror_epoch = &mut ctx.accounts.juror_epoch;
+    let clock = Clock::get()?;
+
+    require!(starts_at > clock.unix_timestamp, TribunalCraftError::InvalidEpochWindow);
+    require!(ends_at > starts_at, TribunalCraftError::InvalidEpochWindow);
+    require!(miss_penalty_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    juror_epoch.config = ctx.accounts.config.key();
+    juror_epoch.index = index;
+    juror_epoch.starts_at = starts_at;
+    juror_epoch.ends_at = ends_at;
+    juror_epoch.miss_penalty_bps = miss_penalty_bps;
+    juror_epoch.bump = ctx.bumps.juror_epoch;
+    juror_epoch.created_at = clock.unix_timestamp;
+
+    msg!("Juror epoch {} opened: {} to {}", index, starts_at, ends_at);
+    Ok(())
+}
+
+/// Point the config's fee share at an epoch, or stop routing fees (admin only)
+#[derive(Accounts)]
+pub struct SetFeeEpoch<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// Epoch receiving the fee share (None = fees stay with the treasury)
+    #[account(
+        has_one = config @ TribunalCraftError::FeeEpochMismatch,
+    )]
+    pub juror_epoch: Option<Account<'info, JurorEpoch>>,
+}
+
+pub fn set_fee_epoch(ctx: Context<SetFeeEpoch>, epoch_fee_bps: u16) -> Result<()> {
+    require!(epoch_fee_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    let config = &mut ctx.accounts.config;
+    config.epoch_fee_bps = epoch_fee_bps;
+    match &ctx.accounts.juror_epoch {
+        Some(juror_epoch) => {
+            require!(!juror_epoch.is_ended(Clock::get()?.unix_timestamp), TribunalCraftError::EpochEnrollmentClosed);
+            config.fee_epoch = juror_epoch.key();
+            config.fee_epoch_ends_at = juror_epoch.ends_at;
+            msg!("Fee epoch set: {} ({} bps of treasury fees)", juror_epoch.index, epoch_fee_bps);
+        }
+        None => {
+            config.fee_epoch = Pubkey::default();
+            config.fee_epoch_ends_at = 0;
+            msg!("Fee epoch cleared");
+        }
+    }
+    Ok(())
+}
+
+/// Add to an epoch's base reward by hand (anyone)
+#[derive(Accounts)]
+pub struct FundJurorEpoch<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_juror_epoch(ctx: Context<FundJurorEpoch>, amount: u64) -> Result<()> {
+    let juror_epoch = &mut ctx.accounts.juror_epoch;
+
+    require!(amount > 0, TribunalCraftError::ZeroAmount);
+    require!(!juror_epoch.is_ended(Clock::get()?.unix_timestamp), TribunalCraftError::EpochEnrollmentClosed);
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: ctx.accounts.funder.to_account_info(),
+            to: juror_epoch.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+    juror_epoch.reward_pool = juror_epoch.reward_pool.checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Juror epoch {} funded: {} lamports (pool: {})", juror_epoch.index, amount, juror_epoch.reward_pool);
+    Ok(())
+}
+
+/// Commit stake to an epoch before it starts
+#[derive(Accounts)]
+pub struct EnrollJurorEpoch<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        constraint = juror_account.is_active @ TribunalCraftError::JurorNotActive,
+        seeds = [JUROR_ACCOUNT_SEED, juror.key().as_ref()],
+        bump = juror_account.bump
+    )]
+    pub juror_account: Account<'info, JurorAccount>,
+
+    #[account(mut)]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+
+    #[account(
+        init,
+        payer = juror,
+        space = EpochEnrollment::LEN,
+        seeds = [EPOCH_ENROLLMENT_SEED, juror_epoch.key().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub enrollment: Account<'info, EpochEnrollment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn enroll_juror_epoch(ctx: Context<EnrollJurorEpoch>, stake: u64) -> Result<()> {
+    let juror_account = &mut ctx.accounts.juror_account;
+    let juror_epoch = &mut ctx.accounts.juror_epoch;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let clock = Clock::get()?;
+
+    require!(juror_epoch.is_enrollment_open(clock.unix_timestamp), TribunalCraftError::EpochEnrollmentClosed);
+    require!(stake > 0, TribunalCraftError::StakeBelowMinimum);
+    require!(stake <= juror_account.available_stake, TribunalCraftError::InsufficientAvailableStake);
+    // One term at a time: a running commitment must end before the next one starts
+    require!(!juror_account.is_committed(clock.unix_timestamp), TribunalCraftError::StakeCommittedToEpoch);
+
+    juror_account.epoch_commitment = stake;
+    juror_account.committed_until = juror_epoch.ends_at;
+
+    enrollment.epoch = juror_epoch.key();
+    enrollment.juror = ctx.accounts.juror.key();
+    enrollment.committed_stake = stake;
+    enrollment.bump = ctx.bumps.enrollment;
+    enrollment.enrolled_at = clock.unix_timestamp;
+
+    juror_epoch.total_committed = juror_epoch.total_committed.checked_add(stake)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    juror_epoch.juror_count = juror_epoch.juror_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Juror enrolled in epoch {}: {} lamports committed", juror_epoch.index, stake);
+    Ok(())
+}
+
+/// Assign a dispute voting during the term to an enrolled juror (admin only)
+#[derive(Accounts)]
+pub struct AssignEpochDispute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        constraint = juror_epoch.config == config.key() @ TribunalCraftError::EpochAccountMismatch,
+    )]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+
+    #[account(
+        constraint = subject.config == config.key() @ TribunalCraftError::InvalidConfig,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        constraint = enrollment.epoch == juror_epoch.key() @ TribunalCraftError::EpochAccountMismatch,
+    )]
+    pub enrollment: Account<'info, EpochEnrollment>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = EpochAssignment::LEN,
+        seeds = [EPOCH_ASSIGNMENT_SEED, juror_epoch.key().as_ref(), dispute.key().as_ref(), enrollment.juror.as_ref()],
+        bump
+    )]
+    pub assignment: Account<'info, EpochAssignment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn assign_epoch_dispute(ctx: Context<AssignEpochDispute>) -> Result<()> {
+    let juror_epoch = &ctx.accounts.juror_epoch;
+    let dispute = &ctx.accounts.dispute;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let assignment = &mut ctx.accounts.assignment;
+    let clock = Clock::get()?;
+
+    require!(juror_epoch.covers(dispute.voting_starts_at), TribunalCraftError::EpochNotCoveringDispute);
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+
+    assignment.epoch = juror_epoch.key();
+    assignment.dispute = dispute.key();
+    assignment.juror = enrollment.juror;
+    assignment.bump = ctx.bumps.assignment;
+    assignment.assigned_at = clock.unix_timestamp;
+
+    enrollment.assigned = enrollment.assigned.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Dispute assigned to epoch {} juror {}", juror_epoch.index, enrollment.juror);
+    Ok(())
+}
+
+/// Mark an assignment served once the juror's vote record exists (permissionless)
+#[derive(Accounts)]
+pub struct RecordEpochService<'info> {
+    #[account(
+        mut,
+        constraint = !assignment.served @ TribunalCraftError::AssignmentAlreadyServed,
+    )]
+    pub assignment: Account<'info, EpochAssignment>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_ENROLLMENT_SEED, assignment.epoch.as_ref(), assignment.juror.as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, EpochEnrollment>,
+
+    #[account(
+        seeds = [VOTE_RECORD_SEED, assignment.dispute.as_ref(), assignment.juror.as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+pub fn record_epoch_service(ctx: Context<RecordEpochService>) -> Result<()> {
+    let assignment = &mut ctx.accounts.assignment;
+    let enrollment = &mut ctx.accounts.enrollment;
+
+    assignment.served = true;
+    enrollment.served = enrollment.served.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Epoch service recorded: {}/{} assignments served", enrollment.served, enrollment.assigned);
+    Ok(())
+}
+
+/// Claim an epoch's base reward into the juror's stake after the term ends
+#[derive(Accounts)]
+pub struct ClaimEpochReward<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        seeds = [JUROR_ACCOUNT_SEED, juror.key().as_ref()],
+        bump = juror_account.bump
+    )]
+    pub juror_account: Account<'info, JurorAccount>,
+
+    #[account(mut)]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+
+    #[account(
+        mut,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        constraint = !enrollment.reward_claimed @ TribunalCraftError::RewardAlreadyClaimed,
+        seeds = [EPOCH_ENROLLMENT_SEED, juror_epoch.key().as_ref(), juror.key().as_ref()],
+        bump = enrollment.bump
+    )]
+    pub enrollment: Account<'info, EpochEnrollment>,
+}
+
+pub fn claim_epoch_reward(ctx: Context<ClaimEpochReward>) -> Result<()> {
+    let juror_account = &mut ctx.accounts.juror_account;
+    let juror_epoch = &mut ctx.accounts.juror_epoch;
+    let enrollment = &mut ctx.accounts.enrollment;
+    let clock = Clock::get()?;
+
+    require!(juror_epoch.is_ended(clock.unix_timestamp), TribunalCraftError::EpochNotEnded);
+
+    let reward = juror_epoch.reward_for(enrollment);
+    if reward > 0 {
+        transfer_lamports(
+            &juror_epoch.to_account_info(),
+            &juror_account.to_account_info(),
+            reward,
+            LamportsMoveReason::EpochReward,
+        )?;
+        juror_account.add_reward(reward)?;
+    }
+
+    enrollment.reward_claimed = true;
+    juror_epoch.rewards_paid = juror_epoch.rewards_paid.checked_add(reward)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    juror_epoch.claimed_count = juror_epoch.claimed_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    emit!(EpochRewardClaimedEvent {
+        epoch: juror_epoch.key(),
+        juror: enrollment.juror,
+        assigned: enrollment.assigned,
+        served: enrollment.served,
+        reward,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Epoch reward claimed: {} lamports ({}/{} served)", reward, enrollment.served, enrollment.assigned);
+    Ok(())
+}
+
+/// Close a finished epoch; penalties and unclaimed rewards go to the treasury (admin only)
+#[derive(Accounts)]
+pub struct CloseJurorEpoch<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+        has_one = treasury @ TribunalCraftError::InvalidConfig,
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Treasury address validated against config
+    #[account(mut)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = treasury,
+        constraint = juror_epoch.config == config.key() @ TribunalCraftError::EpochAccountMismatch,
+    )]
+    pub juror_epoch: Account<'info, JurorEpoch>,
+}
+
+pub fn close_juror_epoch(ctx: Context<CloseJurorEpoch>) -> Result<()> {
+    let juror_epoch = &ctx.accounts.juror_epoch;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(juror_epoch.is_ended(now), TribunalCraftError::EpochNotEnded);
+    require!(
+        juror_epoch.claimed_count >= juror_epoch.juror_count
+            || now >= juror_epoch.ends_at.saturating_add(JUROR_CLAIM_DEADLINE),
+        TribunalCraftError::EpochClaimsPending
+    );
+
+    // Everything left in the epoch, rent included, lands in the treasury on close
+    Treasury::record_income(&ctx.accounts.treasury, juror_epoch.to_account_info().lamports())?;
+
+    msg!(
+        "Juror epoch {} closed: {} of {} lamports paid",
+        juror_epoch.index,
+        juror_epoch.rewards_paid,
+        juror_epoch.reward_pool
+    );
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::{
+    EVIDENCE_LOG_SEED, CHALLENGER_RECORD_SEED, DEFENDER_RECORD_SEED, EVIDENCE_ACK_SEED,
+    JUROR_ACCOUNT_SEED, MAX_REVIEW_DELAY,
+};
+use crate::errors::TribunalCraftError;
+use crate::utils::validate_cid;
+use crate::events::{EvidenceSubmittedEvent, EvidenceAcknowledgedEvent};
+use solana_keccak_hasher as keccak;
+
+/// Append evidence to a dispute (challengers and defenders only)
+/// Allowed during the first half of the voting period
+#[derive(Accounts)]
+pub struct SubmitEvidence<'info> {
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        init_if_needed,
+        payer = submitter,
+        space = EvidenceLog::LEN,
+        seeds = [EVIDENCE_LOG_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub evidence_log: Account<'info, EvidenceLog>,
+
+    /// Optional: submitter's challenger record (submitting as challenger)
+    #[account(
+        seeds = [CHALLENGER_RECORD_SEED, dispute.key().as_ref(), submitter.key().as_ref()],
+        bump = challenger_record.bump
+    )]
+    pub challenger_record: Option<Account<'info, ChallengerRecord>>,
+
+    /// Optional: submitter's defender record (submitting as defender)
+    #[account(
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), submitter.key().as_ref()],
+        bump = defender_record.bump
+    )]
+    pub defender_record: Option<Account<'info, DefenderRecord>>,
+
+    /// Protocol config (CID validation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn submit_evidence(ctx: Context<SubmitEvidence>, cid: String) -> Result<()> {
+    let dispute = &ctx.accounts.dispute;
+    let evidence_log = &mut ctx.accounts.evidence_log;
+    let clock = Clock::get()?;
+
+    validate_cid(&cid, EvidenceEntry::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    require!(
+        dispute.is_evidence_window_open(clock.unix_timestamp),
+        TribunalCraftError::EvidenceWindowClosed
+    );
+    require!(!evidence_log.is_full(), TribunalCraftError::EvidenceLogFull);
+
+    // Side is determined by which record the submitter holds
+    let side = if ctx.accounts.challenger_record.is_some() {
+        EvidenceSide::Challenger
+    } else if ctx.accounts.defender_record.is_some() {
+        EvidenceSide::Defender
+    } else {
+        return Err(TribunalCraftError::Unauthorized.into());
+    };
+
+    // Initialize log on first submission
+    if evidence_log.dispute == Pubkey::default() {
+        evidence_log.dispute = dispute.key();
+        evidence_log.bump = ctx.bumps.evidence_log;
+    }
+
+    let index = evidence_log.entries.len() as u8;
+    evidence_log.entries.push(EvidenceEntry {
+        submitter: ctx.accounts.submitter.key(),
+        side,
+        cid: cid.clone(),
+        submitted_at: clock.unix_timestamp,
+    });
+
+    emit!(EvidenceSubmittedEvent {
+        dispute: dispute.key(),
+        submitter: ctx.accounts.submitter.key(),
+        side,
+        cid,
+        index,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Evidence submitted: {:?} entry {}", side, index);
+    Ok(())
+}
+
+/// Require jurors to acknowledge the opener's evidence before voting (dispute opener only)
+/// Only while the opener is the sole challenger and nobody has voted
+#[derive(Accounts)]
+pub struct RequireEvidenceReview<'info> {
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+        constraint = dispute.challenger_count == 1 && dispute.vote_count == 0 @ TribunalCraftError::EvidenceReviewLocked,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        has_one = dispute,
+        has_one = challenger @ TribunalCraftError::Unauthorized,
+        seeds = [CHALLENGER_RECORD_SEED, dispute.key().as_ref(), challenger.key().as_ref()],
+        bump = challenger_record.bump
+    )]
+    pub challenger_record: Account<'info, ChallengerRecord>,
+}
+
+pub fn require_evidence_review(ctx: Context<RequireEvidenceReview>, review_delay: i64) -> Result<()> {
+    require!(
+        review_delay > 0 && review_delay <= MAX_REVIEW_DELAY,
+        TribunalCraftError::InvalidReviewDelay
+    );
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.review_delay = review_delay;
+    dispute.evidence_hash = keccak::hash(ctx.accounts.challenger_record.details_cid.as_bytes()).to_bytes();
+
+    msg!("Evidence review required: {}s before voting", review_delay);
+    Ok(())
+}
+
+/// Acknowledge a dispute's evidence by its CID hash (active jurors)
+/// Voting opens to the juror review_delay seconds later
+#[derive(Accounts)]
+pub struct AcknowledgeEvidence<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    #[account(
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        constraint = juror_account.is_active @ TribunalCraftError::JurorNotActive,
+        seeds = [JUROR_ACCOUNT_SEED, juror.key().as_ref()],
+        bump = juror_account.bump
+    )]
+    pub juror_account: Account<'info, JurorAccount>,
+
+    #[account(
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+        constraint = dispute.requires_review() @ TribunalCraftError::EvidenceReviewNotRequired,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        init,
+        payer = juror,
+        space = EvidenceAck::LEN,
+        seeds = [EVIDENCE_ACK_SEED, dispute.key().as_ref(), juror.key().as_ref()],
+        bump
+    )]
+    pub evidence_ack: Account<'info, EvidenceAck>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn acknowledge_evidence(ctx: Context<AcknowledgeEvidence>, evidence_hash: [u8; 32]) -> Result<()> {
+    let dispute = &ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    require!(evidence_hash == dispute.evidence_hash, TribunalCraftError::EvidenceHashMismatch);
+
+    let ack = &mut ctx.accounts.evidence_ack;
+    ack.dispute = dispute.key();
+    ack.juror = ctx.accounts.juror.key();
+    ack.acknowledged_at = clock.unix_timestamp;
+    ack.bump = ctx.bumps.evidence_ack;
+
+    emit!(EvidenceAcknowledgedEvent {
+        dispute: ack.dispute,
+        juror: ack.juror,
+        votable_at: clock.unix_timestamp.saturating_add(dispute.review_delay),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Evidence acknowledged");
+    Ok(())
+}
+
+/// Close a juror's evidence acknowledgment and reclaim its rent (the juror)
+/// Only once the dispute can take no more votes: resolving, resolved, or closed with its subject
+#[derive(Accounts)]
+pub struct CloseEvidenceAck<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+
+    /// CHECK: The acknowledged dispute; may already be closed by close_subject
+    pub dispute: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = juror,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        has_one = dispute @ TribunalCraftError::DisputeNotFound,
+        seeds = [EVIDENCE_ACK_SEED, dispute.key().as_ref(), juror.key().as_ref()],
+        bump = evidence_ack.bump
+    )]
+    pub evidence_ack: Account<'info, EvidenceAck>,
+}
+
+pub fn close_evidence_ack(ctx: Context<CloseEvidenceAck>) -> Result<()> {
+    let dispute_info = ctx.accounts.dispute.to_account_info();
+    if !dispute_info.data_is_empty() {
+        require!(dispute_info.owner == &crate::ID, TribunalCraftError::InvalidConfig);
+        let dispute = Dispute::try_deserialize(&mut &dispute_info.try_borrow_data()?[..])?;
+        // A tied vote can reopen voting, so only a settled status is final
+        require!(dispute.status != DisputeStatus::Pending, TribunalCraftError::VotingNotEnded);
+    }
+
+    msg!("Evidence acknowledgment closed");
+    Ok(())
+}
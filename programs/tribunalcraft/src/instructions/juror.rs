@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::constants::{JUROR_ACCOUNT_SEED, INITIAL_REPUTATION, SLASH_THRESHOLD};
+use crate::constants::{JUROR_ACCOUNT_SEED, INITIAL_REPUTATION, SLASH_THRESHOLD, ACCOUNT_VERSION};
 use crate::errors::TribunalCraftError;
+use crate::utils::transfer_lamports;
+use crate::events::LamportsMoveReason;
+use crate::invariants;
 
 #[derive(Accounts)]
 pub struct RegisterJuror<'info> {
@@ -45,8 +48,11 @@ pub fn register_juror(ctx: Context<RegisterJuror>, stake_amount: u64) -> Result<
     juror_account.correct_votes = 0;
     juror_account.is_active = true;
     juror_account.bump = ctx.bumps.juror_account;
+    juror_account.version = ACCOUNT_VERSION;
     juror_account.joined_at = clock.unix_timestamp;
     juror_account.last_vote_at = 0;
+    juror_account.tranches = Vec::new();
+    juror_account.record_tranche(stake_amount, clock.unix_timestamp);
 
     msg!("Juror registered with {} lamports stake", stake_amount);
     Ok(())
@@ -85,13 +91,35 @@ pub fn add_juror_stake(ctx: Context<AddJurorStake>, amount: u64) -> Result<()> {
     anchor_lang::system_program::transfer(cpi_context, amount)?;
 
     // Update stake
-    juror_account.total_stake += amount;
-    juror_account.available_stake += amount;
+    juror_account.deposit(amount)?;
+    juror_account.record_tranche(amount, Clock::get()?.unix_timestamp);
+    invariants::check_juror_account(&juror_account.to_account_info(), juror_account);
 
     msg!("Juror stake added: {} lamports", amount);
     Ok(())
 }
 
+/// Set the wallet that can receive claimed rewards (Pubkey::default() clears it)
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        seeds = [JUROR_ACCOUNT_SEED, juror.key().as_ref()],
+        bump = juror_account.bump
+    )]
+    pub juror_account: Account<'info, JurorAccount>,
+}
+
+pub fn set_beneficiary(ctx: Context<SetBeneficiary>, beneficiary: Pubkey) -> Result<()> {
+    ctx.accounts.juror_account.beneficiary = beneficiary;
+
+    msg!("Juror beneficiary set: {}", beneficiary);
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct WithdrawJurorStake<'info> {
     #[account(mut)]
@@ -112,22 +140,42 @@ pub fn withdraw_juror_stake(ctx: Context<WithdrawJurorStake>, amount: u64) -> Re
     let juror_account = &mut ctx.accounts.juror_account;
 
     require!(juror_account.available_stake >= amount, TribunalCraftError::InsufficientAvailableStake);
+    require!(
+        juror_account.withdrawable_stake(Clock::get()?.unix_timestamp) >= amount,
+        TribunalCraftError::StakeCommittedToEpoch
+    );
 
     // Calculate return based on reputation using fixed slash threshold
     let (return_amount, slash_amount) = juror_account.calculate_withdrawal(amount, SLASH_THRESHOLD);
 
     // Update juror stake
-    juror_account.available_stake -= amount;
-    juror_account.total_stake -= amount;
+    juror_account.withdraw(amount)?;
+    juror_account.release_tranches(amount);
 
     // Transfer return amount to juror (slash amount is burned - stays in account but not tracked)
-    **juror_account.to_account_info().try_borrow_mut_lamports()? -= return_amount;
-    **ctx.accounts.juror.to_account_info().try_borrow_mut_lamports()? += return_amount;
+    transfer_lamports(&juror_account.to_account_info(), &ctx.accounts.juror.to_account_info(), return_amount, LamportsMoveReason::JurorWithdrawal)?;
+    invariants::check_juror_account(&juror_account.to_account_info(), juror_account);
 
     msg!("Juror stake withdrawn: {} returned, {} burned", return_amount, slash_amount);
     Ok(())
 }
 
+/// Burn the reputation slash on a juror's available stake ahead of closing the account
+/// Returns (return_amount, slash_amount)
+pub(crate) fn burn_unregister_slash<'info>(
+    juror_info: &AccountInfo<'info>,
+    juror_account: &JurorAccount,
+    incinerator: &AccountInfo<'info>,
+) -> Result<(u64, u64)> {
+    // Calculate return based on reputation using fixed slash threshold
+    let (return_amount, slash_amount) = juror_account.calculate_withdrawal(
+        juror_account.available_stake,
+        SLASH_THRESHOLD,
+    );
+    transfer_lamports(juror_info, incinerator, slash_amount, LamportsMoveReason::JurorSlash)?;
+    Ok((return_amount, slash_amount))
+}
+
 #[derive(Accounts)]
 pub struct UnregisterJuror<'info> {
     #[account(mut)]
@@ -142,38 +190,33 @@ pub struct UnregisterJuror<'info> {
     )]
     pub juror_account: Account<'info, JurorAccount>,
 
+    /// CHECK: Incinerator receives the slashed part of the stake (burned at end of slot)
+    #[account(mut, address = solana_program::incinerator::ID @ TribunalCraftError::InvalidConfig)]
+    pub incinerator: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn unregister_juror(ctx: Context<UnregisterJuror>) -> Result<()> {
     let juror_account = &ctx.accounts.juror_account;
 
-    // Can only unregister if no locked stake
-    let locked_stake = juror_account.total_stake - juror_account.available_stake;
-    require!(locked_stake == 0, TribunalCraftError::StakeStillLocked);
-
-    // Calculate return based on reputation using fixed slash threshold
-    let (return_amount, slash_amount) = juror_account.calculate_withdrawal(
-        juror_account.available_stake,
-        SLASH_THRESHOLD,
+    // Can only unregister if no locked stake and no vote still awaiting unlock
+    require!(
+        juror_account.held_stake() == 0 && juror_account.active_votes == 0,
+        TribunalCraftError::StakeStillLocked
+    );
+    require!(
+        !juror_account.is_committed(Clock::get()?.unix_timestamp),
+        TribunalCraftError::StakeCommittedToEpoch
     );
 
     // When account closes, all remaining lamports go to juror
-    // But we need to burn the slash amount first
-    if slash_amount > 0 {
-        // Reduce account balance by slash amount (effectively burning it by sending to system)
-        // Note: The close = juror will return remaining lamports after this
-        let juror_info = ctx.accounts.juror.to_account_info();
-        let juror_account_info = ctx.accounts.juror_account.to_account_info();
-
-        // Adjust what gets returned: close will return all lamports, but we want to burn slash_amount
-        // We do this by transferring slash_amount to system program before close
-        **juror_account_info.try_borrow_mut_lamports()? -= slash_amount;
-        // Burn by not transferring to anyone (lamports are lost)
-        // Actually in Solana, lamports can't just disappear - transfer to incinerator
-        // For simplicity, we'll reduce return but close still gives all remaining
-        **juror_info.try_borrow_mut_lamports()? += 0; // No extra transfer, close handles it
-    }
+    // Burn the slash amount first by sending it to the incinerator
+    let (return_amount, slash_amount) = burn_unregister_slash(
+        &ctx.accounts.juror_account.to_account_info(),
+        juror_account,
+        &ctx.accounts.incinerator,
+    )?;
 
     msg!("Juror unregistered: {} returned, {} burned", return_amount, slash_amount);
     Ok(())
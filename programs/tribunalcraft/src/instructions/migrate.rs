@@ -0,0 +1,294 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::constants::{
+    ACCOUNT_VERSION, PROTOCOL_CONFIG_SEED, DEFENDER_POOL_SEED,
+    JUROR_SHARE_BPS, WINNER_SHARE_BPS,
+};
+use crate::errors::TribunalCraftError;
+use crate::events::AccountMigratedEvent;
+
+// =============================================================================
+// ACCOUNT MIGRATION
+// =============================================================================
+//
+// Upgrades are lazy: after a program upgrade changes a layout, clients prepend
+// migrate_account to the first transaction that touches an old account. Fields
+// are only ever appended (or carved out of escrow padding), so an old account is
+// a prefix of the new layout - migration grows it, zero-fills the new fields and
+// runs the per-version upgrade steps below. The one exception is DisputeEscrow,
+// whose v0 Borsh layout is decoded explicitly before it is rewritten zero-copy.
+
+/// Borsh-serialized accounts that carry a layout version
+trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const SPACE: usize;
+    fn version(&self) -> u8;
+    fn set_version(&mut self, version: u8);
+    /// Type-specific data fixups for fields appended after `from_version`
+    fn upgrade_fields(&mut self, _from_version: u8) {}
+}
+
+macro_rules! versioned {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Versioned for $ty {
+            const SPACE: usize = <$ty>::LEN;
+            fn version(&self) -> u8 { self.version }
+            fn set_version(&mut self, version: u8) { self.version = version; }
+        })*
+    };
+}
+
+versioned!(
+    Dispute, DefenderPool, ChallengerAccount,
+    ChallengerRecord, VoteRecord,
+);
+
+impl Versioned for Subject {
+    const SPACE: usize = Subject::LEN;
+    fn version(&self) -> u8 { self.version }
+    fn set_version(&mut self, version: u8) { self.version = version; }
+
+    fn upgrade_fields(&mut self, from_version: u8) {
+        // v0 -> v1: subjects created before configurable splits used the fixed shares
+        if from_version < 1 {
+            self.juror_share_bps = JUROR_SHARE_BPS;
+            self.winner_share_bps = WINNER_SHARE_BPS;
+        }
+        // v6 -> v7: keep the pre-existing behaviour (defender win returns to Active)
+        if from_version < 7 {
+            self.auto_revalidate = true;
+        }
+        // v12 -> v13: existing subjects belong to the original global config
+        if from_version < 13 {
+            self.config = Pubkey::find_program_address(&[PROTOCOL_CONFIG_SEED], &crate::ID).0;
+        }
+    }
+}
+
+impl Versioned for DefenderRecord {
+    const SPACE: usize = DefenderRecord::LEN;
+    fn version(&self) -> u8 { self.version }
+    fn set_version(&mut self, version: u8) { self.version = version; }
+
+    fn upgrade_fields(&mut self, from_version: u8) {
+        // v14 -> v15: outstanding pool stake was always claimed back to the defender's own pool
+        if from_version < 15 && self.pool_stake > 0 {
+            self.payout_pool = Pubkey::find_program_address(
+                &[DEFENDER_POOL_SEED, self.defender.as_ref()],
+                &crate::ID,
+            ).0;
+        }
+    }
+}
+
+impl Versioned for ProtocolConfig {
+    const SPACE: usize = ProtocolConfig::LEN;
+    fn version(&self) -> u8 { self.version }
+    fn set_version(&mut self, version: u8) { self.version = version; }
+
+    fn upgrade_fields(&mut self, from_version: u8) {
+        // v0 -> v1: the original global config only had authority / treasury / bump
+        if from_version < 1 {
+            self.set_defaults();
+        }
+    }
+}
+
+impl Versioned for JurorAccount {
+    const SPACE: usize = JurorAccount::LEN;
+    fn version(&self) -> u8 { self.version }
+    fn set_version(&mut self, version: u8) { self.version = version; }
+
+    fn upgrade_fields(&mut self, from_version: u8) {
+        // v4 -> v5: seed the lock summary from existing holds (vote count is unknown, left at 0)
+        if from_version < 5 {
+            self.locked_stake = self.held_stake();
+        }
+    }
+}
+
+/// Upgrade any versioned program account to the current layout (permissionless)
+/// Payer tops up rent if the account has to grow
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any account owned by this program; layout is identified by discriminator
+    #[account(mut, owner = crate::ID @ TribunalCraftError::InvalidConfig)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let discriminator: [u8; 8] = ctx.accounts.account.try_borrow_data()?
+        .get(..8)
+        .and_then(|d| d.try_into().ok())
+        .ok_or(TribunalCraftError::InvalidConfig)?;
+
+    let from_version = match &discriminator[..] {
+        d if d == Subject::DISCRIMINATOR => upgrade::<Subject>(&ctx)?,
+        d if d == Dispute::DISCRIMINATOR => upgrade::<Dispute>(&ctx)?,
+        d if d == DefenderPool::DISCRIMINATOR => upgrade::<DefenderPool>(&ctx)?,
+        d if d == JurorAccount::DISCRIMINATOR => upgrade::<JurorAccount>(&ctx)?,
+        d if d == ChallengerAccount::DISCRIMINATOR => upgrade::<ChallengerAccount>(&ctx)?,
+        d if d == DefenderRecord::DISCRIMINATOR => upgrade::<DefenderRecord>(&ctx)?,
+        d if d == ChallengerRecord::DISCRIMINATOR => upgrade::<ChallengerRecord>(&ctx)?,
+        d if d == VoteRecord::DISCRIMINATOR => upgrade::<VoteRecord>(&ctx)?,
+        d if d == ProtocolConfig::DISCRIMINATOR => upgrade::<ProtocolConfig>(&ctx)?,
+        d if d == DisputeEscrow::DISCRIMINATOR => upgrade_escrow(&ctx)?,
+        _ => return Err(TribunalCraftError::InvalidConfig.into()),
+    };
+
+    if from_version < ACCOUNT_VERSION {
+        emit!(AccountMigratedEvent {
+            account: ctx.accounts.account.key(),
+            from_version,
+            to_version: ACCOUNT_VERSION,
+        });
+        msg!("Account migrated: v{} -> v{}", from_version, ACCOUNT_VERSION);
+    }
+    Ok(())
+}
+
+/// Grow the account to `space` bytes (new bytes are zero-filled), payer tops up rent
+fn grow(ctx: &Context<MigrateAccount>, space: usize) -> Result<()> {
+    let info = ctx.accounts.account.to_account_info();
+    if info.data_len() >= space {
+        return Ok(());
+    }
+
+    let required = Rent::get()?.minimum_balance(space);
+    let top_up = required.saturating_sub(info.lamports());
+    if top_up > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: info.clone(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, top_up)?;
+    }
+    info.resize(space)?;
+    Ok(())
+}
+
+/// Grow, deserialize, apply upgrade steps and write back. Returns the old version.
+fn upgrade<T: Versioned>(ctx: &Context<MigrateAccount>) -> Result<u8> {
+    let info = ctx.accounts.account.to_account_info();
+    grow(ctx, T::SPACE)?;
+
+    let mut account = T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    let from_version = account.version();
+    if from_version >= ACCOUNT_VERSION {
+        return Ok(from_version);
+    }
+
+    // v0 -> v1: version field introduced (zero-filled by resize). Fields added in the
+    //           unreleased v1 series sit after the v0 prefix; Subject needs share
+    //           defaults (see upgrade_fields), Dispute category_id and DefenderRecord
+    //           pool_stake stay 0
+    // v1 -> v2: JurorAccount tranches appended (empty vec = all existing stake mature)
+    // v2 -> v3: Subject creator appended (default = unknown, only timeout close applies)
+    // v3 -> v4: Subject rationale_required and VoteRecord rationale commitment appended (off)
+    // v4 -> v5: JurorAccount locked_stake / active_votes appended (see upgrade_fields)
+    // v5 -> v6: Subject pledge_threshold appended (0 = pledging disabled)
+    // v6 -> v7: Subject auto_revalidate appended (see upgrade_fields)
+    // v7 -> v8: Subject dispute_cooldown / cooldown_ends_at appended (0 = no cooldown)
+    // v8 -> v9: Subject tie_policy / tie_extension and Dispute tie_extended appended (default = defender wins ties)
+    // v9 -> v10: JurorAccount beneficiary appended (default = rewards stay in the stake balance)
+    // v10 -> v11: Dispute jurors_claimed appended (older disputes sweep after JUROR_CLAIM_DEADLINE)
+    // v11 -> v12: Subject win/loss counters appended (history before migration is not counted)
+    // v12 -> v13: Subject config appended (see upgrade_fields)
+    // v13 -> v14: Dispute has_vote_stats appended (off)
+    // v14 -> v15: DefenderRecord payout_pool appended (see upgrade_fields)
+    // v15 -> v16: Dispute rebate_reserve / rebate_bps appended (no rebates for older resolutions)
+    // v16 -> v17: Subject max_juror_weight_bps, Dispute capped tally and VoteRecord tallied_round appended (uncapped)
+    // v17 -> v18: Dispute timed_out appended (false)
+    // v18 -> v19: ChallengerRecord pledged_bond appended (0 = treated as wallet-funded)
+    // v19 -> v20: Dispute voided appended (false)
+    // v20 -> v21: Dispute juror_incentive / incentive_refunded appended (no boosts)
+    // v21 -> v22: Dispute opposition_stake / opposer_count appended (unopposed)
+    // v22 -> v23: DefenderPool yield_principal / yield_pool_tokens / yield_stake_pool appended (nothing delegated)
+    // v23 -> v24: VoteRecord / ChallengerRecord reputation_snapshot appended (None = judged at current reputation)
+    // v24 -> v25: Subject graded_verdicts, Dispute graded / grade_weights and VoteRecord grade appended (binary)
+    // v25 -> v26: Dispute incident_id appended (no incident)
+    // v26 -> v27: JurorAccount epoch_commitment / committed_until appended (uncommitted)
+    // v27 -> v28: Dispute dependent_program / dependent_account / dependent_notified appended (no escrow linked)
+    // v28 -> v29: VoteRecord reputation_delta / counted_correct appended (older changes are not reversed on void)
+    // v29 -> v30: VoteRecord est_reward_floor appended (0 = no estimate)
+    // v30 -> v31: Subject frozen_by / frozen_at appended (not frozen)
+    // v31 -> v32: Dispute dust_swept / dust_credited appended (older residue still goes to treasury at close)
+    // v32 -> v33: Dispute abstain_count appended (no abstentions before v33)
+    // v33 -> v34: Dispute review_delay / evidence_hash appended (no review required)
+    // v34 -> v35: Dispute type_bonds appended (zero = dominant type is the original type)
+    // v35 -> v36: Dispute resolver_fee_paid / rebond_pool appended (voids of older rounds treat all fees as treasury's)
+    // v36 -> v37: Dispute claims_completed_at appended (0 = sweep bounty ages from the juror claim deadline)
+    // v37 -> v38: ProtocolConfig epoch fee share and Dispute epoch_fee_paid appended (0 = whole fee to treasury)
+    // v38 -> v39: DefenderRecord pool_delegate / delegate_committed appended (nothing charged to a delegate)
+    // v39 -> v40: Dispute rebond_amount appended (older rebonds only linked the pool)
+    account.upgrade_fields(from_version);
+
+    account.set_version(ACCOUNT_VERSION);
+    account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    Ok(from_version)
+}
+
+/// Size of a v0 escrow: Borsh layout with created_at after the counters and no version
+const LEGACY_ESCROW_LEN: usize = 8 + 32 + 32 + 6 * 8 + 4 + 1 + 8;
+
+/// Escrow is zero-copy; its version lives in former padding. A v0 escrow was still
+/// Borsh-serialized without alignment padding, so it is decoded and rewritten.
+fn upgrade_escrow(ctx: &Context<MigrateAccount>) -> Result<u8> {
+    if ctx.accounts.account.data_len() == LEGACY_ESCROW_LEN {
+        let legacy = decode_legacy_escrow(&ctx.accounts.account.try_borrow_data()?)?;
+        grow(ctx, DisputeEscrow::LEN)?;
+
+        let mut data = ctx.accounts.account.try_borrow_mut_data()?;
+        let escrow: &mut DisputeEscrow = bytemuck::from_bytes_mut(&mut data[8..DisputeEscrow::LEN]);
+        *escrow = legacy;
+        escrow.version = ACCOUNT_VERSION;
+        return Ok(0);
+    }
+
+    let mut data = ctx.accounts.account.try_borrow_mut_data()?;
+    require!(data.len() >= DisputeEscrow::LEN, TribunalCraftError::InvalidConfig);
+    let escrow: &mut DisputeEscrow = bytemuck::from_bytes_mut(&mut data[8..DisputeEscrow::LEN]);
+
+    let from_version = escrow.version;
+    if from_version < ACCOUNT_VERSION {
+        // v0 -> v1: Borsh layout replaced by zero-copy (see decode_legacy_escrow)
+        // v1 -> v2 through v39 -> v40: no escrow layout change
+        escrow.version = ACCOUNT_VERSION;
+    }
+    Ok(from_version)
+}
+
+/// Decode the v0 Borsh escrow: dispute, subject, six u64 totals, four u8 counters,
+/// bump, created_at
+fn decode_legacy_escrow(data: &[u8]) -> Result<DisputeEscrow> {
+    let body = data.get(8..LEGACY_ESCROW_LEN).ok_or(TribunalCraftError::InvalidConfig)?;
+    let pubkey = |at: usize| Pubkey::new_from_array(body[at..at + 32].try_into().unwrap());
+    let u64_at = |at: usize| u64::from_le_bytes(body[at..at + 8].try_into().unwrap());
+
+    Ok(DisputeEscrow {
+        dispute: pubkey(0),
+        subject: pubkey(32),
+        total_bonds: u64_at(64),
+        total_stakes: u64_at(72),
+        bonds_claimed: u64_at(80),
+        stakes_claimed: u64_at(88),
+        juror_rewards_paid: u64_at(96),
+        platform_fee_paid: u64_at(104),
+        challengers_claimed: body[112],
+        defenders_claimed: body[113],
+        expected_challengers: body[114],
+        expected_defenders: body[115],
+        bump: body[116],
+        created_at: u64_at(117) as i64,
+        version: 0,
+        _padding: [0; 2],
+    })
+}
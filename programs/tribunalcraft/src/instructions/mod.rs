@@ -6,6 +6,14 @@ pub mod challenger;
 pub mod vote;
 pub mod resolve;
 pub mod appeal;
+pub mod category;
+pub mod evidence;
+pub mod preview;
+pub mod migrate;
+pub mod attestation;
+pub mod pledge;
+pub mod boost;
+pub mod epoch;
 
 pub use config::*;
 pub use defender_pool::*;
@@ -15,3 +23,11 @@ pub use challenger::*;
 pub use vote::*;
 pub use resolve::*;
 pub use appeal::*;
+pub use category::*;
+pub use evidence::*;
+pub use preview::*;
+pub use migrate::*;
+pub use attestation::*;
+pub use pledge::*;
+pub use boost::*;
+pub use epoch::*;
@@ -0,0 +1,484 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::{
+    CHALLENGE_POOL_SEED, CHALLENGE_PLEDGE_SEED, CHALLENGER_ACCOUNT_SEED, CHALLENGER_RECORD_SEED,
+    DISPUTE_SEED, DISPUTE_ESCROW_SEED, PROTOCOL_STATS_SEED, INITIAL_REPUTATION,
+    ACCOUNT_VERSION, PLEDGE_WINDOW,
+};
+use crate::errors::TribunalCraftError;
+use crate::lifecycle_log::{LifecycleLog, LifecycleKind, NOOP_PROGRAM_ID};
+use crate::utils::{transfer_lamports, validate_cid};
+use crate::events::LamportsMoveReason;
+use crate::invariants;
+use super::challenger::hold_defender_stake;
+
+// =============================================================================
+// CHALLENGE PLEDGES (pledge -> activate -> convert, or refund on lapse)
+// =============================================================================
+//
+// Challengers pool bonds against a subject's next dispute slot. Once the total
+// reaches the subject's pledge_threshold, anyone can activate: pledged lamports
+// move into a new dispute's escrow as its challenger bond. Each pledge is then
+// converted into a regular ChallengerRecord so the usual claims apply. If the
+// window expires (or another dispute takes the slot) pledges are refunded -
+// by anyone, so an abandoned pool can't keep the next pool from opening.
+// Pledges skip the reputation-based minimum bond - the threshold governs.
+
+/// Pledge a bond toward a future dispute (opens the pool on first pledge)
+#[derive(Accounts)]
+pub struct PledgeChallenge<'info> {
+    #[account(mut)]
+    pub pledger: Signer<'info>,
+
+    #[account(
+        constraint = subject.can_dispute() @ TribunalCraftError::SubjectCannotBeDisputed,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+        constraint = subject.accepts_pledges() @ TribunalCraftError::PledgingDisabled,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        init_if_needed,
+        payer = pledger,
+        space = ChallengePledgePool::LEN,
+        seeds = [CHALLENGE_POOL_SEED, subject.key().as_ref(), &subject.dispute_count.to_le_bytes()],
+        bump
+    )]
+    pub pledge_pool: Account<'info, ChallengePledgePool>,
+
+    #[account(
+        init_if_needed,
+        payer = pledger,
+        space = ChallengePledge::LEN,
+        seeds = [CHALLENGE_PLEDGE_SEED, pledge_pool.key().as_ref(), pledger.key().as_ref()],
+        bump
+    )]
+    pub pledge: Account<'info, ChallengePledge>,
+
+    /// Protocol config (CID validation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn pledge_challenge(
+    ctx: Context<PledgeChallenge>,
+    dispute_type: DisputeType,
+    details_cid: String,
+    amount: u64,
+) -> Result<()> {
+    let subject = &ctx.accounts.subject;
+    let pool = &mut ctx.accounts.pledge_pool;
+    let pledge = &mut ctx.accounts.pledge;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, TribunalCraftError::BondBelowMinimum);
+
+    // Open the pool on first pledge (opener's type and details apply to the dispute)
+    if pool.created_at == 0 {
+        validate_cid(&details_cid, ChallengePledgePool::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+        pool.subject = subject.key();
+        pool.dispute_index = subject.dispute_count;
+        pool.opener = ctx.accounts.pledger.key();
+        pool.dispute_type = dispute_type;
+        pool.details_cid = details_cid;
+        pool.threshold = subject.pledge_threshold;
+        pool.expires_at = clock.unix_timestamp.saturating_add(PLEDGE_WINDOW);
+        pool.dispute = Pubkey::default();
+        pool.bump = ctx.bumps.pledge_pool;
+        pool.created_at = clock.unix_timestamp;
+    }
+    require!(!pool.is_activated(), TribunalCraftError::DisputeAlreadyExists);
+    require!(clock.unix_timestamp < pool.expires_at, TribunalCraftError::PledgeWindowClosed);
+
+    // New pledger
+    if pledge.pledged_at == 0 {
+        require!(pool.pledger_count < ChallengePledgePool::MAX_PLEDGERS, TribunalCraftError::PledgePoolFull);
+        pledge.pool = pool.key();
+        pledge.pledger = ctx.accounts.pledger.key();
+        pledge.bump = ctx.bumps.pledge;
+        pledge.pledged_at = clock.unix_timestamp;
+        pool.pledger_count = pool.pledger_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        pool.pending_pledges = pool.pending_pledges.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    }
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: ctx.accounts.pledger.to_account_info(),
+            to: pool.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_context, amount)?;
+
+    pledge.amount = pledge.amount.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    pool.total_pledged = pool.total_pledged.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    msg!("Challenge pledged: {} lamports ({} / {} total)", amount, pool.total_pledged, pool.threshold);
+    Ok(())
+}
+
+/// Turn a funded pledge pool into a live dispute (permissionless)
+/// Pledged lamports become the dispute's challenger bond
+#[derive(Accounts)]
+pub struct ActivateDispute<'info> {
+    #[account(mut)]
+    pub activator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = subject.can_dispute() @ TribunalCraftError::SubjectCannotBeDisputed,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        seeds = [CHALLENGE_POOL_SEED, subject.key().as_ref(), &subject.dispute_count.to_le_bytes()],
+        bump = pledge_pool.bump,
+        constraint = !pledge_pool.is_activated() @ TribunalCraftError::DisputeAlreadyExists,
+    )]
+    pub pledge_pool: Account<'info, ChallengePledgePool>,
+
+    /// Optional: defender pool if subject is linked
+    #[account(
+        mut,
+        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub defender_pool: Option<Account<'info, DefenderPool>>,
+
+    /// Optional: pool owner's defender record (required when stake is pulled from the pool)
+    #[account(
+        mut,
+        has_one = subject,
+    )]
+    pub pool_defender_record: Option<Account<'info, DefenderRecord>>,
+
+    #[account(
+        init,
+        payer = activator,
+        space = Dispute::LEN,
+        seeds = [DISPUTE_SEED, subject.key().as_ref(), &subject.dispute_count.to_le_bytes()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA holds all funds for this dispute
+    #[account(
+        init,
+        payer = activator,
+        space = DisputeEscrow::LEN,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    /// Protocol config (cooldown escalation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn activate_dispute(ctx: Context<ActivateDispute>) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    let pool = &mut ctx.accounts.pledge_pool;
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp < pool.expires_at, TribunalCraftError::PledgeWindowClosed);
+    require!(pool.total_pledged >= pool.threshold, TribunalCraftError::PledgeThresholdNotMet);
+    subject.check_cooldown_bond(pool.total_pledged, ctx.accounts.protocol_config.cooldown_escalation_bps, clock.unix_timestamp)?;
+
+    // Move pledged bonds from the holding PDA into escrow
+    let bond = pool.total_pledged;
+    transfer_lamports(&pool.to_account_info(), &escrow.to_account_info(), bond, LamportsMoveReason::PledgeActivation)?;
+
+    // Move held defender stake into escrow (match mode only)
+    let (pool_stake_to_transfer, direct_stake_to_transfer, pool_defender_added) = hold_defender_stake(
+        subject,
+        ctx.accounts.defender_pool.as_mut(),
+        ctx.accounts.pool_defender_record.as_mut(),
+        &escrow.to_account_info(),
+        bond,
+        clock.unix_timestamp,
+    )?;
+
+    // Initialize escrow (all lamport moves above must finish before the data borrow)
+    let mut escrow_data = escrow.load_init()?;
+    escrow_data.dispute = dispute.key();
+    escrow_data.subject = subject.key();
+    escrow_data.total_bonds = bond;
+    escrow_data.total_stakes = pool_stake_to_transfer.checked_add(direct_stake_to_transfer)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    escrow_data.expected_challengers = pool.pledger_count as u8;
+    escrow_data.expected_defenders = subject.defender_count as u8 + pool_defender_added as u8;
+    escrow_data.bump = ctx.bumps.escrow;
+    escrow_data.version = ACCOUNT_VERSION;
+    escrow_data.created_at = clock.unix_timestamp;
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+    // Update subject status
+    subject.status = SubjectStatus::Disputed;
+    subject.dispute = dispute.key();
+    subject.dispute_count = subject.dispute_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    subject.updated_at = clock.unix_timestamp;
+
+    // Initialize dispute
+    dispute.subject = subject.key();
+    dispute.dispute_type = pool.dispute_type;
+    dispute.category_id = subject.category_id;
+    dispute.total_bond = bond;
+    dispute.add_type_bond(pool.dispute_type, bond)?;
+    dispute.stake_held = pool_stake_to_transfer;
+    dispute.direct_stake_held = direct_stake_to_transfer;
+    dispute.challenger_count = pool.pledger_count;
+    dispute.status = DisputeStatus::Pending;
+    dispute.outcome = ResolutionOutcome::None;
+    dispute.bump = ctx.bumps.dispute;
+    dispute.version = ACCOUNT_VERSION;
+    dispute.created_at = clock.unix_timestamp;
+    dispute.graded = subject.graded_verdicts;
+
+    // Snapshot defender state for historical record
+    dispute.snapshot_total_stake = subject.total_stake.saturating_add(direct_stake_to_transfer);
+    dispute.snapshot_defender_count = subject.defender_count;
+
+    // Voting starts immediately
+    dispute.start_voting(clock.unix_timestamp, subject.voting_period);
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_dispute(false, clock.unix_timestamp);
+    }
+
+    pool.dispute = dispute.key();
+    LifecycleLog {
+        kind: LifecycleKind::Disputed,
+        subject: subject.key(),
+        dispute: dispute.key(),
+        status: subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: bond,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
+    msg!("Dispute activated from {} pledges (stakes: {}, bond: {})",
+        pool.pledger_count, escrow_data.total_stakes, bond);
+    Ok(())
+}
+
+/// Convert a pledge into a challenger record on the activated dispute (permissionless)
+/// Payer funds the new records; pledge rent returns to the pledger
+#[derive(Accounts)]
+pub struct ConvertPledge<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Pledger wallet - receives pledge rent
+    #[account(mut)]
+    pub pledger: UncheckedAccount<'info>,
+
+    /// CHECK: Pool opener - receives pool rent when the last pledge is settled
+    #[account(
+        mut,
+        constraint = opener.key() == pledge_pool.opener @ TribunalCraftError::Unauthorized,
+    )]
+    pub opener: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = pledge_pool.is_activated() @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub pledge_pool: Account<'info, ChallengePledgePool>,
+
+    #[account(
+        mut,
+        close = pledger,
+        has_one = pledger,
+        constraint = pledge.pool == pledge_pool.key() @ TribunalCraftError::PledgeAccountMismatch,
+    )]
+    pub pledge: Account<'info, ChallengePledge>,
+
+    #[account(
+        mut,
+        constraint = dispute.key() == pledge_pool.dispute @ TribunalCraftError::PledgeAccountMismatch,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA (challenger count corrected when merging into an existing record)
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ChallengerAccount::LEN,
+        seeds = [CHALLENGER_ACCOUNT_SEED, pledger.key().as_ref()],
+        bump
+    )]
+    pub challenger_account: Account<'info, ChallengerAccount>,
+
+    /// Pledger's record on the dispute; may already exist if they also added bond from their wallet
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ChallengerRecord::LEN,
+        seeds = [CHALLENGER_RECORD_SEED, dispute.key().as_ref(), pledger.key().as_ref()],
+        bump
+    )]
+    pub challenger_record: Account<'info, ChallengerRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn convert_pledge(ctx: Context<ConvertPledge>) -> Result<()> {
+    let pool = &mut ctx.accounts.pledge_pool;
+    let pledge = &ctx.accounts.pledge;
+    let challenger_account = &mut ctx.accounts.challenger_account;
+    let challenger_record = &mut ctx.accounts.challenger_record;
+    let clock = Clock::get()?;
+
+    // Initialize challenger account if new
+    if challenger_account.created_at == 0 {
+        challenger_account.challenger = pledge.pledger;
+        challenger_account.reputation = INITIAL_REPUTATION;
+        challenger_account.bump = ctx.bumps.challenger_account;
+        challenger_account.version = ACCOUNT_VERSION;
+        challenger_account.created_at = clock.unix_timestamp;
+    }
+
+    if challenger_record.challenged_at != 0 {
+        // The pledger already joined through add_to_dispute, which counted them a second
+        // time on top of activation's pledger_count - merge the pledge and drop the duplicate
+        require!(!challenger_record.reward_claimed, TribunalCraftError::RewardAlreadyClaimed);
+        challenger_record.bond = challenger_record.bond.checked_add(pledge.amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        challenger_record.pledged_bond = challenger_record.pledged_bond.checked_add(pledge.amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.challenger_count = dispute.challenger_count.saturating_sub(1);
+        let mut escrow_data = ctx.accounts.escrow.load_mut()?;
+        escrow_data.expected_challengers = escrow_data.expected_challengers.saturating_sub(1);
+
+        settle_pledge(pool, &ctx.accounts.opener)?;
+
+        msg!("Pledge merged into existing record: {} lamports bond (total: {})", pledge.amount, challenger_record.bond);
+        return Ok(());
+    }
+
+    challenger_record.dispute = pool.dispute;
+    challenger_record.challenger = pledge.pledger;
+    challenger_record.challenger_account = challenger_account.key();
+    challenger_record.bond = pledge.amount;
+    challenger_record.pledged_bond = pledge.amount;
+    challenger_record.details_cid = pool.details_cid.clone();
+    challenger_record.reward_claimed = false;
+    challenger_record.bump = ctx.bumps.challenger_record;
+    challenger_record.version = ACCOUNT_VERSION;
+    challenger_record.challenged_at = pledge.pledged_at;
+    challenger_record.reputation_snapshot = Some(challenger_account.reputation);
+
+    challenger_account.disputes_submitted = challenger_account.disputes_submitted.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    challenger_account.last_dispute_at = clock.unix_timestamp;
+
+    settle_pledge(pool, &ctx.accounts.opener)?;
+
+    msg!("Pledge converted: {} lamports bond", pledge.amount);
+    Ok(())
+}
+
+/// Refund a pledge once its pool has lapsed (expired, or the dispute slot was taken)
+/// Permissionless: lamports and pledge rent always go to the pledger, so anyone can
+/// drain a lapsed pool and free the pledge slot for the next pool
+#[derive(Accounts)]
+pub struct RefundPledge<'info> {
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Pledger recorded on the pledge - receives the refund and the pledge rent
+    #[account(mut)]
+    pub pledger: UncheckedAccount<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    /// CHECK: Pool opener - receives pool rent when the last pledge is settled
+    #[account(
+        mut,
+        constraint = opener.key() == pledge_pool.opener @ TribunalCraftError::Unauthorized,
+    )]
+    pub opener: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = subject,
+    )]
+    pub pledge_pool: Account<'info, ChallengePledgePool>,
+
+    #[account(
+        mut,
+        close = pledger,
+        has_one = pledger,
+        constraint = pledge.pool == pledge_pool.key() @ TribunalCraftError::PledgeAccountMismatch,
+    )]
+    pub pledge: Account<'info, ChallengePledge>,
+}
+
+pub fn refund_pledge(ctx: Context<RefundPledge>) -> Result<()> {
+    let pool = &mut ctx.accounts.pledge_pool;
+    let amount = ctx.accounts.pledge.amount;
+    let clock = Clock::get()?;
+
+    require!(
+        pool.is_lapsed(ctx.accounts.subject.dispute_count, clock.unix_timestamp),
+        TribunalCraftError::PledgeNotRefundable
+    );
+
+    transfer_lamports(&pool.to_account_info(), &ctx.accounts.pledger.to_account_info(), amount, LamportsMoveReason::PledgeRefund)?;
+    pool.total_pledged = pool.total_pledged.checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+    settle_pledge(pool, &ctx.accounts.opener)?;
+
+    msg!("Pledge refunded: {} lamports to {}", amount, ctx.accounts.pledger.key());
+    Ok(())
+}
+
+/// Count a pledge as settled; close the pool to its opener once none remain
+fn settle_pledge<'info>(
+    pool: &mut Account<'info, ChallengePledgePool>,
+    opener: &UncheckedAccount<'info>,
+) -> Result<()> {
+    pool.pending_pledges = pool.pending_pledges.saturating_sub(1);
+    if pool.pending_pledges == 0 {
+        pool.close(opener.to_account_info())?;
+    }
+    Ok(())
+}
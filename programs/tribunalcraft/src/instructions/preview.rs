@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::DISPUTE_ESCROW_SEED;
+use crate::errors::TribunalCraftError;
+use crate::instructions::resolve::{
+    juror_payout, juror_incentive_share, challenger_payout, defender_payout, ClaimPayout,
+};
+
+// =============================================================================
+// CLAIM PREVIEWS (read-only, result returned via return data)
+// =============================================================================
+//
+// Each preview runs the same payout math as the matching claim instruction
+// without moving funds, so clients can simulate it to show exact claimables.
+// Already-claimed records preview as zero.
+
+#[derive(Accounts)]
+pub struct PreviewClaimJuror<'info> {
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(has_one = dispute)]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+pub fn preview_claim_juror(ctx: Context<PreviewClaimJuror>) -> Result<u64> {
+    let subject = &ctx.accounts.subject;
+    let vote_record = &ctx.accounts.vote_record;
+
+    require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
+    if vote_record.reward_claimed {
+        return Ok(0);
+    }
+
+    let escrow_data = ctx.accounts.escrow.load()?;
+    let reward = juror_payout(subject, &ctx.accounts.dispute, &escrow_data, vote_record)?;
+    reward.checked_add(juror_incentive_share(&ctx.accounts.dispute, vote_record))
+        .ok_or(TribunalCraftError::ArithmeticOverflow.into())
+}
+
+#[derive(Accounts)]
+pub struct PreviewClaimChallenger<'info> {
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(has_one = dispute)]
+    pub challenger_record: Account<'info, ChallengerRecord>,
+}
+
+pub fn preview_claim_challenger(ctx: Context<PreviewClaimChallenger>) -> Result<ClaimPayout> {
+    let subject = &ctx.accounts.subject;
+    let challenger_record = &ctx.accounts.challenger_record;
+
+    require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
+    if challenger_record.reward_claimed {
+        return Ok(ClaimPayout::default());
+    }
+
+    let escrow_data = ctx.accounts.escrow.load()?;
+    challenger_payout(subject, &ctx.accounts.dispute, &escrow_data, challenger_record)
+}
+
+#[derive(Accounts)]
+pub struct PreviewClaimDefender<'info> {
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(has_one = subject)]
+    pub defender_record: Account<'info, DefenderRecord>,
+}
+
+pub fn preview_claim_defender(ctx: Context<PreviewClaimDefender>) -> Result<ClaimPayout> {
+    let subject = &ctx.accounts.subject;
+    let defender_record = &ctx.accounts.defender_record;
+
+    require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
+    if defender_record.reward_claimed {
+        return Ok(ClaimPayout::default());
+    }
+
+    let escrow_data = ctx.accounts.escrow.load()?;
+    defender_payout(subject, &ctx.accounts.dispute, &escrow_data, defender_record)
+}
+
+#[derive(Accounts)]
+pub struct PreviewOutcome<'info> {
+    pub subject: Account<'info, Subject>,
+
+    #[account(has_one = subject)]
+    pub dispute: Account<'info, Dispute>,
+}
+
+/// Outcome the current tallies resolve to under the subject's tie policy
+/// Returns None when resolving now would reopen voting for a tie
+pub fn preview_outcome(ctx: Context<PreviewOutcome>) -> Result<ResolutionOutcome> {
+    let dispute = &ctx.accounts.dispute;
+    let tie_policy = ctx.accounts.subject.tie_policy;
+
+    if dispute.status != DisputeStatus::Pending {
+        return Ok(dispute.outcome);
+    }
+    if dispute.should_extend_tie(tie_policy) {
+        return Ok(ResolutionOutcome::None);
+    }
+    Ok(dispute.determine_outcome(tie_policy))
+}
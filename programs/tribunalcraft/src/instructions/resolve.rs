@@ -1,12 +1,351 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::constants::{
-    stacked_sigmoid, REPUTATION_GAIN_RATE, REPUTATION_LOSS_RATE,
+    reputation_gain, reputation_loss,
     JUROR_ACCOUNT_SEED, CHALLENGER_ACCOUNT_SEED, DEFENDER_RECORD_SEED,
-    PROTOCOL_CONFIG_SEED, DISPUTE_ESCROW_SEED,
-    TOTAL_FEE_BPS, JUROR_SHARE_BPS, WINNER_SHARE_BPS,
+    DISPUTE_ESCROW_SEED, RESOLUTION_VOID_SEED, RESOLUTION_ATTESTATION_SEED, EXPIRY_QUEUE_SEED, JUROR_CLAIM_DEADLINE,
+    TOTAL_FEE_BPS, PROTOCOL_STATS_SEED, SLASH_THRESHOLD, MAX_BPS, MAX_DUST_PER_CLAIM,
+    ABSTAIN_FEE_BPS, MAX_ABSTAIN_POT_BPS,
 };
 use crate::errors::TribunalCraftError;
+use crate::lifecycle_log::{LifecycleLog, LifecycleKind, NOOP_PROGRAM_ID};
+use crate::utils::{transfer_lamports, validate_cid, create_pda_account};
+use crate::invariants;
+use crate::instructions::subject::credit_direct_stake;
+use crate::events::{
+    TieVotingExtendedEvent, DisputeResolvedEvent, DisputeTimedOutEvent, ResolutionVoidedEvent,
+    ChallengerRewardClaimedEvent, LamportsMoveReason,
+};
+
+// =============================================================================
+// PAYOUT MATH (shared by claims and previews)
+// =============================================================================
+
+/// Claim payout split by the escrow bucket it is drawn from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct ClaimPayout {
+    /// Paid out of challenger bonds
+    pub from_bonds: u64,
+    /// Paid out of defender stakes
+    pub from_stakes: u64,
+}
+
+impl ClaimPayout {
+    pub fn total(&self) -> Result<u64> {
+        Ok(self.from_bonds.checked_add(self.from_stakes)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?)
+    }
+
+    /// Book the paid buckets against the escrow (zero buckets are skipped)
+    /// Claimants are counted separately, once per claim
+    pub fn record(&self, escrow: &mut DisputeEscrow) -> Result<()> {
+        if self.from_bonds > 0 {
+            escrow.record_bond_claim(self.from_bonds)?;
+        }
+        if self.from_stakes > 0 {
+            escrow.record_stake_claim(self.from_stakes)?;
+        }
+        Ok(())
+    }
+}
+
+/// The part of the escrow that was actually contested
+/// In match mode each side only risks what the other side matched: bond above the held
+/// stake (e.g. stake capped by max_stake) is safe and comes back in full, fee-free.
+/// Fees and winnings are taken from the contested amounts only
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContestedPool {
+    /// Challenger bonds at risk
+    pub bonds: u64,
+    /// Defender stakes at risk
+    pub stakes: u64,
+    /// All challenger bonds in escrow
+    pub total_bonds: u64,
+    /// All defender stakes in escrow
+    pub total_stakes: u64,
+}
+
+impl ContestedPool {
+    /// Appeals and proportional mode contest everything in escrow
+    pub fn for_dispute(subject: &Subject, dispute: &Dispute, escrow: &DisputeEscrow) -> Self {
+        let (bonds, stakes) = if subject.match_mode && !dispute.is_appeal {
+            let matched = escrow.total_bonds.min(escrow.total_stakes);
+            (matched, matched)
+        } else {
+            (escrow.total_bonds, escrow.total_stakes)
+        };
+        Self { bonds, stakes, total_bonds: escrow.total_bonds, total_stakes: escrow.total_stakes }
+    }
+
+    /// Fee base: contested bonds + contested stakes
+    pub fn total(&self) -> Result<u64> {
+        Ok(self.bonds.checked_add(self.stakes)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?)
+    }
+
+    /// (at risk, safe) split of one challenger's bond
+    pub fn split_bond(&self, bond: u64) -> (u64, u64) {
+        split_at_risk(bond, self.bonds, self.total_bonds)
+    }
+
+    /// (at risk, safe) split of one defender's stake
+    pub fn split_stake(&self, stake: u64) -> (u64, u64) {
+        split_at_risk(stake, self.stakes, self.total_stakes)
+    }
+}
+
+fn split_at_risk(amount: u64, contested: u64, total: u64) -> (u64, u64) {
+    if total == 0 {
+        return (0, amount);
+    }
+    let at_risk = (amount as u128 * contested as u128 / total as u128) as u64;
+    (at_risk, amount - at_risk)
+}
+
+/// Flat participation fee per abstainer, taken off the top of the juror pot
+fn abstain_fee(dispute: &Dispute, juror_pot: u64) -> u64 {
+    if dispute.abstain_count == 0 {
+        return 0;
+    }
+    let fee_bps = ABSTAIN_FEE_BPS.min(MAX_ABSTAIN_POT_BPS / dispute.abstain_count);
+    (juror_pot as u128 * fee_bps as u128 / 10000) as u64
+}
+
+/// Juror pot: the juror share of the fees on the contested pool
+fn juror_pot(subject: &Subject, dispute: &Dispute, escrow: &DisputeEscrow) -> Result<u64> {
+    let total_pool = ContestedPool::for_dispute(subject, dispute, escrow).total()?;
+    let total_fees = total_pool as u128 * TOTAL_FEE_BPS as u128 / 10000;
+    Ok((total_fees * subject.juror_share_bps as u128 / 10000) as u64)
+}
+
+/// Party refund on a NoParticipation outcome
+/// When every juror abstained there is no fee to pay them from, so the parties fund the
+/// abstainers' flat fees pro rata to what they put in. Each deduction rounds up so the
+/// refunds plus the fees never exceed the escrow
+fn no_participation_refund(
+    amount: u64,
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &DisputeEscrow,
+) -> Result<u64> {
+    if dispute.abstain_count == 0 || subject.free_case {
+        return Ok(amount);
+    }
+    let total_in = escrow.total_bonds.checked_add(escrow.total_stakes)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    if total_in == 0 {
+        return Ok(amount);
+    }
+    let levy = abstain_fee(dispute, juror_pot(subject, dispute, escrow)?) as u128 * dispute.abstain_count as u128;
+    let deduction = (amount as u128 * levy).div_ceil(total_in as u128) as u64;
+    Ok(amount.saturating_sub(deduction))
+}
+
+/// Juror reward: share of the juror pot proportional to voting power (all voters share)
+/// Abstainers get the flat abstain_fee instead; voters split what is left
+pub fn juror_payout(
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &DisputeEscrow,
+    vote_record: &VoteRecord,
+) -> Result<u64> {
+    // Timed-out and voided disputes refund challengers and defenders in full; there is no juror pot
+    if dispute.is_refund_only() {
+        return Ok(0);
+    }
+
+    let juror_pot = juror_pot(subject, dispute, escrow)?;
+
+    // Abstainers are paid before the zero-weight check: when the whole jury abstains the
+    // parties fund the fees out of their refunds (see no_participation_refund)
+    let abstain_fee = abstain_fee(dispute, juror_pot);
+    if vote_record.is_abstain() {
+        return Ok(abstain_fee);
+    }
+
+    // Total weight of ALL voters (not just correct ones)
+    let total_vote_weight = dispute.votes_favor_weight.saturating_add(dispute.votes_against_weight);
+    if juror_pot == 0 || total_vote_weight == 0 {
+        return Ok(0);
+    }
+    let voter_pot = juror_pot - abstain_fee * dispute.abstain_count as u64;
+    Ok((voter_pot as u128 * vote_record.voting_power as u128 / total_vote_weight as u128) as u64)
+}
+
+/// Juror incentive: the juror's voting-power share of boost_juror_pool deposits
+/// Paid from escrow outside the bond/stake accounting
+pub fn juror_incentive_share(dispute: &Dispute, vote_record: &VoteRecord) -> u64 {
+    let total_vote_weight = dispute.votes_favor_weight.saturating_add(dispute.votes_against_weight);
+    if dispute.juror_incentive == 0 || dispute.is_refund_only() || total_vote_weight == 0 {
+        return 0;
+    }
+    (dispute.juror_incentive as u128 * vote_record.voting_power as u128 / total_vote_weight as u128) as u64
+}
+
+/// Lamports all party claims add up to before per-claim flooring
+/// Decided outcomes: winner share of the contested pool plus everything that was safe
+fn party_allocation(subject: &Subject, dispute: &Dispute, escrow: &DisputeEscrow) -> Result<u64> {
+    let total_in = escrow.total_bonds.checked_add(escrow.total_stakes)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    let contested = ContestedPool::for_dispute(subject, dispute, escrow).total()?;
+    let winnings = (contested as u128 * subject.winner_share_bps as u128 / 10000) as u64;
+    Ok(winnings + (total_in - contested))
+}
+
+/// Sweep the round's rounding residue into the juror incentive after the final party claim
+/// Party payouts are floored claim by claim, leaving a few lamports that would otherwise
+/// sit in escrow until close_escrow sends them to treasury. Only decided, voted rounds with
+/// jurors still to claim are swept; the residue is recorded as juror reward up front and
+/// paid by voting power with the incentive
+/// Every expected claimant must have claimed exactly once, with the dispute and escrow
+/// counters in agreement, so unpaid party funds are never mistaken for residue
+fn sweep_dust(dispute: &mut Dispute, subject: &Subject, escrow: &mut DisputeEscrow) -> Result<()> {
+    let claims = dispute.challengers_claimed as u64 + dispute.defenders_claimed as u64;
+    let parties_done = dispute.challengers_claimed == escrow.expected_challengers as u16
+        && dispute.defenders_claimed == escrow.expected_defenders as u16
+        && escrow.challengers_claimed == escrow.expected_challengers
+        && escrow.defenders_claimed == escrow.expected_defenders;
+    let decided = matches!(dispute.outcome, ResolutionOutcome::ChallengerWins | ResolutionOutcome::DefenderWins);
+    if dispute.dust_swept
+        || !parties_done
+        || !decided
+        || dispute.is_refund_only()
+        || subject.free_case
+        || dispute.jurors_claimed >= dispute.vote_count
+    {
+        return Ok(());
+    }
+
+    let paid = escrow.bonds_claimed.checked_add(escrow.stakes_claimed)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    let residue = party_allocation(subject, dispute, escrow)?.saturating_sub(paid);
+    if residue > claims.saturating_mul(MAX_DUST_PER_CLAIM) {
+        return Ok(());
+    }
+
+    dispute.dust_swept = true;
+    if residue > 0 {
+        escrow.record_juror_reward(residue)?;
+        dispute.juror_incentive = dispute.juror_incentive.checked_add(residue)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        dispute.dust_credited = residue;
+        msg!("Rounding residue credited to jurors: {} lamports", residue);
+    }
+    Ok(())
+}
+
+/// Juror fee rebate: the juror's voting-power share of the rebate reserve, scaled by their tier
+/// Tier rebates above the bps reserved at resolution are capped; the rest is swept to treasury
+pub fn juror_rebate(
+    config: &ProtocolConfig,
+    dispute: &Dispute,
+    vote_record: &VoteRecord,
+    reputation: u16,
+) -> u64 {
+    let total_vote_weight = dispute.votes_favor_weight.saturating_add(dispute.votes_against_weight);
+    if dispute.rebate_reserve == 0 || dispute.rebate_bps == 0 || total_vote_weight == 0 {
+        return 0;
+    }
+    let tier_bps = config.rebate_bps_for(reputation).min(dispute.rebate_bps);
+    let share = dispute.rebate_reserve as u128 * vote_record.voting_power as u128 / total_vote_weight as u128;
+    (share * tier_bps as u128 / dispute.rebate_bps as u128) as u64
+}
+
+/// Challenger payout for the dispute outcome
+pub fn challenger_payout(
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &DisputeEscrow,
+    challenger_record: &ChallengerRecord,
+) -> Result<ClaimPayout> {
+    let bond = challenger_record.bond;
+    let pool = ContestedPool::for_dispute(subject, dispute, escrow);
+    let (at_risk, safe) = pool.split_bond(bond);
+    match dispute.outcome {
+        ResolutionOutcome::ChallengerWins => {
+            // Winner: 80% of the slashed defender stake + 80% of own contested bond + safe bond back
+            let slashed = pool.stakes as u128 * dispute.slash_bps() as u128 / MAX_BPS as u128;
+            let defender_contribution = (slashed * subject.winner_share_bps as u128 / 10000) as u64;
+            Ok(ClaimPayout {
+                from_bonds: (at_risk as u128 * subject.winner_share_bps as u128 / 10000) as u64 + safe,
+                from_stakes: challenger_record.calculate_reward_share(defender_contribution, escrow.total_bonds),
+            })
+        }
+        // Loser: loses contested bond, safe bond back
+        ResolutionOutcome::DefenderWins => Ok(ClaimPayout { from_bonds: safe, from_stakes: 0 }),
+        // No votes: full bond return, less its share of any abstainer fees
+        ResolutionOutcome::NoParticipation => Ok(ClaimPayout {
+            from_bonds: no_participation_refund(bond, subject, dispute, escrow)?,
+            from_stakes: 0,
+        }),
+        ResolutionOutcome::None => Err(TribunalCraftError::DisputeNotFound.into()),
+    }
+}
+
+/// Defender payout (wallet + pool) for the dispute outcome
+pub fn defender_payout(
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &DisputeEscrow,
+    defender_record: &DefenderRecord,
+) -> Result<ClaimPayout> {
+    let stake = defender_record.total_stake();
+    let pool = ContestedPool::for_dispute(subject, dispute, escrow);
+    let (at_risk, safe) = pool.split_stake(stake);
+    match dispute.outcome {
+        ResolutionOutcome::DefenderWins => {
+            // Winner: 80% of the contested bonds + 80% of own contested stake + safe stake back
+            let bond_contribution = (pool.bonds as u128 * subject.winner_share_bps as u128 / 10000) as u64;
+            Ok(ClaimPayout {
+                from_bonds: defender_record.calculate_reward_share(bond_contribution, escrow.total_stakes),
+                from_stakes: (at_risk as u128 * subject.winner_share_bps as u128 / 10000) as u64 + safe,
+            })
+        }
+        // Loser: loses the slashed share of contested stake (all of it unless graded),
+        // 80% of the rest and the safe stake back
+        ResolutionOutcome::ChallengerWins => {
+            let kept = at_risk as u128 * (MAX_BPS - dispute.slash_bps()) as u128 / MAX_BPS as u128;
+            Ok(ClaimPayout {
+                from_bonds: 0,
+                from_stakes: (kept * subject.winner_share_bps as u128 / 10000) as u64 + safe,
+            })
+        }
+        // No votes: full stake return, less its share of any abstainer fees
+        ResolutionOutcome::NoParticipation => Ok(ClaimPayout {
+            from_bonds: 0,
+            from_stakes: no_participation_refund(stake, subject, dispute, escrow)?,
+        }),
+        ResolutionOutcome::None => Err(TribunalCraftError::DisputeNotFound.into()),
+    }
+}
+
+/// Opposer payout for an appeal outcome (the defender side of a restoration)
+pub fn opposition_payout(
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &DisputeEscrow,
+    opposition_record: &OppositionRecord,
+) -> Result<ClaimPayout> {
+    let stake = opposition_record.stake;
+    match dispute.outcome {
+        ResolutionOutcome::DefenderWins => {
+            // Restoration rejected: 80% of the appellant's stake + 80% of own stake back
+            let appeal_contribution = (escrow.total_bonds as u128 * subject.winner_share_bps as u128 / 10000) as u64;
+            Ok(ClaimPayout {
+                from_bonds: opposition_record.calculate_reward_share(appeal_contribution, escrow.total_stakes),
+                from_stakes: (stake as u128 * subject.winner_share_bps as u128 / 10000) as u64,
+            })
+        }
+        // Restoration succeeded: opposition stake goes to the appellant
+        ResolutionOutcome::ChallengerWins => Ok(ClaimPayout::default()),
+        // No votes: full stake return, less its share of any abstainer fees
+        ResolutionOutcome::NoParticipation => Ok(ClaimPayout {
+            from_bonds: 0,
+            from_stakes: no_participation_refund(stake, subject, dispute, escrow)?,
+        }),
+        ResolutionOutcome::None => Err(TribunalCraftError::DisputeNotFound.into()),
+    }
+}
 
 // =============================================================================
 // RESOLVE DISPUTE
@@ -14,6 +353,7 @@ use crate::errors::TribunalCraftError;
 
 #[derive(Accounts)]
 pub struct ResolveDispute<'info> {
+    /// Receives the resolver fee
     #[account(mut)]
     pub resolver: Signer<'info>,
 
@@ -31,14 +371,13 @@ pub struct ResolveDispute<'info> {
     #[account(
         mut,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.load()?.bump
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     /// Protocol config for treasury address
     #[account(
-        seeds = [PROTOCOL_CONFIG_SEED],
-        bump = protocol_config.bump
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -49,39 +388,231 @@ pub struct ResolveDispute<'info> {
     )]
     pub treasury: AccountInfo<'info>,
 
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional: the subject's config's keeper expiry queue, kept current when passed
+    #[account(
+        mut,
+        seeds = [EXPIRY_QUEUE_SEED, subject.config.as_ref()],
+        bump = expiry_queue.bump
+    )]
+    pub expiry_queue: Option<Account<'info, ExpiryQueue>>,
+
+    /// Optional: the config's fee epoch, required while it runs and epoch_fee_bps is set
+    #[account(
+        mut,
+        constraint = juror_epoch.key() == protocol_config.fee_epoch @ TribunalCraftError::FeeEpochMismatch,
+    )]
+    pub juror_epoch: Option<Account<'info, JurorEpoch>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+/// remaining_accounts (optional, restorations only): [creator's defender_pool, creator's defender_record]
+pub fn resolve_dispute<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+) -> Result<()> {
     let clock = Clock::get()?;
 
     let dispute_voting_ended = ctx.accounts.dispute.is_voting_ended(clock.unix_timestamp);
     require!(dispute_voting_ended, TribunalCraftError::VotingNotEnded);
+    ctx.accounts.dispute.check_weight_tally(ctx.accounts.subject.max_juror_weight_bps)?;
 
-    // Calculate platform fee from escrow
-    let platform_fee = if !ctx.accounts.subject.free_case {
-        let total_pool = ctx.accounts.escrow.total_bonds
-            .saturating_add(ctx.accounts.escrow.total_stakes);
+    let tie_policy = ctx.accounts.subject.tie_policy;
+    let dispute = &mut ctx.accounts.dispute;
+    if dispute.should_extend_tie(tie_policy) {
+        return extend_tied_vote(dispute, &ctx.accounts.subject, clock.unix_timestamp);
+    }
 
-        if total_pool > 0 {
-            let total_fees = total_pool as u128 * TOTAL_FEE_BPS as u128 / 10000;
-            (total_fees * (10000 - JUROR_SHARE_BPS) as u128 / 10000) as u64
-        } else {
-            0
+    // Determine outcome
+    dispute.outcome = dispute.determine_outcome(tie_policy);
+
+    settle_dispute(
+        dispute,
+        &mut ctx.accounts.subject,
+        &ctx.accounts.escrow,
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.resolver.to_account_info(),
+        ctx.accounts.protocol_stats.as_deref_mut(),
+        ctx.accounts.juror_epoch.as_mut(),
+        clock.unix_timestamp,
+    )?;
+
+    rebond_restored_subject(
+        &mut ctx.accounts.subject,
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.resolver.to_account_info(),
+        ctx.remaining_accounts,
+        &ctx.accounts.system_program.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+
+    enqueue_expiry(ctx.accounts.expiry_queue.as_mut(), &ctx.accounts.dispute, ctx.accounts.escrow.key());
+    log_resolution(&ctx.accounts.subject, &ctx.accounts.dispute, ctx.accounts.noop_program.as_deref(), clock.unix_timestamp)
+}
+
+/// Track a resolved round in the optional keeper expiry queue (see ExpiryQueue)
+fn enqueue_expiry(queue: Option<&mut Account<ExpiryQueue>>, dispute: &Account<Dispute>, escrow: Pubkey) {
+    if let Some(queue) = queue {
+        let entry = ExpiryEntry {
+            dispute: dispute.key(),
+            escrow,
+            sweep_after: dispute.resolved_at.saturating_add(JUROR_CLAIM_DEADLINE),
+        };
+        if !queue.push(entry, dispute.resolved_at) {
+            msg!("Expiry queue full - round not tracked");
         }
+    }
+}
+
+/// Lifecycle record for a resolved dispute or restoration (status after any re-bond)
+fn log_resolution(subject: &Account<Subject>, dispute: &Account<Dispute>, noop_program: Option<&AccountInfo>, now: i64) -> Result<()> {
+    LifecycleLog {
+        kind: LifecycleKind::Resolved,
+        subject: subject.key(),
+        dispute: dispute.key(),
+        status: subject.status,
+        outcome: dispute.outcome,
+        amount: dispute.total_bond.saturating_add(dispute.total_stake_held()),
+        timestamp: now,
+    }.write(noop_program)
+}
+
+/// After a successful restoration the subject has no stake behind it
+/// If the creator resolves and passes their pool, the subject is re-bonded in the same
+/// transaction: min(max_stake, pool available) moves from the pool into the subject as the
+/// creator's direct stake (as revive_subject does), and the subject is linked to the pool so
+/// later disputes can pull more. The creator's record is created if needed (resolver pays rent);
+/// an existing one must have settled its earlier claims and starts a fresh position
+fn rebond_restored_subject<'info>(
+    subject: &mut Account<'info, Subject>,
+    dispute: &mut Dispute,
+    resolver: &AccountInfo<'info>,
+    remaining: &'info [AccountInfo<'info>],
+    system_program: &AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    if remaining.is_empty() {
+        return Ok(());
+    }
+    require!(remaining.len() == 2, TribunalCraftError::InvalidRemainingAccounts);
+    require!(
+        dispute.is_appeal && dispute.outcome == ResolutionOutcome::ChallengerWins,
+        TribunalCraftError::AppealMismatch
+    );
+    require!(resolver.key() == subject.creator, TribunalCraftError::Unauthorized);
+    require!(!subject.free_case, TribunalCraftError::FreeCaseMismatch);
+    require!(!subject.is_linked(), TribunalCraftError::SubjectAlreadyLinked);
+
+    let mut defender_pool: Account<DefenderPool> = Account::try_from(&remaining[0])?;
+    require!(defender_pool.owner == subject.creator, TribunalCraftError::Unauthorized);
+
+    let subject_key = subject.key();
+    let (record_key, record_bump) = Pubkey::find_program_address(
+        &[DEFENDER_RECORD_SEED, subject_key.as_ref(), subject.creator.as_ref()],
+        &crate::ID,
+    );
+    require!(remaining[1].key() == record_key, TribunalCraftError::InvalidRemainingAccounts);
+    let mut defender_record: Account<DefenderRecord> = if remaining[1].data_is_empty() {
+        create_pda_account(
+            resolver, &remaining[1], DefenderRecord::LEN,
+            &[DEFENDER_RECORD_SEED, subject_key.as_ref(), subject.creator.as_ref(), &[record_bump]], system_program,
+        )?;
+        Account::try_from_unchecked(&remaining[1])?
     } else {
-        0
+        let mut record: Account<DefenderRecord> = Account::try_from(&remaining[1])?;
+        // The restoration wiped the subject's stake totals; the old position must be settled
+        require!(
+            record.pool_stake == 0 && (record.stake == 0 || record.reward_claimed),
+            TribunalCraftError::ClaimsNotComplete
+        );
+        record.stake = 0;
+        record.staked_at = 0;
+        record
     };
 
-    let dispute = &mut ctx.accounts.dispute;
-    let subject = &mut ctx.accounts.subject;
-    let escrow = &mut ctx.accounts.escrow;
+    let bond = defender_pool.available.min(subject.max_stake);
+    transfer_lamports(&defender_pool.to_account_info(), &subject.to_account_info(), bond, LamportsMoveReason::ReviveBond)?;
+    defender_pool.total_stake = defender_pool.total_stake.checked_sub(bond)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.available -= bond;
+    defender_pool.subject_count = defender_pool.subject_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.updated_at = now;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), &defender_pool);
+
+    // Books the bond to the creator (a fresh record, so the subject counts one defender)
+    let creator = subject.creator;
+    credit_direct_stake(subject, &mut defender_record, creator, record_bump, bond, now)?;
+    subject.defender_pool = defender_pool.key();
+    dispute.rebond_pool = defender_pool.key();
+    dispute.rebond_amount = bond;
+    defender_pool.exit(&crate::ID)?;
+    defender_record.exit(&crate::ID)?;
+
+    msg!("Restored subject re-bonded from creator's pool {}: {} lamports", defender_pool.key(), bond);
+    Ok(())
+}
 
-    // Determine outcome
-    let outcome = dispute.determine_outcome();
-    dispute.outcome = outcome;
+/// Reopen voting on a tied dispute (TiePolicy::ExtendVoting, once per dispute)
+fn extend_tied_vote(dispute: &mut Account<Dispute>, subject: &Subject, now: i64) -> Result<()> {
+    dispute.extend_for_tie(now, subject.tie_extension);
+
+    emit!(TieVotingExtendedEvent {
+        dispute: dispute.key(),
+        subject: dispute.subject,
+        voting_ends_at: dispute.voting_ends_at,
+        timestamp: now,
+    });
+
+    msg!("Vote tied - voting extended until {}", dispute.voting_ends_at);
+    Ok(())
+}
+
+/// Collect platform fee, mark the dispute resolved and update subject status
+/// Shared by one-shot resolve_dispute and paginated finalize_resolution
+/// The resolver's fee and the fee epoch's share are carved out of the platform fee, so claims are unaffected
+#[allow(clippy::too_many_arguments)]
+fn settle_dispute<'info>(
+    dispute: &mut Account<'info, Dispute>,
+    subject: &mut Account<'info, Subject>,
+    escrow: &AccountLoader<'info, DisputeEscrow>,
+    config: &ProtocolConfig,
+    treasury: &AccountInfo<'info>,
+    resolver: &AccountInfo<'info>,
+    stats: Option<&mut ProtocolStats>,
+    juror_epoch: Option<&mut Account<'info, JurorEpoch>>,
+    now: i64,
+) -> Result<()> {
+    let mut escrow_data = escrow.load_mut()?;
+
+    // Calculate platform fee from escrow
+    let total_pool = escrow_data.total_bonds
+        .checked_add(escrow_data.total_stakes)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    // Fees come from the contested pool only (see ContestedPool)
+    let fee_pool = ContestedPool::for_dispute(subject, dispute, &escrow_data).total()?;
+    let platform_fee = if !subject.free_case && fee_pool > 0 {
+        let total_fees = fee_pool as u128 * TOTAL_FEE_BPS as u128 / 10000;
+        (total_fees * (10000 - subject.juror_share_bps) as u128 / 10000) as u64
+    } else {
+        0
+    };
+
+    let outcome = dispute.outcome;
     dispute.status = DisputeStatus::Resolved;
-    dispute.resolved_at = clock.unix_timestamp;
+    dispute.resolved_at = now;
 
     // Store dispute totals for future appeals
     let dispute_voting_period = dispute.voting_ends_at - dispute.voting_starts_at;
@@ -92,12 +623,41 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
     };
     subject.last_voting_period = dispute_voting_period;
 
-    // Collect platform fees from escrow
-    if platform_fee > 0 && outcome != ResolutionOutcome::NoParticipation {
-        **escrow.to_account_info().try_borrow_mut_lamports()? -= platform_fee;
-        **ctx.accounts.treasury.try_borrow_mut_lamports()? += platform_fee;
-        escrow.record_platform_fee(platform_fee);
-        msg!("Platform fee collected: {} lamports", platform_fee);
+    // Collect platform fees from escrow (resolver is paid out of the platform share)
+    let (fee_collected, resolver_fee) = if platform_fee > 0 && outcome != ResolutionOutcome::NoParticipation {
+        let resolver_fee = config.resolver_fee(fee_pool).min(platform_fee);
+        // Hold back enough of the treasury share to pay the highest rebate tier
+        dispute.rebate_bps = config.max_rebate_bps();
+        dispute.rebate_reserve = ((platform_fee - resolver_fee) as u128 * dispute.rebate_bps as u128 / MAX_BPS as u128) as u64;
+        let protocol_fee = platform_fee - resolver_fee - dispute.rebate_reserve;
+        // Part of the protocol's share funds the running juror epoch's base reward
+        let epoch_fee = config.epoch_fee(protocol_fee, now);
+        if epoch_fee > 0 {
+            let juror_epoch = juror_epoch.ok_or(TribunalCraftError::FeeEpochMismatch)?;
+            transfer_lamports(&escrow.to_account_info(), &juror_epoch.to_account_info(), epoch_fee, LamportsMoveReason::EpochFee)?;
+            juror_epoch.reward_pool = juror_epoch.reward_pool.checked_add(epoch_fee)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        }
+        let treasury_fee = protocol_fee - epoch_fee;
+        transfer_lamports(&escrow.to_account_info(), treasury, treasury_fee, LamportsMoveReason::PlatformFee)?;
+        Treasury::record_income(treasury, treasury_fee)?;
+        transfer_lamports(&escrow.to_account_info(), resolver, resolver_fee, LamportsMoveReason::ResolverFee)?;
+        escrow_data.record_platform_fee(protocol_fee + resolver_fee)?;
+        dispute.resolver_fee_paid = resolver_fee;
+        dispute.epoch_fee_paid = epoch_fee;
+        invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+        msg!(
+            "Platform fee collected: {} lamports ({} to resolver, {} to juror epoch, {} reserved for rebates)",
+            platform_fee, resolver_fee, epoch_fee, dispute.rebate_reserve
+        );
+        (protocol_fee, resolver_fee)
+    } else {
+        (0, 0)
+    };
+
+    if let Some(stats) = stats {
+        stats.record_resolution(outcome, total_pool, fee_collected, now);
+        stats.record_resolver_fee(resolver_fee);
     }
 
     // Update subject status based on outcome
@@ -121,14 +681,24 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
         }
     } else {
         match outcome {
-            ResolutionOutcome::NoParticipation | ResolutionOutcome::DefenderWins => {
+            ResolutionOutcome::NoParticipation => {
                 subject.status = SubjectStatus::Active;
                 subject.dispute = Pubkey::default();
-                msg!("Dispute resolved - defender wins, subject returns to active");
+                msg!("Dispute resolved - no participation, subject returns to active");
+            }
+            ResolutionOutcome::DefenderWins => {
+                subject.status = subject.status_after_defense();
+                subject.dispute = Pubkey::default();
+                subject.cooldown_ends_at = now.saturating_add(subject.dispute_cooldown);
+                if subject.status == SubjectStatus::Dormant {
+                    msg!("Dispute resolved - defender wins, subject dormant until restaked");
+                } else {
+                    msg!("Dispute resolved - defender wins, subject returns to active");
+                }
             }
             ResolutionOutcome::ChallengerWins => {
                 subject.status = SubjectStatus::Invalidated;
-                msg!("Dispute resolved: Challenger wins - subject invalidated");
+                msg!("Dispute resolved: Challenger wins - subject invalidated ({} bps slashed)", dispute.slash_bps());
             }
             ResolutionOutcome::None => {
                 return Err(TribunalCraftError::InvalidVoteChoice.into());
@@ -136,12 +706,630 @@ pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
         }
     }
 
+    subject.record_outcome(dispute.is_appeal, outcome);
+    subject.updated_at = now;
+
+    emit!(DisputeResolvedEvent {
+        dispute: dispute.key(),
+        subject: subject.key(),
+        outcome,
+        dominant_type: dispute.dominant_type(),
+        is_appeal: dispute.is_appeal,
+        disputes_won: subject.disputes_won,
+        disputes_lost: subject.disputes_lost,
+        restorations_won: subject.restorations_won,
+        restorations_lost: subject.restorations_lost,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+// =============================================================================
+// CAPPED WEIGHT TALLY (subjects with max_juror_weight_bps)
+// =============================================================================
+//
+// After voting ends, cranks pass every vote record in chunks; each vote counts
+// for at most max_juror_weight_bps of the final raw total. Resolution waits
+// until all votes are tallied and then decides on the capped weights. Rewards
+// still use raw voting power.
+
+#[derive(Accounts)]
+pub struct TallyCappedVotes<'info> {
+    pub resolver: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
+
+/// remaining_accounts: vote records of this dispute (writable); already-tallied records are skipped
+pub fn tally_capped_votes<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TallyCappedVotes<'info>>,
+) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let dispute_key = dispute.key();
+    let cap_bps = ctx.accounts.subject.max_juror_weight_bps;
+
+    require!(cap_bps > 0, TribunalCraftError::WeightCapNotSet);
+    require!(dispute.is_voting_ended(Clock::get()?.unix_timestamp), TribunalCraftError::VotingNotEnded);
+
+    dispute.weight_cap_bps = cap_bps;
+    let limit = dispute.juror_weight_limit();
+    let round = dispute.tally_round.saturating_add(1);
+
+    let mut tallied: u16 = 0;
+    for info in ctx.remaining_accounts {
+        let mut vote_record: Account<VoteRecord> = Account::try_from(info)?;
+        require!(vote_record.dispute == dispute_key, TribunalCraftError::VoteRecordMismatch);
+
+        if vote_record.tallied_round == round {
+            continue;
+        }
+
+        let counted = vote_record.voting_power.min(limit);
+        let tally = if vote_record.is_favor() {
+            &mut dispute.capped_favor_weight
+        } else {
+            &mut dispute.capped_against_weight
+        };
+        *tally = tally.checked_add(counted).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+
+        vote_record.tallied_round = round;
+        vote_record.exit(&crate::ID)?;
+        tallied += 1;
+    }
+
+    dispute.capped_votes_tallied = dispute.capped_votes_tallied.saturating_add(tallied);
+
+    msg!(
+        "Capped tally: {} records ({}/{}), limit {} per juror",
+        tallied, dispute.capped_votes_tallied, dispute.vote_count, limit
+    );
+    Ok(())
+}
+
+// =============================================================================
+// PAGINATED RESOLUTION (begin -> process chunks -> finalize)
+// =============================================================================
+//
+// For disputes with too many votes to bookkeep in one transaction. The outcome
+// is fixed at begin_resolution; cranks then process vote records in chunks via
+// remaining_accounts; finalize_resolution settles fees and subject status.
+// Chunk processing is optional - anything left unprocessed is handled lazily
+// by claim_juror_reward, so a missing juror account can't block finalization.
+
+#[derive(Accounts)]
+pub struct BeginResolution<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Subject's tie policy decides tied tallies
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn begin_resolution(ctx: Context<BeginResolution>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    require!(dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingNotEnded);
+    dispute.check_weight_tally(ctx.accounts.subject.max_juror_weight_bps)?;
+
+    let tie_policy = ctx.accounts.subject.tie_policy;
+    if dispute.should_extend_tie(tie_policy) {
+        return extend_tied_vote(dispute, &ctx.accounts.subject, clock.unix_timestamp);
+    }
+
+    // Tallies are final once voting ends
+    dispute.outcome = dispute.determine_outcome(tie_policy);
+    dispute.status = DisputeStatus::Resolving;
+    dispute.reputation_processed_count = 0;
+
+    msg!("Resolution started: {} votes to process", dispute.vote_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProcessResolutionChunk<'info> {
+    pub resolver: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolving @ TribunalCraftError::DisputeNotResolving,
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
+
+/// remaining_accounts: [vote_record, juror_account] pairs (both writable)
+pub fn process_resolution_chunk<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessResolutionChunk<'info>>,
+) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let dispute_key = dispute.key();
+    let remaining = ctx.remaining_accounts;
+
+    require!(remaining.len() % 2 == 0, TribunalCraftError::InvalidRemainingAccounts);
+
+    // Free cases have no reputation impact - nothing to process
+    if ctx.accounts.subject.free_case {
+        return Ok(());
+    }
+
+    let mut processed: u16 = 0;
+    for pair in remaining.chunks(2) {
+        let mut vote_record: Account<VoteRecord> = Account::try_from(&pair[0])?;
+        let mut juror_account: Account<JurorAccount> = Account::try_from(&pair[1])?;
+
+        require!(vote_record.dispute == dispute_key, TribunalCraftError::VoteRecordMismatch);
+        require!(vote_record.juror_account == juror_account.key(), TribunalCraftError::VoteRecordMismatch);
+
+        if vote_record.reputation_processed {
+            continue;
+        }
+
+        process_juror_reputation(&mut juror_account, &mut vote_record, dispute)?;
+        vote_record.exit(&crate::ID)?;
+        juror_account.exit(&crate::ID)?;
+        processed += 1;
+    }
+
+    dispute.reputation_processed_count = dispute.reputation_processed_count.saturating_add(processed);
+
+    msg!(
+        "Resolution chunk processed: {} records ({}/{})",
+        processed, dispute.reputation_processed_count, dispute.vote_count
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeResolution<'info> {
+    /// Receives the resolver fee
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolving @ TribunalCraftError::DisputeNotResolving,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(mut)]
+    pub subject: Account<'info, Subject>,
+
+    /// Escrow PDA holds all funds for this dispute
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    /// Protocol config for treasury address
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Treasury account receives platform fees
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury @ TribunalCraftError::InvalidConfig,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional: the subject's config's keeper expiry queue, kept current when passed
+    #[account(
+        mut,
+        seeds = [EXPIRY_QUEUE_SEED, subject.config.as_ref()],
+        bump = expiry_queue.bump
+    )]
+    pub expiry_queue: Option<Account<'info, ExpiryQueue>>,
+
+    /// Optional: the config's fee epoch, required while it runs and epoch_fee_bps is set
+    #[account(
+        mut,
+        constraint = juror_epoch.key() == protocol_config.fee_epoch @ TribunalCraftError::FeeEpochMismatch,
+    )]
+    pub juror_epoch: Option<Account<'info, JurorEpoch>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts (optional, restorations only): [creator's defender_pool, creator's defender_record]
+pub fn finalize_resolution<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeResolution<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    settle_dispute(
+        &mut ctx.accounts.dispute,
+        &mut ctx.accounts.subject,
+        &ctx.accounts.escrow,
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.treasury,
+        &ctx.accounts.resolver.to_account_info(),
+        ctx.accounts.protocol_stats.as_deref_mut(),
+        ctx.accounts.juror_epoch.as_mut(),
+        clock.unix_timestamp,
+    )?;
+
+    rebond_restored_subject(
+        &mut ctx.accounts.subject,
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.resolver.to_account_info(),
+        ctx.remaining_accounts,
+        &ctx.accounts.system_program.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+
+    enqueue_expiry(ctx.accounts.expiry_queue.as_mut(), &ctx.accounts.dispute, ctx.accounts.escrow.key());
+    log_resolution(&ctx.accounts.subject, &ctx.accounts.dispute, ctx.accounts.noop_program.as_deref(), clock.unix_timestamp)
+}
+
+// =============================================================================
+// TIMEOUT REFUND (dispute never resolved)
+// =============================================================================
+//
+// If nobody resolves a dispute for DISPUTE_REFUND_TIMEOUT after voting ends,
+// anyone can close it without an outcome. It settles like NoParticipation:
+// challengers and defenders claim their bonds and stakes back in full through
+// the usual claims, no fees are taken, jurors get no reward or reputation
+// change and can unlock their stake. This covers a paginated resolution that
+// was begun but never finalized; reputation its chunks applied is reversed
+// per vote record when jurors claim or unlock (see settle_refund_only_vote).
+
+#[derive(Accounts)]
+pub struct TimeoutRefund<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = matches!(dispute.status, DisputeStatus::Pending | DisputeStatus::Resolving)
+            @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(mut)]
+    pub subject: Account<'info, Subject>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional: the subject's config's keeper expiry queue, kept current when passed
+    #[account(
+        mut,
+        seeds = [EXPIRY_QUEUE_SEED, subject.config.as_ref()],
+        bump = expiry_queue.bump
+    )]
+    pub expiry_queue: Option<Account<'info, ExpiryQueue>>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+}
+
+pub fn timeout_refund(ctx: Context<TimeoutRefund>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let subject = &mut ctx.accounts.subject;
+    let clock = Clock::get()?;
+
+    require!(dispute.can_timeout_refund(clock.unix_timestamp), TribunalCraftError::RefundTimeoutNotReached);
+
+    dispute.outcome = ResolutionOutcome::NoParticipation;
+    dispute.status = DisputeStatus::Resolved;
+    dispute.resolved_at = clock.unix_timestamp;
+    dispute.timed_out = true;
+
+    // Back to where the subject stood before the dispute (no track record change)
+    subject.status = if dispute.is_appeal {
+        SubjectStatus::Invalidated
+    } else {
+        SubjectStatus::Active
+    };
+    subject.dispute = Pubkey::default();
+    subject.updated_at = clock.unix_timestamp;
+
+    // Counted like a no-participation round: no fees, volume at face value
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        let volume = dispute.total_bond.saturating_add(dispute.total_stake_held());
+        stats.record_resolution(ResolutionOutcome::NoParticipation, volume, 0, clock.unix_timestamp);
+    }
+
+    emit!(DisputeTimedOutEvent {
+        dispute: dispute.key(),
+        subject: subject.key(),
+        is_appeal: dispute.is_appeal,
+        total_bond: dispute.total_bond,
+        total_stake: dispute.total_stake_held(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    log_resolution(subject, dispute, ctx.accounts.noop_program.as_deref(), clock.unix_timestamp)?;
+
+    // The escrow isn't passed here; its address is derived for the queue entry
+    let (escrow, _) = Pubkey::find_program_address(&[DISPUTE_ESCROW_SEED, dispute.key().as_ref()], &crate::ID);
+    enqueue_expiry(ctx.accounts.expiry_queue.as_mut(), dispute, escrow);
+
+    msg!("Dispute timed out unresolved - bonds and stakes refundable in full");
+    Ok(())
+}
+
+// =============================================================================
+// VOID RESOLUTION (config authority, emergency only)
+// =============================================================================
+//
+// For a proven exploit, the subject's config authority can void a resolution
+// within RESOLUTION_VOID_WINDOW, as long as nobody has claimed yet. The round
+// then settles like a timeout refund. The fees already taken go back into
+// escrow so bonds and stakes return in full: a Treasury PDA refunds its share
+// (and its total_collected), the authority covers the resolver fee, the fee
+// epoch's share and any fee sent to a wallet treasury. Resolution side effects
+// are undone - subject status, counters and cooldown, an appeal's restored
+// stake snapshot, the pool link and bond made by a restoration rebond and ProtocolStats. A ResolutionVoid record
+// is written and never closed. A minted ResolutionAttestation is marked voided;
+// a dependent escrow that was already notified has to consult it.
+
+#[derive(Accounts)]
+pub struct VoidResolution<'info> {
+    /// Repays the fees collected at resolution and funds the audit record
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        has_one = authority,
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ResolutionVoid::LEN,
+        seeds = [RESOLUTION_VOID_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub resolution_void: Account<'info, ResolutionVoid>,
+
+    /// CHECK: The dispute's attestation PDA - marked voided if it was minted, empty otherwise
+    #[account(
+        mut,
+        seeds = [RESOLUTION_ATTESTATION_SEED, dispute.key().as_ref()],
+        bump,
+    )]
+    pub attestation: UncheckedAccount<'info>,
+
+    /// CHECK: Configured treasury; a Treasury PDA refunds its share of the fee
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury @ TribunalCraftError::InvalidConfig,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// Pool the restored subject was linked to at resolution (required if dispute.rebond_pool is set)
+    #[account(
+        mut,
+        constraint = defender_pool.key() == dispute.rebond_pool @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub defender_pool: Option<Account<'info, DefenderPool>>,
+
+    /// Creator's stake record the restoration rebond credited (required if dispute.rebond_amount is set)
+    #[account(
+        mut,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), subject.creator.as_ref()],
+        bump = rebond_record.bump,
+    )]
+    pub rebond_record: Option<Account<'info, DefenderRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn void_resolution(ctx: Context<VoidResolution>, reason_cid: String) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    let dispute = &mut ctx.accounts.dispute;
+    let clock = Clock::get()?;
+
+    require!(dispute.can_void(clock.unix_timestamp), TribunalCraftError::ResolutionNotVoidable);
+    require!(!reason_cid.is_empty(), TribunalCraftError::InvalidCid);
+    validate_cid(&reason_cid, ResolutionVoid::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    // A new dispute may already be running on the subject; its state can't be rolled back
+    require!(
+        subject.dispute == Pubkey::default() || subject.dispute == dispute.key(),
+        TribunalCraftError::DisputeAlreadyExists
+    );
+
+    // Put the collected platform and resolver fees back so every party is made whole
+    let (fees_restored, total_pool) = {
+        let escrow_data = ctx.accounts.escrow.load()?;
+        (escrow_data.platform_fee_paid, escrow_data.total_bonds.saturating_add(escrow_data.total_stakes))
+    };
+    let resolver_fee = dispute.resolver_fee_paid.min(fees_restored);
+    let protocol_fee = fees_restored - resolver_fee;
+    let treasury_fee = protocol_fee - dispute.epoch_fee_paid.min(protocol_fee);
+    let treasury = &ctx.accounts.treasury;
+    let from_treasury = if treasury.owner == &crate::ID {
+        let rent = Rent::get()?.minimum_balance(Treasury::LEN);
+        require!(
+            treasury.lamports().saturating_sub(rent) >= treasury_fee,
+            TribunalCraftError::InsufficientTreasuryBalance
+        );
+        transfer_lamports(treasury, &ctx.accounts.escrow.to_account_info(), treasury_fee, LamportsMoveReason::VoidRefund)?;
+        Treasury::reverse_income(treasury, treasury_fee)?;
+        treasury_fee
+    } else {
+        0
+    };
+    let from_authority = fees_restored - from_treasury;
+    if from_authority > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, from_authority)?;
+    }
+    {
+        let mut escrow_data = ctx.accounts.escrow.load_mut()?;
+        escrow_data.platform_fee_paid = 0;
+        invariants::check_escrow(&ctx.accounts.escrow.to_account_info(), &escrow_data);
+    }
+
+    let voided_outcome = dispute.outcome;
+    subject.revert_outcome(dispute.is_appeal, voided_outcome);
+    subject.status = if dispute.is_appeal {
+        SubjectStatus::Invalidated
+    } else {
+        SubjectStatus::Active
+    };
+    subject.dispute = Pubkey::default();
     subject.updated_at = clock.unix_timestamp;
+
+    if dispute.is_appeal {
+        // A successful restoration wiped the subject's stake totals; the appeal snapshot holds them
+        subject.defender_count = dispute.snapshot_defender_count;
+        subject.total_stake = dispute.snapshot_total_stake;
+    } else if voided_outcome == ResolutionOutcome::DefenderWins
+        && subject.cooldown_ends_at == dispute.resolved_at.saturating_add(subject.dispute_cooldown)
+    {
+        // Lift the cooldown the voided defender win imposed
+        subject.cooldown_ends_at = 0;
+    }
+
+    // Undo the pool link a restoration rebond made and hand its bond back to the pool
+    // (the appeal snapshot above already took the bond out of the subject's totals)
+    if dispute.rebond_pool != Pubkey::default() {
+        let defender_pool = ctx.accounts.defender_pool.as_mut().ok_or(TribunalCraftError::PoolAccountMismatch)?;
+        let bond = dispute.rebond_amount;
+        if bond > 0 {
+            let record = ctx.accounts.rebond_record.as_mut().ok_or(TribunalCraftError::PoolAccountMismatch)?;
+            record.stake = record.stake.saturating_sub(bond);
+            transfer_lamports(&subject.to_account_info(), &defender_pool.to_account_info(), bond, LamportsMoveReason::VoidRefund)?;
+            defender_pool.total_stake = defender_pool.total_stake.checked_add(bond)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+            defender_pool.available = defender_pool.available.checked_add(bond)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+            invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
+            dispute.rebond_amount = 0;
+        }
+        defender_pool.subject_count = defender_pool.subject_count.saturating_sub(1);
+        defender_pool.updated_at = clock.unix_timestamp;
+        if subject.defender_pool == defender_pool.key() {
+            subject.defender_pool = Pubkey::default();
+        }
+        dispute.rebond_pool = Pubkey::default();
+    }
+
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.revert_resolution(
+            voided_outcome,
+            total_pool,
+            protocol_fee,
+            resolver_fee,
+            clock.unix_timestamp,
+        );
+    }
+
+    // An attestation minted for the voided outcome must not be acted on any more
+    let attestation_info = ctx.accounts.attestation.to_account_info();
+    if !attestation_info.data_is_empty() {
+        require!(attestation_info.owner == &crate::ID, TribunalCraftError::InvalidConfig);
+        let mut attestation = ResolutionAttestation::try_deserialize(&mut &attestation_info.try_borrow_data()?[..])?;
+        attestation.voided = true;
+        attestation.try_serialize(&mut &mut attestation_info.try_borrow_mut_data()?[..])?;
+    }
+
+    dispute.outcome = ResolutionOutcome::NoParticipation;
+    dispute.voided = true;
+    dispute.rebate_reserve = 0;
+    dispute.rebate_bps = 0;
+
+    let record = &mut ctx.accounts.resolution_void;
+    record.dispute = dispute.key();
+    record.subject = subject.key();
+    record.config = ctx.accounts.protocol_config.key();
+    record.authority = ctx.accounts.authority.key();
+    record.voided_outcome = voided_outcome;
+    record.is_appeal = dispute.is_appeal;
+    record.reason_cid = reason_cid.clone();
+    record.fees_restored = fees_restored;
+    record.resolved_at = dispute.resolved_at;
+    record.voided_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.resolution_void;
+
+    emit!(ResolutionVoidedEvent {
+        dispute: dispute.key(),
+        subject: subject.key(),
+        authority: record.authority,
+        voided_outcome,
+        reason_cid,
+        fees_restored,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Resolution voided - bonds and stakes refundable in full");
     Ok(())
 }
 
 // =============================================================================
-// UNLOCK JUROR STAKE (after 7 day buffer)
+// UNLOCK JUROR STAKE (after 7 day buffer, or at once for refund-only rounds)
 // =============================================================================
 
 #[derive(Accounts)]
@@ -157,10 +1345,8 @@ pub struct UnlockJurorStake<'info> {
     )]
     pub juror_account: Account<'info, JurorAccount>,
 
-    #[account(
-        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
-    )]
-    pub dispute: Account<'info, Dispute>,
+    /// CHECK: Vote's dispute - must be resolved, or already closed by close_subject
+    pub dispute: UncheckedAccount<'info>,
 
     #[account(
         mut,
@@ -176,6 +1362,19 @@ pub fn unlock_juror_stake(ctx: Context<UnlockJurorStake>) -> Result<()> {
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
+    // Closed disputes were resolved before closing (close_subject requires it)
+    let dispute_info = &ctx.accounts.dispute;
+    if !dispute_info.data_is_empty() {
+        require!(dispute_info.owner == &crate::ID, TribunalCraftError::DisputeNotFound);
+        let dispute = Dispute::try_deserialize(&mut &dispute_info.try_borrow_data()?[..])?;
+        require!(dispute.status == DisputeStatus::Resolved, TribunalCraftError::DisputeNotFound);
+
+        // Timed-out and voided rounds release immediately
+        if dispute.is_refund_only() {
+            return settle_refund_only_vote(juror_account, vote_record);
+        }
+    }
+
     // Check 7-day buffer has passed
     require!(
         vote_record.can_unlock(clock.unix_timestamp),
@@ -183,7 +1382,7 @@ pub fn unlock_juror_stake(ctx: Context<UnlockJurorStake>) -> Result<()> {
     );
 
     // Release held stake back to available (accounting only - SOL stays in JurorAccount PDA)
-    juror_account.release_from_vote(vote_record.stake_allocated);
+    juror_account.release_from_vote(vote_record.stake_allocated)?;
     vote_record.stake_unlocked = true;
 
     msg!("Juror stake unlocked: {} lamports", vote_record.stake_allocated);
@@ -210,6 +1409,7 @@ pub struct ClaimJurorReward<'info> {
     pub subject: Account<'info, Subject>,
 
     #[account(
+        mut,
         has_one = subject,
         constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
     )]
@@ -219,94 +1419,225 @@ pub struct ClaimJurorReward<'info> {
     #[account(
         mut,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        mut,
+        has_one = dispute,
+        has_one = juror,
+        constraint = !vote_record.reward_claimed @ TribunalCraftError::RewardAlreadyClaimed,
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// CHECK: Optional: juror's stored beneficiary - receives the reward instead of the stake balance,
+    /// less the reputation slash (burned)
+    #[account(
+        mut,
+        constraint = juror_account.beneficiary != Pubkey::default()
+            && beneficiary.key() == juror_account.beneficiary @ TribunalCraftError::BeneficiaryMismatch,
+    )]
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    /// Protocol config (rebate tiers)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Incinerator receives the reputation slash on rewards paid out of the stake balance
+    #[account(mut, address = solana_program::incinerator::ID @ TribunalCraftError::InvalidConfig)]
+    pub incinerator: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Apply a juror's reputation change for a resolved outcome (once per vote record)
+/// Wrong votes on a narrow restoration (see Dispute::is_narrow_restoration) are not penalized
+/// The change is sized at the reputation snapshotted when the vote was cast, then applied to
+/// the current reputation, so the order claims are processed in does not change the result
+/// Note: Stake unlock is handled separately via unlock_juror_stake after 7 days
+fn process_juror_reputation(
+    juror_account: &mut JurorAccount,
+    vote_record: &mut VoteRecord,
+    dispute: &Dispute,
+) -> Result<()> {
+    if vote_record.reputation_processed {
+        return Ok(());
+    }
+
+    // Timed-out and voided rounds leave reputation untouched
+    if let (false, Some(correct)) = (dispute.is_refund_only(), vote_record.is_correct(dispute.outcome)) {
+        let basis = vote_record.reputation_basis(juror_account.reputation);
+        let before = juror_account.reputation;
+
+        if correct {
+            juror_account.correct_votes = juror_account.correct_votes.checked_add(1)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+            vote_record.counted_correct = true;
+            let gain = reputation_gain(basis);
+            juror_account.reputation = juror_account.reputation.saturating_add(gain).min(MAX_BPS);
+            msg!("Reputation gain: +{}", gain);
+        } else if dispute.is_narrow_restoration() {
+            msg!("Narrow restoration result: no reputation loss");
+        } else {
+            let loss = reputation_loss(basis);
+            juror_account.reputation = juror_account.reputation.saturating_sub(loss);
+            msg!("Reputation loss: -{}", loss);
+        }
+        vote_record.reputation_delta = (juror_account.reputation as i32 - before as i32) as i16;
+    }
+
+    vote_record.reputation_processed = true;
+    Ok(())
+}
+
+/// Settle a vote on a timed-out or voided round: no reputation change and the full
+/// stake allocation released at once (no 7-day buffer)
+/// A change already applied by process_resolution_chunk before a void is reversed
+fn settle_refund_only_vote(juror_account: &mut JurorAccount, vote_record: &mut VoteRecord) -> Result<()> {
+    if vote_record.reputation_delta != 0 || vote_record.counted_correct {
+        let restored = (juror_account.reputation as i32 - vote_record.reputation_delta as i32)
+            .clamp(0, MAX_BPS as i32);
+        juror_account.reputation = restored as u16;
+        if vote_record.counted_correct {
+            juror_account.correct_votes = juror_account.correct_votes.saturating_sub(1);
+        }
+        msg!("Voided round: reputation change of {} reversed", vote_record.reputation_delta);
+        vote_record.reputation_delta = 0;
+        vote_record.counted_correct = false;
+    }
+    vote_record.reputation_processed = true;
+
+    if !vote_record.stake_unlocked {
+        juror_account.release_from_vote(vote_record.stake_allocated)?;
+        vote_record.stake_unlocked = true;
+        msg!("Juror stake released: {} lamports", vote_record.stake_allocated);
+    }
+    Ok(())
+}
 
-    #[account(
-        mut,
-        has_one = dispute,
-        has_one = juror,
-        constraint = !vote_record.reward_claimed @ TribunalCraftError::RewardAlreadyClaimed,
-    )]
-    pub vote_record: Account<'info, VoteRecord>,
+/// Move a juror reward out of escrow, either into the stake balance or to a recipient
+/// A recipient (wallet or beneficiary) gets the reward less the same reputation slash as
+/// withdraw_juror_stake. The slash is burned right away: left in the JurorAccount it would
+/// come back to the juror when unregister_juror closes the account
+fn pay_juror_reward<'info>(
+    escrow: &AccountInfo<'info>,
+    juror_info: &AccountInfo<'info>,
+    juror_account: &mut JurorAccount,
+    recipient: Option<&AccountInfo<'info>>,
+    incinerator: &AccountInfo<'info>,
+    reward: u64,
+) -> Result<()> {
+    match recipient {
+        Some(recipient) => {
+            let (return_amount, slash_amount) = juror_account.calculate_withdrawal(reward, SLASH_THRESHOLD);
+            transfer_lamports(escrow, recipient, return_amount, LamportsMoveReason::JurorReward)?;
+            transfer_lamports(escrow, incinerator, slash_amount, LamportsMoveReason::JurorSlash)?;
+            if slash_amount > 0 {
+                msg!("Reputation slash burned: {} lamports", slash_amount);
+            }
+        }
+        None => {
+            // Transfer reward from escrow to JurorAccount PDA
+            transfer_lamports(escrow, juror_info, reward, LamportsMoveReason::JurorReward)?;
 
-    pub system_program: Program<'info, System>,
+            // Update juror balance accounting
+            juror_account.add_reward(reward)?;
+        }
+    }
+    Ok(())
 }
 
-pub fn claim_juror_reward(ctx: Context<ClaimJurorReward>) -> Result<()> {
+/// claim_to_wallet: pay the juror's wallet instead of the stake balance
+/// Rewards paid out of the stake balance (wallet or beneficiary) take the withdrawal slash, burned
+pub fn claim_juror_reward(ctx: Context<ClaimJurorReward>, claim_to_wallet: bool) -> Result<()> {
     let subject = &ctx.accounts.subject;
-    let dispute = &ctx.accounts.dispute;
-    let escrow = &mut ctx.accounts.escrow;
+    let dispute = &mut ctx.accounts.dispute;
+    let escrow = &ctx.accounts.escrow;
+    let mut escrow_data = escrow.load_mut()?;
     let juror_account = &mut ctx.accounts.juror_account;
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
+    // Timed-out and voided rounds pay no reward: settle the vote and return the stake
+    if dispute.is_refund_only() {
+        settle_refund_only_vote(juror_account, vote_record)?;
+        vote_record.reward_claimed = true;
+        dispute.jurors_claimed = dispute.jurors_claimed.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        msg!("Round voided or timed out - no juror reward");
+        return Ok(());
+    }
+
     require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
+    require!(!vote_record.is_rationale_pending(), TribunalCraftError::RationaleNotRevealed);
+    require!(!(claim_to_wallet && ctx.accounts.beneficiary.is_some()), TribunalCraftError::BeneficiaryConflict);
 
     // =========================================================================
     // PROCESS REPUTATION (if not already done - can't skip)
     // =========================================================================
-    let is_correct = vote_record.is_correct(dispute.outcome);
-
-    if !vote_record.reputation_processed {
-        if let Some(correct) = is_correct {
-            let multiplier = stacked_sigmoid(juror_account.reputation);
-
-            if correct {
-                juror_account.correct_votes += 1;
-                let remaining = 10000u16.saturating_sub(juror_account.reputation);
-                let gain = (remaining as u32 * REPUTATION_GAIN_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16;
-                juror_account.reputation = juror_account.reputation.saturating_add(gain);
-                msg!("Reputation gain: +{}", gain);
-            } else {
-                let loss = (juror_account.reputation as u32 * REPUTATION_LOSS_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16;
-                juror_account.reputation = juror_account.reputation.saturating_sub(loss);
-                msg!("Reputation loss: -{}", loss);
-            }
-        }
-
-        // Note: Stake unlock is handled separately via unlock_juror_stake after 7 days
-        vote_record.reputation_processed = true;
-    }
+    process_juror_reputation(juror_account, vote_record, dispute)?;
 
     // =========================================================================
     // CLAIM REWARD (all voters get reward - incentivizes calling this function)
     // =========================================================================
 
-    // Calculate juror pot from escrow totals
-    let total_pool = escrow.total_bonds.saturating_add(escrow.total_stakes);
-    let total_fees = total_pool as u128 * TOTAL_FEE_BPS as u128 / 10000;
-    let juror_pot = (total_fees * JUROR_SHARE_BPS as u128 / 10000) as u64;
+    let reward = juror_payout(subject, dispute, &escrow_data, vote_record)?;
+    // Reputation after this vote decides the rebate tier
+    let rebate = juror_rebate(&ctx.accounts.protocol_config, dispute, vote_record, juror_account.reputation);
+    let incentive = juror_incentive_share(dispute, vote_record);
+    let reward = reward.checked_add(rebate)
+        .and_then(|r| r.checked_add(incentive))
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
-    if juror_pot == 0 {
+    if reward == 0 {
         vote_record.reward_claimed = true;
-        msg!("No juror pot available");
+        dispute.jurors_claimed = dispute.jurors_claimed.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        msg!("No juror reward available");
         return Ok(());
     }
 
-    // Total weight of ALL voters (not just correct ones)
-    let total_vote_weight = dispute.votes_favor_weight.saturating_add(dispute.votes_against_weight);
+    let recipient = match &ctx.accounts.beneficiary {
+        Some(beneficiary) => Some(beneficiary.to_account_info()),
+        None if claim_to_wallet => Some(ctx.accounts.juror.to_account_info()),
+        None => None,
+    };
 
-    if total_vote_weight == 0 {
-        vote_record.reward_claimed = true;
-        msg!("No votes cast");
-        return Ok(());
+    let juror_info = juror_account.to_account_info();
+    pay_juror_reward(
+        &escrow.to_account_info(),
+        &juror_info,
+        juror_account,
+        recipient.as_ref(),
+        &ctx.accounts.incinerator,
+        reward,
+    )?;
+    // The rebate is part of the platform fee, paid to the juror instead of the treasury
+    // The incentive was never part of the bond/stake accounting, so it isn't recorded
+    escrow_data.record_juror_reward(reward - rebate - incentive)?;
+    escrow_data.record_platform_fee(rebate)?;
+    if rebate > 0 {
+        msg!("Fee rebate included: {} lamports", rebate);
     }
-
-    // Reward proportional to voting power (all jurors share the pot)
-    let reward = (juror_pot as u128 * vote_record.voting_power as u128 / total_vote_weight as u128) as u64;
-
-    // Transfer reward from escrow to JurorAccount PDA
-    **escrow.to_account_info().try_borrow_mut_lamports()? -= reward;
-    **juror_account.to_account_info().try_borrow_mut_lamports()? += reward;
-
-    // Update juror balance accounting
-    juror_account.add_reward(reward);
-    escrow.record_juror_reward(reward);
+    if incentive > 0 {
+        msg!("Juror incentive included: {} lamports", incentive);
+    }
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+    invariants::check_juror_account(&juror_account.to_account_info(), juror_account);
 
     vote_record.reward_claimed = true;
-    msg!("Juror reward claimed: {} lamports (added to balance)", reward);
+    dispute.jurors_claimed = dispute.jurors_claimed.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    if ctx.accounts.beneficiary.is_some() {
+        msg!("Juror reward claimed: {} lamports (sent to beneficiary)", reward);
+    } else if claim_to_wallet {
+        msg!("Juror reward claimed: {} lamports (sent to wallet)", reward);
+    } else {
+        msg!("Juror reward claimed: {} lamports (added to balance)", reward);
+    }
     Ok(())
 }
 
@@ -339,9 +1670,9 @@ pub struct ClaimChallengerReward<'info> {
     #[account(
         mut,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.load()?.bump
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     #[account(
         mut,
@@ -357,67 +1688,64 @@ pub struct ClaimChallengerReward<'info> {
 pub fn claim_challenger_reward(ctx: Context<ClaimChallengerReward>) -> Result<()> {
     let subject = &ctx.accounts.subject;
     let dispute = &ctx.accounts.dispute;
-    let escrow = &mut ctx.accounts.escrow;
+    let escrow = &ctx.accounts.escrow;
+    let mut escrow_data = escrow.load_mut()?;
     let challenger_record = &mut ctx.accounts.challenger_record;
     let challenger_account = &mut ctx.accounts.challenger_account;
 
     require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
 
-    let outcome = dispute.outcome;
-    let bond = challenger_record.bond;
-    let total_bond = escrow.total_bonds;
-    let matched_stake = escrow.total_stakes;
-
-    match outcome {
+    let payout = challenger_payout(subject, dispute, &escrow_data, challenger_record)?;
+    let total_return = payout.total()?;
+
+    // A pledged bond was drawn from this same wallet (the record is keyed by the pledger), so
+    // the whole payout is one transfer; the event reports how it splits by source
+    transfer_lamports(&escrow.to_account_info(), &ctx.accounts.challenger.to_account_info(), total_return, LamportsMoveReason::ChallengerClaim)?;
+    payout.record(&mut escrow_data)?;
+    let (wallet_share, pledged_share) = challenger_record.split_by_source(total_return);
+    emit!(ChallengerRewardClaimedEvent {
+        dispute: dispute.key(),
+        challenger: challenger_record.challenger,
+        outcome: dispute.outcome,
+        amount: total_return,
+        wallet_share,
+        pledged_share,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Sized at the reputation snapshotted when the challenger joined
+    let basis = challenger_record.reputation_basis(challenger_account.reputation);
+    match dispute.outcome {
         ResolutionOutcome::ChallengerWins => {
-            // Winner: 80% of defender's stake + 80% of own bond back
-            let defender_contribution = (matched_stake as u128 * WINNER_SHARE_BPS as u128 / 10000) as u64;
-            let reward = challenger_record.calculate_reward_share(defender_contribution, total_bond);
-            let bond_return = (bond as u128 * WINNER_SHARE_BPS as u128 / 10000) as u64;
-            let total_return = reward + bond_return;
-
-            // All from escrow
-            **escrow.to_account_info().try_borrow_mut_lamports()? -= total_return;
-            **ctx.accounts.challenger.to_account_info().try_borrow_mut_lamports()? += total_return;
-
-            escrow.record_stake_claim(reward);
-            escrow.bonds_claimed = escrow.bonds_claimed.saturating_add(bond_return);
-
             // Update reputation
-            let remaining = 10000u16.saturating_sub(challenger_account.reputation);
-            let multiplier = stacked_sigmoid(challenger_account.reputation);
-            let gain = (remaining as u32 * REPUTATION_GAIN_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16;
-            challenger_account.reputation = challenger_account.reputation.saturating_add(gain);
-            challenger_account.disputes_upheld += 1;
+            let gain = reputation_gain(basis);
+            challenger_account.reputation = challenger_account.reputation.saturating_add(gain).min(MAX_BPS);
+            challenger_account.disputes_upheld = challenger_account.disputes_upheld.checked_add(1)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
             msg!("Challenger reward claimed: {} lamports", total_return);
         }
         ResolutionOutcome::DefenderWins => {
             // Loser: loses bond
-            let multiplier = stacked_sigmoid(challenger_account.reputation);
-            let loss = (challenger_account.reputation as u32 * REPUTATION_LOSS_RATE as u32 * multiplier as u32 / 10000 / 10000) as u16;
+            let loss = reputation_loss(basis);
             challenger_account.reputation = challenger_account.reputation.saturating_sub(loss);
-            challenger_account.disputes_dismissed += 1;
+            challenger_account.disputes_dismissed = challenger_account.disputes_dismissed.checked_add(1)
+                .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
             msg!("Dispute dismissed - challenger loses bond");
         }
-        ResolutionOutcome::NoParticipation => {
-            // No votes: full bond return
-            **escrow.to_account_info().try_borrow_mut_lamports()? -= bond;
-            **ctx.accounts.challenger.to_account_info().try_borrow_mut_lamports()? += bond;
-            escrow.bonds_claimed = escrow.bonds_claimed.saturating_add(bond);
-
-            msg!("No participation - bond returned: {} lamports", bond);
-        }
         _ => {
-            return Err(TribunalCraftError::DisputeNotFound.into());
+            msg!("No participation - bond returned: {} lamports", total_return);
         }
     }
 
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
     challenger_record.reward_claimed = true;
-    escrow.challengers_claimed += 1;
-    ctx.accounts.dispute.challengers_claimed += 1;
-    Ok(())
+    escrow_data.count_challenger_claim()?;
+    ctx.accounts.dispute.challengers_claimed = ctx.accounts.dispute.challengers_claimed.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    ctx.accounts.dispute.note_party_claim(&escrow_data, Clock::get()?.unix_timestamp);
+    sweep_dust(&mut ctx.accounts.dispute, subject, &mut escrow_data)
 }
 
 // =============================================================================
@@ -442,9 +1770,9 @@ pub struct ClaimDefenderReward<'info> {
     #[account(
         mut,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump
+        bump = escrow.load()?.bump
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     #[account(
         mut,
@@ -456,62 +1784,307 @@ pub struct ClaimDefenderReward<'info> {
     )]
     pub defender_record: Account<'info, DefenderRecord>,
 
+    /// Optional: the record's payout pool (required when pool-sourced stake returns to the pool)
+    #[account(
+        mut,
+        constraint = defender_pool.key() == defender_record.payout_pool @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub defender_pool: Option<Account<'info, DefenderPool>>,
+
+    /// CHECK: Delegate grant charged for the record's defend_from_pool stake
+    /// (required when defender_record.delegate_committed > 0; may already be removed)
+    #[account(
+        mut,
+        constraint = pool_delegate.key() == defender_record.pool_delegate @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub pool_delegate: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn claim_defender_reward(ctx: Context<ClaimDefenderReward>) -> Result<()> {
-    let subject = &ctx.accounts.subject;
-    let dispute = &ctx.accounts.dispute;
-    let escrow = &mut ctx.accounts.escrow;
-    let defender_record = &mut ctx.accounts.defender_record;
+/// claim_to_wallet: send the pool-sourced share to the owner's wallet instead of back to the pool
+pub fn claim_defender_reward(ctx: Context<ClaimDefenderReward>, claim_to_wallet: bool) -> Result<()> {
+    settle_defender_claim(
+        &ctx.accounts.subject,
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.escrow,
+        &mut ctx.accounts.defender_record,
+        ctx.accounts.defender_pool.as_mut(),
+        &ctx.accounts.defender.to_account_info(),
+        claim_to_wallet,
+    )?;
+    release_delegate_commitment(&mut ctx.accounts.defender_record, ctx.accounts.pool_delegate.as_ref())
+}
+
+/// Give a delegate back the defend_from_pool commitment once the stake it pulled is settled
+/// (returned or slashed). A grant removed since leaves an empty PDA, which is accepted
+fn release_delegate_commitment(defender_record: &mut DefenderRecord, pool_delegate: Option<&UncheckedAccount>) -> Result<()> {
+    if defender_record.delegate_committed == 0 {
+        return Ok(());
+    }
+    let grant_info = pool_delegate.ok_or(TribunalCraftError::PoolAccountMismatch)?.to_account_info();
+    if !grant_info.data_is_empty() {
+        require!(grant_info.owner == &crate::ID, TribunalCraftError::InvalidConfig);
+        let mut grant = PoolDelegate::try_deserialize(&mut &grant_info.try_borrow_data()?[..])?;
+        grant.release(defender_record.delegate_committed);
+        grant.try_serialize(&mut &mut grant_info.try_borrow_mut_data()?[..])?;
+        msg!("Delegate commitment released: {} lamports", defender_record.delegate_committed);
+    }
+    defender_record.delegate_committed = 0;
+    defender_record.pool_delegate = Pubkey::default();
+    Ok(())
+}
+
+/// Pay out a defender claim, sending the wallet share to `recipient`
+/// Returns the amount sent to `recipient`
+fn settle_defender_claim<'info>(
+    subject: &Subject,
+    dispute_account: &mut Account<'info, Dispute>,
+    escrow: &AccountLoader<'info, DisputeEscrow>,
+    defender_record: &mut DefenderRecord,
+    defender_pool: Option<&mut Account<'info, DefenderPool>>,
+    recipient: &AccountInfo<'info>,
+    claim_to_wallet: bool,
+) -> Result<u64> {
+    let dispute: &Dispute = dispute_account;
+    let mut escrow_data = escrow.load_mut()?;
 
     require!(!subject.free_case, TribunalCraftError::NotEligibleForReward);
 
     let outcome = dispute.outcome;
-    let stake = defender_record.stake;
-    let total_bond = escrow.total_bonds;
-    let total_stakes = escrow.total_stakes;
 
+    // Amount owed back to the defender (wallet + pool)
+    let payout = defender_payout(subject, dispute, &escrow_data, defender_record)?;
+    let total_return = payout.total()?;
     match outcome {
-        ResolutionOutcome::DefenderWins => {
-            // Winner: 80% of challenger's bond + 80% of own stake back
-            let bond_contribution = (total_bond as u128 * WINNER_SHARE_BPS as u128 / 10000) as u64;
-            let reward = defender_record.calculate_reward_share(bond_contribution, total_stakes);
-            let stake_return = (stake as u128 * WINNER_SHARE_BPS as u128 / 10000) as u64;
-            let total_return = reward + stake_return;
+        ResolutionOutcome::DefenderWins => msg!("Defender reward claimed: {} lamports", total_return),
+        ResolutionOutcome::ChallengerWins if total_return > 0 => {
+            msg!("Challenger wins - {} bps slashed, {} lamports returned", dispute.slash_bps(), total_return)
+        }
+        ResolutionOutcome::ChallengerWins => msg!("Challenger wins - defender loses stake"),
+        _ => msg!("No participation - stake returned: {} lamports", total_return),
+    }
 
-            // All from escrow
-            **escrow.to_account_info().try_borrow_mut_lamports()? -= total_return;
-            **ctx.accounts.defender.to_account_info().try_borrow_mut_lamports()? += total_return;
+    // Route the payout back to where the stake came from (pool share to the wallet if asked)
+    let (wallet_amount, pool_amount) = defender_record.split_by_source(total_return);
+    let (wallet_amount, pool_amount) = if claim_to_wallet {
+        (wallet_amount.checked_add(pool_amount).ok_or(TribunalCraftError::ArithmeticOverflow)?, 0)
+    } else {
+        (wallet_amount, pool_amount)
+    };
 
-            escrow.bonds_claimed = escrow.bonds_claimed.saturating_add(reward);
-            escrow.record_stake_claim(stake_return);
+    if wallet_amount > 0 {
+        transfer_lamports(&escrow.to_account_info(), recipient, wallet_amount, LamportsMoveReason::DefenderClaim)?;
+    }
 
-            msg!("Defender reward claimed: {} lamports", total_return);
-        }
-        ResolutionOutcome::ChallengerWins => {
-            // Loser: loses stake (already in escrow, goes to winners)
-            msg!("Challenger wins - defender loses stake");
-        }
-        ResolutionOutcome::NoParticipation => {
-            // No votes: full stake return
-            **escrow.to_account_info().try_borrow_mut_lamports()? -= stake;
-            **ctx.accounts.defender.to_account_info().try_borrow_mut_lamports()? += stake;
-            escrow.record_stake_claim(stake);
+    if pool_amount > 0 {
+        let defender_pool = defender_pool.ok_or(TribunalCraftError::PoolAccountMismatch)?;
+        transfer_lamports(&escrow.to_account_info(), &defender_pool.to_account_info(), pool_amount, LamportsMoveReason::DefenderPoolClaim)?;
 
-            msg!("No participation - stake returned: {} lamports", stake);
-        }
-        _ => {
-            return Err(TribunalCraftError::DisputeNotFound.into());
-        }
+        defender_pool.total_stake = defender_pool.total_stake.checked_add(pool_amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        defender_pool.available = defender_pool.available.checked_add(pool_amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        defender_pool.updated_at = Clock::get()?.unix_timestamp;
+        msg!("Pool share returned to pool: {} lamports", pool_amount);
     }
 
+    payout.record(&mut escrow_data)?;
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+    // Pool stake is settled for this dispute (returned or slashed)
+    defender_record.pool_stake = 0;
     defender_record.reward_claimed = true;
-    escrow.defenders_claimed += 1;
-    ctx.accounts.dispute.defenders_claimed += 1;
+    escrow_data.count_defender_claim()?;
+    dispute_account.defenders_claimed = dispute_account.defenders_claimed.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    dispute_account.note_party_claim(&escrow_data, Clock::get()?.unix_timestamp);
+    sweep_dust(dispute_account, subject, &mut escrow_data)?;
+    Ok(wallet_amount)
+}
+
+// =============================================================================
+// CLAIM DEFENDER REWARD INTO ANOTHER SUBJECT'S STAKE
+// =============================================================================
+
+/// Claim a defender payout and stake it on another subject the defender created
+/// The pool-sourced share still returns to its pool; only the wallet share is rolled over
+#[derive(Accounts)]
+pub struct ClaimDefenderAndBond<'info> {
+    #[account(mut)]
+    pub defender: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA holds all funds
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        has_one = defender,
+        constraint = !defender_record.reward_claimed @ TribunalCraftError::RewardAlreadyClaimed,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), defender.key().as_ref()],
+        bump = defender_record.bump
+    )]
+    pub defender_record: Account<'info, DefenderRecord>,
+
+    /// Optional: the record's payout pool (required when pool-sourced stake returns to the pool)
+    #[account(
+        mut,
+        constraint = defender_pool.key() == defender_record.payout_pool @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub defender_pool: Option<Account<'info, DefenderPool>>,
+
+    /// CHECK: Delegate grant charged for the record's defend_from_pool stake
+    /// (required when defender_record.delegate_committed > 0; may already be removed)
+    #[account(
+        mut,
+        constraint = pool_delegate.key() == defender_record.pool_delegate @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub pool_delegate: Option<UncheckedAccount<'info>>,
+
+    /// Subject receiving the payout as stake (must be created by the defender)
+    #[account(
+        mut,
+        constraint = target_subject.key() != subject.key() @ TribunalCraftError::SameTargetSubject,
+        constraint = target_subject.creator == defender.key() @ TribunalCraftError::Unauthorized,
+        constraint = target_subject.can_stake() @ TribunalCraftError::SubjectCannotBeStaked,
+        constraint = !target_subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = !target_subject.free_case @ TribunalCraftError::FreeCaseMismatch,
+    )]
+    pub target_subject: Account<'info, Subject>,
+
+    #[account(
+        init_if_needed,
+        payer = defender,
+        space = DefenderRecord::LEN,
+        seeds = [DEFENDER_RECORD_SEED, target_subject.key().as_ref(), defender.key().as_ref()],
+        bump
+    )]
+    pub target_defender_record: Account<'info, DefenderRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// extra_stake: additional lamports from the defender's wallet staked alongside the payout
+pub fn claim_defender_and_bond(ctx: Context<ClaimDefenderAndBond>, extra_stake: u64) -> Result<()> {
+    let rolled = settle_defender_claim(
+        &ctx.accounts.subject,
+        &mut ctx.accounts.dispute,
+        &ctx.accounts.escrow,
+        &mut ctx.accounts.defender_record,
+        ctx.accounts.defender_pool.as_mut(),
+        &ctx.accounts.target_subject.to_account_info(),
+        false,
+    )?;
+    release_delegate_commitment(&mut ctx.accounts.defender_record, ctx.accounts.pool_delegate.as_ref())?;
+
+    if extra_stake > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.defender.to_account_info(),
+                to: ctx.accounts.target_subject.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, extra_stake)?;
+    }
+
+    let stake = rolled.checked_add(extra_stake).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    require!(stake > 0, TribunalCraftError::StakeBelowMinimum);
+
+    credit_direct_stake(
+        &mut ctx.accounts.target_subject,
+        &mut ctx.accounts.target_defender_record,
+        ctx.accounts.defender.key(),
+        ctx.bumps.target_defender_record,
+        stake,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    msg!("Rolled {} lamports (+{} from wallet) into subject {}", rolled, extra_stake, ctx.accounts.target_subject.key());
     Ok(())
 }
 
+// =============================================================================
+// CLAIM OPPOSITION REWARD (appeals, from escrow)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimOppositionReward<'info> {
+    #[account(mut)]
+    pub opposer: Signer<'info>,
+
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// Escrow PDA holds all funds
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
+    #[account(
+        mut,
+        has_one = dispute,
+        has_one = opposer,
+        constraint = !opposition_record.reward_claimed @ TribunalCraftError::RewardAlreadyClaimed,
+    )]
+    pub opposition_record: Account<'info, OppositionRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_opposition_reward(ctx: Context<ClaimOppositionReward>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let mut escrow_data = escrow.load_mut()?;
+    let opposition_record = &mut ctx.accounts.opposition_record;
+    let outcome = ctx.accounts.dispute.outcome;
+
+    let payout = opposition_payout(&ctx.accounts.subject, &ctx.accounts.dispute, &escrow_data, opposition_record)?;
+    let total_return = payout.total()?;
+    match outcome {
+        ResolutionOutcome::DefenderWins => msg!("Restoration rejected - opposer reward claimed: {} lamports", total_return),
+        ResolutionOutcome::ChallengerWins => msg!("Restoration succeeded - opposer loses stake"),
+        _ => msg!("No participation - stake returned: {} lamports", total_return),
+    }
+
+    if total_return > 0 {
+        transfer_lamports(&escrow.to_account_info(), &ctx.accounts.opposer.to_account_info(), total_return, LamportsMoveReason::DefenderClaim)?;
+    }
+    payout.record(&mut escrow_data)?;
+    invariants::check_escrow(&escrow.to_account_info(), &escrow_data);
+
+    // Opposers sit on the defender side: one defender-side claim each, whatever buckets paid them
+    opposition_record.reward_claimed = true;
+    escrow_data.count_defender_claim()?;
+    ctx.accounts.dispute.defenders_claimed = ctx.accounts.dispute.defenders_claimed.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    ctx.accounts.dispute.note_party_claim(&escrow_data, Clock::get()?.unix_timestamp);
+    sweep_dust(&mut ctx.accounts.dispute, &ctx.accounts.subject, &mut escrow_data)
+}
+
 // =============================================================================
 // CLOSE ESCROW (after all claims complete)
 // =============================================================================
@@ -521,8 +2094,11 @@ pub struct CloseEscrow<'info> {
     #[account(mut)]
     pub closer: Signer<'info>,
 
+    pub subject: Account<'info, Subject>,
+
     #[account(
-        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::DisputeNotFound,
+        has_one = subject,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::SweepTooEarly,
     )]
     pub dispute: Account<'info, Dispute>,
 
@@ -531,15 +2107,14 @@ pub struct CloseEscrow<'info> {
         mut,
         close = closer,
         seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
-        bump = escrow.bump,
-        constraint = escrow.all_claims_complete() @ TribunalCraftError::ClaimsNotComplete,
+        bump = escrow.load()?.bump,
+        constraint = escrow.load()?.all_claims_complete() @ TribunalCraftError::ClaimsNotComplete,
     )]
-    pub escrow: Account<'info, DisputeEscrow>,
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
 
     /// Protocol config for treasury
     #[account(
-        seeds = [PROTOCOL_CONFIG_SEED],
-        bump = protocol_config.bump
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
     )]
     pub protocol_config: Account<'info, ProtocolConfig>,
 
@@ -550,25 +2125,386 @@ pub struct CloseEscrow<'info> {
     )]
     pub treasury: AccountInfo<'info>,
 
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional: the subject's config's keeper expiry queue, kept current when passed
+    #[account(
+        mut,
+        seeds = [EXPIRY_QUEUE_SEED, subject.config.as_ref()],
+        bump = expiry_queue.bump
+    )]
+    pub expiry_queue: Option<Account<'info, ExpiryQueue>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn close_escrow(ctx: Context<CloseEscrow>) -> Result<()> {
     let escrow = &ctx.accounts.escrow;
 
+    let now = Clock::get()?.unix_timestamp;
+
+    // Don't sweep juror rewards or boost refunds that can still be claimed
+    require!(
+        !ctx.accounts.dispute.has_pending_juror_claims(ctx.accounts.subject.free_case, now),
+        TribunalCraftError::JurorClaimsPending
+    );
+    require!(!ctx.accounts.dispute.has_pending_boost_refunds(now), TribunalCraftError::BoostRefundsPending);
+
     // Calculate dust (any remaining balance after all claims)
     let rent = Rent::get()?.minimum_balance(DisputeEscrow::LEN);
     let current_balance = escrow.to_account_info().lamports();
     let dust = current_balance.saturating_sub(rent);
 
     if dust > 0 {
-        // Send dust to treasury before closing
-        **ctx.accounts.escrow.to_account_info().try_borrow_mut_lamports()? -= dust;
-        **ctx.accounts.treasury.try_borrow_mut_lamports()? += dust;
-        msg!("Dust sent to treasury: {} lamports", dust);
+        // Closer takes the age-scaled bounty, the rest goes to treasury before closing
+        let bounty = ctx.accounts.protocol_config.sweep_bounty(dust, ctx.accounts.dispute.sweep_age(now));
+        transfer_lamports(&escrow.to_account_info(), &ctx.accounts.closer.to_account_info(), bounty, LamportsMoveReason::SweepBounty)?;
+        let to_treasury = dust - bounty;
+        transfer_lamports(&escrow.to_account_info(), &ctx.accounts.treasury, to_treasury, LamportsMoveReason::EscrowDust)?;
+        Treasury::record_income(&ctx.accounts.treasury, to_treasury)?;
+        msg!("Dust swept: {} lamports bounty, {} lamports to treasury", bounty, to_treasury);
+    }
+
+    LifecycleLog {
+        kind: LifecycleKind::Swept,
+        subject: ctx.accounts.subject.key(),
+        dispute: ctx.accounts.dispute.key(),
+        status: ctx.accounts.subject.status,
+        outcome: ctx.accounts.dispute.outcome,
+        amount: dust,
+        timestamp: now,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
+    if let Some(queue) = ctx.accounts.expiry_queue.as_mut() {
+        queue.remove(&escrow.key(), now);
     }
 
     // Account closure handled by `close = closer` attribute
     msg!("Escrow closed, rent returned to closer");
     Ok(())
 }
+
+/// Drop queue entries whose escrows were closed without the queue (anyone)
+#[derive(Accounts)]
+pub struct PruneExpiryQueue<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [EXPIRY_QUEUE_SEED, expiry_queue.config.as_ref()],
+        bump = expiry_queue.bump
+    )]
+    pub expiry_queue: Account<'info, ExpiryQueue>,
+}
+
+/// remaining_accounts: escrow accounts listed in the queue; only closed (empty) ones are removed
+pub fn prune_expiry_queue<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneExpiryQueue<'info>>,
+) -> Result<()> {
+    let queue = &mut ctx.accounts.expiry_queue;
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut removed: u16 = 0;
+    for info in ctx.remaining_accounts {
+        if info.data_is_empty() && queue.remove(info.key, now) {
+            removed += 1;
+        }
+    }
+
+    msg!("Expiry queue pruned: {} removed, {} tracked", removed, queue.entries.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck::Zeroable;
+
+    /// Decided proportional round: 1000 bond vs 1000 stake, one claimant per side, two jurors
+    fn decided_round() -> (Subject, Dispute, DisputeEscrow) {
+        let subject = Subject { winner_share_bps: 8000, ..Default::default() };
+        let dispute = Dispute {
+            outcome: ResolutionOutcome::DefenderWins,
+            vote_count: 2,
+            ..Default::default()
+        };
+        let mut escrow = DisputeEscrow::zeroed();
+        escrow.total_bonds = 1000;
+        escrow.total_stakes = 1000;
+        escrow.expected_challengers = 1;
+        escrow.expected_defenders = 1;
+        (subject, dispute, escrow)
+    }
+
+    /// The winning defender's claim, floored 3 lamports short of the 1600 party allocation
+    fn claim_defender(dispute: &mut Dispute, escrow: &mut DisputeEscrow) {
+        ClaimPayout { from_bonds: 597, from_stakes: 1000 }.record(escrow).unwrap();
+        escrow.count_defender_claim().unwrap();
+        dispute.defenders_claimed = dispute.defenders_claimed.checked_add(1).unwrap();
+    }
+
+    fn claim_challenger(dispute: &mut Dispute, escrow: &mut DisputeEscrow) {
+        ClaimPayout::default().record(escrow).unwrap();
+        escrow.count_challenger_claim().unwrap();
+        dispute.challengers_claimed = dispute.challengers_claimed.checked_add(1).unwrap();
+    }
+
+    #[test]
+    fn sweep_waits_for_every_party() {
+        let (subject, mut dispute, mut escrow) = decided_round();
+        claim_defender(&mut dispute, &mut escrow);
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+
+        assert!(!dispute.dust_swept);
+        assert_eq!(dispute.juror_incentive, 0);
+        assert_eq!(escrow.juror_rewards_paid, 0);
+    }
+
+    #[test]
+    fn sweep_credits_residue_after_final_claim() {
+        let (subject, mut dispute, mut escrow) = decided_round();
+        claim_defender(&mut dispute, &mut escrow);
+        claim_challenger(&mut dispute, &mut escrow);
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+
+        assert!(dispute.dust_swept);
+        assert_eq!(dispute.dust_credited, 3);
+        assert_eq!(dispute.juror_incentive, 3);
+        assert_eq!(escrow.juror_rewards_paid, 3);
+    }
+
+    #[test]
+    fn residue_is_credited_exactly_once() {
+        let (subject, mut dispute, mut escrow) = decided_round();
+        claim_defender(&mut dispute, &mut escrow);
+        claim_challenger(&mut dispute, &mut escrow);
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+        // Every later claim path runs the sweep again
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+        dispute.jurors_claimed = 1;
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+
+        assert_eq!(dispute.dust_credited, 3);
+        assert_eq!(dispute.juror_incentive, 3);
+        assert_eq!(escrow.juror_rewards_paid, 3);
+    }
+
+    #[test]
+    fn zero_payout_claims_count_once() {
+        let (_, mut dispute, mut escrow) = decided_round();
+        claim_challenger(&mut dispute, &mut escrow);
+
+        assert_eq!(escrow.challengers_claimed, 1);
+        assert_eq!(escrow.defenders_claimed, 0);
+        assert_eq!(escrow.bonds_claimed + escrow.stakes_claimed, 0);
+        assert!(!escrow.all_claims_complete());
+    }
+
+    #[test]
+    fn sweep_skips_disagreeing_counters() {
+        let (subject, mut dispute, mut escrow) = decided_round();
+        claim_defender(&mut dispute, &mut escrow);
+        // Escrow says the challenger claimed, the dispute does not
+        escrow.count_challenger_claim().unwrap();
+        sweep_dust(&mut dispute, &subject, &mut escrow).unwrap();
+
+        assert!(!dispute.dust_swept);
+    }
+
+    /// Match-mode round: 1500 bond vs 1000 stake, so 500 of the bond is safe
+    fn match_round(outcome: ResolutionOutcome) -> (Subject, Dispute, DisputeEscrow) {
+        let subject = Subject { winner_share_bps: 8000, match_mode: true, ..Default::default() };
+        let dispute = Dispute { outcome, ..Default::default() };
+        let mut escrow = DisputeEscrow::zeroed();
+        escrow.total_bonds = 1500;
+        escrow.total_stakes = 1000;
+        (subject, dispute, escrow)
+    }
+
+    fn challenger(bond: u64) -> ChallengerRecord {
+        ChallengerRecord { bond, ..Default::default() }
+    }
+
+    fn defender(stake: u64) -> DefenderRecord {
+        DefenderRecord { stake, ..Default::default() }
+    }
+
+    #[test]
+    fn split_at_risk_floors_the_contested_share() {
+        assert_eq!(split_at_risk(300, 500, 1000), (150, 150));
+        assert_eq!(split_at_risk(100, 2, 3), (66, 34));
+        assert_eq!(split_at_risk(1, 1, 3), (0, 1));
+        // Nothing in escrow: everything is safe
+        assert_eq!(split_at_risk(50, 0, 0), (0, 50));
+    }
+
+    #[test]
+    fn match_mode_contests_only_the_matched_amount() {
+        let (subject, mut dispute, escrow) = match_round(ResolutionOutcome::ChallengerWins);
+        let pool = ContestedPool::for_dispute(&subject, &dispute, &escrow);
+        assert_eq!((pool.bonds, pool.stakes), (1000, 1000));
+        assert_eq!(pool.total().unwrap(), 2000);
+        assert_eq!(pool.split_bond(300), (200, 100));
+        assert_eq!(pool.split_stake(400), (400, 0));
+
+        // Appeals contest everything in escrow
+        dispute.is_appeal = true;
+        let pool = ContestedPool::for_dispute(&subject, &dispute, &escrow);
+        assert_eq!((pool.bonds, pool.stakes), (1500, 1000));
+        assert_eq!(pool.split_bond(300), (300, 0));
+    }
+
+    #[test]
+    fn proportional_mode_contests_everything() {
+        let (mut subject, dispute, escrow) = match_round(ResolutionOutcome::ChallengerWins);
+        subject.match_mode = false;
+        let pool = ContestedPool::for_dispute(&subject, &dispute, &escrow);
+        assert_eq!(pool.total().unwrap(), 2500);
+        assert_eq!(pool.split_bond(1500), (1500, 0));
+    }
+
+    #[test]
+    fn challenger_win_returns_safe_bond_fee_free() {
+        let (subject, dispute, escrow) = match_round(ResolutionOutcome::ChallengerWins);
+        let won = challenger_payout(&subject, &dispute, &escrow, &challenger(1500)).unwrap();
+        // 80% of the 1000 contested bond + the 500 safe bond, and 80% of the slashed stake
+        assert_eq!(won, ClaimPayout { from_bonds: 1300, from_stakes: 800 });
+
+        let lost = defender_payout(&subject, &dispute, &escrow, &defender(1000)).unwrap();
+        assert_eq!(lost, ClaimPayout::default());
+
+        // Fees are 20% of the 2000 contested, never of the safe bond
+        assert_eq!(2500 - won.total().unwrap() - lost.total().unwrap(), 400);
+    }
+
+    #[test]
+    fn defender_win_returns_only_the_safe_bond() {
+        let (subject, dispute, escrow) = match_round(ResolutionOutcome::DefenderWins);
+        let lost = challenger_payout(&subject, &dispute, &escrow, &challenger(1500)).unwrap();
+        assert_eq!(lost, ClaimPayout { from_bonds: 500, from_stakes: 0 });
+
+        let won = defender_payout(&subject, &dispute, &escrow, &defender(1000)).unwrap();
+        assert_eq!(won, ClaimPayout { from_bonds: 800, from_stakes: 800 });
+        assert_eq!(2500 - won.total().unwrap() - lost.total().unwrap(), 400);
+    }
+
+    #[test]
+    fn graded_win_slashes_the_median_grade() {
+        let (subject, mut dispute, escrow) = match_round(ResolutionOutcome::ChallengerWins);
+        dispute.graded = true;
+        // Median grade 2 of 4 slashes half the contested stake
+        dispute.grade_weights = [0, 0, 10, 0, 0];
+        assert_eq!(dispute.slash_bps(), 5000);
+
+        let won = challenger_payout(&subject, &dispute, &escrow, &challenger(1500)).unwrap();
+        assert_eq!(won, ClaimPayout { from_bonds: 1300, from_stakes: 400 });
+
+        // The defender keeps 80% of the unslashed half
+        let lost = defender_payout(&subject, &dispute, &escrow, &defender(1000)).unwrap();
+        assert_eq!(lost, ClaimPayout { from_bonds: 0, from_stakes: 400 });
+    }
+
+    #[test]
+    fn no_participation_refunds_in_full() {
+        let (subject, dispute, escrow) = match_round(ResolutionOutcome::NoParticipation);
+        let bond = challenger_payout(&subject, &dispute, &escrow, &challenger(1500)).unwrap();
+        let stake = defender_payout(&subject, &dispute, &escrow, &defender(1000)).unwrap();
+        assert_eq!(bond.total().unwrap() + stake.total().unwrap(), 2500);
+    }
+
+    #[test]
+    fn rounding_floors_every_claim_below_the_allocation() {
+        let subject = Subject { winner_share_bps: 8000, ..Default::default() };
+        let dispute = Dispute { outcome: ResolutionOutcome::ChallengerWins, ..Default::default() };
+        let mut escrow = DisputeEscrow::zeroed();
+        escrow.total_bonds = 3;
+        escrow.total_stakes = 7;
+
+        let small = challenger_payout(&subject, &dispute, &escrow, &challenger(1)).unwrap();
+        let large = challenger_payout(&subject, &dispute, &escrow, &challenger(2)).unwrap();
+        assert_eq!(small, ClaimPayout { from_bonds: 0, from_stakes: 1 });
+        assert_eq!(large, ClaimPayout { from_bonds: 1, from_stakes: 3 });
+
+        let paid = small.total().unwrap() + large.total().unwrap();
+        assert!(paid <= party_allocation(&subject, &dispute, &escrow).unwrap());
+    }
+
+    fn lamport_account<'a>(key: &'a Pubkey, lamports: &'a mut u64, data: &'a mut [u8]) -> AccountInfo<'a> {
+        AccountInfo::new(key, false, true, lamports, data, &crate::ID, false, 0)
+    }
+
+    #[test]
+    fn wallet_claim_slash_does_not_return_on_unregister() {
+        use crate::instructions::juror::burn_unregister_slash;
+
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), solana_program::incinerator::ID];
+        // 100 lamports of rent behind 400 of available stake
+        let (mut escrow_lamports, mut juror_lamports, mut wallet_lamports, mut burned) = (1000u64, 500u64, 0u64, 0u64);
+        let (mut d0, mut d1, mut d2, mut d3) = ([0u8; 0], [0u8; 0], [0u8; 0], [0u8; 0]);
+        let escrow = lamport_account(&keys[0], &mut escrow_lamports, &mut d0);
+        let juror_info = lamport_account(&keys[1], &mut juror_lamports, &mut d1);
+        let wallet = lamport_account(&keys[2], &mut wallet_lamports, &mut d2);
+        let incinerator = lamport_account(&keys[3], &mut burned, &mut d3);
+
+        // 25% reputation keeps half of anything paid out of the stake balance
+        let mut juror_account = JurorAccount { reputation: 2500, available_stake: 400, ..Default::default() };
+        pay_juror_reward(&escrow, &juror_info, &mut juror_account, Some(&wallet), &incinerator, 1000).unwrap();
+        assert_eq!((escrow.lamports(), wallet.lamports(), incinerator.lamports()), (0, 500, 500));
+        assert_eq!(juror_info.lamports(), 500);
+
+        // unregister_juror burns the stake slash, then the close hands the rest to the wallet
+        let (_, slash) = burn_unregister_slash(&juror_info, &juror_account, &incinerator).unwrap();
+        assert_eq!(slash, 200);
+        let closed = juror_info.lamports();
+        transfer_lamports(&juror_info, &wallet, closed, LamportsMoveReason::JurorWithdrawal).unwrap();
+
+        // Half the reward and half the stake, plus rent: the reward slash stayed burned
+        assert_eq!(wallet.lamports(), 500 + 200 + 100);
+        assert_eq!(incinerator.lamports(), 700);
+    }
+
+    #[test]
+    fn balance_claim_keeps_the_full_reward() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), solana_program::incinerator::ID];
+        let (mut escrow_lamports, mut juror_lamports, mut burned) = (1000u64, 100u64, 0u64);
+        let (mut d0, mut d1, mut d2) = ([0u8; 0], [0u8; 0], [0u8; 0]);
+        let escrow = lamport_account(&keys[0], &mut escrow_lamports, &mut d0);
+        let juror_info = lamport_account(&keys[1], &mut juror_lamports, &mut d1);
+        let incinerator = lamport_account(&keys[2], &mut burned, &mut d2);
+
+        let mut juror_account = JurorAccount { reputation: 2500, ..Default::default() };
+        pay_juror_reward(&escrow, &juror_info, &mut juror_account, None, &incinerator, 1000).unwrap();
+        assert_eq!((juror_info.lamports(), incinerator.lamports()), (1100, 0));
+        assert_eq!(juror_account.available_stake, 1000);
+    }
+
+    #[test]
+    fn beneficiary_claim_slash_is_burned() {
+        use crate::instructions::juror::burn_unregister_slash;
+
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), solana_program::incinerator::ID];
+        let (mut escrow_lamports, mut juror_lamports, mut wallet_lamports, mut beneficiary_lamports, mut burned) =
+            (1000u64, 100u64, 0u64, 0u64, 0u64);
+        let (mut d0, mut d1, mut d2, mut d3, mut d4) = ([0u8; 0], [0u8; 0], [0u8; 0], [0u8; 0], [0u8; 0]);
+        let escrow = lamport_account(&keys[0], &mut escrow_lamports, &mut d0);
+        let juror_info = lamport_account(&keys[1], &mut juror_lamports, &mut d1);
+        let wallet = lamport_account(&keys[2], &mut wallet_lamports, &mut d2);
+        let beneficiary = lamport_account(&keys[3], &mut beneficiary_lamports, &mut d3);
+        let incinerator = lamport_account(&keys[4], &mut burned, &mut d4);
+
+        let mut juror_account = JurorAccount { reputation: 1000, beneficiary: keys[3], ..Default::default() };
+        pay_juror_reward(&escrow, &juror_info, &mut juror_account, Some(&beneficiary), &incinerator, 1000).unwrap();
+        // 10% reputation keeps 20%
+        assert_eq!((beneficiary.lamports(), incinerator.lamports()), (200, 800));
+        assert_eq!(juror_account.available_stake, 0);
+
+        // Closing the account returns only its rent
+        burn_unregister_slash(&juror_info, &juror_account, &incinerator).unwrap();
+        let closed = juror_info.lamports();
+        transfer_lamports(&juror_info, &wallet, closed, LamportsMoveReason::JurorWithdrawal).unwrap();
+        assert_eq!(wallet.lamports(), 100);
+        assert_eq!(incinerator.lamports(), 800);
+    }
+}
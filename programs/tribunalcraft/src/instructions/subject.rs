@@ -1,11 +1,25 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::constants::{SUBJECT_SEED, DEFENDER_RECORD_SEED, DEFENDER_POOL_SEED};
+use crate::constants::{
+    SUBJECT_SEED, DEFENDER_RECORD_SEED, DEFENDER_POOL_SEED, CATEGORY_REGISTRY_SEED,
+    CREATOR_ALLOWLIST_SEED, JUROR_SHARE_BPS, WINNER_SHARE_BPS,
+    ACCOUNT_VERSION, SUBJECT_TOMBSTONE_SEED, DISPUTE_ESCROW_SEED, SUBJECT_CLOSE_GRACE,
+    SUBJECT_CLOSE_TIMEOUT, PROTOCOL_STATS_SEED, POOL_DELEGATE_SEED, SUBJECT_INDEX_SEED, MAX_BPS,
+    REVIVE_CRANK_FEE, MIN_REVIVE_BOND,
+};
 use crate::errors::TribunalCraftError;
+use crate::lifecycle_log::{LifecycleLog, LifecycleKind, NOOP_PROGRAM_ID};
+use crate::events::{SubjectClosedEvent, SubjectFreezeChangedEvent, LamportsMoveReason};
+use crate::utils::{transfer_lamports, validate_cid};
+use crate::invariants;
 
 /// Create a standalone subject (not linked to pool)
 #[derive(Accounts)]
-#[instruction(subject_id: Pubkey)]
+#[instruction(
+    subject_id: Pubkey, details_cid: String, max_stake: u64, match_mode: bool, free_case: bool,
+    voting_period: i64, stake: u64, category_id: u16, reward_split: Option<RewardSplit>,
+    rationale_required: bool, auto_revalidate: bool, index_page: u32,
+)]
 pub struct CreateSubject<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -28,6 +42,56 @@ pub struct CreateSubject<'info> {
     )]
     pub defender_record: Account<'info, DefenderRecord>,
 
+    /// Optional: category registry (required when category_id != 0)
+    #[account(
+        seeds = [CATEGORY_REGISTRY_SEED, protocol_config.key().as_ref()],
+        bump = category_registry.bump
+    )]
+    pub category_registry: Option<Account<'info, CategoryRegistry>>,
+
+    /// Protocol instance the subject is created under (creator gate, categories, economics)
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Optional: creator allowlist (required when the gate is enabled)
+    #[account(
+        seeds = [CREATOR_ALLOWLIST_SEED, protocol_config.key().as_ref()],
+        bump = creator_allowlist.bump
+    )]
+    pub creator_allowlist: Option<Account<'info, CreatorAllowlist>>,
+
+    /// CHECK: Tombstone PDA - must be empty (subject was never closed)
+    #[account(
+        seeds = [SUBJECT_TOMBSTONE_SEED, subject_id.as_ref()],
+        bump,
+        constraint = subject_tombstone.data_is_empty() @ TribunalCraftError::SubjectClosed,
+    )]
+    pub subject_tombstone: UncheckedAccount<'info>,
+
+    /// Creator's subject index page (first page that is not full)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = SubjectIndex::LEN,
+        seeds = [SUBJECT_INDEX_SEED, creator.key().as_ref(), &index_page.to_le_bytes()],
+        bump
+    )]
+    pub subject_index: Account<'info, SubjectIndex>,
+
+    /// Creator's previous index page (required when index_page opens a new page past 0)
+    pub previous_index_page: Option<Account<'info, SubjectIndex>>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,6 +104,11 @@ pub fn create_subject(
     free_case: bool,
     voting_period: i64,
     stake: u64,
+    category_id: u16,
+    reward_split: Option<RewardSplit>,
+    rationale_required: bool,
+    auto_revalidate: bool,
+    index_page: u32,
 ) -> Result<()> {
     let subject = &mut ctx.accounts.subject;
     let defender_record = &mut ctx.accounts.defender_record;
@@ -49,7 +118,15 @@ pub fn create_subject(
     if !free_case {
         require!(stake > 0, TribunalCraftError::StakeBelowMinimum);
     }
-    require!(voting_period > 0, TribunalCraftError::InvalidConfig);
+    require!(voting_period > 0, TribunalCraftError::InvalidVotingPeriod);
+    validate_cid(&details_cid, Subject::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    validate_category(ctx.accounts.category_registry.as_deref(), category_id)?;
+    check_creator_gate(
+        &ctx.accounts.protocol_config,
+        ctx.accounts.creator_allowlist.as_ref(),
+        &ctx.accounts.creator.key(),
+    )?;
+    let reward_split = resolve_reward_split(&ctx.accounts.protocol_config, reward_split)?;
 
     // Transfer stake to subject account (if any)
     if stake > 0 {
@@ -65,8 +142,11 @@ pub fn create_subject(
 
     // Initialize subject (standalone mode)
     subject.subject_id = subject_id;
+    subject.creator = ctx.accounts.creator.key();
+    subject.config = ctx.accounts.protocol_config.key();
     subject.defender_pool = Pubkey::default(); // standalone
     subject.details_cid = details_cid;
+    subject.category_id = category_id;
     subject.status = SubjectStatus::Active;
     subject.total_stake = stake;
     subject.max_stake = max_stake;
@@ -75,8 +155,15 @@ pub fn create_subject(
     subject.dispute_count = 0;
     subject.match_mode = match_mode;
     subject.free_case = free_case;
+    subject.juror_share_bps = reward_split.juror_share_bps;
+    subject.winner_share_bps = reward_split.winner_share_bps;
+    subject.rationale_required = rationale_required;
+    subject.auto_revalidate = auto_revalidate;
     subject.dispute = Pubkey::default();
+    subject.dispute_cooldown = ctx.accounts.protocol_config.default_dispute_cooldown;
+    subject.cooldown_ends_at = 0;
     subject.bump = ctx.bumps.subject;
+    subject.version = ACCOUNT_VERSION;
     subject.created_at = clock.unix_timestamp;
     subject.updated_at = clock.unix_timestamp;
 
@@ -84,17 +171,43 @@ pub fn create_subject(
     defender_record.subject = subject.key();
     defender_record.defender = ctx.accounts.creator.key();
     defender_record.stake = stake;
+    defender_record.pool_stake = 0;
     defender_record.reward_claimed = false;
     defender_record.bump = ctx.bumps.defender_record;
+    defender_record.version = ACCOUNT_VERSION;
     defender_record.staked_at = clock.unix_timestamp;
 
+    ctx.accounts.subject_index.append(
+        ctx.accounts.creator.key(),
+        index_page,
+        ctx.bumps.subject_index,
+        subject_id,
+        ctx.accounts.previous_index_page.as_deref(),
+    )?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_subject(clock.unix_timestamp);
+    }
+    LifecycleLog {
+        kind: LifecycleKind::Created,
+        subject: ctx.accounts.subject.key(),
+        dispute: Pubkey::default(),
+        status: ctx.accounts.subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: stake,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
     msg!("Subject created: {} (free_case: {})", subject_id, free_case);
     Ok(())
 }
 
 /// Create a subject linked to a staker pool
 #[derive(Accounts)]
-#[instruction(subject_id: Pubkey)]
+#[instruction(
+    subject_id: Pubkey, details_cid: String, max_stake: u64, match_mode: bool, free_case: bool,
+    voting_period: i64, category_id: u16, reward_split: Option<RewardSplit>,
+    rationale_required: bool, auto_revalidate: bool, index_page: u32,
+)]
 pub struct CreateLinkedSubject<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -116,6 +229,66 @@ pub struct CreateLinkedSubject<'info> {
     )]
     pub subject: Account<'info, Subject>,
 
+    /// Pool owner's record - receives pool_stake when disputes pull from the pool
+    #[account(
+        init,
+        payer = owner,
+        space = DefenderRecord::LEN,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub defender_record: Account<'info, DefenderRecord>,
+
+    /// Optional: category registry (required when category_id != 0)
+    #[account(
+        seeds = [CATEGORY_REGISTRY_SEED, protocol_config.key().as_ref()],
+        bump = category_registry.bump
+    )]
+    pub category_registry: Option<Account<'info, CategoryRegistry>>,
+
+    /// Protocol instance the subject is created under (creator gate, categories, economics)
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Optional: creator allowlist (required when the gate is enabled)
+    #[account(
+        seeds = [CREATOR_ALLOWLIST_SEED, protocol_config.key().as_ref()],
+        bump = creator_allowlist.bump
+    )]
+    pub creator_allowlist: Option<Account<'info, CreatorAllowlist>>,
+
+    /// CHECK: Tombstone PDA - must be empty (subject was never closed)
+    #[account(
+        seeds = [SUBJECT_TOMBSTONE_SEED, subject_id.as_ref()],
+        bump,
+        constraint = subject_tombstone.data_is_empty() @ TribunalCraftError::SubjectClosed,
+    )]
+    pub subject_tombstone: UncheckedAccount<'info>,
+
+    /// Creator's subject index page (first page that is not full)
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = SubjectIndex::LEN,
+        seeds = [SUBJECT_INDEX_SEED, owner.key().as_ref(), &index_page.to_le_bytes()],
+        bump
+    )]
+    pub subject_index: Account<'info, SubjectIndex>,
+
+    /// Creator's previous index page (required when index_page opens a new page past 0)
+    pub previous_index_page: Option<Account<'info, SubjectIndex>>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -127,12 +300,26 @@ pub fn create_linked_subject(
     match_mode: bool,
     free_case: bool,
     voting_period: i64,
+    category_id: u16,
+    reward_split: Option<RewardSplit>,
+    rationale_required: bool,
+    auto_revalidate: bool,
+    index_page: u32,
 ) -> Result<()> {
     let defender_pool = &mut ctx.accounts.defender_pool;
     let subject = &mut ctx.accounts.subject;
+    let defender_record = &mut ctx.accounts.defender_record;
     let clock = Clock::get()?;
 
-    require!(voting_period > 0, TribunalCraftError::InvalidConfig);
+    require!(voting_period > 0, TribunalCraftError::InvalidVotingPeriod);
+    validate_cid(&details_cid, Subject::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    validate_category(ctx.accounts.category_registry.as_deref(), category_id)?;
+    check_creator_gate(
+        &ctx.accounts.protocol_config,
+        ctx.accounts.creator_allowlist.as_ref(),
+        &ctx.accounts.owner.key(),
+    )?;
+    let reward_split = resolve_reward_split(&ctx.accounts.protocol_config, reward_split)?;
 
     // Note: max_stake is a risk cap per subject, not a reservation
     // No need to check pool.available >= max_stake here
@@ -140,8 +327,11 @@ pub fn create_linked_subject(
 
     // Initialize subject (linked mode)
     subject.subject_id = subject_id;
+    subject.creator = ctx.accounts.owner.key();
+    subject.config = ctx.accounts.protocol_config.key();
     subject.defender_pool = defender_pool.key(); // linked
     subject.details_cid = details_cid;
+    subject.category_id = category_id;
     subject.status = SubjectStatus::Active;
     subject.total_stake = 0; // can be added by direct stakers
     subject.max_stake = max_stake;
@@ -150,22 +340,63 @@ pub fn create_linked_subject(
     subject.dispute_count = 0;
     subject.match_mode = match_mode;
     subject.free_case = free_case;
+    subject.juror_share_bps = reward_split.juror_share_bps;
+    subject.winner_share_bps = reward_split.winner_share_bps;
+    subject.rationale_required = rationale_required;
+    subject.auto_revalidate = auto_revalidate;
     subject.dispute = Pubkey::default();
+    subject.dispute_cooldown = ctx.accounts.protocol_config.default_dispute_cooldown;
+    subject.cooldown_ends_at = 0;
     subject.bump = ctx.bumps.subject;
+    subject.version = ACCOUNT_VERSION;
     subject.created_at = clock.unix_timestamp;
     subject.updated_at = clock.unix_timestamp;
 
+    // Initialize pool owner's record (no direct stake; pool_stake set at dispute time)
+    defender_record.subject = subject.key();
+    defender_record.defender = ctx.accounts.owner.key();
+    defender_record.stake = 0;
+    defender_record.pool_stake = 0;
+    defender_record.payout_pool = defender_pool.key();
+    defender_record.reward_claimed = false;
+    defender_record.bump = ctx.bumps.defender_record;
+    defender_record.version = ACCOUNT_VERSION;
+    defender_record.staked_at = 0; // Set when the owner first stakes directly (counts as a defender)
+
     // Update pool
-    defender_pool.subject_count += 1;
+    defender_pool.subject_count = defender_pool.subject_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     defender_pool.updated_at = clock.unix_timestamp;
 
+    ctx.accounts.subject_index.append(
+        ctx.accounts.owner.key(),
+        index_page,
+        ctx.bumps.subject_index,
+        subject_id,
+        ctx.accounts.previous_index_page.as_deref(),
+    )?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_subject(clock.unix_timestamp);
+    }
+    LifecycleLog {
+        kind: LifecycleKind::Created,
+        subject: ctx.accounts.subject.key(),
+        dispute: Pubkey::default(),
+        status: ctx.accounts.subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: 0,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
     msg!("Linked subject created: {} (free_case: {})", subject_id, free_case);
     Ok(())
 }
 
 /// Create a free subject (no stake, no staker record - just Subject)
 #[derive(Accounts)]
-#[instruction(subject_id: Pubkey)]
+#[instruction(
+    subject_id: Pubkey, details_cid: String, voting_period: i64, category_id: u16, index_page: u32,
+)]
 pub struct CreateFreeSubject<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -179,6 +410,56 @@ pub struct CreateFreeSubject<'info> {
     )]
     pub subject: Account<'info, Subject>,
 
+    /// Optional: category registry (required when category_id != 0)
+    #[account(
+        seeds = [CATEGORY_REGISTRY_SEED, protocol_config.key().as_ref()],
+        bump = category_registry.bump
+    )]
+    pub category_registry: Option<Account<'info, CategoryRegistry>>,
+
+    /// Protocol instance the subject is created under (creator gate, categories, economics)
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// Optional: creator allowlist (required when the gate is enabled)
+    #[account(
+        seeds = [CREATOR_ALLOWLIST_SEED, protocol_config.key().as_ref()],
+        bump = creator_allowlist.bump
+    )]
+    pub creator_allowlist: Option<Account<'info, CreatorAllowlist>>,
+
+    /// CHECK: Tombstone PDA - must be empty (subject was never closed)
+    #[account(
+        seeds = [SUBJECT_TOMBSTONE_SEED, subject_id.as_ref()],
+        bump,
+        constraint = subject_tombstone.data_is_empty() @ TribunalCraftError::SubjectClosed,
+    )]
+    pub subject_tombstone: UncheckedAccount<'info>,
+
+    /// Creator's subject index page (first page that is not full)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = SubjectIndex::LEN,
+        seeds = [SUBJECT_INDEX_SEED, creator.key().as_ref(), &index_page.to_le_bytes()],
+        bump
+    )]
+    pub subject_index: Account<'info, SubjectIndex>,
+
+    /// Creator's previous index page (required when index_page opens a new page past 0)
+    pub previous_index_page: Option<Account<'info, SubjectIndex>>,
+
+    /// Optional: protocol-wide statistics, updated when passed (see ProtocolStats)
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATS_SEED],
+        bump = protocol_stats.bump
+    )]
+    pub protocol_stats: Option<Account<'info, ProtocolStats>>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -187,16 +468,28 @@ pub fn create_free_subject(
     subject_id: Pubkey,
     details_cid: String,
     voting_period: i64,
+    category_id: u16,
+    index_page: u32,
 ) -> Result<()> {
     let subject = &mut ctx.accounts.subject;
     let clock = Clock::get()?;
 
-    require!(voting_period > 0, TribunalCraftError::InvalidConfig);
+    require!(voting_period > 0, TribunalCraftError::InvalidVotingPeriod);
+    validate_cid(&details_cid, Subject::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    validate_category(ctx.accounts.category_registry.as_deref(), category_id)?;
+    check_creator_gate(
+        &ctx.accounts.protocol_config,
+        ctx.accounts.creator_allowlist.as_ref(),
+        &ctx.accounts.creator.key(),
+    )?;
 
     // Initialize free subject (no stake, no records)
     subject.subject_id = subject_id;
+    subject.creator = ctx.accounts.creator.key();
+    subject.config = ctx.accounts.protocol_config.key();
     subject.defender_pool = Pubkey::default();
     subject.details_cid = details_cid;
+    subject.category_id = category_id;
     subject.status = SubjectStatus::Active;
     subject.total_stake = 0;
     subject.max_stake = 0;
@@ -205,15 +498,347 @@ pub fn create_free_subject(
     subject.dispute_count = 0;
     subject.match_mode = false;
     subject.free_case = true;
+    subject.juror_share_bps = JUROR_SHARE_BPS;
+    subject.winner_share_bps = WINNER_SHARE_BPS;
+    subject.rationale_required = false;
+    subject.auto_revalidate = true;
     subject.dispute = Pubkey::default();
+    subject.dispute_cooldown = ctx.accounts.protocol_config.default_dispute_cooldown;
+    subject.cooldown_ends_at = 0;
     subject.bump = ctx.bumps.subject;
+    subject.version = ACCOUNT_VERSION;
     subject.created_at = clock.unix_timestamp;
     subject.updated_at = clock.unix_timestamp;
 
+    ctx.accounts.subject_index.append(
+        ctx.accounts.creator.key(),
+        index_page,
+        ctx.bumps.subject_index,
+        subject_id,
+        ctx.accounts.previous_index_page.as_deref(),
+    )?;
+    if let Some(stats) = ctx.accounts.protocol_stats.as_mut() {
+        stats.record_subject(clock.unix_timestamp);
+    }
+    LifecycleLog {
+        kind: LifecycleKind::Created,
+        subject: ctx.accounts.subject.key(),
+        dispute: Pubkey::default(),
+        status: ctx.accounts.subject.status,
+        outcome: ResolutionOutcome::None,
+        amount: 0,
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
     msg!("Free subject created: {}", subject_id);
     Ok(())
 }
 
+/// Link a standalone subject to a defender pool (subject creator and pool authority both sign)
+/// The pool can belong to someone else, e.g. a shared team pool; disputes then pull from it
+/// The authority is the pool owner or a delegate, whose cap is charged the subject's max_stake
+#[derive(Accounts)]
+pub struct LinkDefenderPool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Pool owner or delegate consenting to back the subject
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+        constraint = !subject.is_linked() @ TribunalCraftError::SubjectAlreadyLinked,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch, // Free subjects hold no stake
+        constraint = subject.can_stake() @ TribunalCraftError::SubjectCannotBeStaked,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        seeds = [DEFENDER_POOL_SEED, defender_pool.owner.as_ref()],
+        bump = defender_pool.bump
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    /// Optional: authority's delegate grant (required unless the authority is the pool owner)
+    #[account(
+        mut,
+        seeds = [POOL_DELEGATE_SEED, defender_pool.key().as_ref(), authority.key().as_ref()],
+        bump = pool_delegate.bump
+    )]
+    pub pool_delegate: Option<Account<'info, PoolDelegate>>,
+
+    /// Pool owner's record - receives pool_stake when disputes pull from the pool
+    /// May already exist if the owner staked on the subject directly
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = DefenderRecord::LEN,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), defender_pool.owner.as_ref()],
+        bump
+    )]
+    pub defender_record: Account<'info, DefenderRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn link_defender_pool(ctx: Context<LinkDefenderPool>) -> Result<()> {
+    let defender_pool = &mut ctx.accounts.defender_pool;
+    let subject = &mut ctx.accounts.subject;
+    let defender_record = &mut ctx.accounts.defender_record;
+    let clock = Clock::get()?;
+
+    if ctx.accounts.authority.key() != defender_pool.owner {
+        let pool_delegate = ctx.accounts.pool_delegate.as_mut()
+            .ok_or(TribunalCraftError::Unauthorized)?;
+        pool_delegate.commit(subject.max_stake)?;
+    }
+
+    // Initialize pool owner's record if new (no direct stake; pool_stake set at dispute time)
+    if defender_record.subject == Pubkey::default() {
+        defender_record.subject = subject.key();
+        defender_record.defender = defender_pool.owner;
+        defender_record.stake = 0;
+        defender_record.pool_stake = 0;
+        defender_record.reward_claimed = false;
+        defender_record.bump = ctx.bumps.defender_record;
+        defender_record.version = ACCOUNT_VERSION;
+        defender_record.staked_at = 0;
+    }
+    // Pool stake pulled for this subject's disputes is paid back to the linked pool
+    defender_record.payout_pool = defender_pool.key();
+
+    subject.defender_pool = defender_pool.key();
+    subject.updated_at = clock.unix_timestamp;
+
+    defender_pool.subject_count = defender_pool.subject_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.updated_at = clock.unix_timestamp;
+
+    msg!("Subject linked to defender pool: {}", defender_pool.key());
+    Ok(())
+}
+
+/// Set the pledged bond total that activates a pledged dispute (creator only)
+/// Open pledge pools keep the threshold they snapshotted; 0 disables pledging
+#[derive(Accounts)]
+pub struct SetPledgeThreshold<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+    )]
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn set_pledge_threshold(ctx: Context<SetPledgeThreshold>, threshold: u64) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    subject.pledge_threshold = threshold;
+    subject.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Pledge threshold set: {} lamports", threshold);
+    Ok(())
+}
+
+/// Choose how tied votes resolve (creator only, not while a dispute is open)
+#[derive(Accounts)]
+pub struct SetTiePolicy<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+    )]
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn set_tie_policy(ctx: Context<SetTiePolicy>, tie_policy: TiePolicy, tie_extension: i64) -> Result<()> {
+    if tie_policy == TiePolicy::ExtendVoting {
+        require!(tie_extension > 0, TribunalCraftError::InvalidTieExtension);
+    }
+
+    let subject = &mut ctx.accounts.subject;
+    subject.tie_policy = tie_policy;
+    subject.tie_extension = if tie_policy == TiePolicy::ExtendVoting { tie_extension } else { 0 };
+    subject.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Tie policy set (extension: {} seconds)", subject.tie_extension);
+    Ok(())
+}
+
+/// Cap how much of the total voting weight a single juror's vote counts for (creator only)
+/// Not while a dispute is open; 0 removes the cap
+#[derive(Accounts)]
+pub struct SetJurorWeightCap<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+    )]
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn set_juror_weight_cap(ctx: Context<SetJurorWeightCap>, max_juror_weight_bps: u16) -> Result<()> {
+    require!(max_juror_weight_bps <= MAX_BPS, TribunalCraftError::InvalidConfig);
+
+    let subject = &mut ctx.accounts.subject;
+    // The graded median is taken over raw voting power
+    require!(max_juror_weight_bps == 0 || !subject.graded_verdicts, TribunalCraftError::WeightCapConflict);
+    subject.max_juror_weight_bps = max_juror_weight_bps;
+    subject.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Juror weight cap set: {} bps", max_juror_weight_bps);
+    Ok(())
+}
+
+/// Decide disputes by graded votes instead of a binary verdict (creator only)
+/// Jurors vote a 0-100% slash and the stake-weighted median is applied to the defender stake
+/// Not while a dispute is open; not combined with a juror weight cap
+#[derive(Accounts)]
+pub struct SetGradedVerdicts<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+        constraint = !subject.has_active_dispute() @ TribunalCraftError::DisputeAlreadyExists,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch, // Nothing to slash
+    )]
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn set_graded_verdicts(ctx: Context<SetGradedVerdicts>, enabled: bool) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    require!(!enabled || subject.max_juror_weight_bps == 0, TribunalCraftError::WeightCapConflict);
+
+    subject.graded_verdicts = enabled;
+    subject.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Graded verdicts: {}", enabled);
+    Ok(())
+}
+
+/// Choose whether a defender win returns the subject to Active or Dormant (creator only)
+#[derive(Accounts)]
+pub struct SetAutoRevalidate<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ TribunalCraftError::Unauthorized,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch, // Free subjects can't be restaked
+    )]
+    pub subject: Account<'info, Subject>,
+}
+
+pub fn set_auto_revalidate(ctx: Context<SetAutoRevalidate>, auto_revalidate: bool) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    subject.auto_revalidate = auto_revalidate;
+    subject.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Auto revalidate set: {}", auto_revalidate);
+    Ok(())
+}
+
+/// Freeze a subject for a legal hold (creator or config authority)
+/// Blocks new disputes, stakes, bonds and restorations without deciding validity;
+/// a dispute already open runs to completion. A creator's hold lapses after
+/// MAX_CREATOR_FREEZE and can't be renewed until CREATOR_FREEZE_COOLDOWN has passed
+#[derive(Accounts)]
+pub struct FreezeSubject<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn freeze_subject(ctx: Context<FreezeSubject>) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    let signer = ctx.accounts.signer.key();
+    let authority = ctx.accounts.protocol_config.authority;
+    let clock = Clock::get()?;
+
+    require!(signer == subject.creator || signer == authority, TribunalCraftError::Unauthorized);
+    if signer != authority {
+        require!(subject.creator_can_freeze(clock.unix_timestamp), TribunalCraftError::CreatorFreezeCooldown);
+    }
+
+    subject.frozen_by = signer;
+    subject.frozen_at = clock.unix_timestamp;
+    subject.updated_at = clock.unix_timestamp;
+
+    emit!(SubjectFreezeChangedEvent {
+        subject: subject.key(),
+        by: signer,
+        frozen: true,
+        has_active_dispute: subject.has_active_dispute(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Subject frozen by {}", signer);
+    Ok(())
+}
+
+/// Lift a freeze (config authority, or the creator if they froze it)
+#[derive(Accounts)]
+pub struct UnfreezeSubject<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectNotFrozen,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn unfreeze_subject(ctx: Context<UnfreezeSubject>) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    let signer = ctx.accounts.signer.key();
+    let authority = ctx.accounts.protocol_config.authority;
+    let clock = Clock::get()?;
+
+    // A hold placed by the authority can't be lifted by the creator
+    require!(
+        signer == authority || (signer == subject.creator && subject.frozen_by == subject.creator),
+        TribunalCraftError::Unauthorized
+    );
+
+    // frozen_at stays as the last freeze start for the creator cooldown
+    subject.frozen_by = Pubkey::default();
+    subject.updated_at = clock.unix_timestamp;
+
+    emit!(SubjectFreezeChangedEvent {
+        subject: subject.key(),
+        by: signer,
+        frozen: false,
+        has_active_dispute: subject.has_active_dispute(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Subject unfrozen by {}", signer);
+    Ok(())
+}
+
 /// Add stake to a standalone subject (or add more if already staked)
 #[derive(Accounts)]
 pub struct AddToStake<'info> {
@@ -223,7 +848,8 @@ pub struct AddToStake<'info> {
     #[account(
         mut,
         constraint = subject.can_stake() @ TribunalCraftError::SubjectCannotBeStaked,
-        constraint = !subject.free_case @ TribunalCraftError::InvalidConfig, // Free subjects don't accept stake
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = !subject.free_case @ TribunalCraftError::FreeCaseMismatch, // Free subjects don't accept stake
     )]
     pub subject: Account<'info, Subject>,
 
@@ -240,10 +866,6 @@ pub struct AddToStake<'info> {
 }
 
 pub fn add_to_stake(ctx: Context<AddToStake>, stake: u64) -> Result<()> {
-    let subject = &mut ctx.accounts.subject;
-    let defender_record = &mut ctx.accounts.defender_record;
-    let clock = Clock::get()?;
-
     require!(stake > 0, TribunalCraftError::StakeBelowMinimum);
 
     // Transfer stake to subject account
@@ -251,14 +873,40 @@ pub fn add_to_stake(ctx: Context<AddToStake>, stake: u64) -> Result<()> {
         ctx.accounts.system_program.to_account_info(),
         anchor_lang::system_program::Transfer {
             from: ctx.accounts.staker.to_account_info(),
-            to: subject.to_account_info(),
+            to: ctx.accounts.subject.to_account_info(),
         },
     );
     anchor_lang::system_program::transfer(cpi_context, stake)?;
 
-    // Update subject
-    subject.total_stake += stake;
-    subject.updated_at = clock.unix_timestamp;
+    credit_direct_stake(
+        &mut ctx.accounts.subject,
+        &mut ctx.accounts.defender_record,
+        ctx.accounts.staker.key(),
+        ctx.bumps.defender_record,
+        stake,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Book stake that has already landed in the subject account to the staker's record
+/// Shared by add_to_stake and claim_defender_and_bond
+pub(crate) fn credit_direct_stake(
+    subject: &mut Account<Subject>,
+    defender_record: &mut Account<DefenderRecord>,
+    staker: Pubkey,
+    record_bump: u8,
+    stake: u64,
+    now: i64,
+) -> Result<()> {
+    // Update subject (fresh stake reactivates a dormant subject)
+    subject.total_stake = subject.total_stake.checked_add(stake)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    if subject.status == SubjectStatus::Dormant {
+        subject.status = SubjectStatus::Active;
+        msg!("Dormant subject reactivated");
+    }
+    subject.updated_at = now;
+    invariants::check_subject(&subject.to_account_info(), subject);
 
     // Check if this is a new staker or adding more to existing
     let is_new_staker = defender_record.staked_at == 0;
@@ -266,19 +914,297 @@ pub fn add_to_stake(ctx: Context<AddToStake>, stake: u64) -> Result<()> {
     if is_new_staker {
         // Initialize new staker record
         defender_record.subject = subject.key();
-        defender_record.defender = ctx.accounts.staker.key();
+        defender_record.defender = staker;
         defender_record.stake = stake;
         defender_record.reward_claimed = false;
-        defender_record.bump = ctx.bumps.defender_record;
-        defender_record.staked_at = clock.unix_timestamp;
+        defender_record.bump = record_bump;
+        defender_record.version = ACCOUNT_VERSION;
+        defender_record.staked_at = now;
 
-        subject.defender_count += 1;
+        subject.defender_count = subject.defender_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
         msg!("New staker added: {} lamports", stake);
     } else {
         // Add to existing stake (don't increment staker_count)
-        defender_record.stake += stake;
+        defender_record.stake = defender_record.stake.checked_add(stake)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
         msg!("Added to existing stake: {} lamports (total: {})", stake, defender_record.stake);
     }
 
     Ok(())
 }
+
+/// Reactivate a dormant linked subject whose creator has opted back into revalidation (anyone)
+/// Pulls min(max_stake, pool available) from the pool as the owner's direct stake; the
+/// cranker is paid REVIVE_CRANK_FEE (plus the record rent if it fronted it) out of the pool
+#[derive(Accounts)]
+pub struct ReviveSubject<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = subject.status == SubjectStatus::Dormant @ TribunalCraftError::SubjectNotDormant,
+        constraint = !subject.is_frozen(Clock::get()?.unix_timestamp) @ TribunalCraftError::SubjectFrozen,
+        constraint = subject.is_linked() @ TribunalCraftError::SubjectNotLinked,
+        constraint = subject.auto_revalidate @ TribunalCraftError::RevivalNotOptedIn,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        constraint = defender_pool.key() == subject.defender_pool @ TribunalCraftError::PoolAccountMismatch,
+    )]
+    pub defender_pool: Account<'info, DefenderPool>,
+
+    /// Pool owner's stake record on the subject (receives the revival bond)
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = DefenderRecord::LEN,
+        seeds = [DEFENDER_RECORD_SEED, subject.key().as_ref(), defender_pool.owner.as_ref()],
+        bump
+    )]
+    pub defender_record: Account<'info, DefenderRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revive_subject(ctx: Context<ReviveSubject>) -> Result<()> {
+    let subject = &mut ctx.accounts.subject;
+    let defender_pool = &mut ctx.accounts.defender_pool;
+    let now = Clock::get()?.unix_timestamp;
+
+    // A cranker creating the owner's record is refunded its rent along with the fee
+    let fee = if ctx.accounts.defender_record.staked_at == 0 {
+        REVIVE_CRANK_FEE.checked_add(Rent::get()?.minimum_balance(DefenderRecord::LEN))
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?
+    } else {
+        REVIVE_CRANK_FEE
+    };
+    let bond = defender_pool.available.saturating_sub(fee).min(subject.max_stake);
+    require!(bond >= MIN_REVIVE_BOND, TribunalCraftError::RevivalBondTooLow);
+
+    transfer_lamports(
+        &defender_pool.to_account_info(),
+        &ctx.accounts.cranker.to_account_info(),
+        fee,
+        LamportsMoveReason::ReviveFee,
+    )?;
+    transfer_lamports(
+        &defender_pool.to_account_info(),
+        &subject.to_account_info(),
+        bond,
+        LamportsMoveReason::ReviveBond,
+    )?;
+    let pulled = fee + bond;
+    defender_pool.total_stake = defender_pool.total_stake.checked_sub(pulled)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    defender_pool.available -= pulled;
+    defender_pool.updated_at = now;
+    invariants::check_defender_pool(&defender_pool.to_account_info(), defender_pool);
+
+    // Books the bond to the pool owner and flips the subject back to Active
+    let owner = defender_pool.owner;
+    credit_direct_stake(
+        subject,
+        &mut ctx.accounts.defender_record,
+        owner,
+        ctx.bumps.defender_record,
+        bond,
+        now,
+    )?;
+
+    msg!("Dormant subject revived: {} lamports bonded from the pool (max {})", bond, subject.max_stake);
+    Ok(())
+}
+
+/// Close a terminally invalidated subject with its last dispute and escrow
+/// Creator may close after SUBJECT_CLOSE_GRACE, anyone after SUBJECT_CLOSE_TIMEOUT
+/// (measured from resolution). Rent goes to the creator; a tombstone blocks re-creation.
+#[derive(Accounts)]
+pub struct CloseSubject<'info> {
+    #[account(mut)]
+    pub closer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        constraint = subject.status == SubjectStatus::Invalidated @ TribunalCraftError::SubjectCannotBeClosed,
+    )]
+    pub subject: Account<'info, Subject>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        has_one = subject,
+        constraint = dispute.key() == subject.dispute @ TribunalCraftError::SubjectCannotBeClosed,
+        constraint = dispute.status == DisputeStatus::Resolved @ TribunalCraftError::SubjectCannotBeClosed,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    /// CHECK: The dispute's escrow PDA - closed here, or already empty after close_escrow
+    #[account(
+        mut,
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = closer,
+        space = SubjectTombstone::LEN,
+        seeds = [SUBJECT_TOMBSTONE_SEED, subject.subject_id.as_ref()],
+        bump
+    )]
+    pub subject_tombstone: Account<'info, SubjectTombstone>,
+
+    /// CHECK: Receives rent - the subject creator (closer for subjects created before creator tracking)
+    #[account(
+        mut,
+        constraint = rent_recipient.key() == subject.rent_recipient(&closer.key()) @ TribunalCraftError::Unauthorized,
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Protocol config for treasury address
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: Treasury receives unclaimed escrow balance
+    #[account(
+        mut,
+        constraint = treasury.key() == protocol_config.treasury @ TribunalCraftError::InvalidConfig,
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    /// CHECK: Optional SPL noop program for compact lifecycle records (see lifecycle_log)
+    #[account(address = NOOP_PROGRAM_ID @ TribunalCraftError::InvalidConfig)]
+    pub noop_program: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_subject(ctx: Context<CloseSubject>) -> Result<()> {
+    let subject = &ctx.accounts.subject;
+    let dispute = &ctx.accounts.dispute;
+    let closer = ctx.accounts.closer.key();
+    let clock = Clock::get()?;
+
+    let is_creator = subject.creator != Pubkey::default() && closer == subject.creator;
+    let wait = if is_creator { SUBJECT_CLOSE_GRACE } else { SUBJECT_CLOSE_TIMEOUT };
+    require!(
+        clock.unix_timestamp.saturating_sub(dispute.resolved_at) >= wait,
+        TribunalCraftError::SubjectCannotBeClosed
+    );
+
+    // Unclaimed juror rewards and rounding dust go to treasury less the sweep bounty; rent is returned
+    // An escrow already closed via close_escrow has no data left at its address
+    let escrow = ctx.accounts.escrow.to_account_info();
+    if !escrow.data_is_empty() {
+        require!(escrow.owner == &crate::ID, TribunalCraftError::InvalidConfig);
+        let claims_complete = {
+            let data = escrow.try_borrow_data()?;
+            require!(
+                data.len() >= DisputeEscrow::LEN && data[..8] == *DisputeEscrow::DISCRIMINATOR,
+                TribunalCraftError::InvalidConfig
+            );
+            bytemuck::from_bytes::<DisputeEscrow>(&data[8..DisputeEscrow::LEN]).all_claims_complete()
+        };
+        require!(claims_complete, TribunalCraftError::ClaimsNotComplete);
+        require!(
+            !dispute.has_pending_juror_claims(subject.free_case, clock.unix_timestamp),
+            TribunalCraftError::JurorClaimsPending
+        );
+        require!(!dispute.has_pending_boost_refunds(clock.unix_timestamp), TribunalCraftError::BoostRefundsPending);
+        let rent = Rent::get()?.minimum_balance(DisputeEscrow::LEN);
+        let dust = escrow.lamports().saturating_sub(rent);
+        let bounty = ctx.accounts.protocol_config
+            .sweep_bounty(dust, dispute.sweep_age(clock.unix_timestamp));
+        transfer_lamports(&escrow, &ctx.accounts.closer.to_account_info(), bounty, LamportsMoveReason::SweepBounty)?;
+        transfer_lamports(&escrow, &ctx.accounts.treasury, dust - bounty, LamportsMoveReason::EscrowDust)?;
+        Treasury::record_income(&ctx.accounts.treasury, dust - bounty)?;
+
+        // Close the escrow the way `close = rent_recipient` would: rent back, data wiped
+        let rent_recipient = ctx.accounts.rent_recipient.to_account_info();
+        let remaining = escrow.lamports();
+        **rent_recipient.try_borrow_mut_lamports()? = rent_recipient.lamports()
+            .checked_add(remaining)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        **escrow.try_borrow_mut_lamports()? = 0;
+        escrow.assign(&System::id());
+        escrow.resize(0)?;
+
+        LifecycleLog {
+            kind: LifecycleKind::Swept,
+            subject: subject.key(),
+            dispute: dispute.key(),
+            status: subject.status,
+            outcome: dispute.outcome,
+            amount: dust,
+            timestamp: clock.unix_timestamp,
+        }.write(ctx.accounts.noop_program.as_deref())?;
+    }
+
+    LifecycleLog {
+        kind: LifecycleKind::Closed,
+        subject: subject.key(),
+        dispute: dispute.key(),
+        status: subject.status,
+        outcome: dispute.outcome,
+        amount: subject.to_account_info().lamports(),
+        timestamp: clock.unix_timestamp,
+    }.write(ctx.accounts.noop_program.as_deref())?;
+
+    let tombstone = &mut ctx.accounts.subject_tombstone;
+    tombstone.subject_id = subject.subject_id;
+    tombstone.closed_at = clock.unix_timestamp;
+    tombstone.bump = ctx.bumps.subject_tombstone;
+
+    emit!(SubjectClosedEvent {
+        subject: subject.key(),
+        subject_id: subject.subject_id,
+        dispute: dispute.key(),
+        closer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Subject closed: {}", subject.subject_id);
+    Ok(())
+}
+
+/// Validate a category id against the registry (0 = uncategorized, always allowed)
+fn validate_category(registry: Option<&CategoryRegistry>, category_id: u16) -> Result<()> {
+    if category_id == 0 {
+        return Ok(());
+    }
+    let registry = registry.ok_or(TribunalCraftError::CategoryNotFound)?;
+    require!(registry.contains(category_id), TribunalCraftError::CategoryNotFound);
+    Ok(())
+}
+
+/// Enforce the subject creator gate (no-op when the gate is disabled)
+fn check_creator_gate(
+    config: &ProtocolConfig,
+    allowlist: Option<&Account<CreatorAllowlist>>,
+    creator: &Pubkey,
+) -> Result<()> {
+    let Some(gate) = config.subject_creator_gate else {
+        return Ok(());
+    };
+    let allowlist = allowlist.ok_or(TribunalCraftError::CreatorNotAllowed)?;
+    require!(allowlist.key() == gate, TribunalCraftError::CreatorNotAllowed);
+    require!(allowlist.contains(creator), TribunalCraftError::CreatorNotAllowed);
+    Ok(())
+}
+
+/// Apply an optional reward split override (None = protocol defaults)
+fn resolve_reward_split(config: &ProtocolConfig, split: Option<RewardSplit>) -> Result<RewardSplit> {
+    let Some(split) = split else {
+        return Ok(RewardSplit::default());
+    };
+    require!(config.allows_reward_split(&split), TribunalCraftError::InvalidRewardSplit);
+    Ok(split)
+}
@@ -1,7 +1,99 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::constants::{VOTE_RECORD_SEED, JUROR_ACCOUNT_SEED, STAKE_UNLOCK_BUFFER};
+use crate::constants::{
+    VOTE_RECORD_SEED, JUROR_ACCOUNT_SEED, STAKE_UNLOCK_BUFFER, VOTE_CHANGE_PENALTY_BPS,
+    ACCOUNT_VERSION, VOTE_STATS_SEED, GRADE_STEPS, DISPUTE_ESCROW_SEED, ABSTAIN_STAKE_BPS,
+    EVIDENCE_ACK_SEED,
+};
 use crate::errors::TribunalCraftError;
+use crate::utils::validate_cid;
+use crate::events::{VoteChangedEvent, RationaleRevealedEvent, VoteCastEvent};
+use crate::instructions::resolve::{juror_payout, juror_incentive_share};
+use solana_keccak_hasher as keccak;
+
+/// Store the vote's rationale: either a CID now, or a keccak commitment to one
+/// Rationale-required subjects must commit and reveal via reveal_rationale before claiming
+fn set_rationale(
+    subject: &Subject,
+    vote_record: &mut VoteRecord,
+    rationale_cid: String,
+    rationale_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    match rationale_hash {
+        Some(hash) => {
+            require!(hash != [0u8; 32], TribunalCraftError::RationaleHashRequired);
+            vote_record.rationale_hash = hash;
+            vote_record.rationale_revealed = false;
+        }
+        None => {
+            require!(!subject.rationale_required, TribunalCraftError::RationaleHashRequired);
+            vote_record.rationale_cid = rationale_cid;
+        }
+    }
+    Ok(())
+}
+
+/// Store the juror's projected reward at the current totals and emit it for UIs
+/// Uses the claim-time payout math, so the estimate moves with later votes and bonds
+fn record_reward_estimate(
+    subject: &Subject,
+    dispute: &Dispute,
+    escrow: &AccountLoader<DisputeEscrow>,
+    vote_record: &mut VoteRecord,
+    now: i64,
+) -> Result<()> {
+    vote_record.est_reward_floor = if subject.free_case {
+        0
+    } else {
+        juror_payout(subject, dispute, &*escrow.load()?, vote_record)?
+            .saturating_add(juror_incentive_share(dispute, vote_record))
+    };
+
+    emit!(VoteCastEvent {
+        dispute: vote_record.dispute,
+        juror: vote_record.juror,
+        is_appeal_vote: vote_record.is_appeal_vote,
+        stake_allocated: vote_record.stake_allocated,
+        voting_power: vote_record.voting_power,
+        est_reward_floor: vote_record.est_reward_floor,
+        timestamp: now,
+    });
+    Ok(())
+}
+
+/// Stake an abstention locks out of the requested allocation (see ABSTAIN_STAKE_BPS)
+fn abstain_allocation(stake_allocation: u64) -> u64 {
+    (stake_allocation as u128 * ABSTAIN_STAKE_BPS as u128 / 10000) as u64
+}
+
+/// Keep the optional VoteStats in step with a vote
+/// Stats are exact only while every vote passes them: a vote without them drops
+/// dispute.has_vote_stats for good, and stale stats passed afterwards are ignored
+fn update_vote_stats(
+    dispute: &mut Dispute,
+    vote_stats: Option<&mut Account<VoteStats>>,
+    update: impl FnOnce(&mut VoteStats) -> Result<()>,
+) -> Result<()> {
+    match vote_stats {
+        Some(vote_stats) if dispute.has_vote_stats => update(vote_stats),
+        None if dispute.has_vote_stats => {
+            dispute.has_vote_stats = false;
+            msg!("Vote stats not passed - no longer tracked for this dispute");
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Disputes requiring review only take votes review_delay after the juror's acknowledgment
+fn check_evidence_review(dispute: &Dispute, evidence_ack: Option<&Account<EvidenceAck>>, now: i64) -> Result<()> {
+    if !dispute.requires_review() {
+        return Ok(());
+    }
+    let ack = evidence_ack.ok_or(TribunalCraftError::EvidenceNotReviewed)?;
+    require!(ack.is_reviewed(dispute.review_delay, now), TribunalCraftError::EvidenceNotReviewed);
+    Ok(())
+}
 
 #[derive(Accounts)]
 pub struct VoteOnDispute<'info> {
@@ -18,7 +110,7 @@ pub struct VoteOnDispute<'info> {
     pub juror_account: Account<'info, JurorAccount>,
 
     #[account(
-        constraint = subject.key() == dispute.subject @ TribunalCraftError::InvalidConfig,
+        constraint = subject.key() == dispute.subject @ TribunalCraftError::SubjectMismatch,
     )]
     pub subject: Account<'info, Subject>,
 
@@ -29,6 +121,13 @@ pub struct VoteOnDispute<'info> {
     )]
     pub dispute: Account<'info, Dispute>,
 
+    /// Escrow totals for the reward estimate
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
     #[account(
         init,
         payer = juror,
@@ -38,6 +137,27 @@ pub struct VoteOnDispute<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    /// Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)
+    #[account(
+        mut,
+        seeds = [VOTE_STATS_SEED, dispute.key().as_ref()],
+        bump = vote_stats.bump
+    )]
+    pub vote_stats: Option<Account<'info, VoteStats>>,
+
+    /// Optional: juror's evidence acknowledgment (required when the dispute requires review)
+    #[account(
+        seeds = [EVIDENCE_ACK_SEED, dispute.key().as_ref(), juror.key().as_ref()],
+        bump = evidence_ack.bump
+    )]
+    pub evidence_ack: Option<Account<'info, EvidenceAck>>,
+
+    /// Protocol config (stake-age weighting and allocation caps)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -46,8 +166,41 @@ pub fn vote_on_dispute(
     choice: VoteChoice,
     stake_allocation: u64,
     rationale_cid: String,
+    rationale_hash: Option<[u8; 32]>,
 ) -> Result<()> {
-    require!(rationale_cid.len() <= VoteRecord::MAX_CID_LEN, TribunalCraftError::InvalidConfig);
+    // Graded disputes take a grade (use vote_graded); abstaining works for both
+    require!(
+        !ctx.accounts.dispute.graded || choice == VoteChoice::Abstain,
+        TribunalCraftError::GradedVoteMismatch
+    );
+    cast_dispute_vote(ctx, choice, 0, stake_allocation, rationale_cid, rationale_hash)
+}
+
+/// Vote a slash grade on a graded dispute (0 = for the defender, 1-4 = 25-100% slash)
+/// Any non-zero grade counts as a ForChallenger vote in the binary tallies
+pub fn vote_graded(
+    ctx: Context<VoteOnDispute>,
+    grade: u8,
+    stake_allocation: u64,
+    rationale_cid: String,
+    rationale_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    require!(ctx.accounts.dispute.graded, TribunalCraftError::GradedVoteMismatch);
+    require!((grade as usize) < GRADE_STEPS, TribunalCraftError::InvalidGrade);
+
+    let choice = if grade > 0 { VoteChoice::ForChallenger } else { VoteChoice::ForDefender };
+    cast_dispute_vote(ctx, choice, grade, stake_allocation, rationale_cid, rationale_hash)
+}
+
+fn cast_dispute_vote(
+    ctx: Context<VoteOnDispute>,
+    choice: VoteChoice,
+    grade: u8,
+    stake_allocation: u64,
+    rationale_cid: String,
+    rationale_hash: Option<[u8; 32]>,
+) -> Result<()> {
+    validate_cid(&rationale_cid, VoteRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
     let juror_account = &mut ctx.accounts.juror_account;
     let subject = &ctx.accounts.subject;
     let dispute = &mut ctx.accounts.dispute;
@@ -57,28 +210,42 @@ pub fn vote_on_dispute(
     require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
 
     // Ensure this is not an appeal (use vote_on_appeal for appeals)
-    require!(!dispute.is_appeal, TribunalCraftError::InvalidConfig);
+    require!(!dispute.is_appeal, TribunalCraftError::AppealMismatch);
+    check_evidence_review(dispute, ctx.accounts.evidence_ack.as_ref(), clock.unix_timestamp)?;
 
-    // Validate stake allocation (any amount > 0 is allowed - platform can enforce minimums)
+    // Abstentions lock a reduced stake and carry no voting power
+    let abstain = choice == VoteChoice::Abstain;
+    let stake_allocation = if abstain { abstain_allocation(stake_allocation) } else { stake_allocation };
+
+    // Validate stake allocation (the config's minimum is enforced by check_allocation_caps below)
     require!(stake_allocation > 0, TribunalCraftError::VoteAllocationBelowMinimum);
     require!(stake_allocation <= juror_account.available_stake, TribunalCraftError::InsufficientAvailableStake);
 
     // Calculate voting power
-    let voting_power = juror_account.calculate_voting_power(stake_allocation);
+    let maturity_period = ctx.accounts.protocol_config.stake_maturity_period;
+    let weighted_stake = juror_account.age_weighted_stake(stake_allocation, clock.unix_timestamp, maturity_period);
+    let voting_power = if abstain { 0 } else { juror_account.calculate_voting_power(weighted_stake)? };
 
     // Lock stake
-    juror_account.available_stake -= stake_allocation;
-
-    // Update dispute vote weights
-    match choice {
-        VoteChoice::ForChallenger => {
-            dispute.votes_favor_weight += voting_power;
-        }
-        VoteChoice::ForDefender => {
-            dispute.votes_against_weight += voting_power;
+    juror_account.allocate_for_vote(stake_allocation)?;
+    juror_account.open_vote()?;
+    ctx.accounts.protocol_config.check_allocation_caps(juror_account, stake_allocation)?;
+
+    // Update dispute vote weights (abstentions are counted separately)
+    if abstain {
+        dispute.abstain_count = dispute.abstain_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    } else {
+        dispute.add_vote_weight(choice == VoteChoice::ForChallenger, voting_power)?;
+        if dispute.graded {
+            dispute.add_grade_weight(grade, voting_power)?;
         }
+        update_vote_stats(dispute, ctx.accounts.vote_stats.as_mut(), |stats| {
+            stats.add_vote(choice == VoteChoice::ForChallenger, voting_power)
+        })?;
     }
-    dispute.vote_count += 1;
+    dispute.vote_count = dispute.vote_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
     // Initialize vote record
     vote_record.dispute = dispute.key();
@@ -87,6 +254,7 @@ pub fn vote_on_dispute(
     vote_record.choice = choice;
     vote_record.appeal_choice = AppealVoteChoice::default();
     vote_record.is_appeal_vote = false;
+    vote_record.grade = grade;
     vote_record.stake_allocated = stake_allocation;
     vote_record.voting_power = voting_power;
     // Free cases: no lock, stake can be unlocked immediately after voting ends
@@ -100,11 +268,15 @@ pub fn vote_on_dispute(
     vote_record.reward_claimed = false;
     vote_record.stake_unlocked = false;
     vote_record.bump = ctx.bumps.vote_record;
+    vote_record.version = ACCOUNT_VERSION;
     vote_record.voted_at = clock.unix_timestamp;
-    vote_record.rationale_cid = rationale_cid;
+    vote_record.reputation_snapshot = Some(juror_account.reputation);
+    set_rationale(subject, vote_record, rationale_cid, rationale_hash)?;
+    record_reward_estimate(subject, dispute, &ctx.accounts.escrow, vote_record, clock.unix_timestamp)?;
 
     // Update juror stats
-    juror_account.votes_cast += 1;
+    juror_account.votes_cast = juror_account.votes_cast.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     juror_account.last_vote_at = clock.unix_timestamp;
 
     msg!("Vote cast: {:?} with {} voting power", choice, voting_power);
@@ -126,7 +298,7 @@ pub struct AddToVote<'info> {
     pub juror_account: Account<'info, JurorAccount>,
 
     #[account(
-        constraint = subject.key() == dispute.subject @ TribunalCraftError::InvalidConfig,
+        constraint = subject.key() == dispute.subject @ TribunalCraftError::SubjectMismatch,
     )]
     pub subject: Account<'info, Subject>,
 
@@ -137,6 +309,13 @@ pub struct AddToVote<'info> {
     )]
     pub dispute: Account<'info, Dispute>,
 
+    /// Escrow totals for the reward estimate
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
     #[account(
         mut,
         has_one = dispute,
@@ -146,6 +325,20 @@ pub struct AddToVote<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    /// Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)
+    #[account(
+        mut,
+        seeds = [VOTE_STATS_SEED, dispute.key().as_ref()],
+        bump = vote_stats.bump
+    )]
+    pub vote_stats: Option<Account<'info, VoteStats>>,
+
+    /// Protocol config (stake-age weighting and allocation caps)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -162,42 +355,39 @@ pub fn add_to_vote(
 
     // Ensure voting is still active
     require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    require!(!vote_record.is_abstain(), TribunalCraftError::VoteAbstained);
 
     // Validate stake allocation
     require!(additional_stake > 0, TribunalCraftError::VoteAllocationBelowMinimum);
     require!(additional_stake <= juror_account.available_stake, TribunalCraftError::InsufficientAvailableStake);
 
     // Calculate additional voting power
-    let additional_voting_power = juror_account.calculate_voting_power(additional_stake);
+    let maturity_period = ctx.accounts.protocol_config.stake_maturity_period;
+    let weighted_stake = juror_account.age_weighted_stake(additional_stake, clock.unix_timestamp, maturity_period);
+    let additional_voting_power = juror_account.calculate_voting_power(weighted_stake)?;
 
     // Lock additional stake
-    juror_account.available_stake -= additional_stake;
-
-    // Update dispute vote weights based on original choice
-    // Handle both regular disputes and appeals
-    if vote_record.is_appeal_vote {
-        match vote_record.appeal_choice {
-            AppealVoteChoice::ForRestoration => {
-                dispute.votes_favor_weight += additional_voting_power;
-            }
-            AppealVoteChoice::AgainstRestoration => {
-                dispute.votes_against_weight += additional_voting_power;
-            }
-        }
-    } else {
-        match vote_record.choice {
-            VoteChoice::ForChallenger => {
-                dispute.votes_favor_weight += additional_voting_power;
-            }
-            VoteChoice::ForDefender => {
-                dispute.votes_against_weight += additional_voting_power;
-            }
-        }
+    juror_account.allocate_for_vote(additional_stake)?;
+    let vote_total = vote_record.stake_allocated.checked_add(additional_stake)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    ctx.accounts.protocol_config.check_allocation_caps(juror_account, vote_total)?;
+
+    // Update dispute vote weights based on original choice (regular or appeal)
+    dispute.add_vote_weight(vote_record.is_favor(), additional_voting_power)?;
+    if dispute.graded {
+        dispute.add_grade_weight(vote_record.grade, additional_voting_power)?;
     }
 
     // Update vote record totals
-    vote_record.stake_allocated += additional_stake;
-    vote_record.voting_power += additional_voting_power;
+    let old_power = vote_record.voting_power;
+    vote_record.stake_allocated = vote_record.stake_allocated.checked_add(additional_stake)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    vote_record.voting_power = vote_record.voting_power.checked_add(additional_voting_power)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    let favor = vote_record.is_favor();
+    update_vote_stats(dispute, ctx.accounts.vote_stats.as_mut(), |stats| {
+        stats.replace_vote(favor, old_power, favor, vote_record.voting_power)
+    })?;
 
     // Extend unlock time if needed (use latest voting_ends_at)
     let new_unlock_at = if subject.free_case {
@@ -208,11 +398,119 @@ pub fn add_to_vote(
     if new_unlock_at > vote_record.unlock_at {
         vote_record.unlock_at = new_unlock_at;
     }
+    record_reward_estimate(subject, dispute, &ctx.accounts.escrow, vote_record, clock.unix_timestamp)?;
 
     msg!("Added {} stake to vote, new total voting power: {}", additional_stake, vote_record.voting_power);
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        has_one = dispute,
+        has_one = juror,
+        seeds = [VOTE_RECORD_SEED, dispute.key().as_ref(), juror.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    /// Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)
+    #[account(
+        mut,
+        seeds = [VOTE_STATS_SEED, dispute.key().as_ref()],
+        bump = vote_stats.bump
+    )]
+    pub vote_stats: Option<Account<'info, VoteStats>>,
+}
+
+/// Flip an existing vote to the other side before voting ends
+/// The moved voting power is reduced by VOTE_CHANGE_PENALTY_BPS
+pub fn change_vote(ctx: Context<ChangeVote>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+
+    require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    // A graded vote has no single opposite side to flip to, nor does an abstention
+    require!(!dispute.graded, TribunalCraftError::GradedVoteMismatch);
+    require!(!vote_record.is_abstain(), TribunalCraftError::VoteAbstained);
+
+    let old_power = vote_record.voting_power;
+    let new_power = (old_power as u128 * (10000 - VOTE_CHANGE_PENALTY_BPS) as u128 / 10000) as u64;
+
+    // Move power between tallies
+    let was_favor = vote_record.is_favor();
+    dispute.remove_vote_weight(was_favor, old_power)?;
+    dispute.add_vote_weight(!was_favor, new_power)?;
+    update_vote_stats(dispute, ctx.accounts.vote_stats.as_mut(), |stats| {
+        stats.replace_vote(was_favor, old_power, !was_favor, new_power)
+    })?;
+
+    vote_record.flip_choice();
+    vote_record.voting_power = new_power;
+
+    emit!(VoteChangedEvent {
+        dispute: dispute.key(),
+        juror: ctx.accounts.juror.key(),
+        is_appeal_vote: vote_record.is_appeal_vote,
+        new_choice: vote_record.choice,
+        new_appeal_choice: vote_record.appeal_choice,
+        old_voting_power: old_power,
+        new_voting_power: new_power,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vote changed: voting power {} -> {}", old_power, new_power);
+    Ok(())
+}
+
+/// Create the optional vote statistics PDA for a dispute (anyone, before the first vote)
+/// Votes that skip it are not blocked; they end tracking instead (see update_vote_stats)
+#[derive(Accounts)]
+pub struct InitVoteStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
+        constraint = dispute.vote_count == 0 @ TribunalCraftError::VoteStatsMismatch,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = VoteStats::LEN,
+        seeds = [VOTE_STATS_SEED, dispute.key().as_ref()],
+        bump
+    )]
+    pub vote_stats: Account<'info, VoteStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_vote_stats(ctx: Context<InitVoteStats>) -> Result<()> {
+    let vote_stats = &mut ctx.accounts.vote_stats;
+    vote_stats.dispute = ctx.accounts.dispute.key();
+    vote_stats.bump = ctx.bumps.vote_stats;
+    vote_stats.created_at = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.dispute.has_vote_stats = true;
+
+    msg!("Vote stats enabled for dispute {}", vote_stats.dispute);
+    Ok(())
+}
+
 // =============================================================================
 // Appeal Voting
 // =============================================================================
@@ -232,7 +530,7 @@ pub struct VoteOnAppeal<'info> {
     pub juror_account: Account<'info, JurorAccount>,
 
     #[account(
-        constraint = subject.key() == dispute.subject @ TribunalCraftError::InvalidConfig,
+        constraint = subject.key() == dispute.subject @ TribunalCraftError::SubjectMismatch,
     )]
     pub subject: Account<'info, Subject>,
 
@@ -240,10 +538,17 @@ pub struct VoteOnAppeal<'info> {
         mut,
         has_one = subject,
         constraint = dispute.status == DisputeStatus::Pending @ TribunalCraftError::DisputeAlreadyResolved,
-        constraint = dispute.is_appeal @ TribunalCraftError::InvalidConfig, // Must be an appeal
+        constraint = dispute.is_appeal @ TribunalCraftError::AppealMismatch, // Must be an appeal
     )]
     pub dispute: Account<'info, Dispute>,
 
+    /// Escrow totals for the reward estimate
+    #[account(
+        seeds = [DISPUTE_ESCROW_SEED, dispute.key().as_ref()],
+        bump = escrow.load()?.bump
+    )]
+    pub escrow: AccountLoader<'info, DisputeEscrow>,
+
     #[account(
         init,
         payer = juror,
@@ -253,49 +558,83 @@ pub struct VoteOnAppeal<'info> {
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
+    /// Optional: dispute vote stats (omitting them once created stops tracking, see update_vote_stats)
+    #[account(
+        mut,
+        seeds = [VOTE_STATS_SEED, dispute.key().as_ref()],
+        bump = vote_stats.bump
+    )]
+    pub vote_stats: Option<Account<'info, VoteStats>>,
+
+    /// Optional: juror's evidence acknowledgment (required when the dispute requires review)
+    #[account(
+        seeds = [EVIDENCE_ACK_SEED, dispute.key().as_ref(), juror.key().as_ref()],
+        bump = evidence_ack.bump
+    )]
+    pub evidence_ack: Option<Account<'info, EvidenceAck>>,
+
+    /// Protocol config (stake-age weighting and allocation caps)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Vote on an appeal with stake allocation
 /// ForRestoration = vote to restore subject to Active
 /// AgainstRestoration = vote to keep subject Invalidated
+/// Abstain = lock ABSTAIN_STAKE_BPS of the allocation for a flat fee, outside the tally
 pub fn vote_on_appeal(
     ctx: Context<VoteOnAppeal>,
     choice: AppealVoteChoice,
     stake_allocation: u64,
     rationale_cid: String,
+    rationale_hash: Option<[u8; 32]>,
 ) -> Result<()> {
-    require!(rationale_cid.len() <= VoteRecord::MAX_CID_LEN, TribunalCraftError::InvalidConfig);
+    validate_cid(&rationale_cid, VoteRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
     let juror_account = &mut ctx.accounts.juror_account;
-    let _subject = &ctx.accounts.subject; // Kept for account validation
+    let subject = &ctx.accounts.subject;
     let dispute = &mut ctx.accounts.dispute;
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
     require!(!dispute.is_voting_ended(clock.unix_timestamp), TribunalCraftError::VotingEnded);
+    check_evidence_review(dispute, ctx.accounts.evidence_ack.as_ref(), clock.unix_timestamp)?;
+
+    // Abstentions lock a reduced stake and carry no voting power
+    let abstain = choice == AppealVoteChoice::Abstain;
+    let stake_allocation = if abstain { abstain_allocation(stake_allocation) } else { stake_allocation };
 
     // Validate stake allocation
     require!(stake_allocation > 0, TribunalCraftError::VoteAllocationBelowMinimum);
     require!(stake_allocation <= juror_account.available_stake, TribunalCraftError::InsufficientAvailableStake);
 
     // Calculate voting power
-    let voting_power = juror_account.calculate_voting_power(stake_allocation);
+    let maturity_period = ctx.accounts.protocol_config.stake_maturity_period;
+    let weighted_stake = juror_account.age_weighted_stake(stake_allocation, clock.unix_timestamp, maturity_period);
+    let voting_power = if abstain { 0 } else { juror_account.calculate_voting_power(weighted_stake)? };
 
     // Lock stake
-    juror_account.available_stake -= stake_allocation;
+    juror_account.allocate_for_vote(stake_allocation)?;
+    juror_account.open_vote()?;
+    ctx.accounts.protocol_config.check_allocation_caps(juror_account, stake_allocation)?;
 
-    // Update dispute vote weights
+    // Update dispute vote weights (abstentions are counted separately)
     // ForRestoration maps to votes_favor (ChallengerWins = subject restored)
     // AgainstRestoration maps to votes_against (DefenderWins = subject stays invalidated)
-    match choice {
-        AppealVoteChoice::ForRestoration => {
-            dispute.votes_favor_weight += voting_power;
-        }
-        AppealVoteChoice::AgainstRestoration => {
-            dispute.votes_against_weight += voting_power;
-        }
+    if abstain {
+        dispute.abstain_count = dispute.abstain_count.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    } else {
+        dispute.add_vote_weight(choice == AppealVoteChoice::ForRestoration, voting_power)?;
+        update_vote_stats(dispute, ctx.accounts.vote_stats.as_mut(), |stats| {
+            stats.add_vote(choice == AppealVoteChoice::ForRestoration, voting_power)
+        })?;
     }
-    dispute.vote_count += 1;
+    dispute.vote_count = dispute.vote_count.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
 
     // Initialize vote record
     vote_record.dispute = dispute.key();
@@ -312,13 +651,70 @@ pub fn vote_on_appeal(
     vote_record.reward_claimed = false;
     vote_record.stake_unlocked = false;
     vote_record.bump = ctx.bumps.vote_record;
+    vote_record.version = ACCOUNT_VERSION;
     vote_record.voted_at = clock.unix_timestamp;
-    vote_record.rationale_cid = rationale_cid;
+    vote_record.reputation_snapshot = Some(juror_account.reputation);
+    set_rationale(subject, vote_record, rationale_cid, rationale_hash)?;
+    record_reward_estimate(subject, dispute, &ctx.accounts.escrow, vote_record, clock.unix_timestamp)?;
 
     // Update juror stats
-    juror_account.votes_cast += 1;
+    juror_account.votes_cast = juror_account.votes_cast.checked_add(1)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
     juror_account.last_vote_at = clock.unix_timestamp;
 
     msg!("Appeal vote cast: {:?} with {} voting power", choice, voting_power);
     Ok(())
 }
+
+// =============================================================================
+// Rationale Reveal
+// =============================================================================
+
+/// Reveal the rationale CID committed at vote time (any time before claiming)
+#[derive(Accounts)]
+pub struct RevealRationale<'info> {
+    pub juror: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = juror @ TribunalCraftError::Unauthorized,
+        has_one = dispute,
+        constraint = vote_record.is_rationale_pending() @ TribunalCraftError::RationaleNotPending,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    #[account(has_one = subject)]
+    pub dispute: Account<'info, Dispute>,
+
+    pub subject: Account<'info, Subject>,
+
+    /// Protocol config (CID validation)
+    #[account(
+        constraint = protocol_config.key() == subject.config @ TribunalCraftError::InvalidConfig
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn reveal_rationale(ctx: Context<RevealRationale>, rationale_cid: String) -> Result<()> {
+    let vote_record = &mut ctx.accounts.vote_record;
+    let clock = Clock::get()?;
+
+    validate_cid(&rationale_cid, VoteRecord::MAX_CID_LEN, ctx.accounts.protocol_config.validate_cids)?;
+    require!(
+        keccak::hash(rationale_cid.as_bytes()).to_bytes() == vote_record.rationale_hash,
+        TribunalCraftError::RationaleHashMismatch
+    );
+
+    vote_record.rationale_cid = rationale_cid.clone();
+    vote_record.rationale_revealed = true;
+
+    emit!(RationaleRevealedEvent {
+        dispute: vote_record.dispute,
+        juror: vote_record.juror,
+        rationale_cid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Rationale revealed for dispute {}", vote_record.dispute);
+    Ok(())
+}
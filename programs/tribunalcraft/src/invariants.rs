@@ -0,0 +1,53 @@
+// =============================================================================
+// DEBUG-ONLY SOLVENCY CHECKS
+// =============================================================================
+//
+// Each check asserts that an account holds at least the lamports its tracked
+// balance says it holds (plus rent). They compile to nothing in release builds
+// and fire in tests/debug builds when lamports and accounting diverge.
+
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Lamports held above the rent-exempt minimum for `space` bytes
+fn spendable(info: &AccountInfo, space: usize) -> u64 {
+    // Rent sysvar is unavailable off-chain; treat the whole balance as spendable there
+    let rent = Rent::get().map(|r| r.minimum_balance(space)).unwrap_or(0);
+    info.lamports().saturating_sub(rent)
+}
+
+/// Escrow must cover bonds + stakes not yet paid out
+pub fn check_escrow(info: &AccountInfo, escrow: &DisputeEscrow) {
+    debug_assert!(
+        spendable(info, DisputeEscrow::LEN) >= escrow.available_balance(),
+        "escrow lamports below tracked balance"
+    );
+}
+
+/// Subject must cover its direct defender stake
+pub fn check_subject(info: &AccountInfo, subject: &Subject) {
+    debug_assert!(
+        spendable(info, Subject::LEN) >= subject.total_stake,
+        "subject lamports below total_stake"
+    );
+}
+
+/// Defender pool must cover its total stake
+pub fn check_defender_pool(info: &AccountInfo, pool: &DefenderPool) {
+    debug_assert!(
+        spendable(info, DefenderPool::LEN) >= pool.total_stake,
+        "defender pool lamports below total_stake"
+    );
+}
+
+/// Juror account must cover its total stake, and its lock summary must match holds
+pub fn check_juror_account(info: &AccountInfo, juror: &JurorAccount) {
+    debug_assert!(
+        spendable(info, JurorAccount::LEN) >= juror.total_stake,
+        "juror account lamports below total_stake"
+    );
+    debug_assert!(
+        juror.locked_stake == juror.held_stake(),
+        "juror locked_stake diverged from held stake"
+    );
+}
@@ -2,11 +2,18 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod state;
 pub mod instructions;
+pub mod utils;
+pub(crate) mod invariants;
+pub(crate) mod yield_cpi;
+pub mod escrow_interface;
+pub mod lifecycle_log;
 
 pub use constants::*;
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 pub use instructions::*;
 
@@ -20,16 +27,181 @@ pub mod tribunalcraft {
     // Protocol Config Instructions
     // =========================================================================
 
-    /// Initialize protocol config (one-time setup by deployer)
+    /// Initialize a protocol config instance (one per authority)
     pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
         instructions::initialize_config(ctx)
     }
 
+    /// Initialize the global config (one-time, program upgrade authority only)
+    pub fn initialize_global_config(ctx: Context<InitializeGlobalConfig>) -> Result<()> {
+        instructions::initialize_global_config(ctx)
+    }
+
+    /// Initialize protocol statistics account (one-time, program upgrade authority only)
+    pub fn initialize_stats(ctx: Context<InitializeStats>) -> Result<()> {
+        instructions::initialize_stats(ctx)
+    }
+
+    /// Initialize a config's keeper expiry queue (one-time, config authority)
+    pub fn initialize_expiry_queue(ctx: Context<InitializeExpiryQueue>) -> Result<()> {
+        instructions::initialize_expiry_queue(ctx)
+    }
+
     /// Update treasury address (admin only)
     pub fn update_treasury(ctx: Context<UpdateTreasury>, new_treasury: Pubkey) -> Result<()> {
         instructions::update_treasury(ctx, new_treasury)
     }
 
+    /// Create the config's accounting treasury PDA and route fees to it (admin only)
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::initialize_treasury(ctx)
+    }
+
+    /// Withdraw from the accounting treasury PDA (admin only)
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::withdraw_treasury(ctx, amount)
+    }
+
+    /// Add a wallet to the subject creator allowlist (admin only)
+    pub fn add_allowed_creator(ctx: Context<AddAllowedCreator>, creator: Pubkey) -> Result<()> {
+        instructions::add_allowed_creator(ctx, creator)
+    }
+
+    /// Remove a wallet from the subject creator allowlist (admin only)
+    pub fn remove_allowed_creator(ctx: Context<RemoveAllowedCreator>, creator: Pubkey) -> Result<()> {
+        instructions::remove_allowed_creator(ctx, creator)
+    }
+
+    /// Enable/disable allowlist gating of subject creation (admin only)
+    pub fn set_subject_creator_gate(ctx: Context<SetSubjectCreatorGate>, enabled: bool) -> Result<()> {
+        instructions::set_subject_creator_gate(ctx, enabled)
+    }
+
+    /// Update bounds for per-subject reward split overrides (admin only)
+    pub fn update_share_bounds(
+        ctx: Context<UpdateShareBounds>,
+        min_juror_share_bps: u16,
+        max_juror_share_bps: u16,
+        min_winner_share_bps: u16,
+        max_winner_share_bps: u16,
+    ) -> Result<()> {
+        instructions::update_share_bounds(ctx, min_juror_share_bps, max_juror_share_bps, min_winner_share_bps, max_winner_share_bps)
+    }
+
+    /// Update how long new juror stake takes to reach full voting weight (admin only)
+    pub fn update_stake_maturity_period(ctx: Context<UpdateStakeMaturityPeriod>, period: i64) -> Result<()> {
+        instructions::update_stake_maturity_period(ctx, period)
+    }
+
+    /// Update juror per-dispute and concurrent lock caps (admin only)
+    pub fn update_allocation_caps(
+        ctx: Context<UpdateAllocationCaps>,
+        max_vote_allocation_bps: u16,
+        max_locked_bps: u16,
+    ) -> Result<()> {
+        instructions::update_allocation_caps(ctx, max_vote_allocation_bps, max_locked_bps)
+    }
+
+    /// Update the minimum stake per vote, absolute and relative to juror stake (admin only)
+    pub fn update_min_vote_allocation(
+        ctx: Context<UpdateMinVoteAllocation>,
+        min_vote_allocation: u64,
+        min_vote_allocation_bps: u16,
+    ) -> Result<()> {
+        instructions::update_min_vote_allocation(ctx, min_vote_allocation, min_vote_allocation_bps)
+    }
+
+    /// Update the sweep bounty curve paid to escrow closers (admin only)
+    pub fn update_sweep_bounty(
+        ctx: Context<UpdateSweepBounty>,
+        sweep_bounty_min: u64,
+        sweep_bounty_base_bps: u16,
+        sweep_bounty_bps_per_day: u16,
+        sweep_bounty_max: u64,
+    ) -> Result<()> {
+        instructions::update_sweep_bounty(
+            ctx,
+            sweep_bounty_min,
+            sweep_bounty_base_bps,
+            sweep_bounty_bps_per_day,
+            sweep_bounty_max,
+        )
+    }
+
+    /// Update the resolver fee and its per-resolution cap (admin only)
+    pub fn update_resolver_fee(
+        ctx: Context<UpdateResolverFee>,
+        resolver_fee_bps: u16,
+        max_resolver_fee: u64,
+    ) -> Result<()> {
+        instructions::update_resolver_fee(ctx, resolver_fee_bps, max_resolver_fee)
+    }
+
+    /// Update the post-defense dispute cooldown and escalation (admin only)
+    pub fn update_dispute_cooldown(
+        ctx: Context<UpdateDisputeCooldown>,
+        default_dispute_cooldown: i64,
+        cooldown_escalation_bps: u16,
+    ) -> Result<()> {
+        instructions::update_dispute_cooldown(ctx, default_dispute_cooldown, cooldown_escalation_bps)
+    }
+
+    /// Enable or disable CID format validation for details and rationale CIDs (admin only)
+    pub fn set_cid_validation(ctx: Context<SetCidValidation>, enabled: bool) -> Result<()> {
+        instructions::set_cid_validation(ctx, enabled)
+    }
+
+    /// Update the reputation tiers that rebate part of the treasury fee to jurors (admin only)
+    pub fn update_rebate_tiers(
+        ctx: Context<UpdateRebateTiers>,
+        rebate_tiers: [RebateTier; ProtocolConfig::REBATE_TIERS],
+    ) -> Result<()> {
+        instructions::update_rebate_tiers(ctx, rebate_tiers)
+    }
+
+    /// Update the final share of voting closed to new challenger bond (admin only)
+    pub fn update_late_join_cutoff(
+        ctx: Context<UpdateLateJoinCutoff>,
+        late_join_cutoff_bps: u16,
+    ) -> Result<()> {
+        instructions::update_late_join_cutoff(ctx, late_join_cutoff_bps)
+    }
+
+    /// Approve the stake pool for idle defender pool stake and the delegable share (admin only)
+    pub fn set_yield_stake_pool(
+        ctx: Context<SetYieldStakePool>,
+        stake_pool: Pubkey,
+        max_yield_bps: u16,
+    ) -> Result<()> {
+        instructions::set_yield_stake_pool(ctx, stake_pool, max_yield_bps)
+    }
+
+    /// Upgrade a program account to the current layout version (permissionless)
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account(ctx)
+    }
+
+    // =========================================================================
+    // Category Registry Instructions
+    // =========================================================================
+
+    /// Add a platform-defined category (admin only)
+    pub fn add_category(
+        ctx: Context<AddCategory>,
+        category_id: u16,
+        metadata_cid: String,
+    ) -> Result<()> {
+        instructions::add_category(ctx, category_id, metadata_cid)
+    }
+
+    /// Remove a category from the registry (admin only)
+    pub fn remove_category(
+        ctx: Context<RemoveCategory>,
+        category_id: u16,
+    ) -> Result<()> {
+        instructions::remove_category(ctx, category_id)
+    }
+
     // =========================================================================
     // Defender Pool Instructions
     // =========================================================================
@@ -58,6 +230,46 @@ pub mod tribunalcraft {
         instructions::withdraw_pool(ctx, amount)
     }
 
+    /// Delegate available pool stake to the config's approved stake pool (owner only)
+    pub fn deposit_pool_yield(
+        ctx: Context<DepositPoolYield>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit_pool_yield(ctx, amount)
+    }
+
+    /// Redeem stake pool tokens back into available pool stake (owner only)
+    pub fn withdraw_pool_yield(
+        ctx: Context<WithdrawPoolYield>,
+        pool_tokens: u64,
+    ) -> Result<()> {
+        instructions::withdraw_pool_yield(ctx, pool_tokens)
+    }
+
+    /// Authorize a wallet to link subjects to the pool up to a spending cap (owner only)
+    pub fn set_pool_delegate(
+        ctx: Context<SetPoolDelegate>,
+        delegate: Pubkey,
+        spending_cap: u64,
+    ) -> Result<()> {
+        instructions::set_pool_delegate(ctx, delegate, spending_cap)
+    }
+
+    /// Revoke a pool delegate (owner only)
+    pub fn remove_pool_delegate(
+        ctx: Context<RemovePoolDelegate>,
+    ) -> Result<()> {
+        instructions::remove_pool_delegate(ctx)
+    }
+
+    /// Back a disputed subject from any defender pool (pool owner or delegate)
+    pub fn defend_from_pool(
+        ctx: Context<DefendFromPool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::defend_from_pool(ctx, amount)
+    }
+
     // =========================================================================
     // Subject Instructions
     // =========================================================================
@@ -72,8 +284,13 @@ pub mod tribunalcraft {
         free_case: bool,
         voting_period: i64,
         stake: u64,
+        category_id: u16,
+        reward_split: Option<RewardSplit>,
+        rationale_required: bool,
+        auto_revalidate: bool,
+        index_page: u32,
     ) -> Result<()> {
-        instructions::create_subject(ctx, subject_id, details_cid, max_stake, match_mode, free_case, voting_period, stake)
+        instructions::create_subject(ctx, subject_id, details_cid, max_stake, match_mode, free_case, voting_period, stake, category_id, reward_split, rationale_required, auto_revalidate, index_page)
     }
 
     /// Create a subject linked to a defender pool
@@ -85,8 +302,13 @@ pub mod tribunalcraft {
         match_mode: bool,
         free_case: bool,
         voting_period: i64,
+        category_id: u16,
+        reward_split: Option<RewardSplit>,
+        rationale_required: bool,
+        auto_revalidate: bool,
+        index_page: u32,
     ) -> Result<()> {
-        instructions::create_linked_subject(ctx, subject_id, details_cid, max_stake, match_mode, free_case, voting_period)
+        instructions::create_linked_subject(ctx, subject_id, details_cid, max_stake, match_mode, free_case, voting_period, category_id, reward_split, rationale_required, auto_revalidate, index_page)
     }
 
     /// Create a free subject (no stake required, just Subject account)
@@ -95,8 +317,10 @@ pub mod tribunalcraft {
         subject_id: Pubkey,
         details_cid: String,
         voting_period: i64,
+        category_id: u16,
+        index_page: u32,
     ) -> Result<()> {
-        instructions::create_free_subject(ctx, subject_id, details_cid, voting_period)
+        instructions::create_free_subject(ctx, subject_id, details_cid, voting_period, category_id, index_page)
     }
 
     /// Add stake to a standalone subject
@@ -107,6 +331,78 @@ pub mod tribunalcraft {
         instructions::add_to_stake(ctx, stake)
     }
 
+    /// Reactivate an opted-in dormant subject by bonding from its defender pool (anyone, pays a small fee)
+    pub fn revive_subject(ctx: Context<ReviveSubject>) -> Result<()> {
+        instructions::revive_subject(ctx)
+    }
+
+    /// Set the pledged bond total that activates a pledged dispute (creator only)
+    pub fn set_pledge_threshold(
+        ctx: Context<SetPledgeThreshold>,
+        threshold: u64,
+    ) -> Result<()> {
+        instructions::set_pledge_threshold(ctx, threshold)
+    }
+
+    /// Link a standalone subject to any defender pool (creator and pool owner or delegate sign)
+    pub fn link_defender_pool(
+        ctx: Context<LinkDefenderPool>,
+    ) -> Result<()> {
+        instructions::link_defender_pool(ctx)
+    }
+
+    /// Choose how tied votes resolve: defender, challenger, or one voting extension (creator only)
+    pub fn set_tie_policy(
+        ctx: Context<SetTiePolicy>,
+        tie_policy: TiePolicy,
+        tie_extension: i64,
+    ) -> Result<()> {
+        instructions::set_tie_policy(ctx, tie_policy, tie_extension)
+    }
+
+    /// Cap one juror's counted weight at a share of the total (creator only, 0 = uncapped)
+    pub fn set_juror_weight_cap(
+        ctx: Context<SetJurorWeightCap>,
+        max_juror_weight_bps: u16,
+    ) -> Result<()> {
+        instructions::set_juror_weight_cap(ctx, max_juror_weight_bps)
+    }
+
+    /// Decide disputes by graded votes and a median slash instead of a binary verdict (creator only)
+    pub fn set_graded_verdicts(ctx: Context<SetGradedVerdicts>, enabled: bool) -> Result<()> {
+        instructions::set_graded_verdicts(ctx, enabled)
+    }
+
+    /// Freeze a subject for a legal hold: no new disputes, stakes, bonds or restorations
+    /// (authority until lifted, or the creator for up to MAX_CREATOR_FREEZE)
+    pub fn freeze_subject(
+        ctx: Context<FreezeSubject>,
+    ) -> Result<()> {
+        instructions::freeze_subject(ctx)
+    }
+
+    /// Lift a subject freeze (authority, or the creator for their own freeze)
+    pub fn unfreeze_subject(
+        ctx: Context<UnfreezeSubject>,
+    ) -> Result<()> {
+        instructions::unfreeze_subject(ctx)
+    }
+
+    /// Choose whether a defender win returns the subject to Active or Dormant (creator only)
+    pub fn set_auto_revalidate(
+        ctx: Context<SetAutoRevalidate>,
+        auto_revalidate: bool,
+    ) -> Result<()> {
+        instructions::set_auto_revalidate(ctx, auto_revalidate)
+    }
+
+    /// Close an invalidated subject with its last dispute and escrow, returning rent
+    pub fn close_subject(
+        ctx: Context<CloseSubject>,
+    ) -> Result<()> {
+        instructions::close_subject(ctx)
+    }
+
     // =========================================================================
     // Juror Instructions
     // =========================================================================
@@ -142,6 +438,14 @@ pub mod tribunalcraft {
         instructions::unregister_juror(ctx)
     }
 
+    /// Set the wallet that can receive claimed juror rewards
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        beneficiary: Pubkey,
+    ) -> Result<()> {
+        instructions::set_beneficiary(ctx, beneficiary)
+    }
+
     // =========================================================================
     // Challenger Instructions
     // =========================================================================
@@ -156,13 +460,26 @@ pub mod tribunalcraft {
         instructions::submit_dispute(ctx, dispute_type, details_cid, bond)
     }
 
-    /// Add to existing dispute (additional challengers)
+    /// Open disputes on several subjects for one incident (shared incident_id)
+    /// remaining_accounts: 6 per subject, see instructions::create_disputes_batch
+    pub fn create_disputes_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateDisputesBatch<'info>>,
+        incident_id: Pubkey,
+        dispute_type: DisputeType,
+        details_cid: String,
+        bonds: Vec<u64>,
+    ) -> Result<()> {
+        instructions::create_disputes_batch(ctx, incident_id, dispute_type, details_cid, bonds)
+    }
+
+    /// Add to existing dispute (additional challengers, optionally claiming a different type)
     pub fn add_to_dispute(
         ctx: Context<AddToDispute>,
         details_cid: String,
         bond: u64,
+        dispute_type: Option<DisputeType>,
     ) -> Result<()> {
-        instructions::add_to_dispute(ctx, details_cid, bond)
+        instructions::add_to_dispute(ctx, details_cid, bond, dispute_type)
     }
 
     /// Submit a free dispute (no bond required, just Dispute account)
@@ -174,6 +491,154 @@ pub mod tribunalcraft {
         instructions::submit_free_dispute(ctx, dispute_type, details_cid)
     }
 
+    /// Pledge a bond toward a future dispute (opens the pledge pool on first pledge)
+    pub fn pledge_challenge(
+        ctx: Context<PledgeChallenge>,
+        dispute_type: DisputeType,
+        details_cid: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::pledge_challenge(ctx, dispute_type, details_cid, amount)
+    }
+
+    /// Turn a pledge pool that reached its threshold into a live dispute
+    pub fn activate_dispute(
+        ctx: Context<ActivateDispute>,
+    ) -> Result<()> {
+        instructions::activate_dispute(ctx)
+    }
+
+    /// Convert a pledge into a challenger record on the activated dispute
+    pub fn convert_pledge(
+        ctx: Context<ConvertPledge>,
+    ) -> Result<()> {
+        instructions::convert_pledge(ctx)
+    }
+
+    /// Refund a pledge to its pledger after the pool expires or loses its dispute slot (anyone)
+    pub fn refund_pledge(
+        ctx: Context<RefundPledge>,
+    ) -> Result<()> {
+        instructions::refund_pledge(ctx)
+    }
+
+    /// Add a juror incentive to an open dispute (anyone, paid to jurors by voting power)
+    pub fn boost_juror_pool(
+        ctx: Context<BoostJurorPool>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::boost_juror_pool(ctx, amount)
+    }
+
+    /// Refund a juror incentive after the dispute resolved with no votes
+    pub fn refund_juror_boost(
+        ctx: Context<RefundJurorBoost>,
+    ) -> Result<()> {
+        instructions::refund_juror_boost(ctx)
+    }
+
+    // =========================================================================
+    // Juror Epoch Instructions
+    // =========================================================================
+
+    /// Open a juror epoch with an enrollment window and availability penalty (admin only)
+    pub fn open_juror_epoch(
+        ctx: Context<OpenJurorEpoch>,
+        index: u32,
+        starts_at: i64,
+        ends_at: i64,
+        miss_penalty_bps: u16,
+    ) -> Result<()> {
+        instructions::open_juror_epoch(ctx, index, starts_at, ends_at, miss_penalty_bps)
+    }
+
+    /// Route a share of treasury fees into a juror epoch, or stop routing with no epoch (admin only)
+    pub fn set_fee_epoch(
+        ctx: Context<SetFeeEpoch>,
+        epoch_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_fee_epoch(ctx, epoch_fee_bps)
+    }
+
+    /// Add to a juror epoch's base reward (anyone)
+    pub fn fund_juror_epoch(
+        ctx: Context<FundJurorEpoch>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_juror_epoch(ctx, amount)
+    }
+
+    /// Commit stake to a juror epoch before it starts
+    pub fn enroll_juror_epoch(
+        ctx: Context<EnrollJurorEpoch>,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::enroll_juror_epoch(ctx, stake)
+    }
+
+    /// Assign a dispute to an enrolled epoch juror (admin only)
+    pub fn assign_epoch_dispute(
+        ctx: Context<AssignEpochDispute>,
+    ) -> Result<()> {
+        instructions::assign_epoch_dispute(ctx)
+    }
+
+    /// Mark an epoch assignment served once the juror has voted (permissionless)
+    pub fn record_epoch_service(
+        ctx: Context<RecordEpochService>,
+    ) -> Result<()> {
+        instructions::record_epoch_service(ctx)
+    }
+
+    /// Claim a juror epoch's base reward into the stake balance after the term
+    pub fn claim_epoch_reward(
+        ctx: Context<ClaimEpochReward>,
+    ) -> Result<()> {
+        instructions::claim_epoch_reward(ctx)
+    }
+
+    /// Close a finished juror epoch, sweeping the remainder to the treasury (admin only)
+    pub fn close_juror_epoch(
+        ctx: Context<CloseJurorEpoch>,
+    ) -> Result<()> {
+        instructions::close_juror_epoch(ctx)
+    }
+
+    // =========================================================================
+    // Evidence Instructions
+    // =========================================================================
+
+    /// Append evidence to a dispute (challengers/defenders, first half of voting)
+    pub fn submit_evidence(
+        ctx: Context<SubmitEvidence>,
+        cid: String,
+    ) -> Result<()> {
+        instructions::submit_evidence(ctx, cid)
+    }
+
+    /// Require jurors to acknowledge the evidence before voting (dispute opener, before any votes)
+    pub fn require_evidence_review(
+        ctx: Context<RequireEvidenceReview>,
+        review_delay: i64,
+    ) -> Result<()> {
+        instructions::require_evidence_review(ctx, review_delay)
+    }
+
+    /// Acknowledge a dispute's evidence by its CID hash (voting opens review_delay later)
+    pub fn acknowledge_evidence(
+        ctx: Context<AcknowledgeEvidence>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::acknowledge_evidence(ctx, evidence_hash)
+    }
+
+    /// Close an evidence acknowledgment once the dispute stops taking votes (refunds rent to the juror)
+    pub fn close_evidence_ack(
+        ctx: Context<CloseEvidenceAck>,
+    ) -> Result<()> {
+        instructions::close_evidence_ack(ctx)
+    }
+
     // =========================================================================
     // Appeal Instructions
     // =========================================================================
@@ -190,18 +655,39 @@ pub mod tribunalcraft {
         instructions::submit_appeal(ctx, dispute_type, details_cid, stake_amount)
     }
 
+    /// Stake against a pending restoration (defender side of the appeal)
+    pub fn oppose_restore(
+        ctx: Context<OpposeRestore>,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::oppose_restore(ctx, stake)
+    }
+
     // =========================================================================
     // Voting Instructions
     // =========================================================================
 
     /// Vote on a dispute with stake allocation
+    /// Abstain locks a reduced stake for a flat share of the juror pot and stays out of the tally
     pub fn vote_on_dispute(
         ctx: Context<VoteOnDispute>,
         choice: VoteChoice,
         stake_allocation: u64,
         rationale_cid: String,
+        rationale_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::vote_on_dispute(ctx, choice, stake_allocation, rationale_cid, rationale_hash)
+    }
+
+    /// Vote a slash grade on a graded dispute (0 = none, 1-4 = 25-100% of the defender stake)
+    pub fn vote_graded(
+        ctx: Context<VoteOnDispute>,
+        grade: u8,
+        stake_allocation: u64,
+        rationale_cid: String,
+        rationale_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::vote_on_dispute(ctx, choice, stake_allocation, rationale_cid)
+        instructions::vote_graded(ctx, grade, stake_allocation, rationale_cid, rationale_hash)
     }
 
     /// Vote on an appeal with stake allocation
@@ -212,8 +698,9 @@ pub mod tribunalcraft {
         choice: AppealVoteChoice,
         stake_allocation: u64,
         rationale_cid: String,
+        rationale_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        instructions::vote_on_appeal(ctx, choice, stake_allocation, rationale_cid)
+        instructions::vote_on_appeal(ctx, choice, stake_allocation, rationale_cid, rationale_hash)
     }
 
     /// Add more stake to an existing vote
@@ -224,29 +711,89 @@ pub mod tribunalcraft {
         instructions::add_to_vote(ctx, additional_stake)
     }
 
+    /// Reveal the rationale CID committed with a vote (must match the keccak hash)
+    pub fn reveal_rationale(
+        ctx: Context<RevealRationale>,
+        rationale_cid: String,
+    ) -> Result<()> {
+        instructions::reveal_rationale(ctx, rationale_cid)
+    }
+
+    /// Flip an existing vote before voting ends (voting power penalty applies)
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
+    ) -> Result<()> {
+        instructions::change_vote(ctx)
+    }
+
+    /// Create a dispute's vote statistics PDA (before the first vote)
+    pub fn init_vote_stats(ctx: Context<InitVoteStats>) -> Result<()> {
+        instructions::init_vote_stats(ctx)
+    }
+
     // =========================================================================
     // Resolution Instructions
     // =========================================================================
 
     /// Resolve a dispute after voting period ends
-    pub fn resolve_dispute(
-        ctx: Context<ResolveDispute>,
+    /// A creator resolving a successful appeal can pass their pool to re-bond the subject from it
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
     ) -> Result<()> {
         instructions::resolve_dispute(ctx)
     }
 
-    /// Unlock juror stake after 7-day buffer
+    /// Count a chunk of vote records against the subject's juror weight cap
+    pub fn tally_capped_votes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TallyCappedVotes<'info>>,
+    ) -> Result<()> {
+        instructions::tally_capped_votes(ctx)
+    }
+
+    /// Refund a dispute nobody resolved within DISPUTE_REFUND_TIMEOUT of voting end (permissionless)
+    pub fn timeout_refund(ctx: Context<TimeoutRefund>) -> Result<()> {
+        instructions::timeout_refund(ctx)
+    }
+
+    /// Void a corrupted resolution before any claims (config authority, emergency only)
+    pub fn void_resolution(ctx: Context<VoidResolution>, reason_cid: String) -> Result<()> {
+        instructions::void_resolution(ctx, reason_cid)
+    }
+
+    /// Start paginated resolution: fix the outcome after voting ends
+    pub fn begin_resolution(
+        ctx: Context<BeginResolution>,
+    ) -> Result<()> {
+        instructions::begin_resolution(ctx)
+    }
+
+    /// Process a chunk of [vote_record, juror_account] pairs via remaining_accounts
+    pub fn process_resolution_chunk<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessResolutionChunk<'info>>,
+    ) -> Result<()> {
+        instructions::process_resolution_chunk(ctx)
+    }
+
+    /// Finish paginated resolution: collect fees and update subject status
+    pub fn finalize_resolution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeResolution<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_resolution(ctx)
+    }
+
+    /// Unlock juror stake after 7-day buffer (immediately for timed-out or voided rounds)
     pub fn unlock_juror_stake(
         ctx: Context<UnlockJurorStake>,
     ) -> Result<()> {
         instructions::unlock_juror_stake(ctx)
     }
 
-    /// Claim juror reward for correct vote
+    /// Claim juror reward for correct vote (to the stake balance, or the wallet with the withdrawal slash burned)
     pub fn claim_juror_reward(
         ctx: Context<ClaimJurorReward>,
+        claim_to_wallet: bool,
     ) -> Result<()> {
-        instructions::claim_juror_reward(ctx)
+        instructions::claim_juror_reward(ctx, claim_to_wallet)
     }
 
     /// Claim challenger reward (if dispute upheld)
@@ -256,11 +803,55 @@ pub mod tribunalcraft {
         instructions::claim_challenger_reward(ctx)
     }
 
-    /// Claim defender reward (if dispute dismissed)
+    /// Claim defender reward (if dispute dismissed); claim_to_wallet skips the pool
     pub fn claim_defender_reward(
         ctx: Context<ClaimDefenderReward>,
+        claim_to_wallet: bool,
+    ) -> Result<()> {
+        instructions::claim_defender_reward(ctx, claim_to_wallet)
+    }
+
+    /// Claim defender reward and stake it on another subject you created (plus optional extra stake)
+    pub fn claim_defender_and_bond(
+        ctx: Context<ClaimDefenderAndBond>,
+        extra_stake: u64,
     ) -> Result<()> {
-        instructions::claim_defender_reward(ctx)
+        instructions::claim_defender_and_bond(ctx, extra_stake)
+    }
+
+    /// Claim an opposer's payout after a restoration resolves
+    pub fn claim_opposition_reward(
+        ctx: Context<ClaimOppositionReward>,
+    ) -> Result<()> {
+        instructions::claim_opposition_reward(ctx)
+    }
+
+    /// Preview a juror's claimable reward (simulate; no state change)
+    pub fn preview_claim_juror(
+        ctx: Context<PreviewClaimJuror>,
+    ) -> Result<u64> {
+        instructions::preview_claim_juror(ctx)
+    }
+
+    /// Preview a challenger's claimable payout (simulate; no state change)
+    pub fn preview_claim_challenger(
+        ctx: Context<PreviewClaimChallenger>,
+    ) -> Result<ClaimPayout> {
+        instructions::preview_claim_challenger(ctx)
+    }
+
+    /// Preview a defender's claimable payout (simulate; no state change)
+    pub fn preview_claim_defender(
+        ctx: Context<PreviewClaimDefender>,
+    ) -> Result<ClaimPayout> {
+        instructions::preview_claim_defender(ctx)
+    }
+
+    /// Preview the outcome current tallies resolve to (simulate; no state change)
+    pub fn preview_outcome(
+        ctx: Context<PreviewOutcome>,
+    ) -> Result<ResolutionOutcome> {
+        instructions::preview_outcome(ctx)
     }
 
     /// Close escrow after all claims are complete
@@ -270,4 +861,32 @@ pub mod tribunalcraft {
     ) -> Result<()> {
         instructions::close_escrow(ctx)
     }
+
+    /// Drop expiry queue entries for escrows closed without the queue (anyone)
+    pub fn prune_expiry_queue<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneExpiryQueue<'info>>,
+    ) -> Result<()> {
+        instructions::prune_expiry_queue(ctx)
+    }
+
+    /// Write an immutable outcome attestation for a resolved dispute (permissionless)
+    pub fn mint_resolution_attestation(
+        ctx: Context<MintResolutionAttestation>,
+    ) -> Result<()> {
+        instructions::mint_resolution_attestation(ctx)
+    }
+
+    /// Link an external escrow account to an open dispute (CPI from the escrow program, creator co-signs)
+    pub fn register_dependent_escrow(
+        ctx: Context<RegisterDependentEscrow>,
+    ) -> Result<()> {
+        instructions::register_dependent_escrow(ctx)
+    }
+
+    /// Deliver the attested outcome to the linked escrow's on_tribunal_resolution (permissionless)
+    pub fn notify_dependent_escrow<'info>(
+        ctx: Context<'_, '_, 'info, 'info, NotifyDependentEscrow<'info>>,
+    ) -> Result<()> {
+        instructions::notify_dependent_escrow(ctx)
+    }
 }
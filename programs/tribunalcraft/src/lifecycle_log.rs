@@ -0,0 +1,74 @@
+//! Compact subject lifecycle records written through the SPL noop program
+//!
+//! Anchor events live in program logs, which are truncated on busy
+//! transactions. Critical transitions are also sent as the data of a CPI to
+//! the SPL noop program, so indexers can rebuild them from inner instructions.
+//! The noop account is optional on every instruction that logs; when it is
+//! omitted nothing is written.
+//!
+//! Payload (84 bytes, little-endian, Borsh):
+//! [0] layout version, [1] kind, [2..34] subject, [34..66] dispute (zero = none),
+//! [66] subject status after the transition, [67] dispute outcome,
+//! [68..76] amount (bond, stake or swept lamports), [76..84] unix timestamp
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use crate::state::{SubjectStatus, ResolutionOutcome};
+
+/// SPL noop program
+pub const NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+/// Bumped whenever the payload layout changes
+pub const LIFECYCLE_LOG_VERSION: u8 = 1;
+
+/// Which transition a record describes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleKind {
+    /// Subject created (amount = initial stake)
+    Created,
+    /// Dispute opened against the subject (amount = bond)
+    Disputed,
+    /// Restoration (appeal) filed on an invalidated subject (amount = appeal stake)
+    RestorationFiled,
+    /// Dispute or restoration resolved, including timeouts (amount = bonds + stakes)
+    Resolved,
+    /// Escrow closed and dust swept to treasury (amount = dust)
+    Swept,
+    /// Subject closed with its last dispute (amount = subject lamports returned to the rent recipient)
+    Closed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LifecycleLog {
+    pub kind: LifecycleKind,
+    pub subject: Pubkey,
+    pub dispute: Pubkey,
+    pub status: SubjectStatus,
+    pub outcome: ResolutionOutcome,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+impl LifecycleLog {
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 1 + 8 + 8;
+
+    /// Write the record as noop instruction data (no-op when the noop account was not passed)
+    pub fn write(&self, noop_program: Option<&AccountInfo>) -> Result<()> {
+        let Some(noop_program) = noop_program else {
+            return Ok(());
+        };
+
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.push(LIFECYCLE_LOG_VERSION);
+        self.serialize(&mut data)?;
+
+        let ix = Instruction {
+            program_id: NOOP_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        };
+        invoke(&ix, std::slice::from_ref(noop_program))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Platform-defined category (id + metadata describing it off-chain)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Category {
+    /// Category identifier (0 is reserved for "uncategorized")
+    pub id: u16,
+
+    /// Metadata CID (IPFS/Arweave) - name, description, rules
+    pub metadata_cid: String,
+}
+
+impl Category {
+    pub const MAX_CID_LEN: usize = 64;
+
+    pub const LEN: usize = 2 +  // id
+        4 + Self::MAX_CID_LEN;  // metadata_cid
+}
+
+/// Admin-managed category registry
+/// Lets platforms define their own taxonomy without a program upgrade
+#[account]
+#[derive(Default)]
+pub struct CategoryRegistry {
+    /// Registered categories
+    pub categories: Vec<Category>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CategoryRegistry {
+    pub const MAX_CATEGORIES: usize = 32;
+
+    pub const LEN: usize = 8 +  // discriminator
+        4 + Self::MAX_CATEGORIES * Category::LEN + // categories
+        1;      // bump
+
+    /// Check if a category id is registered
+    pub fn contains(&self, id: u16) -> bool {
+        self.categories.iter().any(|c| c.id == id)
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::DisputeType;
+
+/// Holding PDA for challenge pledges against a subject's next dispute slot
+/// Pledged lamports sit here until activate_dispute moves them into a new
+/// dispute's escrow, or pledgers refund after the window expires
+#[account]
+#[derive(Default)]
+pub struct ChallengePledgePool {
+    /// Subject the pledges target
+    pub subject: Pubkey,
+
+    /// Subject's dispute_count when the pool opened (the dispute PDA index it can activate)
+    pub dispute_index: u32,
+
+    /// Wallet that opened the pool (receives rent when the pool closes)
+    pub opener: Pubkey,
+
+    /// Dispute type proposed by the opener
+    pub dispute_type: DisputeType,
+
+    /// Details CID proposed by the opener (used for every converted challenger record)
+    pub details_cid: String,
+
+    /// Subject's pledge threshold snapshotted at open
+    pub threshold: u64,
+
+    /// Total lamports pledged
+    pub total_pledged: u64,
+
+    /// Number of distinct pledgers
+    pub pledger_count: u16,
+
+    /// Pledges not yet converted or refunded (pool closes at zero)
+    pub pending_pledges: u16,
+
+    /// Pledges can be added and activated until this time
+    pub expires_at: i64,
+
+    /// Dispute created by activate_dispute (default = not activated)
+    pub dispute: Pubkey,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Creation timestamp
+    pub created_at: i64,
+}
+
+impl ChallengePledgePool {
+    pub const MAX_CID_LEN: usize = 64;
+
+    /// Escrow counts challengers in a u8
+    pub const MAX_PLEDGERS: u16 = u8::MAX as u16;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // subject
+        4 +     // dispute_index
+        32 +    // opener
+        1 +     // dispute_type
+        4 + Self::MAX_CID_LEN + // details_cid
+        8 +     // threshold
+        8 +     // total_pledged
+        2 +     // pledger_count
+        2 +     // pending_pledges
+        8 +     // expires_at
+        32 +    // dispute
+        1 +     // bump
+        8;      // created_at
+
+    /// Whether activate_dispute has turned the pledges into a live dispute
+    pub fn is_activated(&self) -> bool {
+        self.dispute != Pubkey::default()
+    }
+
+    /// Whether the pool can no longer activate (window expired or the slot was taken)
+    pub fn is_lapsed(&self, subject_dispute_count: u32, now: i64) -> bool {
+        !self.is_activated() && (now >= self.expires_at || subject_dispute_count != self.dispute_index)
+    }
+}
+
+/// Individual pledger's contribution to a pledge pool
+#[account]
+#[derive(Default)]
+pub struct ChallengePledge {
+    /// Pool this pledge belongs to
+    pub pool: Pubkey,
+
+    /// Pledger's wallet address
+    pub pledger: Pubkey,
+
+    /// Lamports pledged (becomes the challenger bond on conversion)
+    pub amount: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// First pledge timestamp
+    pub pledged_at: i64,
+}
+
+impl ChallengePledge {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // pool
+        32 +    // pledger
+        8 +     // amount
+        1 +     // bump
+        8;      // pledged_at
+}
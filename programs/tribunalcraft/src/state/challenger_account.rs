@@ -28,6 +28,9 @@ pub struct ChallengerAccount {
 
     /// Last dispute timestamp
     pub last_dispute_at: i64,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
 }
 
 impl ChallengerAccount {
@@ -39,7 +42,8 @@ impl ChallengerAccount {
         8 +     // disputes_dismissed
         1 +     // bump
         8 +     // created_at
-        8;      // last_dispute_at
+        8 +     // last_dispute_at
+        1;      // version
 
     /// Calculate minimum bond based on reputation
     /// multiplier = sqrt(0.5 / reputation)
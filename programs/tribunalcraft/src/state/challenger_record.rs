@@ -14,7 +14,7 @@ pub struct ChallengerRecord {
     /// Challenger account PDA
     pub challenger_account: Pubkey,
 
-    /// Bond amount contributed by this challenger
+    /// Bond amount contributed by this challenger (wallet + pledged)
     pub bond: u64,
 
     /// Evidence CID (IPFS hash)
@@ -28,6 +28,15 @@ pub struct ChallengerRecord {
 
     /// Timestamp when this challenger joined
     pub challenged_at: i64,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Part of the bond that came from a converted challenge pledge
+    pub pledged_bond: u64,
+
+    /// Challenger reputation when they first joined the dispute (None = pre-v24 record)
+    pub reputation_snapshot: Option<u16>,
 }
 
 impl ChallengerRecord {
@@ -41,7 +50,10 @@ impl ChallengerRecord {
         4 + Self::MAX_CID_LEN + // details_cid (string with length prefix)
         1 +     // reward_claimed
         1 +     // bump
-        8;      // challenged_at
+        8 +     // challenged_at
+        1 +     // version
+        8 +     // pledged_bond
+        (1 + 2); // reputation_snapshot
 
     /// Calculate challenger's share of reward based on bond weight
     /// reward = total_reward * (this_bond / total_bond)
@@ -51,4 +63,19 @@ impl ChallengerRecord {
         }
         (total_reward as u128 * self.bond as u128 / total_bond as u128) as u64
     }
+
+    /// Reputation the claim's gain/loss is judged at (snapshot, else the challenger's current reputation)
+    pub fn reputation_basis(&self, current: u16) -> u16 {
+        self.reputation_snapshot.unwrap_or(current)
+    }
+
+    /// Split a payout between the wallet and pledge sources in proportion to the bond
+    /// Returns (wallet_amount, pledge_amount)
+    pub fn split_by_source(&self, amount: u64) -> (u64, u64) {
+        if self.bond == 0 {
+            return (amount, 0);
+        }
+        let pledge_amount = (amount as u128 * self.pledged_bond as u128 / self.bond as u128) as u64;
+        (amount - pledge_amount, pledge_amount)
+    }
 }
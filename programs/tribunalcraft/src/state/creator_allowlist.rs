@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Allowlist of wallets permitted to create subjects
+/// Only enforced while ProtocolConfig.subject_creator_gate points at it
+#[account]
+#[derive(Default)]
+pub struct CreatorAllowlist {
+    /// Wallets allowed to create subjects
+    pub creators: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl CreatorAllowlist {
+    pub const MAX_CREATORS: usize = 32;
+
+    pub const LEN: usize = 8 +  // discriminator
+        4 + Self::MAX_CREATORS * 32 + // creators
+        1;      // bump
+
+    /// Check if a wallet is on the allowlist
+    pub fn contains(&self, creator: &Pubkey) -> bool {
+        self.creators.contains(creator)
+    }
+}
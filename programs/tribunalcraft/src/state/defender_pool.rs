@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
+use crate::constants::MAX_BPS;
 
 /// Defender's pool that can back multiple subjects - global per wallet
 #[account]
@@ -30,6 +32,18 @@ pub struct DefenderPool {
 
     /// Last update timestamp
     pub updated_at: i64,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Lamports delegated to the stake pool (not counted in total_stake or available)
+    pub yield_principal: u64,
+
+    /// Stake pool tokens held for this pool by its yield vault
+    pub yield_pool_tokens: u64,
+
+    /// Stake pool the tokens belong to (one at a time; default = none)
+    pub yield_stake_pool: Pubkey,
 }
 
 impl DefenderPool {
@@ -42,14 +56,35 @@ impl DefenderPool {
         4 +     // pending_disputes
         1 +     // bump
         8 +     // created_at
-        8;      // updated_at
+        8 +     // updated_at
+        1 +     // version
+        8 +     // yield_principal
+        8 +     // yield_pool_tokens
+        32;     // yield_stake_pool
+
+    /// Check a delegation keeps the pool within max_yield_bps of its stake
+    /// Only available stake can move; held stake always stays liquid
+    pub fn can_delegate(&self, amount: u64, max_yield_bps: u16) -> bool {
+        let total = self.total_stake as u128 + self.yield_principal as u128;
+        let delegated = self.yield_principal as u128 + amount as u128;
+        amount <= self.available && delegated * MAX_BPS as u128 <= total * max_yield_bps as u128
+    }
+
+    /// Principal covered by `tokens` of the pool's stake pool tokens
+    pub fn principal_for_tokens(&self, tokens: u64) -> u64 {
+        if self.yield_pool_tokens == 0 {
+            return 0;
+        }
+        (self.yield_principal as u128 * tokens as u128 / self.yield_pool_tokens as u128) as u64
+    }
 
     /// Hold stake for a dispute (match mode)
     pub fn hold_stake(&mut self, amount: u64) -> Result<()> {
         require!(self.available >= amount, DefenderPoolError::InsufficientAvailable);
         self.available -= amount;
-        self.held += amount;
-        self.pending_disputes += 1;
+        self.held = self.held.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.pending_disputes = self.pending_disputes.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
         Ok(())
     }
 
@@ -57,7 +92,7 @@ impl DefenderPool {
     pub fn release_stake(&mut self, amount: u64) -> Result<()> {
         require!(self.held >= amount, DefenderPoolError::InsufficientHeld);
         self.held -= amount;
-        self.available += amount;
+        self.available = self.available.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
         self.pending_disputes = self.pending_disputes.saturating_sub(1);
         Ok(())
     }
@@ -66,7 +101,7 @@ impl DefenderPool {
     pub fn slash_stake(&mut self, amount: u64) -> Result<()> {
         require!(self.held >= amount, DefenderPoolError::InsufficientHeld);
         self.held -= amount;
-        self.total_stake -= amount;
+        self.total_stake = self.total_stake.checked_sub(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
         self.pending_disputes = self.pending_disputes.saturating_sub(1);
         Ok(())
     }
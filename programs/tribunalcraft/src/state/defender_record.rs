@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
 
 /// Individual defender's contribution to backing a subject
 /// Supports cumulative staking where multiple defenders back a subject
@@ -11,7 +12,7 @@ pub struct DefenderRecord {
     /// Defender's wallet address
     pub defender: Pubkey,
 
-    /// Amount staked to back the subject
+    /// Amount staked directly from the defender's wallet
     pub stake: u64,
 
     /// Whether reward has been claimed
@@ -22,6 +23,24 @@ pub struct DefenderRecord {
 
     /// Timestamp when this defender joined
     pub staked_at: i64,
+
+    // Fields below were added after the initial release (old accounts are a prefix)
+
+    /// Amount pulled from the defender's pool into the current dispute's escrow
+    /// Tracked separately so claims return it to the pool, not the wallet
+    pub pool_stake: u64,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Pool that pool_stake is paid back to (default = wallet only, no pool stake)
+    pub payout_pool: Pubkey,
+
+    /// PoolDelegate grant charged for pool stake a delegate committed via defend_from_pool
+    pub pool_delegate: Pubkey,
+
+    /// Amount charged to pool_delegate's cap, given back when the defender claim settles
+    pub delegate_committed: u64,
 }
 
 impl DefenderRecord {
@@ -31,7 +50,41 @@ impl DefenderRecord {
         8 +     // stake
         1 +     // reward_claimed
         1 +     // bump
-        8;      // staked_at
+        8 +     // staked_at
+        8 +     // pool_stake
+        1 +     // version
+        32 +    // payout_pool
+        32 +    // pool_delegate
+        8;      // delegate_committed
+
+    /// Credit stake pulled from a pool; all pool stake in a dispute must come from one pool
+    pub fn credit_pool_stake(&mut self, pool: Pubkey, amount: u64) -> Result<()> {
+        require!(
+            self.pool_stake == 0 || self.payout_pool == pool,
+            TribunalCraftError::PoolStakeFromOtherPool
+        );
+        self.pool_stake = self.pool_stake.checked_add(amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.payout_pool = pool;
+        Ok(())
+    }
+
+    /// Charge defend_from_pool stake to a delegate grant; one delegate per dispute
+    pub fn charge_delegate(&mut self, pool_delegate: Pubkey, amount: u64) -> Result<()> {
+        require!(
+            self.delegate_committed == 0 || self.pool_delegate == pool_delegate,
+            TribunalCraftError::DelegateCommitConflict
+        );
+        self.delegate_committed = self.delegate_committed.checked_add(amount)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.pool_delegate = pool_delegate;
+        Ok(())
+    }
+
+    /// Total stake at risk from both sources (wallet + pool)
+    pub fn total_stake(&self) -> u64 {
+        self.stake.saturating_add(self.pool_stake)
+    }
 
     /// Calculate defender's share of reward based on stake weight
     /// reward = total_reward * (this_stake / total_stake)
@@ -39,6 +92,17 @@ impl DefenderRecord {
         if total_stake == 0 {
             return 0;
         }
-        (total_reward as u128 * self.stake as u128 / total_stake as u128) as u64
+        (total_reward as u128 * self.total_stake() as u128 / total_stake as u128) as u64
+    }
+
+    /// Split a payout between wallet and pool in proportion to where the stake came from
+    /// Returns (wallet_amount, pool_amount)
+    pub fn split_by_source(&self, amount: u64) -> (u64, u64) {
+        let total = self.total_stake();
+        if total == 0 {
+            return (amount, 0);
+        }
+        let pool_amount = (amount as u128 * self.pool_stake as u128 / total as u128) as u64;
+        (amount - pool_amount, pool_amount)
     }
 }
@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
+use crate::state::{TiePolicy, DisputeEscrow};
+use crate::constants::{
+    JUROR_CLAIM_DEADLINE, MAX_BPS, RESTORATION_NARROW_MARGIN_BPS, DISPUTE_REFUND_TIMEOUT,
+    RESOLUTION_VOID_WINDOW, GRADE_STEPS, GRADE_STEP_BPS,
+};
 
 /// Dispute status
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -6,6 +12,7 @@ pub enum DisputeStatus {
     #[default]
     Pending,
     Resolved,
+    Resolving,  // Outcome fixed, paginated bookkeeping in progress (appended to keep Resolved's encoding)
 }
 
 /// Resolution outcome
@@ -32,6 +39,27 @@ pub enum DisputeType {
     DamagesClaim,     // Claim for damages
 }
 
+impl DisputeType {
+    /// Number of variants (size of Dispute::type_bonds)
+    pub const COUNT: usize = 8;
+
+    pub const ALL: [DisputeType; Self::COUNT] = [
+        DisputeType::Other,
+        DisputeType::Breach,
+        DisputeType::Fraud,
+        DisputeType::QualityDispute,
+        DisputeType::NonDelivery,
+        DisputeType::Misrepresentation,
+        DisputeType::PolicyViolation,
+        DisputeType::DamagesClaim,
+    ];
+
+    /// Slot in Dispute::type_bonds
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// Dispute (supports cumulative challengers)
 #[account]
 #[derive(Default)]
@@ -66,7 +94,7 @@ pub struct Dispute {
     /// Cumulative voting power for "ForDefender" votes
     pub votes_against_weight: u64,
 
-    /// Number of jurors who voted
+    /// Number of jurors who voted (including abstentions)
     pub vote_count: u16,
 
     /// Whether voting has started (match mode waits for matching)
@@ -119,6 +147,121 @@ pub struct Dispute {
 
     /// Stake posted by appellant (for appeals only)
     pub appeal_stake: u64,
+
+    // Fields below were added after the initial release (old accounts are a prefix)
+
+    /// Subject's category id at dispute creation (0 = uncategorized)
+    pub category_id: u16,
+
+    /// Vote records whose reputation was processed by resolution chunks
+    pub reputation_processed_count: u16,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Voting was already reopened once under TiePolicy::ExtendVoting
+    pub tie_extended: bool,
+
+    /// Number of jurors who have claimed their reward (including zero rewards)
+    pub jurors_claimed: u16,
+
+    /// A VoteStats PDA exists and has counted every vote so far (cleared by a vote that skips it)
+    pub has_vote_stats: bool,
+
+    /// Treasury fee held back in escrow for juror rebates (swept to treasury on close)
+    pub rebate_reserve: u64,
+
+    /// Highest tier rebate (bps) when the reserve was set aside - caps later tier changes
+    pub rebate_bps: u16,
+
+    // =========================================================================
+    // Capped weight tally (subjects with max_juror_weight_bps)
+    // =========================================================================
+
+    /// Per-juror cap (bps of total weight) the capped tally is using (0 = uncapped)
+    pub weight_cap_bps: u16,
+
+    /// Capped voting power for "ForChallenger" votes
+    pub capped_favor_weight: u64,
+
+    /// Capped voting power for "ForDefender" votes
+    pub capped_against_weight: u64,
+
+    /// Vote records counted into the capped tally this round
+    pub capped_votes_tallied: u16,
+
+    /// Bumped when a tie extension reopens voting (the capped tally starts over)
+    pub tally_round: u8,
+
+    /// Never resolved and refunded by timeout_refund (no outcome, no fees, no juror rewards)
+    pub timed_out: bool,
+
+    /// Resolution voided by the config authority (see ResolutionVoid)
+    /// Refunds like timed_out; reputation applied by resolution chunks is reversed per vote record
+    pub voided: bool,
+
+    /// Juror incentive deposited by boost_juror_pool (held in escrow, paid to jurors by voting power)
+    pub juror_incentive: u64,
+
+    /// Juror incentive refunded to boosters after a NoParticipation outcome
+    pub incentive_refunded: u64,
+
+    /// Appeals only: total staked against the restoration by oppose_restore
+    pub opposition_stake: u64,
+
+    /// Appeals only: number of distinct opposers
+    pub opposer_count: u16,
+
+    /// Decided by graded votes (copied from Subject::graded_verdicts at creation)
+    pub graded: bool,
+
+    /// Graded disputes: voting power per grade (index = grade)
+    pub grade_weights: [u64; GRADE_STEPS],
+
+    /// Incident shared by disputes opened together by create_disputes_batch (default = none)
+    pub incident_id: Pubkey,
+
+    /// External program whose account is released on this dispute's outcome (default = none)
+    pub dependent_program: Pubkey,
+
+    /// External escrow account linked by register_dependent_escrow
+    pub dependent_account: Pubkey,
+
+    /// notify_dependent_escrow has delivered the outcome callback
+    pub dependent_notified: bool,
+
+    /// Party rounding residue has been checked after the final party claim
+    pub dust_swept: bool,
+
+    /// Rounding residue credited to the juror incentive by the sweep
+    pub dust_credited: u64,
+
+    /// Jurors who abstained (included in vote_count, never in the weight tallies)
+    pub abstain_count: u16,
+
+    /// Seconds a juror must wait after acknowledge_evidence before voting (0 = no review required)
+    pub review_delay: i64,
+
+    /// keccak256 of the opener's evidence CID jurors must acknowledge
+    pub evidence_hash: [u8; 32],
+
+    /// Challenger bond per dispute type, indexed by DisputeType::index (all zero before v35)
+    pub type_bonds: [u64; DisputeType::COUNT],
+
+    /// Resolver fee paid out of the platform fee (the rest of platform_fee_paid went to treasury and the fee epoch)
+    pub resolver_fee_paid: u64,
+
+    /// Pool a restored subject was linked to at resolution (unlinked again if voided)
+    pub rebond_pool: Pubkey,
+
+    /// When the last expected party claim landed (0 = still pending)
+    pub claims_completed_at: i64,
+
+    /// Share of the platform fee routed into the config's fee epoch (see ProtocolConfig::epoch_fee)
+    pub epoch_fee_paid: u64,
+
+    /// Bond a restoration rebond moved from rebond_pool into the subject (returned if voided)
+    pub rebond_amount: u64,
 }
 
 impl Dispute {
@@ -146,11 +289,71 @@ impl Dispute {
         2 +     // challengers_claimed
         2 +     // defenders_claimed
         1 +     // is_appeal
-        8;      // appeal_stake
+        8 +     // appeal_stake
+        2 +     // category_id
+        2 +     // reputation_processed_count
+        1 +     // version
+        1 +     // tie_extended
+        2 +     // jurors_claimed
+        1 +     // has_vote_stats
+        8 +     // rebate_reserve
+        2 +     // rebate_bps
+        2 +     // weight_cap_bps
+        8 +     // capped_favor_weight
+        8 +     // capped_against_weight
+        2 +     // capped_votes_tallied
+        1 +     // tally_round
+        1 +     // timed_out
+        1 +     // voided
+        8 +     // juror_incentive
+        8 +     // incentive_refunded
+        8 +     // opposition_stake
+        2 +     // opposer_count
+        1 +     // graded
+        8 * GRADE_STEPS + // grade_weights
+        32 +    // incident_id
+        32 +    // dependent_program
+        32 +    // dependent_account
+        1 +     // dependent_notified
+        1 +     // dust_swept
+        8 +     // dust_credited
+        2 +     // abstain_count
+        8 +     // review_delay
+        32 +    // evidence_hash
+        8 * DisputeType::COUNT + // type_bonds
+        8 +     // resolver_fee_paid
+        32 +    // rebond_pool
+        8 +     // claims_completed_at
+        8 +     // epoch_fee_paid
+        8;      // rebond_amount
+
+    /// Count a challenger bond toward its dispute type
+    pub fn add_type_bond(&mut self, dispute_type: DisputeType, bond: u64) -> Result<()> {
+        let slot = &mut self.type_bonds[dispute_type.index()];
+        *slot = slot.checked_add(bond).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Dispute type backed by the most bond (ties and unbonded disputes keep the original type)
+    pub fn dominant_type(&self) -> DisputeType {
+        DisputeType::ALL.iter().fold(self.dispute_type, |best, &t| {
+            if self.type_bonds[t.index()] > self.type_bonds[best.index()] { t } else { best }
+        })
+    }
+
+    /// Check if jurors must acknowledge the evidence before voting
+    pub fn requires_review(&self) -> bool {
+        self.review_delay > 0
+    }
+
+    /// Check if an external escrow is linked to this dispute
+    pub fn has_dependent_escrow(&self) -> bool {
+        self.dependent_account != Pubkey::default()
+    }
 
     /// Total stake held from all sources (pool + direct)
     pub fn total_stake_held(&self) -> u64 {
-        self.stake_held + self.direct_stake_held
+        self.stake_held.saturating_add(self.direct_stake_held)
     }
 
     /// Check if matching condition is met (stake >= bond)
@@ -170,23 +373,220 @@ impl Dispute {
         self.voting_started && current_time >= self.voting_ends_at
     }
 
+    /// Check if the last cutoff_bps of the voting window has started (no new challenger bond)
+    pub fn in_late_join_window(&self, current_time: i64, cutoff_bps: u16) -> bool {
+        if cutoff_bps == 0 || !self.voting_started {
+            return false;
+        }
+        let window = self.voting_ends_at.saturating_sub(self.voting_starts_at).max(0);
+        let cutoff = (window as i128 * cutoff_bps as i128 / MAX_BPS as i128) as i64;
+        current_time >= self.voting_ends_at.saturating_sub(cutoff)
+    }
+
     /// Check if voting is active (started but not ended)
     pub fn is_voting_active(&self, current_time: i64) -> bool {
         self.voting_started && current_time < self.voting_ends_at
     }
 
+    /// Add voting power to a tally (favor = ForChallenger / ForRestoration)
+    pub fn add_vote_weight(&mut self, favor: bool, power: u64) -> Result<()> {
+        let tally = if favor { &mut self.votes_favor_weight } else { &mut self.votes_against_weight };
+        *tally = tally.checked_add(power).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Remove voting power from a tally (favor = ForChallenger / ForRestoration)
+    pub fn remove_vote_weight(&mut self, favor: bool, power: u64) -> Result<()> {
+        let tally = if favor { &mut self.votes_favor_weight } else { &mut self.votes_against_weight };
+        *tally = tally.checked_sub(power).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Add voting power to a grade's tally (graded disputes)
+    pub fn add_grade_weight(&mut self, grade: u8, power: u64) -> Result<()> {
+        let tally = &mut self.grade_weights[grade as usize];
+        *tally = tally.checked_add(power).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Stake-weighted median grade (lower median; 0 if nobody voted)
+    pub fn median_grade(&self) -> u8 {
+        let total: u128 = self.grade_weights.iter().map(|w| *w as u128).sum();
+        let mut cumulative = 0u128;
+        for (grade, weight) in self.grade_weights.iter().enumerate() {
+            cumulative += *weight as u128;
+            if total > 0 && cumulative * 2 >= total {
+                return grade as u8;
+            }
+        }
+        0
+    }
+
+    /// Share of the defender stake a ChallengerWins outcome slashes (bps)
+    /// Binary disputes slash everything; graded disputes slash the median grade
+    pub fn slash_bps(&self) -> u16 {
+        if self.graded {
+            self.median_grade() as u16 * GRADE_STEP_BPS
+        } else {
+            MAX_BPS
+        }
+    }
+
+    /// Check if evidence can still be submitted (first half of voting period)
+    pub fn is_evidence_window_open(&self, current_time: i64) -> bool {
+        let half_period = (self.voting_ends_at - self.voting_starts_at) / 2;
+        self.is_voting_active(current_time) && current_time < self.voting_starts_at + half_period
+    }
+
+    /// Check if votes were cast and both sides hold equal weight
+    pub fn is_tied(&self) -> bool {
+        let (favor, against) = self.counted_weights();
+        favor > 0 && favor == against
+    }
+
+    /// Tallies the outcome is decided on: capped weights once every vote is tallied, raw otherwise
+    pub fn counted_weights(&self) -> (u64, u64) {
+        if self.weight_cap_bps > 0 && self.capped_votes_tallied >= self.vote_count {
+            (self.capped_favor_weight, self.capped_against_weight)
+        } else {
+            (self.votes_favor_weight, self.votes_against_weight)
+        }
+    }
+
+    /// Per-juror weight limit for the capped tally (share of the final raw total)
+    pub fn juror_weight_limit(&self) -> u64 {
+        let total = self.votes_favor_weight as u128 + self.votes_against_weight as u128;
+        (total * self.weight_cap_bps as u128 / MAX_BPS as u128) as u64
+    }
+
+    /// Subjects with a juror weight cap resolve only after tally_capped_votes counted every vote
+    pub fn check_weight_tally(&self, max_juror_weight_bps: u16) -> Result<()> {
+        if max_juror_weight_bps > 0 {
+            require!(
+                self.weight_cap_bps == max_juror_weight_bps && self.capped_votes_tallied >= self.vote_count,
+                TribunalCraftError::WeightTallyIncomplete
+            );
+        }
+        Ok(())
+    }
+
+    /// Check if this is an appeal decided within RESTORATION_NARROW_MARGIN_BPS
+    pub fn is_narrow_restoration(&self) -> bool {
+        let total = self.votes_favor_weight as u128 + self.votes_against_weight as u128;
+        let winning = self.votes_favor_weight.max(self.votes_against_weight) as u128;
+        self.is_appeal
+            && total > 0
+            && winning * (MAX_BPS as u128) < RESTORATION_NARROW_MARGIN_BPS as u128 * total
+    }
+
+    /// Stamp the moment every expected party claim is in (first time only)
+    pub fn note_party_claim(&mut self, escrow: &DisputeEscrow, now: i64) {
+        if self.claims_completed_at == 0 && escrow.all_claims_complete() {
+            self.claims_completed_at = now;
+        }
+    }
+
+    /// Age used to scale the sweep bounty: counted from when the escrow became sweepable,
+    /// i.e. the later of the juror claim deadline and the last party claim
+    pub fn sweep_age(&self, now: i64) -> i64 {
+        let sweepable_at = self.resolved_at
+            .saturating_add(JUROR_CLAIM_DEADLINE)
+            .max(self.claims_completed_at);
+        now.saturating_sub(sweepable_at)
+    }
+
+    /// Check if the claim window before an escrow sweep is still open (JUROR_CLAIM_DEADLINE after resolution)
+    pub fn is_claim_window_open(&self, current_time: i64) -> bool {
+        current_time < self.resolved_at.saturating_add(JUROR_CLAIM_DEADLINE)
+    }
+
+    /// Check if jurors can still claim rewards that an escrow sweep would take
+    /// Free cases pay no juror rewards; after JUROR_CLAIM_DEADLINE unclaimed rewards are forfeit
+    pub fn has_pending_juror_claims(&self, free_case: bool, current_time: i64) -> bool {
+        !free_case
+            && !self.is_refund_only()
+            && self.jurors_claimed < self.vote_count
+            && self.is_claim_window_open(current_time)
+    }
+
+    /// Timed out or voided: bonds and stakes return in full, no fees or juror rewards
+    pub fn is_refund_only(&self) -> bool {
+        self.timed_out || self.voided
+    }
+
+    /// Check if the config authority can still void this resolution
+    /// Only within RESOLUTION_VOID_WINDOW and before anyone has claimed
+    pub fn can_void(&self, current_time: i64) -> bool {
+        self.status == DisputeStatus::Resolved
+            && !self.is_refund_only()
+            && current_time < self.resolved_at.saturating_add(RESOLUTION_VOID_WINDOW)
+            && self.jurors_claimed == 0
+            && self.challengers_claimed == 0
+            && self.defenders_claimed == 0
+    }
+
+    /// Check if the dispute sat unresolved long enough for timeout_refund
+    pub fn can_timeout_refund(&self, current_time: i64) -> bool {
+        current_time >= self.voting_ends_at.saturating_add(DISPUTE_REFUND_TIMEOUT)
+    }
+
+    /// Juror incentive goes back to boosters when no juror can earn it
+    pub fn incentive_refundable(&self) -> bool {
+        self.status == DisputeStatus::Resolved && self.outcome == ResolutionOutcome::NoParticipation
+    }
+
+    /// Check if boosters can still refund incentive that an escrow sweep would take
+    /// Boosters get the same JUROR_CLAIM_DEADLINE as jurors
+    pub fn has_pending_boost_refunds(&self, current_time: i64) -> bool {
+        self.incentive_refundable()
+            && self.incentive_refunded < self.juror_incentive
+            && self.is_claim_window_open(current_time)
+    }
+
+    /// Check if resolution should reopen voting instead of settling a tie
+    /// Graded disputes settle on the median and never tie
+    pub fn should_extend_tie(&self, tie_policy: TiePolicy) -> bool {
+        tie_policy == TiePolicy::ExtendVoting && !self.graded && !self.tie_extended && self.is_tied()
+    }
+
+    /// Reopen voting once after a tie
+    /// New votes change the total, so any capped tally starts over
+    pub fn extend_for_tie(&mut self, current_time: i64, extension: i64) {
+        self.voting_ends_at = current_time.saturating_add(extension);
+        self.tie_extended = true;
+        self.capped_favor_weight = 0;
+        self.capped_against_weight = 0;
+        self.capped_votes_tallied = 0;
+        self.tally_round = self.tally_round.saturating_add(1);
+    }
+
     /// Determine outcome based on votes
-    pub fn determine_outcome(&self) -> ResolutionOutcome {
-        let total_power = self.votes_favor_weight + self.votes_against_weight;
+    /// Ties follow the subject's tie policy; an already-extended tie goes to the defender
+    /// Graded disputes: a non-zero median slash is a ChallengerWins (see slash_bps)
+    pub fn determine_outcome(&self, tie_policy: TiePolicy) -> ResolutionOutcome {
+        let (favor, against) = self.counted_weights();
+        let total_power = favor.saturating_add(against);
+
+        if self.graded && total_power > 0 {
+            return if self.median_grade() > 0 {
+                ResolutionOutcome::ChallengerWins
+            } else {
+                ResolutionOutcome::DefenderWins
+            };
+        }
 
         if total_power == 0 {
             // No votes cast
             ResolutionOutcome::NoParticipation
-        } else if self.votes_favor_weight > total_power / 2 {
+        } else if favor > against {
             // Majority voted for challenger (>50%)
             ResolutionOutcome::ChallengerWins
+        } else if favor < against {
+            // Majority voted for defender
+            ResolutionOutcome::DefenderWins
+        } else if tie_policy == TiePolicy::ChallengerWins {
+            ResolutionOutcome::ChallengerWins
         } else {
-            // Majority voted for defender or tied
             ResolutionOutcome::DefenderWins
         }
     }
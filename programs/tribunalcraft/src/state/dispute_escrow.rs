@@ -1,8 +1,15 @@
 use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
 
 /// DisputeEscrow holds all funds for a single dispute.
 /// One PDA per dispute - consolidates bonds and stakes in one place.
-#[account]
+///
+/// Zero-copy: resolve and every claim touch the escrow, so it is read in place
+/// instead of being Borsh-deserialized on each instruction. Fields keep their
+/// original order; explicit padding after the one-byte fields aligns created_at.
+/// That padding makes the account 3 bytes larger than the Borsh layout (136 vs 133);
+/// migrate_account grows older escrows. The compute saving has not been benchmarked.
+#[account(zero_copy)]
 pub struct DisputeEscrow {
     /// Associated dispute account
     pub dispute: Pubkey,
@@ -37,6 +44,10 @@ pub struct DisputeEscrow {
 
     /// PDA bump
     pub bump: u8,
+    /// Account layout version (0 = created before versioning)
+    pub version: u8,
+    /// Explicit padding to 8-byte alignment
+    pub _padding: [u8; 2],
     /// Creation timestamp
     pub created_at: i64,
 }
@@ -56,6 +67,8 @@ impl DisputeEscrow {
         + 1   // expected_challengers
         + 1   // expected_defenders
         + 1   // bump
+        + 1   // version
+        + 2   // _padding
         + 8;  // created_at
 
     /// Calculate current balance in escrow (lamports held - lamports paid out)
@@ -75,34 +88,70 @@ impl DisputeEscrow {
     }
 
     /// Add bond to escrow (called when challenger joins)
-    pub fn add_bond(&mut self, amount: u64) {
-        self.total_bonds = self.total_bonds.saturating_add(amount);
+    pub fn add_bond(&mut self, amount: u64) -> Result<()> {
+        self.total_bonds = checked_add(self.total_bonds, amount)?;
+        Ok(())
     }
 
     /// Add stake to escrow (called when stakes transferred from subject/pool)
-    pub fn add_stake(&mut self, amount: u64) {
-        self.total_stakes = self.total_stakes.saturating_add(amount);
+    pub fn add_stake(&mut self, amount: u64) -> Result<()> {
+        self.total_stakes = checked_add(self.total_stakes, amount)?;
+        Ok(())
     }
 
-    /// Record bond claimed by challenger
-    pub fn record_bond_claim(&mut self, amount: u64) {
-        self.bonds_claimed = self.bonds_claimed.saturating_add(amount);
-        self.challengers_claimed = self.challengers_claimed.saturating_add(1);
+    /// Record lamports paid out of the bond bucket (amount only, see count_challenger_claim)
+    pub fn record_bond_claim(&mut self, amount: u64) -> Result<()> {
+        self.bonds_claimed = checked_add(self.bonds_claimed, amount)?;
+        Ok(())
     }
 
-    /// Record stake claimed by defender
-    pub fn record_stake_claim(&mut self, amount: u64) {
-        self.stakes_claimed = self.stakes_claimed.saturating_add(amount);
-        self.defenders_claimed = self.defenders_claimed.saturating_add(1);
+    /// Record lamports paid out of the stake bucket (amount only, see count_defender_claim)
+    pub fn record_stake_claim(&mut self, amount: u64) -> Result<()> {
+        self.stakes_claimed = checked_add(self.stakes_claimed, amount)?;
+        Ok(())
+    }
+
+    /// Expect one more challenger-side claimant
+    pub fn expect_challenger(&mut self) -> Result<()> {
+        self.expected_challengers = self.expected_challengers.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Expect one more defender-side claimant (direct stakers, pool owners and opposers)
+    pub fn expect_defender(&mut self) -> Result<()> {
+        self.expected_defenders = self.expected_defenders.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Count one finished challenger-side claim (once per claimant)
+    pub fn count_challenger_claim(&mut self) -> Result<()> {
+        self.challengers_claimed = self.challengers_claimed.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Count one finished defender-side claim (once per claimant)
+    pub fn count_defender_claim(&mut self) -> Result<()> {
+        self.defenders_claimed = self.defenders_claimed.checked_add(1)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
     }
 
     /// Record juror reward paid
-    pub fn record_juror_reward(&mut self, amount: u64) {
-        self.juror_rewards_paid = self.juror_rewards_paid.saturating_add(amount);
+    pub fn record_juror_reward(&mut self, amount: u64) -> Result<()> {
+        self.juror_rewards_paid = checked_add(self.juror_rewards_paid, amount)?;
+        Ok(())
     }
 
     /// Record platform fee paid
-    pub fn record_platform_fee(&mut self, amount: u64) {
-        self.platform_fee_paid = self.platform_fee_paid.saturating_add(amount);
+    pub fn record_platform_fee(&mut self, amount: u64) -> Result<()> {
+        self.platform_fee_paid = checked_add(self.platform_fee_paid, amount)?;
+        Ok(())
     }
 }
+
+fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or(TribunalCraftError::ArithmeticOverflow.into())
+}
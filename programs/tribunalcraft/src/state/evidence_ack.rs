@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Juror's acknowledgment of a dispute's evidence (one PDA per dispute per juror)
+/// Required before voting when the dispute opener set a review delay
+/// Closed by the juror via close_evidence_ack once the dispute stops taking votes
+#[account]
+#[derive(Default)]
+pub struct EvidenceAck {
+    /// The dispute whose evidence was acknowledged
+    pub dispute: Pubkey,
+
+    /// Juror's wallet address
+    pub juror: Pubkey,
+
+    /// Acknowledgment timestamp
+    pub acknowledged_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl EvidenceAck {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        32 +    // juror
+        8 +     // acknowledged_at
+        1;      // bump
+
+    /// Check if the review delay has passed since the acknowledgment
+    pub fn is_reviewed(&self, review_delay: i64, current_time: i64) -> bool {
+        current_time >= self.acknowledged_at.saturating_add(review_delay)
+    }
+}
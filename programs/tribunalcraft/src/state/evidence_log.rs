@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+/// Which side of the dispute submitted evidence
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum EvidenceSide {
+    #[default]
+    Challenger,
+    Defender,
+}
+
+/// Single evidence entry appended during the voting period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct EvidenceEntry {
+    /// Wallet that submitted the evidence
+    pub submitter: Pubkey,
+
+    /// Side the submitter is on
+    pub side: EvidenceSide,
+
+    /// Evidence CID (IPFS/Arweave)
+    pub cid: String,
+
+    /// Submission timestamp
+    pub submitted_at: i64,
+}
+
+impl EvidenceEntry {
+    pub const MAX_CID_LEN: usize = 64;
+
+    pub const LEN: usize = 32 + // submitter
+        1 +     // side
+        4 + Self::MAX_CID_LEN + // cid
+        8;      // submitted_at
+}
+
+/// Append-only evidence log for a dispute (one PDA per dispute)
+#[account]
+#[derive(Default)]
+pub struct EvidenceLog {
+    /// The dispute this log belongs to
+    pub dispute: Pubkey,
+
+    /// Evidence entries in submission order
+    pub entries: Vec<EvidenceEntry>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl EvidenceLog {
+    pub const MAX_ENTRIES: usize = 16;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        4 + Self::MAX_ENTRIES * EvidenceEntry::LEN + // entries
+        1;      // bump
+
+    /// Check if the log has room for another entry
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= Self::MAX_ENTRIES
+    }
+}
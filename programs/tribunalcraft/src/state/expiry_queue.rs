@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+/// A resolved round whose escrow is still open
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct ExpiryEntry {
+    /// Resolved dispute (close_escrow takes it with its subject)
+    pub dispute: Pubkey,
+
+    /// The dispute's escrow PDA
+    pub escrow: Pubkey,
+
+    /// When close_escrow stops waiting for juror claims and boost refunds (resolved_at + JUROR_CLAIM_DEADLINE)
+    /// The escrow can be swept earlier once every claim is in
+    pub sweep_after: i64,
+}
+
+/// Per-config list of resolved rounds with open escrows, for keepers
+/// Entries are added at resolution and removed when the escrow is closed, so keepers
+/// find claimable and sweepable rounds without scanning every escrow. Like ProtocolStats
+/// it never blocks an instruction: a full queue skips the entry and counts it in `dropped`
+#[account]
+#[derive(Default)]
+pub struct ExpiryQueue {
+    /// Open rounds, unordered (keepers sort by sweep_after)
+    pub entries: Vec<ExpiryEntry>,
+
+    /// Rounds not tracked because the queue was full
+    pub dropped: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// Config instance whose rounds this queue tracks (PDA seed)
+    pub config: Pubkey,
+}
+
+impl ExpiryQueue {
+    pub const MAX_ENTRIES: usize = 64;
+
+    pub const LEN: usize = 8 +  // discriminator
+        4 + Self::MAX_ENTRIES * (32 + 32 + 8) + // entries
+        8 +     // dropped
+        1 +     // bump
+        8 +     // updated_at
+        32;     // config
+
+    /// Track a round; returns false (and counts it as dropped) when the queue is full
+    pub fn push(&mut self, entry: ExpiryEntry, now: i64) -> bool {
+        self.updated_at = now;
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.dropped = self.dropped.saturating_add(1);
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+
+    /// Stop tracking a round by its escrow; returns whether it was listed
+    pub fn remove(&mut self, escrow: &Pubkey, now: i64) -> bool {
+        match self.entries.iter().position(|entry| entry.escrow == *escrow) {
+            Some(index) => {
+                self.entries.swap_remove(index);
+                self.updated_at = now;
+                true
+            }
+            None => false,
+        }
+    }
+}
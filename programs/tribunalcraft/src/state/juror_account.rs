@@ -1,5 +1,20 @@
 use anchor_lang::prelude::*;
 use crate::errors::TribunalCraftError;
+use crate::constants::{FRESH_STAKE_WEIGHT_BPS, MAX_BPS};
+
+/// Stake deposited at one time (for stake-age weighting)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct StakeTranche {
+    /// Remaining amount from this deposit
+    pub amount: u64,
+    /// Deposit timestamp
+    pub deposited_at: i64,
+}
+
+impl StakeTranche {
+    pub const LEN: usize = 8 +  // amount
+        8;      // deposited_at
+}
 
 /// Juror (arbiter) account - global per wallet
 ///
@@ -7,8 +22,13 @@ use crate::errors::TribunalCraftError;
 /// - `total_stake`: Total SOL held in this PDA (actual lamports)
 /// - `available_stake`: SOL available to vote or withdraw
 /// - Held (locked): `total_stake - available_stake` (locked in active disputes)
+/// - `locked_stake` / `active_votes`: running summary of vote locks (locked_stake == held)
 ///
 /// SOL only transfers on deposit/withdraw. Voting is accounting only.
+///
+/// Stake Age:
+/// - Deposits are tracked as tranches (oldest first) so fresh stake votes with less power
+/// - Stake not covered by tranches (rewards, pre-tranche stake, merged-out deposits) is mature
 #[account]
 #[derive(Default)]
 pub struct JurorAccount {
@@ -41,9 +61,33 @@ pub struct JurorAccount {
 
     /// Last activity timestamp
     pub last_vote_at: i64,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Recent deposits, oldest first (untracked remainder of total_stake is mature)
+    pub tranches: Vec<StakeTranche>,
+
+    /// Stake locked across unreleased votes (always total_stake - available_stake)
+    pub locked_stake: u64,
+
+    /// Votes whose stake has not been unlocked yet
+    pub active_votes: u16,
+
+    /// Wallet that can receive claimed rewards instead of the stake balance (default = none)
+    /// Rewards sent there take the withdrawal slash, burned like a wallet claim's
+    pub beneficiary: Pubkey,
+
+    /// Stake committed to a juror epoch (see EpochEnrollment)
+    pub epoch_commitment: u64,
+
+    /// The commitment holds until this time (end of the epoch)
+    pub committed_until: i64,
 }
 
 impl JurorAccount {
+    pub const MAX_STAKE_TRANCHES: usize = 8;
+
     pub const LEN: usize = 8 +  // discriminator
         32 +    // juror
         8 +     // total_stake
@@ -54,50 +98,147 @@ impl JurorAccount {
         1 +     // is_active
         1 +     // bump
         8 +     // joined_at
-        8;      // last_vote_at
+        8 +     // last_vote_at
+        1 +     // version
+        4 + Self::MAX_STAKE_TRANCHES * StakeTranche::LEN + // tranches
+        8 +     // locked_stake
+        2 +     // active_votes
+        32 +    // beneficiary
+        8 +     // epoch_commitment
+        8;      // committed_until
 
     /// Get currently held (locked) stake
     pub fn held_stake(&self) -> u64 {
         self.total_stake.saturating_sub(self.available_stake)
     }
 
+    /// Check if stake is committed to a running juror epoch
+    pub fn is_committed(&self, current_time: i64) -> bool {
+        self.epoch_commitment > 0 && current_time < self.committed_until
+    }
+
+    /// Available stake that is not committed to a running juror epoch
+    /// Committed stake can still vote; it just can't leave the account
+    pub fn withdrawable_stake(&self, current_time: i64) -> u64 {
+        if self.is_committed(current_time) {
+            self.available_stake.saturating_sub(self.epoch_commitment)
+        } else {
+            self.available_stake
+        }
+    }
+
     /// Deposit SOL to balance (after actual transfer to PDA)
-    pub fn deposit(&mut self, amount: u64) {
-        self.total_stake = self.total_stake.saturating_add(amount);
-        self.available_stake = self.available_stake.saturating_add(amount);
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_stake = self.total_stake.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.available_stake = self.available_stake.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
     }
 
     /// Withdraw SOL from balance (before actual transfer from PDA)
     pub fn withdraw(&mut self, amount: u64) -> Result<()> {
         require!(self.available_stake >= amount, TribunalCraftError::InsufficientAvailableStake);
-        self.total_stake = self.total_stake.saturating_sub(amount);
-        self.available_stake = self.available_stake.saturating_sub(amount);
+        self.total_stake = self.total_stake.checked_sub(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.available_stake -= amount;
+        Ok(())
+    }
+
+    /// Count a newly cast vote (call once per vote record, alongside allocate_for_vote)
+    pub fn open_vote(&mut self) -> Result<()> {
+        self.active_votes = self.active_votes.checked_add(1).ok_or(TribunalCraftError::ArithmeticOverflow)?;
         Ok(())
     }
 
     /// Allocate stake for voting (accounting only, no SOL transfer)
     pub fn allocate_for_vote(&mut self, amount: u64) -> Result<()> {
         require!(self.available_stake >= amount, TribunalCraftError::InsufficientAvailableStake);
-        self.available_stake = self.available_stake.saturating_sub(amount);
+        self.available_stake -= amount;
+        self.locked_stake = self.locked_stake.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
         // Note: total_stake unchanged - SOL stays in PDA, just locked
         Ok(())
     }
 
-    /// Release stake from vote (accounting only, no SOL transfer)
-    pub fn release_from_vote(&mut self, amount: u64) {
-        self.available_stake = self.available_stake.saturating_add(amount);
+    /// Release a vote's stake on unlock (accounting only, no SOL transfer)
+    pub fn release_from_vote(&mut self, amount: u64) -> Result<()> {
+        self.available_stake = self.available_stake.checked_add(amount).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        // Saturating: accounts migrated from before vote tracking may undercount
+        self.locked_stake = self.locked_stake.saturating_sub(amount);
+        self.active_votes = self.active_votes.saturating_sub(1);
         // Note: total_stake unchanged - SOL was always in PDA
+        Ok(())
+    }
+
+    /// Track a new deposit for stake-age weighting (call alongside deposit)
+    /// When full, the two oldest tranches merge and keep the younger timestamp
+    pub fn record_tranche(&mut self, amount: u64, now: i64) {
+        if amount == 0 {
+            return;
+        }
+        if self.tranches.len() >= Self::MAX_STAKE_TRANCHES {
+            let oldest = self.tranches.remove(0);
+            self.tranches[0].amount = self.tranches[0].amount.saturating_add(oldest.amount);
+        }
+        self.tranches.push(StakeTranche { amount, deposited_at: now });
+    }
+
+    /// Drop withdrawn stake from the newest tranches first (call alongside withdraw)
+    pub fn release_tranches(&mut self, mut amount: u64) {
+        while amount > 0 {
+            let Some(last) = self.tranches.last_mut() else {
+                return; // remainder came from untracked (mature) stake
+            };
+            let taken = amount.min(last.amount);
+            last.amount -= taken;
+            amount -= taken;
+            if last.amount == 0 {
+                self.tranches.pop();
+            }
+        }
+    }
+
+    /// Age-weighted amount of a new allocation from available stake
+    /// Held stake is assumed to occupy the oldest stake, so the allocation is
+    /// drawn from the oldest available stake onwards. Fresh stake counts
+    /// FRESH_STAKE_WEIGHT_BPS, ramping linearly to full weight over maturity_period.
+    pub fn age_weighted_stake(&self, amount: u64, now: i64, maturity_period: i64) -> u64 {
+        if maturity_period <= 0 {
+            return amount;
+        }
+        let full_weight = MAX_BPS as u64;
+        let fresh_weight = FRESH_STAKE_WEIGHT_BPS as u64;
+
+        let tracked: u64 = self.tranches.iter().map(|t| t.amount).fold(0, u64::saturating_add);
+        let untracked = self.total_stake.saturating_sub(tracked);
+        let segments = core::iter::once((untracked, full_weight))
+            .chain(self.tranches.iter().map(|t| {
+                let age = now.saturating_sub(t.deposited_at).clamp(0, maturity_period);
+                let ramp = (full_weight - fresh_weight) as u128 * age as u128 / maturity_period as u128;
+                (t.amount, fresh_weight + ramp as u64)
+            }));
+
+        let mut skip = self.held_stake();
+        let mut remaining = amount;
+        let mut weighted: u128 = 0;
+        for (segment, weight_bps) in segments {
+            let skipped = skip.min(segment);
+            skip -= skipped;
+            let taken = remaining.min(segment - skipped);
+            remaining -= taken;
+            weighted += taken as u128 * weight_bps as u128 / full_weight as u128;
+            if remaining == 0 {
+                break;
+            }
+        }
+        weighted as u64
     }
 
     /// Add reward to balance (after actual transfer to PDA)
-    pub fn add_reward(&mut self, amount: u64) {
-        self.total_stake = self.total_stake.saturating_add(amount);
-        self.available_stake = self.available_stake.saturating_add(amount);
+    pub fn add_reward(&mut self, amount: u64) -> Result<()> {
+        self.deposit(amount)
     }
 
     /// Calculate voting power: sqrt(stake) * reputation * sqrt(votes + 1)
     /// Returns scaled value (multiplied by WEIGHT_PRECISION)
-    pub fn calculate_voting_power(&self, stake_allocated: u64) -> u64 {
+    pub fn calculate_voting_power(&self, stake_allocated: u64) -> Result<u64> {
         use crate::constants::WEIGHT_PRECISION;
 
         // sqrt(stake_allocated) - using integer sqrt approximation
@@ -110,8 +251,9 @@ impl JurorAccount {
         let rep = self.reputation as u64;
 
         // voting_power = sqrt(stake) * (rep / 10000) * sqrt(votes + 1)
-        // Scale by WEIGHT_PRECISION for precision
-        (sqrt_stake * rep * sqrt_votes * WEIGHT_PRECISION) / 10000
+        // Scale by WEIGHT_PRECISION for precision (u128 intermediate - large stakes overflow u64)
+        let power = sqrt_stake as u128 * rep as u128 * sqrt_votes as u128 * WEIGHT_PRECISION as u128 / 10000;
+        u64::try_from(power).map_err(|_| TribunalCraftError::ArithmeticOverflow.into())
     }
 
     /// Calculate withdrawal return based on reputation
@@ -124,7 +266,7 @@ impl JurorAccount {
             // return_percentage = reputation * 2 (in basis points)
             // e.g., 25% rep = 50% return
             let return_bps = (self.reputation as u64) * 2;
-            let return_amount = (amount * return_bps) / 10000;
+            let return_amount = (amount as u128 * return_bps as u128 / 10000) as u64;
             let slash_amount = amount - return_amount;
             (return_amount, slash_amount)
         }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// One booster's juror incentive deposit on a dispute
+/// Lamports sit in the dispute escrow; the record lets the booster refund
+/// them if the dispute resolves without votes
+#[account]
+#[derive(Default)]
+pub struct JurorBoost {
+    /// Boosted dispute
+    pub dispute: Pubkey,
+
+    /// Wallet that deposited the incentive
+    pub booster: Pubkey,
+
+    /// Lamports deposited
+    pub amount: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// First deposit timestamp
+    pub created_at: i64,
+}
+
+impl JurorBoost {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        32 +    // booster
+        8 +     // amount
+        1 +     // bump
+        8;      // created_at
+}
@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_BPS;
+
+/// A juror epoch: a term jurors commit stake to in exchange for a base reward
+/// Funded with lamports (a share of platform fees, see set_fee_epoch) held in this PDA and shared by
+/// committed stake; jurors who skip assigned disputes lose part of their share
+#[account]
+#[derive(Default)]
+pub struct JurorEpoch {
+    /// Protocol config the epoch belongs to
+    pub config: Pubkey,
+
+    /// Sequential epoch index within the config
+    pub index: u32,
+
+    /// Enrollment closes and the term starts
+    pub starts_at: i64,
+
+    /// Term ends; rewards become claimable
+    pub ends_at: i64,
+
+    /// Base reward routed from platform fees at resolution or deposited by fund_juror_epoch
+    pub reward_pool: u64,
+
+    /// Rewards paid to jurors so far
+    pub rewards_paid: u64,
+
+    /// Stake committed by all enrolled jurors
+    pub total_committed: u64,
+
+    /// Number of enrolled jurors
+    pub juror_count: u32,
+
+    /// Number of jurors who claimed their reward
+    pub claimed_count: u32,
+
+    /// Reward lost per skipped assignment (bps of the juror's share)
+    pub miss_penalty_bps: u16,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Creation timestamp
+    pub created_at: i64,
+}
+
+impl JurorEpoch {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // config
+        4 +     // index
+        8 +     // starts_at
+        8 +     // ends_at
+        8 +     // reward_pool
+        8 +     // rewards_paid
+        8 +     // total_committed
+        4 +     // juror_count
+        4 +     // claimed_count
+        2 +     // miss_penalty_bps
+        1 +     // bump
+        8;      // created_at
+
+    /// Check if jurors can still enroll
+    pub fn is_enrollment_open(&self, current_time: i64) -> bool {
+        current_time < self.starts_at
+    }
+
+    /// Check if the term is over and rewards can be claimed
+    pub fn is_ended(&self, current_time: i64) -> bool {
+        current_time >= self.ends_at
+    }
+
+    /// Check if a dispute whose voting started at `voting_starts_at` falls in the term
+    pub fn covers(&self, voting_starts_at: i64) -> bool {
+        voting_starts_at >= self.starts_at && voting_starts_at < self.ends_at
+    }
+
+    /// A juror's reward: committed-stake share of the pool, less the availability penalty
+    pub fn reward_for(&self, enrollment: &EpochEnrollment) -> u64 {
+        if self.total_committed == 0 {
+            return 0;
+        }
+        let share = self.reward_pool as u128 * enrollment.committed_stake as u128 / self.total_committed as u128;
+        let missed = enrollment.assigned.saturating_sub(enrollment.served) as u32;
+        let penalty_bps = (missed * self.miss_penalty_bps as u32).min(MAX_BPS as u32);
+        (share * (MAX_BPS as u32 - penalty_bps) as u128 / MAX_BPS as u128) as u64
+    }
+}
+
+/// A juror's enrollment in an epoch
+#[account]
+#[derive(Default)]
+pub struct EpochEnrollment {
+    /// Epoch enrolled in
+    pub epoch: Pubkey,
+
+    /// Juror's wallet address
+    pub juror: Pubkey,
+
+    /// Stake committed for the term (not withdrawable until it ends)
+    pub committed_stake: u64,
+
+    /// Disputes assigned to the juror during the term
+    pub assigned: u16,
+
+    /// Assigned disputes the juror voted on
+    pub served: u16,
+
+    /// Whether the epoch reward has been claimed
+    pub reward_claimed: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Enrollment timestamp
+    pub enrolled_at: i64,
+}
+
+impl EpochEnrollment {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // epoch
+        32 +    // juror
+        8 +     // committed_stake
+        2 +     // assigned
+        2 +     // served
+        1 +     // reward_claimed
+        1 +     // bump
+        8;      // enrolled_at
+}
+
+/// A dispute assigned to an enrolled juror during an epoch
+#[account]
+#[derive(Default)]
+pub struct EpochAssignment {
+    /// Epoch the assignment counts toward
+    pub epoch: Pubkey,
+
+    /// Assigned dispute
+    pub dispute: Pubkey,
+
+    /// Assigned juror's wallet address
+    pub juror: Pubkey,
+
+    /// Juror voted on the dispute (recorded by record_epoch_service)
+    pub served: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Assignment timestamp
+    pub assigned_at: i64,
+}
+
+impl EpochAssignment {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // epoch
+        32 +    // dispute
+        32 +    // juror
+        1 +     // served
+        1 +     // bump
+        8;      // assigned_at
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1_000_000 lamport pool shared by 300 committed stake, 10% lost per missed assignment
+    fn funded_epoch() -> JurorEpoch {
+        JurorEpoch { reward_pool: 1_000_000, total_committed: 300, miss_penalty_bps: 1000, ..Default::default() }
+    }
+
+    fn enrollment(committed_stake: u64, assigned: u16, served: u16) -> EpochEnrollment {
+        EpochEnrollment { committed_stake, assigned, served, ..Default::default() }
+    }
+
+    #[test]
+    fn reward_follows_committed_stake() {
+        let epoch = funded_epoch();
+        assert_eq!(epoch.reward_for(&enrollment(100, 0, 0)), 333_333);
+        assert_eq!(epoch.reward_for(&enrollment(200, 0, 0)), 666_666);
+    }
+
+    #[test]
+    fn full_service_pays_no_penalty() {
+        let epoch = funded_epoch();
+        assert_eq!(epoch.reward_for(&enrollment(100, 3, 3)), epoch.reward_for(&enrollment(100, 0, 0)));
+    }
+
+    #[test]
+    fn each_missed_assignment_costs_the_penalty() {
+        let epoch = funded_epoch();
+        assert_eq!(epoch.reward_for(&enrollment(150, 1, 0)), 450_000);
+        assert_eq!(epoch.reward_for(&enrollment(150, 3, 1)), 400_000);
+    }
+
+    #[test]
+    fn penalty_caps_at_the_whole_share() {
+        let epoch = funded_epoch();
+        assert_eq!(epoch.reward_for(&enrollment(150, 10, 0)), 0);
+        assert_eq!(epoch.reward_for(&enrollment(150, u16::MAX, 0)), 0);
+    }
+
+    #[test]
+    fn payouts_never_exceed_the_pool() {
+        let epoch = JurorEpoch { reward_pool: 1_000, total_committed: 7, ..funded_epoch() };
+        let paid: u64 = [1, 2, 4].iter().map(|&s| epoch.reward_for(&enrollment(s, 0, 0))).sum();
+        assert!(paid <= epoch.reward_pool);
+    }
+
+    #[test]
+    fn empty_epoch_pays_nothing() {
+        let epoch = JurorEpoch { total_committed: 0, ..funded_epoch() };
+        assert_eq!(epoch.reward_for(&enrollment(0, 0, 0)), 0);
+    }
+}
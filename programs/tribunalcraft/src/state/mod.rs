@@ -8,6 +8,23 @@ pub mod challenger_record;
 pub mod defender_record;
 pub mod vote_record;
 pub mod protocol_config;
+pub mod category_registry;
+pub mod evidence_log;
+pub mod creator_allowlist;
+pub mod subject_tombstone;
+pub mod protocol_stats;
+pub mod resolution_attestation;
+pub mod challenge_pledge;
+pub mod pool_delegate;
+pub mod vote_stats;
+pub mod subject_index;
+pub mod resolution_void;
+pub mod juror_boost;
+pub mod opposition_record;
+pub mod juror_epoch;
+pub mod expiry_queue;
+pub mod treasury;
+pub mod evidence_ack;
 
 pub use defender_pool::*;
 pub use subject::*;
@@ -19,3 +36,20 @@ pub use challenger_record::*;
 pub use defender_record::*;
 pub use vote_record::*;
 pub use protocol_config::*;
+pub use category_registry::*;
+pub use evidence_log::*;
+pub use creator_allowlist::*;
+pub use subject_tombstone::*;
+pub use protocol_stats::*;
+pub use resolution_attestation::*;
+pub use challenge_pledge::*;
+pub use pool_delegate::*;
+pub use vote_stats::*;
+pub use subject_index::*;
+pub use resolution_void::*;
+pub use juror_boost::*;
+pub use opposition_record::*;
+pub use juror_epoch::*;
+pub use expiry_queue::*;
+pub use treasury::*;
+pub use evidence_ack::*;
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Stake placed against a restoration (appeal) by oppose_restore
+/// The defender side of an appeal: wins when the restoration is rejected
+#[account]
+#[derive(Default)]
+pub struct OppositionRecord {
+    /// Appeal this stake opposes
+    pub dispute: Pubkey,
+
+    /// Opposer's wallet address
+    pub opposer: Pubkey,
+
+    /// Lamports staked against the restoration
+    pub stake: u64,
+
+    /// Whether the payout has been claimed
+    pub reward_claimed: bool,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// First stake timestamp
+    pub opposed_at: i64,
+}
+
+impl OppositionRecord {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        32 +    // opposer
+        8 +     // stake
+        1 +     // reward_claimed
+        1 +     // bump
+        8;      // opposed_at
+
+    /// Opposer's share of a reward based on stake weight
+    pub fn calculate_reward_share(&self, total_reward: u64, total_stake: u64) -> u64 {
+        if total_stake == 0 {
+            return 0;
+        }
+        (total_reward as u128 * self.stake as u128 / total_stake as u128) as u64
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
+
+/// Wallet authorized by a pool owner to link subjects to their defender pool
+/// Each link commits the subject's max_stake (its per-dispute pull limit) against the cap
+/// for as long as the link lasts; stake committed via defend_from_pool is given back
+/// when the defender claim for that dispute settles
+#[account]
+#[derive(Default)]
+pub struct PoolDelegate {
+    /// Defender pool this delegate can commit
+    pub pool: Pubkey,
+
+    /// Delegate's wallet address
+    pub delegate: Pubkey,
+
+    /// Max cumulative max_stake the delegate can link to the pool
+    pub spending_cap: u64,
+
+    /// Linked subjects' max_stake plus defend_from_pool stake not yet settled
+    pub committed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Creation timestamp
+    pub created_at: i64,
+}
+
+impl PoolDelegate {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // pool
+        32 +    // delegate
+        8 +     // spending_cap
+        8 +     // committed
+        1 +     // bump
+        8;      // created_at
+
+    /// Commit a subject's max_stake against the spending cap
+    pub fn commit(&mut self, amount: u64) -> Result<()> {
+        let committed = self.committed.saturating_add(amount);
+        require!(committed <= self.spending_cap, TribunalCraftError::DelegateCapExceeded);
+        self.committed = committed;
+        Ok(())
+    }
+
+    /// Give back a commitment once the stake it covered is settled
+    pub fn release(&mut self, amount: u64) {
+        self.committed = self.committed.saturating_sub(amount);
+    }
+}
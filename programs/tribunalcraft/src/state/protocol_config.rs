@@ -1,4 +1,24 @@
 use anchor_lang::prelude::*;
+use crate::state::{RewardSplit, JurorAccount};
+use crate::constants::{
+    MAX_BPS, SECONDS_PER_DAY,
+    DEFAULT_MIN_JUROR_SHARE_BPS, DEFAULT_MAX_JUROR_SHARE_BPS,
+    DEFAULT_MIN_WINNER_SHARE_BPS, DEFAULT_MAX_WINNER_SHARE_BPS, DEFAULT_STAKE_MATURITY_PERIOD,
+    DEFAULT_RESOLVER_FEE_BPS, DEFAULT_MAX_RESOLVER_FEE,
+    DEFAULT_DISPUTE_COOLDOWN, DEFAULT_COOLDOWN_ESCALATION_BPS, DEFAULT_LATE_JOIN_CUTOFF_BPS,
+    DEFAULT_SWEEP_BOUNTY_MIN, DEFAULT_SWEEP_BOUNTY_BASE_BPS, DEFAULT_SWEEP_BOUNTY_BPS_PER_DAY,
+    DEFAULT_SWEEP_BOUNTY_MAX,
+};
+use crate::errors::TribunalCraftError;
+
+/// Platform fee rebate for jurors at or above a reputation level
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct RebateTier {
+    /// Minimum juror reputation (bps) for this tier
+    pub min_reputation: u16,
+    /// Share of the juror's slice of the treasury fee rebated (bps, 0 = tier unused)
+    pub rebate_bps: u16,
+}
 
 /// Protocol-wide configuration account
 /// Stores treasury address and admin authority for fee collection
@@ -10,11 +30,193 @@ pub struct ProtocolConfig {
     pub treasury: Pubkey,
     /// PDA bump seed
     pub bump: u8,
+    /// Creator allowlist PDA enforced on subject creation (None = anyone can create)
+    pub subject_creator_gate: Option<Pubkey>,
+    /// Bounds for per-subject juror share of fees (bps)
+    pub min_juror_share_bps: u16,
+    pub max_juror_share_bps: u16,
+    /// Bounds for per-subject winner share of loser's contribution (bps)
+    pub min_winner_share_bps: u16,
+    pub max_winner_share_bps: u16,
+    /// Seconds for new juror stake to reach full voting weight (0 = no stake-age weighting)
+    pub stake_maturity_period: i64,
+    /// Max stake a juror can allocate to one dispute (bps of their total stake)
+    pub max_vote_allocation_bps: u16,
+    /// Max stake a juror can have locked across all disputes (bps of their total stake)
+    pub max_locked_bps: u16,
+    /// Resolver fee (bps of the dispute's total pool), paid out of the platform fee
+    pub resolver_fee_bps: u16,
+    /// Max resolver fee per resolution (lamports)
+    pub max_resolver_fee: u64,
+    /// Default cooldown after a defender win, copied to new subjects (seconds)
+    pub default_dispute_cooldown: i64,
+    /// Bond needed during cooldown (bps of the last dispute's total)
+    pub cooldown_escalation_bps: u16,
+    /// Reject CID inputs (details, rationale, evidence, category metadata, void reasons) that are not IPFS CIDv0/v1 or Arweave ids
+    pub validate_cids: bool,
+    /// Juror fee rebate tiers, ascending by reputation (all zero = rebates off)
+    pub rebate_tiers: [RebateTier; ProtocolConfig::REBATE_TIERS],
+    /// Final share of the voting window (bps) in which add_to_dispute is closed (0 = off)
+    pub late_join_cutoff_bps: u16,
+    /// SPL stake pool defender pools may delegate idle stake to (default = yield off)
+    pub yield_stake_pool: Pubkey,
+    /// Max share of a defender pool's stake (bps) delegated to the stake pool
+    pub max_yield_bps: u16,
+    /// Minimum stake per vote (lamports, 0 = off)
+    pub min_vote_allocation: u64,
+    /// Minimum stake per vote (bps of the juror's total stake, 0 = off)
+    pub min_vote_allocation_bps: u16,
+    /// Sweep bounty floor (lamports), never more than the dust swept
+    pub sweep_bounty_min: u64,
+    /// Sweep bounty at resolution (bps of the dust)
+    pub sweep_bounty_base_bps: u16,
+    /// Sweep bounty growth per full day since resolution (bps of the dust)
+    pub sweep_bounty_bps_per_day: u16,
+    /// Max sweep bounty per escrow (lamports)
+    pub sweep_bounty_max: u64,
+    /// Account layout version (0 = original 73-byte global config, upgraded via migrate_account)
+    pub version: u8,
+    /// Share of the treasury fee (bps) routed into the fee epoch at resolution (0 = off)
+    pub epoch_fee_bps: u16,
+    /// Juror epoch receiving the fee share (default = none)
+    pub fee_epoch: Pubkey,
+    /// fee_epoch's end; resolutions after it keep the whole fee in treasury
+    pub fee_epoch_ends_at: i64,
 }
 
 impl ProtocolConfig {
+    pub const REBATE_TIERS: usize = 3;
+
     pub const LEN: usize = 8   // discriminator
         + 32                   // authority
         + 32                   // treasury
-        + 1;                   // bump
+        + 1                    // bump
+        + (1 + 32)             // subject_creator_gate
+        + 2                    // min_juror_share_bps
+        + 2                    // max_juror_share_bps
+        + 2                    // min_winner_share_bps
+        + 2                    // max_winner_share_bps
+        + 8                    // stake_maturity_period
+        + 2                    // max_vote_allocation_bps
+        + 2                    // max_locked_bps
+        + 2                    // resolver_fee_bps
+        + 8                    // max_resolver_fee
+        + 8                    // default_dispute_cooldown
+        + 2                    // cooldown_escalation_bps
+        + 1                    // validate_cids
+        + 4 * Self::REBATE_TIERS // rebate_tiers
+        + 2                    // late_join_cutoff_bps
+        + 32                   // yield_stake_pool
+        + 2                    // max_yield_bps
+        + 8                    // min_vote_allocation
+        + 2                    // min_vote_allocation_bps
+        + 8                    // sweep_bounty_min
+        + 2                    // sweep_bounty_base_bps
+        + 2                    // sweep_bounty_bps_per_day
+        + 8                    // sweep_bounty_max
+        + 1                    // version
+        + 2                    // epoch_fee_bps
+        + 32                   // fee_epoch
+        + 8;                   // fee_epoch_ends_at
+
+    /// Reset every setting to its default (authority, treasury and bump are left alone)
+    pub fn set_defaults(&mut self) {
+        self.subject_creator_gate = None;
+        self.min_juror_share_bps = DEFAULT_MIN_JUROR_SHARE_BPS;
+        self.max_juror_share_bps = DEFAULT_MAX_JUROR_SHARE_BPS;
+        self.min_winner_share_bps = DEFAULT_MIN_WINNER_SHARE_BPS;
+        self.max_winner_share_bps = DEFAULT_MAX_WINNER_SHARE_BPS;
+        self.stake_maturity_period = DEFAULT_STAKE_MATURITY_PERIOD;
+        self.max_vote_allocation_bps = MAX_BPS;
+        self.max_locked_bps = MAX_BPS;
+        self.resolver_fee_bps = DEFAULT_RESOLVER_FEE_BPS;
+        self.max_resolver_fee = DEFAULT_MAX_RESOLVER_FEE;
+        self.default_dispute_cooldown = DEFAULT_DISPUTE_COOLDOWN;
+        self.cooldown_escalation_bps = DEFAULT_COOLDOWN_ESCALATION_BPS;
+        self.validate_cids = false;
+        self.rebate_tiers = [RebateTier::default(); Self::REBATE_TIERS];
+        self.late_join_cutoff_bps = DEFAULT_LATE_JOIN_CUTOFF_BPS;
+        self.yield_stake_pool = Pubkey::default();
+        self.max_yield_bps = 0;
+        self.min_vote_allocation = 0;
+        self.min_vote_allocation_bps = 0;
+        self.sweep_bounty_min = DEFAULT_SWEEP_BOUNTY_MIN;
+        self.sweep_bounty_base_bps = DEFAULT_SWEEP_BOUNTY_BASE_BPS;
+        self.sweep_bounty_bps_per_day = DEFAULT_SWEEP_BOUNTY_BPS_PER_DAY;
+        self.sweep_bounty_max = DEFAULT_SWEEP_BOUNTY_MAX;
+        self.epoch_fee_bps = 0;
+        self.fee_epoch = Pubkey::default();
+        self.fee_epoch_ends_at = 0;
+    }
+
+    /// Check a reward split against the configured bounds
+    pub fn allows_reward_split(&self, split: &RewardSplit) -> bool {
+        (self.min_juror_share_bps..=self.max_juror_share_bps).contains(&split.juror_share_bps)
+            && (self.min_winner_share_bps..=self.max_winner_share_bps).contains(&split.winner_share_bps)
+    }
+
+    /// Highest rebate any tier pays (bps) - reserved out of the treasury fee at resolution
+    pub fn max_rebate_bps(&self) -> u16 {
+        self.rebate_tiers.iter().map(|t| t.rebate_bps).max().unwrap_or(0)
+    }
+
+    /// Rebate for a juror's reputation (highest tier reached, 0 below every tier)
+    pub fn rebate_bps_for(&self, reputation: u16) -> u16 {
+        self.rebate_tiers.iter()
+            .filter(|t| t.rebate_bps > 0 && reputation >= t.min_reputation)
+            .map(|t| t.rebate_bps)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Resolver fee for a dispute pool (before capping at the platform fee)
+    pub fn resolver_fee(&self, total_pool: u64) -> u64 {
+        let fee = (total_pool as u128 * self.resolver_fee_bps as u128 / MAX_BPS as u128) as u64;
+        fee.min(self.max_resolver_fee)
+    }
+
+    /// Share of a treasury fee routed into the fee epoch (0 once the epoch has ended)
+    pub fn epoch_fee(&self, treasury_fee: u64, now: i64) -> u64 {
+        if self.fee_epoch == Pubkey::default() || now >= self.fee_epoch_ends_at {
+            return 0;
+        }
+        (treasury_fee as u128 * self.epoch_fee_bps as u128 / MAX_BPS as u128) as u64
+    }
+
+    /// Bounty for sweeping `dust` out of an escrow `age` seconds after it became sweepable
+    /// (see Dispute::sweep_age; negative ages pay the base rate)
+    /// The rate grows daily so abandoned escrows eventually pay enough to be closed
+    pub fn sweep_bounty(&self, dust: u64, age: i64) -> u64 {
+        let days = (age.max(0) / SECONDS_PER_DAY) as u64;
+        let bps = (self.sweep_bounty_base_bps as u64)
+            .saturating_add(days.saturating_mul(self.sweep_bounty_bps_per_day as u64))
+            .min(MAX_BPS as u64);
+        let bounty = (dust as u128 * bps as u128 / MAX_BPS as u128) as u64;
+        bounty.max(self.sweep_bounty_min).min(self.sweep_bounty_max).min(dust)
+    }
+
+    /// Minimum allocation for one vote (the larger of the absolute and relative floors)
+    pub fn min_vote_allocation_for(&self, juror: &JurorAccount) -> u64 {
+        let relative = (juror.total_stake as u128 * self.min_vote_allocation_bps as u128 / MAX_BPS as u128) as u64;
+        self.min_vote_allocation.max(relative)
+    }
+
+    /// Check a juror's allocation against the minimum and the exposure caps (after the stake is locked)
+    /// `vote_total` is the vote's full allocation including this call
+    pub fn check_allocation_caps(&self, juror: &JurorAccount, vote_total: u64) -> Result<()> {
+        require!(
+            vote_total >= self.min_vote_allocation_for(juror),
+            TribunalCraftError::VoteAllocationBelowMinimum
+        );
+        let total = juror.total_stake as u128;
+        require!(
+            vote_total as u128 * MAX_BPS as u128 <= total * self.max_vote_allocation_bps as u128,
+            TribunalCraftError::VoteAllocationAboveCap
+        );
+        require!(
+            juror.locked_stake as u128 * MAX_BPS as u128 <= total * self.max_locked_bps as u128,
+            TribunalCraftError::LockedStakeAboveCap
+        );
+        Ok(())
+    }
 }
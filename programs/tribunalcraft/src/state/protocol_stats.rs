@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::state::ResolutionOutcome;
+
+/// Protocol-wide running totals for dashboards - best-effort, not authoritative
+/// Fixed-size counters only, so clients can read fields at fixed offsets
+/// Counters saturate rather than fail - stats never block an instruction
+/// Optional on every instruction that updates it: one global writable account would
+/// serialize all protocol throughput, so totals only count transactions that passed it
+/// and undercount otherwise. Exact figures come from indexing the program's events
+#[account]
+#[derive(Default)]
+pub struct ProtocolStats {
+    /// Subjects created (all modes)
+    pub total_subjects: u64,
+
+    /// Disputes submitted (excluding appeals)
+    pub total_disputes: u64,
+
+    /// Appeals submitted
+    pub total_appeals: u64,
+
+    /// Resolved disputes/appeals where the challenger (or appellant) won
+    pub challenger_wins: u64,
+
+    /// Resolved disputes/appeals where the defender won
+    pub defender_wins: u64,
+
+    /// Resolved disputes/appeals with no votes
+    pub no_participation: u64,
+
+    /// Lamports escrowed (bonds + stakes) across resolved disputes
+    pub total_volume: u64,
+
+    /// Platform fees sent to treasury
+    pub total_fees_collected: u64,
+
+    /// Resolver fees paid to whoever settled a dispute
+    pub total_resolver_fees: u64,
+
+    /// Resolutions that paid a resolver fee
+    pub paid_resolutions: u64,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProtocolStats {
+    pub const LEN: usize = 8 +  // discriminator
+        8 +     // total_subjects
+        8 +     // total_disputes
+        8 +     // total_appeals
+        8 +     // challenger_wins
+        8 +     // defender_wins
+        8 +     // no_participation
+        8 +     // total_volume
+        8 +     // total_fees_collected
+        8 +     // total_resolver_fees
+        8 +     // paid_resolutions
+        8 +     // updated_at
+        1;      // bump
+
+    pub fn record_subject(&mut self, now: i64) {
+        self.total_subjects = self.total_subjects.saturating_add(1);
+        self.updated_at = now;
+    }
+
+    pub fn record_dispute(&mut self, is_appeal: bool, now: i64) {
+        if is_appeal {
+            self.total_appeals = self.total_appeals.saturating_add(1);
+        } else {
+            self.total_disputes = self.total_disputes.saturating_add(1);
+        }
+        self.updated_at = now;
+    }
+
+    pub fn record_resolution(&mut self, outcome: ResolutionOutcome, volume: u64, fee: u64, now: i64) {
+        match outcome {
+            ResolutionOutcome::ChallengerWins => self.challenger_wins = self.challenger_wins.saturating_add(1),
+            ResolutionOutcome::DefenderWins => self.defender_wins = self.defender_wins.saturating_add(1),
+            ResolutionOutcome::NoParticipation => self.no_participation = self.no_participation.saturating_add(1),
+            ResolutionOutcome::None => {}
+        }
+        self.total_volume = self.total_volume.saturating_add(volume);
+        self.total_fees_collected = self.total_fees_collected.saturating_add(fee);
+        self.updated_at = now;
+    }
+
+    pub fn record_resolver_fee(&mut self, fee: u64) {
+        if fee > 0 {
+            self.total_resolver_fees = self.total_resolver_fees.saturating_add(fee);
+            self.paid_resolutions = self.paid_resolutions.saturating_add(1);
+        }
+    }
+
+    /// Take a voided resolution back out of the totals (inverse of record_resolution + record_resolver_fee)
+    pub fn revert_resolution(&mut self, outcome: ResolutionOutcome, volume: u64, fee: u64, resolver_fee: u64, now: i64) {
+        match outcome {
+            ResolutionOutcome::ChallengerWins => self.challenger_wins = self.challenger_wins.saturating_sub(1),
+            ResolutionOutcome::DefenderWins => self.defender_wins = self.defender_wins.saturating_sub(1),
+            ResolutionOutcome::NoParticipation => self.no_participation = self.no_participation.saturating_sub(1),
+            ResolutionOutcome::None => {}
+        }
+        self.total_volume = self.total_volume.saturating_sub(volume);
+        self.total_fees_collected = self.total_fees_collected.saturating_sub(fee);
+        if resolver_fee > 0 {
+            self.total_resolver_fees = self.total_resolver_fees.saturating_sub(resolver_fee);
+            self.paid_resolutions = self.paid_resolutions.saturating_sub(1);
+        }
+        self.updated_at = now;
+    }
+}
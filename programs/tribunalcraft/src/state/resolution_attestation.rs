@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::state::{DisputeType, ResolutionOutcome};
+
+/// Immutable proof of a dispute's outcome for off-chain consumers
+/// Written once after resolution and never closed, so the outcome stays
+/// provable after the escrow, dispute and subject accounts are closed
+#[account]
+#[derive(Default)]
+pub struct ResolutionAttestation {
+    /// Subject account the dispute was raised against
+    pub subject: Pubkey,
+
+    /// Subject identifier
+    pub subject_id: Pubkey,
+
+    /// Attested dispute
+    pub dispute: Pubkey,
+
+    /// Dispute type
+    pub dispute_type: DisputeType,
+
+    /// Whether the dispute was an appeal
+    pub is_appeal: bool,
+
+    /// Final outcome
+    pub outcome: ResolutionOutcome,
+
+    /// Voting power for "ForChallenger" / "ForRestoration"
+    pub votes_favor_weight: u64,
+
+    /// Voting power for "ForDefender" / "AgainstRestoration"
+    pub votes_against_weight: u64,
+
+    /// Number of jurors who voted
+    pub vote_count: u16,
+
+    /// Resolution timestamp
+    pub resolved_at: i64,
+
+    /// Attestation timestamp
+    pub attested_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Resolution was voided by the config authority - the outcome must not be acted on
+    pub voided: bool,
+}
+
+impl ResolutionAttestation {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // subject
+        32 +    // subject_id
+        32 +    // dispute
+        1 +     // dispute_type
+        1 +     // is_appeal
+        1 +     // outcome
+        8 +     // votes_favor_weight
+        8 +     // votes_against_weight
+        2 +     // vote_count
+        8 +     // resolved_at
+        8 +     // attested_at
+        1 +     // bump
+        1;      // voided
+}
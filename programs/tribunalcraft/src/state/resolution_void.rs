@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::ResolutionOutcome;
+
+/// Audit record of an emergency void by the config authority
+/// Written once by void_resolution and never closed
+#[account]
+#[derive(Default)]
+pub struct ResolutionVoid {
+    /// Voided dispute
+    pub dispute: Pubkey,
+
+    /// Subject the dispute was raised against
+    pub subject: Pubkey,
+
+    /// Config the subject is bound to
+    pub config: Pubkey,
+
+    /// Config authority that voided the round
+    pub authority: Pubkey,
+
+    /// Outcome the resolution had reached before the void
+    pub voided_outcome: ResolutionOutcome,
+
+    /// Whether the dispute was an appeal
+    pub is_appeal: bool,
+
+    /// Exploit report CID (IPFS hash)
+    pub reason_cid: String,
+
+    /// Fees the authority paid back into escrow so every party is refunded in full
+    pub fees_restored: u64,
+
+    /// Original resolution timestamp
+    pub resolved_at: i64,
+
+    /// Void timestamp
+    pub voided_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ResolutionVoid {
+    pub const MAX_CID_LEN: usize = 64;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        32 +    // subject
+        32 +    // config
+        32 +    // authority
+        1 +     // voided_outcome
+        1 +     // is_appeal
+        4 + Self::MAX_CID_LEN + // reason_cid
+        8 +     // fees_restored
+        8 +     // resolved_at
+        8 +     // voided_at
+        1;      // bump
+}
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{JUROR_SHARE_BPS, WINNER_SHARE_BPS, MAX_BPS, MAX_CREATOR_FREEZE, CREATOR_FREEZE_COOLDOWN};
+use crate::errors::TribunalCraftError;
+use crate::state::ResolutionOutcome;
 
 /// Subject status
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -7,6 +10,34 @@ pub enum SubjectStatus {
     Active,      // Can be staked on and disputed
     Disputed,    // Currently has an active dispute
     Invalidated, // Dispute upheld, challengers won (terminal)
+    Dormant,     // Defender won with auto_revalidate off - needs fresh stake to reactivate
+}
+
+/// How a dispute with equal favor/against weight resolves
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TiePolicy {
+    #[default]
+    DefenderWins,   // Status quo stands
+    ChallengerWins, // Ties go to the challenger (or appellant)
+    ExtendVoting,   // Reopen voting once for tie_extension seconds, then defender wins
+}
+
+/// Per-subject reward split override (bps), bounded by ProtocolConfig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RewardSplit {
+    /// Juror share of fees (remainder goes to platform)
+    pub juror_share_bps: u16,
+    /// Winner share of loser's contribution
+    pub winner_share_bps: u16,
+}
+
+impl Default for RewardSplit {
+    fn default() -> Self {
+        Self {
+            juror_share_bps: JUROR_SHARE_BPS,
+            winner_share_bps: WINNER_SHARE_BPS,
+        }
+    }
 }
 
 /// Subject that defenders back - global (identified by subject_id)
@@ -67,13 +98,80 @@ pub struct Subject {
 
     /// Previous dispute's voting period - appeals use 2x this value
     pub last_voting_period: i64,
+
+    // Fields below were added after the initial release (old accounts are a prefix)
+
+    /// Platform category id from the CategoryRegistry (0 = uncategorized)
+    pub category_id: u16,
+
+    /// Juror share of fees (bps) used at resolution
+    pub juror_share_bps: u16,
+
+    /// Winner share of loser's contribution (bps) used at resolution
+    pub winner_share_bps: u16,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// Wallet that created the subject (receives rent on close_subject)
+    pub creator: Pubkey,
+
+    /// Jurors must commit a rationale hash when voting and reveal it before claiming
+    pub rationale_required: bool,
+
+    /// Pledged bond total that activates a pledged dispute (0 = pledging disabled)
+    pub pledge_threshold: u64,
+
+    /// After a defender win: true = back to Active, false = Dormant until restaked
+    pub auto_revalidate: bool,
+
+    /// Cooldown after a defender win (seconds, from ProtocolConfig default at creation)
+    pub dispute_cooldown: i64,
+
+    /// New disputes need an escalated bond until this time
+    pub cooldown_ends_at: i64,
+
+    /// How tied votes resolve
+    pub tie_policy: TiePolicy,
+
+    /// Voting extension for TiePolicy::ExtendVoting (seconds)
+    pub tie_extension: i64,
+
+    /// Disputes the defenders won
+    pub disputes_won: u32,
+
+    /// Disputes the challengers won (subject invalidated)
+    pub disputes_lost: u32,
+
+    /// Appeals that restored the subject
+    pub restorations_won: u32,
+
+    /// Appeals that left the subject invalidated
+    pub restorations_lost: u32,
+
+    /// Protocol instance (ProtocolConfig) whose fees, treasury and rules apply
+    pub config: Pubkey,
+
+    /// Max share of total voting weight one juror's vote counts for at resolution (bps, 0 = uncapped)
+    pub max_juror_weight_bps: u16,
+
+    /// Disputes are decided by graded votes (stake-weighted median slash) instead of a binary verdict
+    pub graded_verdicts: bool,
+
+    /// Who froze the subject for a legal hold (creator or config authority, default = not frozen)
+    pub frozen_by: Pubkey,
+
+    /// When the current (or last) freeze started; kept after unfreeze for the creator cooldown
+    pub frozen_at: i64,
 }
 
 impl Subject {
+    pub const MAX_CID_LEN: usize = 64;
+
     pub const LEN: usize = 8 +  // discriminator
         32 +    // subject_id
         32 +    // defender_pool
-        (4 + 64) + // details_cid (String: 4 byte length + 64 byte content)
+        (4 + Self::MAX_CID_LEN) + // details_cid (String: 4 byte length + 64 byte content)
         1 +     // status
         8 +     // total_stake
         8 +     // max_stake
@@ -87,7 +185,28 @@ impl Subject {
         8 +     // created_at
         8 +     // updated_at
         8 +     // last_dispute_total
-        8;      // last_voting_period
+        8 +     // last_voting_period
+        2 +     // category_id
+        2 +     // juror_share_bps
+        2 +     // winner_share_bps
+        1 +     // version
+        32 +    // creator
+        1 +     // rationale_required
+        8 +     // pledge_threshold
+        1 +     // auto_revalidate
+        8 +     // dispute_cooldown
+        8 +     // cooldown_ends_at
+        1 +     // tie_policy
+        8 +     // tie_extension
+        4 +     // disputes_won
+        4 +     // disputes_lost
+        4 +     // restorations_won
+        4 +     // restorations_lost
+        32 +    // config
+        2 +     // max_juror_weight_bps
+        1 +     // graded_verdicts
+        32 +    // frozen_by
+        8;      // frozen_at
 
     /// Check if subject is linked to a pool (vs standalone)
     pub fn is_linked(&self) -> bool {
@@ -95,9 +214,18 @@ impl Subject {
     }
 
     /// Check if subject can accept new stakes (both standalone and linked)
-    /// Invalidated is terminal - no more staking allowed
+    /// Invalidated is terminal - no more staking allowed; Dormant reactivates on stake
     pub fn can_stake(&self) -> bool {
-        matches!(self.status, SubjectStatus::Active | SubjectStatus::Disputed)
+        matches!(self.status, SubjectStatus::Active | SubjectStatus::Disputed | SubjectStatus::Dormant)
+    }
+
+    /// Status after a defender win
+    pub fn status_after_defense(&self) -> SubjectStatus {
+        if self.auto_revalidate {
+            SubjectStatus::Active
+        } else {
+            SubjectStatus::Dormant
+        }
     }
 
     /// Check if subject can be disputed (original dispute on active subjects)
@@ -110,6 +238,23 @@ impl Subject {
         self.status == SubjectStatus::Invalidated
     }
 
+    /// Check if the subject is frozen (no new disputes, stakes, bonds or restorations)
+    /// A dispute already open when the freeze started runs to completion. A creator's own
+    /// freeze lapses after MAX_CREATOR_FREEZE; the config authority's lasts until lifted
+    pub fn is_frozen(&self, now: i64) -> bool {
+        if self.frozen_by == Pubkey::default() {
+            return false;
+        }
+        self.frozen_by != self.creator || now < self.frozen_at.saturating_add(MAX_CREATOR_FREEZE)
+    }
+
+    /// Check if the creator may freeze: the last freeze started at least
+    /// MAX_CREATOR_FREEZE + CREATOR_FREEZE_COOLDOWN ago, so holds can't be chained
+    pub fn creator_can_freeze(&self, now: i64) -> bool {
+        self.frozen_at == 0
+            || now >= self.frozen_at.saturating_add(MAX_CREATOR_FREEZE + CREATOR_FREEZE_COOLDOWN)
+    }
+
     /// Check if there's an active dispute
     pub fn has_active_dispute(&self) -> bool {
         self.status == SubjectStatus::Disputed && self.dispute != Pubkey::default()
@@ -120,8 +265,110 @@ impl Subject {
         self.last_voting_period.saturating_mul(2)
     }
 
+    /// Update the lifetime track record for a resolved dispute or appeal
+    /// Disputes with no participation count toward neither side
+    pub fn record_outcome(&mut self, is_appeal: bool, outcome: ResolutionOutcome) {
+        let counter = match (is_appeal, outcome) {
+            (false, ResolutionOutcome::DefenderWins) => &mut self.disputes_won,
+            (false, ResolutionOutcome::ChallengerWins) => &mut self.disputes_lost,
+            (true, ResolutionOutcome::ChallengerWins) => &mut self.restorations_won,
+            (true, ResolutionOutcome::DefenderWins | ResolutionOutcome::NoParticipation) => &mut self.restorations_lost,
+            _ => return,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    /// Undo record_outcome for a voided resolution
+    pub fn revert_outcome(&mut self, is_appeal: bool, outcome: ResolutionOutcome) {
+        let counter = match (is_appeal, outcome) {
+            (false, ResolutionOutcome::DefenderWins) => &mut self.disputes_won,
+            (false, ResolutionOutcome::ChallengerWins) => &mut self.disputes_lost,
+            (true, ResolutionOutcome::ChallengerWins) => &mut self.restorations_won,
+            (true, ResolutionOutcome::DefenderWins | ResolutionOutcome::NoParticipation) => &mut self.restorations_lost,
+            _ => return,
+        };
+        *counter = counter.saturating_sub(1);
+    }
+
+    /// Check if the post-defense cooldown is running
+    pub fn in_cooldown(&self, now: i64) -> bool {
+        now < self.cooldown_ends_at
+    }
+
+    /// During cooldown, a new dispute's bond must reach escalation_bps of the last dispute's total
+    /// Free cases have no bond to escalate and wait out the cooldown
+    pub fn check_cooldown_bond(&self, bond: u64, escalation_bps: u16, now: i64) -> Result<()> {
+        if self.in_cooldown(now) {
+            require!(!self.free_case, TribunalCraftError::DisputeCooldownActive);
+            let required = self.last_dispute_total as u128 * escalation_bps as u128 / MAX_BPS as u128;
+            require!(bond as u128 >= required, TribunalCraftError::DisputeCooldownActive);
+        }
+        Ok(())
+    }
+
+    /// Check if challengers can pool bonds via pledge_challenge
+    pub fn accepts_pledges(&self) -> bool {
+        self.pledge_threshold > 0 && !self.free_case
+    }
+
+    /// Rent recipient on close (creator, or the closer if the creator is unknown)
+    pub fn rent_recipient(&self, closer: &Pubkey) -> Pubkey {
+        if self.creator == Pubkey::default() {
+            *closer
+        } else {
+            self.creator
+        }
+    }
+
     /// Get minimum stake required for appeal
     pub fn min_appeal_stake(&self) -> u64 {
         self.last_dispute_total
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FROZEN_AT: i64 = 1_000_000;
+
+    fn frozen_by_creator() -> Subject {
+        let creator = Pubkey::new_unique();
+        Subject { creator, frozen_by: creator, frozen_at: FROZEN_AT, ..Default::default() }
+    }
+
+    #[test]
+    fn creator_freeze_lapses_after_the_maximum() {
+        let subject = frozen_by_creator();
+        assert!(subject.is_frozen(FROZEN_AT + MAX_CREATOR_FREEZE - 1));
+        assert!(!subject.is_frozen(FROZEN_AT + MAX_CREATOR_FREEZE));
+    }
+
+    #[test]
+    fn authority_freeze_never_lapses() {
+        let subject = Subject { frozen_by: Pubkey::new_unique(), ..frozen_by_creator() };
+        assert!(subject.is_frozen(FROZEN_AT + MAX_CREATOR_FREEZE + CREATOR_FREEZE_COOLDOWN));
+    }
+
+    #[test]
+    fn creator_can_freeze_a_never_frozen_subject() {
+        assert!(Subject::default().creator_can_freeze(0));
+    }
+
+    #[test]
+    fn creator_refreeze_waits_out_lapse_and_cooldown() {
+        let subject = frozen_by_creator();
+        // Lapsed but still cooling down
+        assert!(!subject.creator_can_freeze(FROZEN_AT + MAX_CREATOR_FREEZE));
+        assert!(!subject.creator_can_freeze(FROZEN_AT + MAX_CREATOR_FREEZE + CREATOR_FREEZE_COOLDOWN - 1));
+        assert!(subject.creator_can_freeze(FROZEN_AT + MAX_CREATOR_FREEZE + CREATOR_FREEZE_COOLDOWN));
+    }
+
+    #[test]
+    fn early_unfreeze_keeps_the_cooldown() {
+        // unfreeze_subject clears frozen_by but keeps frozen_at
+        let subject = Subject { frozen_by: Pubkey::default(), ..frozen_by_creator() };
+        assert!(!subject.is_frozen(FROZEN_AT + 1));
+        assert!(!subject.creator_can_freeze(FROZEN_AT + 1));
+    }
+}
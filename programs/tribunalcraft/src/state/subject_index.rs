@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
+
+/// One page of a creator's subject list (append-only, filled in page order)
+/// Clients append to the first page that is not full and enumerate pages from 0
+/// until one is missing; a page only opens once the previous one is full. Closed subjects stay listed (see SubjectTombstone).
+#[account]
+#[derive(Default)]
+pub struct SubjectIndex {
+    /// Creator whose subjects are listed
+    pub creator: Pubkey,
+
+    /// Page number (0-based)
+    pub page: u32,
+
+    /// Subject ids in creation order
+    pub subject_ids: Vec<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SubjectIndex {
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // creator
+        4 +     // page
+        4 + Self::MAX_ENTRIES * 32 + // subject_ids
+        1;      // bump
+
+    /// Check if the page has room for another subject
+    pub fn is_full(&self) -> bool {
+        self.subject_ids.len() >= Self::MAX_ENTRIES
+    }
+
+    /// Append a subject id, initializing the page on first use
+    /// A new page past 0 needs the creator's full previous page, so pages stay contiguous
+    pub fn append(
+        &mut self,
+        creator: Pubkey,
+        page: u32,
+        bump: u8,
+        subject_id: Pubkey,
+        previous: Option<&SubjectIndex>,
+    ) -> Result<()> {
+        require!(!self.is_full(), TribunalCraftError::SubjectIndexPageFull);
+        if self.creator == Pubkey::default() {
+            if page > 0 {
+                let previous = previous.ok_or(TribunalCraftError::SubjectIndexPageOutOfOrder)?;
+                require!(
+                    previous.creator == creator && previous.page == page - 1 && previous.is_full(),
+                    TribunalCraftError::SubjectIndexPageOutOfOrder
+                );
+            }
+            self.creator = creator;
+            self.page = page;
+            self.bump = bump;
+        }
+        self.subject_ids.push(subject_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Marker left behind when a subject is closed
+/// Blocks re-creating the subject at the same PDA, which would let old
+/// dispute/vote/defender records alias the new subject's accounts
+#[account]
+#[derive(Default)]
+pub struct SubjectTombstone {
+    /// Closed subject's identifier
+    pub subject_id: Pubkey,
+
+    /// Close timestamp
+    pub closed_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl SubjectTombstone {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // subject_id
+        8 +     // closed_at
+        1;      // bump
+}
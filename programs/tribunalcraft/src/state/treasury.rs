@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+/// Program-owned treasury for one protocol config, with running totals
+/// Opt-in: initialize_treasury points ProtocolConfig::treasury at this PDA. Fee and sweep
+/// transfers credit total_collected whenever the configured treasury is this account;
+/// a plain wallet treasury keeps receiving lamports without accounting
+#[account]
+#[derive(Default)]
+pub struct Treasury {
+    /// Config this treasury belongs to (its authority withdraws)
+    pub config: Pubkey,
+
+    /// Lamports received from fees, escrow sweeps and closed epochs
+    pub total_collected: u64,
+
+    /// Lamports withdrawn by the config authority
+    pub total_withdrawn: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Last update timestamp
+    pub updated_at: i64,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // config
+        8 +     // total_collected
+        8 +     // total_withdrawn
+        1 +     // bump
+        8;      // updated_at
+
+    /// Record lamports already moved into the configured treasury account
+    /// No-op for wallet treasuries (anything not owned by this program)
+    pub fn record_income(info: &AccountInfo, amount: u64) -> Result<()> {
+        if amount == 0 || info.owner != &crate::ID {
+            return Ok(());
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let mut treasury = Treasury::try_deserialize(&mut &data[..])?;
+        treasury.total_collected = treasury.total_collected.saturating_add(amount);
+        treasury.updated_at = Clock::get()?.unix_timestamp;
+        treasury.try_serialize(&mut &mut data[..])
+    }
+
+    /// Record lamports already moved back out of the treasury for a voided resolution
+    /// No-op for wallet treasuries (anything not owned by this program)
+    pub fn reverse_income(info: &AccountInfo, amount: u64) -> Result<()> {
+        if amount == 0 || info.owner != &crate::ID {
+            return Ok(());
+        }
+        let mut data = info.try_borrow_mut_data()?;
+        let mut treasury = Treasury::try_deserialize(&mut &data[..])?;
+        treasury.total_collected = treasury.total_collected.saturating_sub(amount);
+        treasury.updated_at = Clock::get()?.unix_timestamp;
+        treasury.try_serialize(&mut &mut data[..])
+    }
+}
@@ -6,6 +6,7 @@ pub enum VoteChoice {
     #[default]
     ForChallenger,  // Vote for the challenger (dispute is valid, subject should be invalidated)
     ForDefender,    // Vote for the defender (dispute is invalid, subject stays active)
+    Abstain,        // Reviewed but undecided: reduced stake, flat fee, no tally or reputation effect
 }
 
 /// Vote choice for appeals (separate enum for clearer semantics)
@@ -14,6 +15,7 @@ pub enum AppealVoteChoice {
     #[default]
     ForRestoration,    // Vote to restore subject to Active status
     AgainstRestoration, // Vote to keep subject Invalidated
+    Abstain,            // Reviewed but undecided (see VoteChoice::Abstain)
 }
 
 /// Juror's vote on a dispute
@@ -64,6 +66,34 @@ pub struct VoteRecord {
 
     /// IPFS CID for vote rationale (optional)
     pub rationale_cid: String,
+
+    /// Account layout version (see ACCOUNT_VERSION, upgraded via migrate_account)
+    pub version: u8,
+
+    /// keccak256 of the rationale CID committed at vote time (zero = no commitment)
+    pub rationale_hash: [u8; 32],
+
+    /// Whether the committed rationale CID has been revealed
+    pub rationale_revealed: bool,
+
+    /// Capped weight tally round this vote was counted in (Dispute::tally_round + 1, 0 = never)
+    pub tallied_round: u8,
+
+    /// Juror reputation when the vote was cast (None = pre-v24 record)
+    pub reputation_snapshot: Option<u16>,
+
+    /// Graded disputes only: slash voted for, in GRADE_STEP_BPS steps (0 = none, 4 = full)
+    pub grade: u8,
+
+    /// Reputation change applied for this vote, kept so a later void can reverse it
+    pub reputation_delta: i16,
+
+    /// Whether this vote was counted in the juror's correct_votes
+    pub counted_correct: bool,
+
+    /// Reward estimate at the last vote/add_to_vote: fee-pot + incentive share at the totals then
+    /// Excludes fee rebates; later votes dilute it, later bonds/stakes/boosts grow it
+    pub est_reward_floor: u64,
 }
 
 impl VoteRecord {
@@ -84,7 +114,62 @@ impl VoteRecord {
         1 +     // stake_unlocked
         1 +     // bump
         8 +     // voted_at
-        4 + Self::MAX_CID_LEN;  // rationale_cid (4 bytes length + string)
+        4 + Self::MAX_CID_LEN + // rationale_cid (4 bytes length + string)
+        1 +     // version
+        32 +    // rationale_hash
+        1 +     // rationale_revealed
+        1 +     // tallied_round
+        (1 + 2) + // reputation_snapshot
+        1 +     // grade
+        2 +     // reputation_delta
+        1 +     // counted_correct
+        8;      // est_reward_floor
+
+    /// Reputation the vote's gain/loss is judged at (snapshot, else the juror's current reputation)
+    pub fn reputation_basis(&self, current: u16) -> u16 {
+        self.reputation_snapshot.unwrap_or(current)
+    }
+
+    /// Whether a committed rationale still has to be revealed
+    pub fn is_rationale_pending(&self) -> bool {
+        self.rationale_hash != [0u8; 32] && !self.rationale_revealed
+    }
+
+    /// Whether the juror abstained (regular or appeal)
+    pub fn is_abstain(&self) -> bool {
+        if self.is_appeal_vote {
+            self.appeal_choice == AppealVoteChoice::Abstain
+        } else {
+            self.choice == VoteChoice::Abstain
+        }
+    }
+
+    /// Flip the vote to the opposite side (regular or appeal)
+    /// Abstentions have no opposite side and are left as they are
+    pub fn flip_choice(&mut self) {
+        if self.is_appeal_vote {
+            self.appeal_choice = match self.appeal_choice {
+                AppealVoteChoice::ForRestoration => AppealVoteChoice::AgainstRestoration,
+                AppealVoteChoice::AgainstRestoration => AppealVoteChoice::ForRestoration,
+                AppealVoteChoice::Abstain => AppealVoteChoice::Abstain,
+            };
+        } else {
+            self.choice = match self.choice {
+                VoteChoice::ForChallenger => VoteChoice::ForDefender,
+                VoteChoice::ForDefender => VoteChoice::ForChallenger,
+                VoteChoice::Abstain => VoteChoice::Abstain,
+            };
+        }
+    }
+
+    /// Whether this vote counts toward votes_favor_weight (vs votes_against_weight)
+    pub fn is_favor(&self) -> bool {
+        if self.is_appeal_vote {
+            self.appeal_choice == AppealVoteChoice::ForRestoration
+        } else {
+            self.choice == VoteChoice::ForChallenger
+        }
+    }
 
     /// Check if stake can be unlocked
     pub fn can_unlock(&self, current_time: i64) -> bool {
@@ -94,6 +179,7 @@ impl VoteRecord {
     /// Check if vote was correct based on outcome
     /// For regular disputes: ForChallenger wins if ChallengerWins, ForDefender wins if DefenderWins
     /// For appeals: ForRestoration wins if ChallengerWins (subject restored), AgainstRestoration wins if DefenderWins
+    /// Abstentions are never right or wrong
     pub fn is_correct(&self, outcome: crate::state::dispute::ResolutionOutcome) -> Option<bool> {
         use crate::state::dispute::ResolutionOutcome;
 
@@ -104,6 +190,7 @@ impl VoteRecord {
                 (AppealVoteChoice::AgainstRestoration, ResolutionOutcome::DefenderWins) => Some(true),
                 (AppealVoteChoice::ForRestoration, ResolutionOutcome::DefenderWins) => Some(false),
                 (AppealVoteChoice::AgainstRestoration, ResolutionOutcome::ChallengerWins) => Some(false),
+                (AppealVoteChoice::Abstain, _) => None,
                 (_, ResolutionOutcome::NoParticipation) => None,
                 (_, ResolutionOutcome::None) => None,
             }
@@ -114,6 +201,7 @@ impl VoteRecord {
                 (VoteChoice::ForDefender, ResolutionOutcome::DefenderWins) => Some(true),
                 (VoteChoice::ForChallenger, ResolutionOutcome::DefenderWins) => Some(false),
                 (VoteChoice::ForDefender, ResolutionOutcome::ChallengerWins) => Some(false),
+                (VoteChoice::Abstain, _) => None,
                 (_, ResolutionOutcome::NoParticipation) => None,
                 (_, ResolutionOutcome::None) => None,
             }
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_BPS;
+use crate::errors::TribunalCraftError;
+
+/// Running allocation statistics for one side of a vote
+/// Everything here can be reversed, so changed and topped-up votes stay exact
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SideStats {
+    /// Jurors currently voting on this side
+    pub jurors: u16,
+
+    /// Sum of voting power (mirrors the dispute tally)
+    pub total_power: u64,
+
+    /// Sum of squared voting power (concentration index numerator)
+    pub power_squares: u128,
+
+    /// Juror count per voting power bucket; bucket i covers [4^i, 4^(i+1))
+    pub buckets: [u16; VoteStats::BUCKETS],
+}
+
+impl SideStats {
+    pub const LEN: usize = 2 + // jurors
+        8 +     // total_power
+        16 +    // power_squares
+        2 * VoteStats::BUCKETS; // buckets
+
+    fn bucket(power: u64) -> usize {
+        (power.checked_ilog2().unwrap_or(0) / 2) as usize
+    }
+
+    fn add(&mut self, power: u64) -> Result<()> {
+        self.jurors = self.jurors.checked_add(1).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.total_power = self.total_power.checked_add(power).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.power_squares = self.power_squares.checked_add(power as u128 * power as u128)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        let bucket = &mut self.buckets[Self::bucket(power)];
+        *bucket = bucket.checked_add(1).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    fn remove(&mut self, power: u64) -> Result<()> {
+        self.jurors = self.jurors.checked_sub(1).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.total_power = self.total_power.checked_sub(power).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        self.power_squares = self.power_squares.checked_sub(power as u128 * power as u128)
+            .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        let bucket = &mut self.buckets[Self::bucket(power)];
+        *bucket = bucket.checked_sub(1).ok_or(TribunalCraftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Lower bound of the bucket holding the median vote (0 = no votes)
+    pub fn median_power_floor(&self) -> u64 {
+        let mut seen: u32 = 0;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += *count as u32;
+            if seen > 0 && seen * 2 >= self.jurors as u32 {
+                return 1u64 << (2 * i);
+            }
+        }
+        0
+    }
+
+    /// Herfindahl concentration of voting power in bps
+    /// MAX_BPS = a single juror holds the side, MAX_BPS / jurors = perfectly even
+    pub fn concentration_bps(&self) -> u16 {
+        if self.total_power == 0 {
+            return 0;
+        }
+        let total_squared = self.total_power as u128 * self.total_power as u128;
+        (self.power_squares * MAX_BPS as u128 / total_squared) as u16
+    }
+}
+
+/// Optional per-dispute vote statistics (one PDA per dispute)
+/// Created before the first vote; exact only while Dispute::has_vote_stats is still set
+#[account]
+#[derive(Default)]
+pub struct VoteStats {
+    /// The dispute these statistics describe
+    pub dispute: Pubkey,
+
+    /// ForChallenger / ForRestoration votes
+    pub favor: SideStats,
+
+    /// ForDefender / AgainstRestoration votes
+    pub against: SideStats,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Creation timestamp
+    pub created_at: i64,
+}
+
+impl VoteStats {
+    /// Power-of-four buckets covering the full u64 range
+    pub const BUCKETS: usize = 32;
+
+    pub const LEN: usize = 8 +  // discriminator
+        32 +    // dispute
+        SideStats::LEN + // favor
+        SideStats::LEN + // against
+        1 +     // bump
+        8;      // created_at
+
+    fn side(&mut self, favor: bool) -> &mut SideStats {
+        if favor { &mut self.favor } else { &mut self.against }
+    }
+
+    /// Record a new vote
+    pub fn add_vote(&mut self, favor: bool, power: u64) -> Result<()> {
+        self.side(favor).add(power)
+    }
+
+    /// Replace a vote's power, moving it to another side if the choice changed
+    pub fn replace_vote(&mut self, was_favor: bool, old_power: u64, favor: bool, new_power: u64) -> Result<()> {
+        self.side(was_favor).remove(old_power)?;
+        self.side(favor).add(new_power)
+    }
+}
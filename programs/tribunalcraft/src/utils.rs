@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::errors::TribunalCraftError;
+use crate::events::{LamportsMovedEvent, LamportsMoveReason};
+
+/// Move lamports directly between accounts (source must be owned by this program)
+/// Uses checked math so an accounting bug fails the transaction instead of wrapping
+/// Every non-zero move emits LamportsMovedEvent for reconciliation
+pub fn transfer_lamports(
+    from: &AccountInfo,
+    to: &AccountInfo,
+    amount: u64,
+    reason: LamportsMoveReason,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let from_balance = from.lamports()
+        .checked_sub(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    let to_balance = to.lamports()
+        .checked_add(amount)
+        .ok_or(TribunalCraftError::ArithmeticOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+
+    emit!(LamportsMovedEvent {
+        from: from.key(),
+        to: to.key(),
+        amount,
+        reason,
+    });
+    Ok(())
+}
+
+/// Create a program-owned PDA passed through remaining_accounts (what `init` does for typed accounts)
+/// A PDA that was pre-funded is topped up to rent exemption, then allocated and assigned
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount { from: payer.clone(), to: account.clone() },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    require!(account.owner == &system_program::ID && account.data_is_empty(), TribunalCraftError::InvalidConfig);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(system_program.clone(), Transfer { from: payer.clone(), to: account.clone() }),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: account.clone() }, signer),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program.clone(), Assign { account_to_assign: account.clone() }, signer),
+        &crate::ID,
+    )
+}
+
+/// Check a content id: length always, format (IPFS CIDv0/v1 or Arweave tx id) when `check_format`
+/// Empty CIDs are allowed - every CID field is optional
+pub fn validate_cid(cid: &str, max_len: usize, check_format: bool) -> Result<()> {
+    require!(cid.len() <= max_len, TribunalCraftError::InvalidCid);
+    if check_format && !cid.is_empty() {
+        require!(is_well_formed_cid(cid), TribunalCraftError::InvalidCid);
+    }
+    Ok(())
+}
+
+fn is_well_formed_cid(cid: &str) -> bool {
+    let base58 = |c: u8| c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l');
+    let body = &cid.as_bytes()[1..];
+    match cid.as_bytes()[0] {
+        // CIDv0: base58btc sha2-256 multihash ("Qm" + 44 chars)
+        b'Q' if cid.len() == 46 => cid.starts_with("Qm") && body.iter().all(|c| base58(*c)),
+        // CIDv1, base32 multibase ("bafy..." is 59 chars for sha2-256)
+        b'b' if cid.len() >= 59 => body.iter().all(|c| matches!(c, b'a'..=b'z' | b'2'..=b'7')),
+        // CIDv1, base58btc multibase
+        b'z' if cid.len() >= 48 => body.iter().all(|c| base58(*c)),
+        // Arweave transaction id: 32 bytes, base64url without padding
+        _ => cid.len() == 43 && cid.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_'),
+    }
+}
@@ -0,0 +1,152 @@
+//! Minimal SPL stake pool CPI for idle defender pool balances
+//!
+//! Instructions are built by hand (DepositSol / WithdrawSol) to avoid pulling the
+//! stake pool and token crates into the program. Only the accounts these two
+//! instructions need are passed through; the stake pool program validates them.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar;
+use crate::errors::TribunalCraftError;
+
+/// SPL stake pool program
+pub const STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+/// SPL token program (stake pool tokens)
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Native stake program (WithdrawSol merges/splits reserve stake)
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+
+/// StakePoolInstruction variant tags
+const DEPOSIT_SOL: u8 = 14;
+const WITHDRAW_SOL: u8 = 16;
+
+/// Accounts shared by DepositSol and WithdrawSol
+pub struct StakePoolAccounts<'a, 'info> {
+    pub stake_pool_program: &'a AccountInfo<'info>,
+    pub stake_pool: &'a AccountInfo<'info>,
+    pub withdraw_authority: &'a AccountInfo<'info>,
+    pub reserve_stake: &'a AccountInfo<'info>,
+    pub manager_fee_account: &'a AccountInfo<'info>,
+    pub pool_mint: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+fn instruction_data(tag: u8, amount: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(9);
+    data.push(tag);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Deposit `lamports` from the vault into the stake pool, minting pool tokens to `pool_tokens`
+/// The vault is a system-owned PDA and signs with `vault_seeds`
+pub fn deposit_sol<'info>(
+    accounts: &StakePoolAccounts<'_, 'info>,
+    vault: &AccountInfo<'info>,
+    pool_tokens: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    lamports: u64,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.withdraw_authority.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(vault.key(), true),
+            AccountMeta::new(pool_tokens.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            // Referral fee comes back to the same token account
+            AccountMeta::new(pool_tokens.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data: instruction_data(DEPOSIT_SOL, lamports),
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.stake_pool.clone(),
+            accounts.withdraw_authority.clone(),
+            accounts.reserve_stake.clone(),
+            vault.clone(),
+            pool_tokens.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.pool_mint.clone(),
+            system_program.clone(),
+            accounts.token_program.clone(),
+            accounts.stake_pool_program.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+    Ok(())
+}
+
+/// Burn `tokens` pool tokens held by the vault and withdraw SOL from the reserve to the vault
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_sol<'info>(
+    accounts: &StakePoolAccounts<'_, 'info>,
+    vault: &AccountInfo<'info>,
+    pool_tokens: &AccountInfo<'info>,
+    clock: &AccountInfo<'info>,
+    stake_history: &AccountInfo<'info>,
+    stake_program: &AccountInfo<'info>,
+    tokens: u64,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: STAKE_POOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.stake_pool.key(), false),
+            AccountMeta::new_readonly(accounts.withdraw_authority.key(), false),
+            AccountMeta::new_readonly(vault.key(), true),
+            AccountMeta::new(pool_tokens.key(), false),
+            AccountMeta::new(accounts.reserve_stake.key(), false),
+            AccountMeta::new(vault.key(), false),
+            AccountMeta::new(accounts.manager_fee_account.key(), false),
+            AccountMeta::new(accounts.pool_mint.key(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::stake_history::ID, false),
+            AccountMeta::new_readonly(stake_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data: instruction_data(WITHDRAW_SOL, tokens),
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.stake_pool.clone(),
+            accounts.withdraw_authority.clone(),
+            vault.clone(),
+            pool_tokens.clone(),
+            accounts.reserve_stake.clone(),
+            accounts.manager_fee_account.clone(),
+            accounts.pool_mint.clone(),
+            clock.clone(),
+            stake_history.clone(),
+            stake_program.clone(),
+            accounts.token_program.clone(),
+            accounts.stake_pool_program.clone(),
+        ],
+        &[vault_seeds],
+    )?;
+    Ok(())
+}
+
+/// Read the balance of an SPL token account, checking its mint and authority
+/// Layout: mint (0..32), owner (32..64), amount (64..72)
+pub fn token_balance(info: &AccountInfo, mint: &Pubkey, authority: &Pubkey) -> Result<u64> {
+    require!(info.owner == &TOKEN_PROGRAM_ID, TribunalCraftError::InvalidConfig);
+    let data = info.try_borrow_data()?;
+    require!(data.len() >= 72, TribunalCraftError::InvalidConfig);
+    require!(&data[0..32] == mint.as_ref(), TribunalCraftError::InvalidConfig);
+    require!(&data[32..64] == authority.as_ref(), TribunalCraftError::InvalidConfig);
+    let mut amount = [0u8; 8];
+    amount.copy_from_slice(&data[64..72]);
+    Ok(u64::from_le_bytes(amount))
+}